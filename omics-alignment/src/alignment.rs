@@ -18,9 +18,11 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
-use omics_coordinate::interbase::Coordinate;
-use omics_coordinate::interval::interbase::Interval;
+use omics_coordinate::Coordinate;
+use omics_coordinate::Interval;
 use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
+use omics_coordinate::system::Interbase;
 use thiserror::Error;
 
 use crate::cigar::Axis;
@@ -30,7 +32,7 @@ use crate::step::Step;
 
 /// An error constructing an [`Alignment`].
 #[derive(Clone, Debug, Eq, Error, PartialEq)]
-pub enum Error {
+pub enum Error<W: Width = Number> {
     /// An operation moved a coordinate out of representable bounds.
     #[error(
         "operation {operation_index} ({operation}) moved the {axis} coordinate out of bounds at \
@@ -46,7 +48,7 @@ pub enum Error {
         /// The coordinate at which the movement failed. This is the
         /// coordinate as it stood before the failed move, since a failed
         /// move never mutates its input.
-        coordinate: Coordinate,
+        coordinate: Coordinate<Interbase<W>>,
     },
 }
 
@@ -58,23 +60,26 @@ pub enum Error {
 /// query into alignment with the reference. Construction eagerly validates
 /// that every operation's movement stays within representable coordinate
 /// bounds, so [`Alignment::steps`] is infallible.
+///
+/// Alignments are generic over the [`Width`] of their coordinates and default
+/// to the default position width of `omics-coordinate`.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Alignment {
+pub struct Alignment<W: Width = Number> {
     /// The starting reference coordinate.
-    reference_start: Coordinate,
+    reference_start: Coordinate<Interbase<W>>,
     /// The starting query coordinate.
-    query_start: Coordinate,
+    query_start: Coordinate<Interbase<W>>,
     /// The validated CIGAR describing the alignment operations.
     cigar: Cigar,
     /// The ending reference coordinate, reached after consuming every
     /// reference-consuming operation in `cigar`.
-    reference_end: Coordinate,
+    reference_end: Coordinate<Interbase<W>>,
     /// The ending query coordinate, reached after consuming every
     /// query-consuming operation in `cigar`.
-    query_end: Coordinate,
+    query_end: Coordinate<Interbase<W>>,
 }
 
-impl Alignment {
+impl<W: Width> Alignment<W> {
     /// Constructs a new [`Alignment`], eagerly validating that every
     /// operation in `cigar` can be applied to `reference_start` and
     /// `query_start` without moving a coordinate out of bounds.
@@ -90,16 +95,16 @@ impl Alignment {
     /// Returns [`Error::OutOfBounds`] if any operation would move the
     /// reference or query coordinate out of representable bounds.
     pub fn try_new(
-        reference_start: Coordinate,
-        query_start: Coordinate,
+        reference_start: Coordinate<Interbase<W>>,
+        query_start: Coordinate<Interbase<W>>,
         cigar: Cigar,
-    ) -> Result<Self, Error> {
+    ) -> Result<Self, Error<W>> {
         let mut reference = reference_start.clone();
         let mut query = query_start.clone();
 
         for (operation_index, operation) in cigar.iter().enumerate() {
             let kind = operation.kind();
-            let length = operation.length();
+            let length = W::from(operation.length());
 
             if kind.consumes_reference() && !reference.move_forward(length) {
                 return Err(Error::OutOfBounds {
@@ -135,24 +140,24 @@ impl Alignment {
     }
 
     /// Returns the starting reference coordinate.
-    pub fn reference_start(&self) -> &Coordinate {
+    pub fn reference_start(&self) -> &Coordinate<Interbase<W>> {
         &self.reference_start
     }
 
     /// Returns the starting query coordinate.
-    pub fn query_start(&self) -> &Coordinate {
+    pub fn query_start(&self) -> &Coordinate<Interbase<W>> {
         &self.query_start
     }
 
     /// Returns the ending reference coordinate, reached after consuming
     /// every reference-consuming operation.
-    pub fn reference_end(&self) -> &Coordinate {
+    pub fn reference_end(&self) -> &Coordinate<Interbase<W>> {
         &self.reference_end
     }
 
     /// Returns the ending query coordinate, reached after consuming every
     /// query-consuming operation.
-    pub fn query_end(&self) -> &Coordinate {
+    pub fn query_end(&self) -> &Coordinate<Interbase<W>> {
         &self.query_end
     }
 
@@ -175,13 +180,13 @@ impl Alignment {
     /// yield a [`Step`] with no reference or query interval. No item in this
     /// iterator is fallible; [`Alignment::try_new`] has already proven that
     /// every consumed move stays within bounds.
-    pub fn steps(&self) -> impl Iterator<Item = Step> + '_ {
+    pub fn steps(&self) -> impl Iterator<Item = Step<W>> + '_ {
         let mut reference = self.reference_start.clone();
         let mut query = self.query_start.clone();
 
         self.cigar.iter().copied().map(move |operation| {
             let kind = operation.kind();
-            let length = operation.length();
+            let length = W::from(operation.length());
 
             let reference_interval = kind
                 .consumes_reference()
@@ -214,7 +219,7 @@ impl Alignment {
     /// every consumed move stays within representable bounds, so the move
     /// performed here cannot overflow or underflow and the interval
     /// constructed from it cannot be rejected.
-    fn advance(pointer: &mut Coordinate, length: Number) -> Interval {
+    fn advance(pointer: &mut Coordinate<Interbase<W>>, length: W) -> Interval<Interbase<W>> {
         let start = pointer.clone();
         // The move below cannot fail; see the proof on this function.
         let moved = pointer.move_forward(length);
@@ -227,6 +232,45 @@ impl Alignment {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<W: Width> serde::Serialize for Alignment<W> {
+    /// Serializes an alignment as its starting coordinates and CIGAR. The
+    /// ending coordinates are derived from these upon deserialization.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct as _;
+
+        let mut state = serializer.serialize_struct("Alignment", 3)?;
+        state.serialize_field("reference_start", &self.reference_start)?;
+        state.serialize_field("query_start", &self.query_start)?;
+        state.serialize_field("cigar", &self.cigar)?;
+        state.end()
+    }
+}
+
+/// The serialized fields of an [`Alignment`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound = "", deny_unknown_fields, rename = "Alignment")]
+struct Fields<W: Width> {
+    /// The starting reference coordinate.
+    reference_start: Coordinate<Interbase<W>>,
+    /// The starting query coordinate.
+    query_start: Coordinate<Interbase<W>>,
+    /// The CIGAR describing the alignment operations.
+    cigar: Cigar,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, W: Width> serde::Deserialize<'de> for Alignment<W> {
+    /// Deserializes an alignment, validating it as [`Alignment::try_new`]
+    /// does.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fields = Fields::<W>::deserialize(deserializer)?;
+        Self::try_new(fields.reference_start, fields.query_start, fields.cigar)
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use omics_coordinate::interbase::Coordinate;
@@ -331,6 +375,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn wide_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_start =
+            "ref:+:5000000000".parse::<omics_coordinate::Coordinate<Interbase<u64>>>()?;
+        let query_start = "query:+:0".parse::<omics_coordinate::Coordinate<Interbase<u64>>>()?;
+        let cigar = "3M1D2M".parse::<Cigar>()?;
+
+        let alignment = Alignment::try_new(reference_start, query_start, cigar)?;
+        assert_eq!(alignment.reference_end().to_string(), "ref:+:5000000006");
+        assert_eq!(alignment.query_end().to_string(), "query:+:5");
+
+        let steps = alignment.steps().collect::<Vec<_>>();
+        assert_eq!(
+            steps[1].reference().unwrap().to_string(),
+            "ref:+:5000000003-5000000004"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn negative_strand_query_underflow_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
         let reference_start = "ref:+:0".parse::<Coordinate>()?;
@@ -403,4 +467,26 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_start = "ref:+:0".parse::<Coordinate>()?;
+        let query_start = "query:-:5".parse::<Coordinate>()?;
+        let cigar = "3M1I1M".parse::<Cigar>()?;
+        let alignment = Alignment::try_new(reference_start, query_start, cigar)?;
+
+        let json = serde_json::to_string(&alignment)?;
+        assert_eq!(
+            json,
+            r#"{"reference_start":"ref:+:0","query_start":"query:-:5","cigar":"3M1I1M"}"#
+        );
+        assert_eq!(serde_json::from_str::<Alignment>(&json)?, alignment);
+
+        // Alignments are validated just as they are when constructed.
+        let json = r#"{"reference_start":"ref:+:0","query_start":"query:-:3","cigar":"3M1I1M"}"#;
+        assert!(serde_json::from_str::<Alignment>(json).is_err());
+
+        Ok(())
+    }
 }
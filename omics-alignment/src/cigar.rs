@@ -441,6 +441,25 @@ impl<'a> IntoIterator for &'a Cigar {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Cigar {
+    /// Serializes a CIGAR in its string form.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Cigar {
+    /// Deserializes a CIGAR from its string form, validating it as
+    /// [`Cigar::try_new`] does.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use omics_coordinate::position::Number;
@@ -610,4 +629,16 @@ mod tests {
         assert_eq!((&c).into_iter().count(), 3);
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let cigar = "1S3M1I1M".parse::<Cigar>().unwrap();
+        let json = serde_json::to_string(&cigar).unwrap();
+        assert_eq!(json, r#""1S3M1I1M""#);
+        assert_eq!(serde_json::from_str::<Cigar>(&json).unwrap(), cigar);
+
+        assert!(serde_json::from_str::<Cigar>(r#""*""#).is_err());
+        assert!(serde_json::from_str::<Cigar>(r#""3M1S1M""#).is_err());
+    }
 }
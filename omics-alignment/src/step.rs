@@ -1,6 +1,9 @@
 //! A single lossless step of an [`Alignment`](crate::Alignment) traversal.
 
-use omics_coordinate::interval::interbase::Interval;
+use omics_coordinate::Interval;
+use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
+use omics_coordinate::system::Interbase;
 
 use crate::cigar::Operation;
 
@@ -11,22 +14,22 @@ use crate::cigar::Operation;
 /// which consume neither the reference nor the query axis. When an axis is
 /// not consumed by the operation, the corresponding interval is `None`.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Step {
+pub struct Step<W: Width = Number> {
     /// The CIGAR operation this step corresponds to.
     operation: Operation,
     /// The reference interbase interval consumed by this step, if any.
-    reference: Option<Interval>,
+    reference: Option<Interval<Interbase<W>>>,
     /// The query interbase interval consumed by this step, if any.
-    query: Option<Interval>,
+    query: Option<Interval<Interbase<W>>>,
 }
 
-impl Step {
+impl<W: Width> Step<W> {
     /// Constructs a new [`Step`] from an operation and its optional reference
     /// and query intervals.
     pub(crate) fn new(
         operation: Operation,
-        reference: Option<Interval>,
-        query: Option<Interval>,
+        reference: Option<Interval<Interbase<W>>>,
+        query: Option<Interval<Interbase<W>>>,
     ) -> Self {
         Self {
             operation,
@@ -44,7 +47,7 @@ impl Step {
     ///
     /// This is `None` when the operation does not consume the reference
     /// axis, such as an insertion, a hard clip, or padding.
-    pub fn reference(&self) -> Option<&Interval> {
+    pub fn reference(&self) -> Option<&Interval<Interbase<W>>> {
         self.reference.as_ref()
     }
 
@@ -52,7 +55,7 @@ impl Step {
     ///
     /// This is `None` when the operation does not consume the query axis,
     /// such as a deletion, a reference skip, or padding.
-    pub fn query(&self) -> Option<&Interval> {
+    pub fn query(&self) -> Option<&Interval<Interbase<W>>> {
         self.query.as_ref()
     }
 
@@ -24,9 +24,44 @@ pub mod interbase {
     }
 }
 
+pub mod pool {
+    use std::hint::black_box;
+
+    use criterion::Criterion;
+    use omics_coordinate::Contig;
+    use omics_coordinate::contig::ContigPool;
+
+    pub fn benches(c: &mut Criterion) {
+        let mut pool = ContigPool::new();
+
+        // SAFETY: the contig names are not empty, so these will always unwrap.
+        let a = pool.intern("seq0").unwrap();
+        let b = pool.intern("seq1").unwrap();
+
+        c.bench_function("contig_pool::contig", |bencher| {
+            bencher.iter(|| black_box(pool.contig(black_box("seq0")).unwrap()))
+        });
+
+        c.bench_function("contig_pool::symbol_eq", |bencher| {
+            bencher.iter(|| black_box(black_box(a) == black_box(b)))
+        });
+
+        let (x, y) = (Contig::new_unchecked("seq0"), Contig::new_unchecked("seq0"));
+        c.bench_function("contig::eq", |bencher| {
+            bencher.iter(|| black_box(black_box(&x) == black_box(&y)))
+        });
+
+        // SAFETY: the contig names are not empty, so these will always unwrap.
+        let (x, y) = (pool.contig("seq0").unwrap(), pool.contig("seq0").unwrap());
+        c.bench_function("contig_pool::contig_eq", |bencher| {
+            bencher.iter(|| black_box(black_box(&x) == black_box(&y)))
+        });
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////
 // Registration
 ////////////////////////////////////////////////////////////////////////////////////////
 
-criterion_group!(benches, interbase::benches);
+criterion_group!(benches, interbase::benches, pool::benches);
 criterion_main!(benches);
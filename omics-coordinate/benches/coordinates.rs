@@ -23,9 +23,94 @@ pub mod interbase {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////
+// Packed coordinates
+////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod packed {
+    use std::hint::black_box;
+
+    use criterion::BatchSize;
+    use criterion::Criterion;
+    use omics_coordinate::Assembly;
+    use omics_coordinate::Coordinate;
+    use omics_coordinate::packed::PackedCoordinate;
+    use omics_coordinate::system::Interbase;
+
+    /// The number of contigs within the benchmark assembly.
+    const CONTIGS: usize = 25;
+
+    /// The number of coordinates used within the benchmarks.
+    const COORDINATES: usize = 100_000;
+
+    /// Creates the benchmark assembly.
+    fn assembly() -> Assembly {
+        let index = (1..=CONTIGS)
+            .map(|i| format!("chr{i}\t1000000\n"))
+            .collect::<String>();
+
+        Assembly::read_fai(index.as_bytes()).unwrap()
+    }
+
+    /// Creates a deterministic, unsorted set of coordinates.
+    fn coordinates() -> Vec<Coordinate<Interbase>> {
+        (0..COORDINATES)
+            .map(|i| {
+                let contig = format!("chr{}", (i * 7919) % CONTIGS + 1);
+                let strand = if i % 2 == 0 { "+" } else { "-" };
+                let position = ((i * 104_729) % 1_000_000) as _;
+                Coordinate::try_new(contig, strand, position).unwrap()
+            })
+            .collect()
+    }
+
+    pub fn benches(c: &mut Criterion) {
+        let assembly = assembly();
+        let coordinates = coordinates();
+        let packed = coordinates
+            .iter()
+            .map(|c| PackedCoordinate::pack(c, &assembly).unwrap())
+            .collect::<Vec<_>>();
+
+        c.bench_function("coordinates::packed::pack", |b| {
+            b.iter(|| {
+                for coordinate in &coordinates {
+                    black_box(PackedCoordinate::pack(coordinate, &assembly).unwrap());
+                }
+            })
+        });
+
+        c.bench_function("coordinates::packed::unpack", |b| {
+            b.iter(|| {
+                for packed in &packed {
+                    black_box(packed.unpack(&assembly).unwrap());
+                }
+            })
+        });
+
+        c.bench_function("coordinates::packed::sort", |b| {
+            b.iter_batched(
+                || packed.clone(),
+                |mut packed| packed.sort_unstable(),
+                BatchSize::LargeInput,
+            )
+        });
+
+        c.bench_function("coordinates::packed::sort_rich", |b| {
+            b.iter_batched(
+                || coordinates.clone(),
+                |mut coordinates| {
+                    coordinates.sort_unstable_by(|a, b| assembly.compare_coordinates(a, b))
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////
 // Registration
 ////////////////////////////////////////////////////////////////////////////////////////
 
-criterion_group!(benches, interbase::benches);
+criterion_group!(benches, interbase::benches, packed::benches);
 criterion_main!(benches);
@@ -55,9 +55,140 @@ pub mod interbase {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////
+// Interval indices
+////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod index {
+    use std::hint::black_box;
+
+    use criterion::BatchSize;
+    use criterion::Criterion;
+    use omics_coordinate::Interval;
+    use omics_coordinate::IntervalIndex;
+    use omics_coordinate::system::Interbase;
+
+    /// The number of intervals placed in the index.
+    const SIZE: u32 = 100_000;
+
+    /// Creates a deterministic, sorted set of intervals with varying lengths.
+    fn intervals() -> Vec<(Interval<Interbase>, u32)> {
+        (0..SIZE)
+            .map(|i| {
+                let start = i * 100;
+                let end = start + (i * 7919) % 1_000;
+                // SAFETY: the start is always less than or equal to the end.
+                let interval = format!("seq0:+:{start}-{end}")
+                    .parse::<Interval<Interbase>>()
+                    .unwrap();
+                (interval, i)
+            })
+            .collect()
+    }
+
+    pub fn benches(c: &mut Criterion) {
+        let entries = intervals();
+
+        c.bench_function("intervals::index::try_from_sorted", |b| {
+            b.iter_batched(
+                || entries.clone(),
+                |entries| {
+                    // SAFETY: the entries are generated in sorted order.
+                    black_box(IntervalIndex::try_from_sorted(entries).unwrap())
+                },
+                BatchSize::LargeInput,
+            )
+        });
+
+        let index = entries.into_iter().collect::<IntervalIndex<Interbase, _>>();
+
+        // SAFETY: the query is a valid interbase interval.
+        let query = "seq0:+:5000000-5002000"
+            .parse::<Interval<Interbase>>()
+            .unwrap();
+
+        c.bench_function("intervals::index::find_overlapping", |b| {
+            b.iter(|| black_box(index.find_overlapping(black_box(&query))))
+        });
+
+        c.bench_function("intervals::index::find_containing", |b| {
+            b.iter(|| black_box(index.find_containing(black_box(&query))))
+        });
+
+        // SAFETY: the query is a valid interbase interval.
+        let query = "seq0:+:20000000-20000010"
+            .parse::<Interval<Interbase>>()
+            .unwrap();
+
+        c.bench_function("intervals::index::find_nearest", |b| {
+            b.iter(|| black_box(index.find_nearest(black_box(&query))))
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Interval sets
+////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod set {
+    use std::hint::black_box;
+
+    use criterion::BatchSize;
+    use criterion::Criterion;
+    use omics_coordinate::Interval;
+    use omics_coordinate::IntervalSet;
+    use omics_coordinate::system::Interbase;
+
+    /// The number of intervals placed in each set.
+    const SIZE: u32 = 100_000;
+
+    /// Creates a deterministic set of intervals with varying lengths that are
+    /// offset by `shift`.
+    fn intervals(shift: u32) -> Vec<Interval<Interbase>> {
+        (0..SIZE)
+            .map(|i| {
+                let start = i * 100 + shift;
+                let end = start + (i * 7919) % 150;
+                // SAFETY: the start is always less than or equal to the end.
+                format!("seq0:+:{start}-{end}")
+                    .parse::<Interval<Interbase>>()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    pub fn benches(c: &mut Criterion) {
+        let a = intervals(0);
+        let b = intervals(50);
+
+        c.bench_function("intervals::set::from_iter", |bencher| {
+            bencher.iter_batched(
+                || a.clone(),
+                |intervals| black_box(intervals.into_iter().collect::<IntervalSet<_>>()),
+                BatchSize::LargeInput,
+            )
+        });
+
+        let a = a.into_iter().collect::<IntervalSet<Interbase>>();
+        let b = b.into_iter().collect::<IntervalSet<Interbase>>();
+
+        c.bench_function("intervals::set::union", |bencher| {
+            bencher.iter(|| black_box(a.union(black_box(&b))))
+        });
+
+        c.bench_function("intervals::set::intersection", |bencher| {
+            bencher.iter(|| black_box(a.intersection(black_box(&b))))
+        });
+
+        c.bench_function("intervals::set::difference", |bencher| {
+            bencher.iter(|| black_box(a.difference(black_box(&b))))
+        });
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////
 // Registration
 ////////////////////////////////////////////////////////////////////////////////////////
 
-criterion_group!(benches, interbase::benches);
+criterion_group!(benches, interbase::benches, index::benches, set::benches);
 criterion_main!(benches);
@@ -0,0 +1,1027 @@
+//! Reference assemblies (also known as sequence dictionaries).
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::System;
+use crate::contig;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+
+/// The prefix of a SAM header line describing a reference sequence.
+#[cfg(feature = "std")]
+const SAM_SEQUENCE_PREFIX: &str = "@SQ";
+
+/// The SAM header tag containing a reference sequence's name.
+#[cfg(feature = "std")]
+const SAM_NAME_TAG: &str = "SN";
+
+/// The SAM header tag containing a reference sequence's length.
+#[cfg(feature = "std")]
+const SAM_LENGTH_TAG: &str = "LN";
+
+/// The SAM header tag containing a reference sequence's alternative names.
+#[cfg(feature = "std")]
+const SAM_ALIASES_TAG: &str = "AN";
+
+/// The SAM header tag containing a reference sequence's topology.
+#[cfg(feature = "std")]
+const SAM_TOPOLOGY_TAG: &str = "TP";
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing an assembly.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A required field was missing from a line.
+    #[error("line {line}: missing {field}")]
+    MissingField {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// A description of the missing field.
+        field: &'static str,
+    },
+
+    /// An invalid contig name was encountered.
+    #[error("line {line}: invalid contig name: {inner}")]
+    Contig {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The inner error.
+        inner: contig::Error,
+    },
+
+    /// An invalid contig length was encountered.
+    #[error("line {line}: invalid contig length `{value}`")]
+    Length {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+
+    /// An invalid contig topology was encountered.
+    #[error("line {line}: invalid contig topology `{value}`")]
+    Topology {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to an assembly.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// A duplicate name error.
+    ///
+    /// This error occurs when a contig name or alias is used by more than one
+    /// contig within the assembly.
+    #[error("duplicate contig name or alias: `{0}`")]
+    Duplicate(Contig),
+
+    /// An I/O error.
+    #[cfg(feature = "std")]
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An out of bounds error.
+    ///
+    /// This error occurs when a position lies beyond the end of its contig.
+    #[error("position `{position}` is out of bounds for contig `{contig}` of length `{length}`")]
+    OutOfBounds {
+        /// The contig.
+        contig: Contig,
+
+        /// The position.
+        position: u64,
+
+        /// The length of the contig.
+        length: u64,
+    },
+
+    /// A parse error.
+    #[error("parse error: {0}")]
+    Parse(#[from] ParseError),
+
+    /// An unknown contig error.
+    ///
+    /// This error occurs when a contig is not present within the assembly.
+    #[error("unknown contig: `{0}`")]
+    UnknownContig(Contig),
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Length sources
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A source of contig lengths.
+///
+/// This is implemented by [`Assembly`] as well as by simple maps from contig
+/// names to lengths, which is convenient when a full assembly is not needed.
+///
+/// Lengths are reported as [`u64`] values so that a single source can describe
+/// contigs for positions of any [`Width`].
+pub trait LengthSource {
+    /// Gets the length of a contig by name (if the contig is known).
+    fn contig_length(&self, name: &str) -> Option<u64>;
+}
+
+impl LengthSource for Assembly {
+    fn contig_length(&self, name: &str) -> Option<u64> {
+        self.length(name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: Width, H: core::hash::BuildHasher> LengthSource
+    for std::collections::HashMap<Contig, W, H>
+{
+    fn contig_length(&self, name: &str) -> Option<u64> {
+        self.get(&Contig::new_unchecked(name))
+            .copied()
+            .map(Width::to_u64)
+    }
+}
+
+impl<W: Width> LengthSource for alloc::collections::BTreeMap<Contig, W> {
+    fn contig_length(&self, name: &str) -> Option<u64> {
+        self.get(&Contig::new_unchecked(name))
+            .copied()
+            .map(Width::to_u64)
+    }
+}
+
+impl<T: LengthSource + ?Sized> LengthSource for &T {
+    fn contig_length(&self, name: &str) -> Option<u64> {
+        (**self).contig_length(name)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Entries
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The topology of a contig.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// A linear contig (e.g., a nuclear chromosome).
+    #[default]
+    Linear,
+
+    /// A circular contig (e.g., a mitochondrial genome or a plasmid).
+    ///
+    /// See [`CircularContig`](crate::circular::CircularContig) for working with
+    /// coordinates that wrap around the origin of a circular contig.
+    Circular,
+}
+
+impl core::fmt::Display for Topology {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Topology::Linear => write!(f, "linear"),
+            Topology::Circular => write!(f, "circular"),
+        }
+    }
+}
+
+/// A contig within an assembly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    /// The contig.
+    contig: Contig,
+
+    /// The length of the contig.
+    length: u64,
+
+    /// The alternative names for the contig.
+    aliases: Vec<Contig>,
+
+    /// The topology of the contig.
+    topology: Topology,
+}
+
+impl Entry {
+    /// Creates a new entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::assembly::Entry;
+    ///
+    /// let entry = Entry::new(Contig::new_unchecked("chr1"), 248_956_422);
+    /// assert_eq!(entry.contig().as_str(), "chr1");
+    /// assert_eq!(entry.length(), 248_956_422);
+    /// ```
+    pub fn new(contig: Contig, length: u64) -> Self {
+        Self {
+            contig,
+            length,
+            aliases: Default::default(),
+            topology: Default::default(),
+        }
+    }
+
+    /// Consumes `self` and returns an entry with the provided aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::assembly::Entry;
+    ///
+    /// let entry = Entry::new(Contig::new_unchecked("chr1"), 248_956_422)
+    ///     .with_aliases([Contig::new_unchecked("1")]);
+    /// assert_eq!(entry.aliases()[0].as_str(), "1");
+    /// ```
+    #[must_use = "this method returns a new entry"]
+    pub fn with_aliases(mut self, aliases: impl IntoIterator<Item = Contig>) -> Self {
+        self.aliases.extend(aliases);
+        self
+    }
+
+    /// Consumes `self` and returns an entry with the provided topology.
+    ///
+    /// Entries are [`Topology::Linear`] unless otherwise specified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::assembly::Entry;
+    /// use omics_coordinate::assembly::Topology;
+    ///
+    /// let entry = Entry::new(Contig::new_unchecked("chrM"), 16_569);
+    /// assert_eq!(entry.topology(), Topology::Linear);
+    ///
+    /// let entry = entry.with_topology(Topology::Circular);
+    /// assert_eq!(entry.topology(), Topology::Circular);
+    /// ```
+    #[must_use = "this method returns a new entry"]
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Gets the contig.
+    pub fn contig(&self) -> &Contig {
+        &self.contig
+    }
+
+    /// Gets the topology of the contig.
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    /// Gets the length of the contig.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Gets the alternative names for the contig.
+    pub fn aliases(&self) -> &[Contig] {
+        &self.aliases
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Assembly
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A reference assembly.
+///
+/// An assembly (also known as a sequence dictionary) describes the contigs
+/// that make up a genome build: each contig's name, its length, any
+/// alternative names it is known by, and the canonical order of the contigs.
+///
+/// Contigs may be looked up by their name or by any of their aliases.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::Assembly;
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::system::Base;
+///
+/// let fai = "chr1\t248956422\t112\t70\t71\nchr2\t242193529\t252513167\t70\t71\n";
+/// let assembly = Assembly::read_fai(fai.as_bytes())?;
+///
+/// assert_eq!(assembly.len(), 2);
+/// assert_eq!(assembly.get("chr2").unwrap().length(), 242_193_529);
+///
+/// let coordinate = "chr1:+:248956423".parse::<Coordinate<Base>>()?;
+/// assert!(assembly.validate_coordinate(&coordinate).is_err());
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Assembly {
+    /// The entries in canonical order.
+    entries: Vec<Entry>,
+
+    /// A lookup from each name and alias to the index of its entry.
+    names: crate::Map<Arc<str>, usize>,
+}
+
+impl Assembly {
+    /// Attempts to create a new assembly from entries in canonical order.
+    ///
+    /// If any name or alias is shared by more than one contig, an
+    /// [`Error::Duplicate`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::assembly::Entry;
+    ///
+    /// let assembly = Assembly::try_new([
+    ///     Entry::new(Contig::new_unchecked("chr1"), 1_000),
+    ///     Entry::new(Contig::new_unchecked("chr2"), 500),
+    /// ])?;
+    /// assert_eq!(assembly.len(), 2);
+    ///
+    /// let result = Assembly::try_new([
+    ///     Entry::new(Contig::new_unchecked("chr1"), 1_000),
+    ///     Entry::new(Contig::new_unchecked("chr1"), 500),
+    /// ]);
+    /// assert!(result.is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(entries: impl IntoIterator<Item = Entry>) -> Result<Self> {
+        let mut assembly = Self::default();
+
+        for entry in entries {
+            assembly.push(entry)?;
+        }
+
+        Ok(assembly)
+    }
+
+    /// Attempts to add an entry to the end of the assembly.
+    ///
+    /// If any name or alias of the entry is already used within the assembly,
+    /// an [`Error::Duplicate`] is returned and the assembly is left unchanged.
+    pub fn push(&mut self, entry: Entry) -> Result<()> {
+        let index = self.entries.len();
+
+        for (i, name) in core::iter::once(&entry.contig)
+            .chain(entry.aliases.iter())
+            .enumerate()
+        {
+            let repeated = core::iter::once(&entry.contig)
+                .chain(entry.aliases.iter())
+                .take(i)
+                .any(|other| other == name);
+
+            if repeated || self.names.contains_key(name.as_str()) {
+                return Err(Error::Duplicate(name.clone()));
+            }
+        }
+
+        for name in core::iter::once(&entry.contig).chain(entry.aliases.iter()) {
+            self.names.insert(name.shared().clone(), index);
+        }
+
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Attempts to read an assembly from a FASTA index (`.fai`).
+    ///
+    /// Only the first two columns (the contig name and its length) are used.
+    /// Contigs are ordered as they appear within the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Assembly;
+    ///
+    /// let fai = "chr1\t248956422\t112\t70\t71\n";
+    /// let assembly = Assembly::read_fai(fai.as_bytes())?;
+    /// assert_eq!(assembly.get("chr1").unwrap().length(), 248_956_422);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_fai(reader: impl BufRead) -> Result<Self> {
+        let mut assembly = Self::default();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let number = i + 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let mut fields = line.split('\t');
+
+            let name = fields.next().ok_or(ParseError::MissingField {
+                line: number,
+                field: "contig name",
+            })?;
+            let length = fields.next().ok_or(ParseError::MissingField {
+                line: number,
+                field: "contig length",
+            })?;
+
+            assembly.push(Entry::new(
+                parse_contig(name, number)?,
+                parse_length(length, number)?,
+            ))?;
+        }
+
+        Ok(assembly)
+    }
+
+    /// Attempts to read an assembly from the `@SQ` lines of a SAM header.
+    ///
+    /// This is also the format of a sequence dictionary (`.dict`) file. The
+    /// `SN` (name) and `LN` (length) tags are required, any alternative names
+    /// within the `AN` tag are registered as aliases, and the `TP` tag sets the
+    /// [`Topology`] of the contig (defaulting to linear). Other header lines
+    /// are ignored, and reading stops at the first line that is not a header
+    /// line (i.e., does not start with `@`). Contigs are ordered as they appear
+    /// within the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Assembly;
+    ///
+    /// let dict = "@HD\tVN:1.6\n@SQ\tSN:chr1\tLN:248956422\tAN:1,NC_000001.11\n";
+    /// let assembly = Assembly::read_sam_header(dict.as_bytes())?;
+    ///
+    /// assert_eq!(assembly.get("chr1").unwrap().length(), 248_956_422);
+    /// assert_eq!(assembly.get("1").unwrap().contig().as_str(), "chr1");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_sam_header(reader: impl BufRead) -> Result<Self> {
+        let mut assembly = Self::default();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let number = i + 1;
+
+            if !line.starts_with('@') {
+                break;
+            }
+
+            let mut fields = line.split('\t');
+
+            if fields.next() != Some(SAM_SEQUENCE_PREFIX) {
+                continue;
+            }
+
+            let (mut name, mut length, mut aliases) = (None, None, Vec::new());
+            let mut topology = Topology::default();
+
+            for field in fields {
+                match field.split_once(':') {
+                    Some((SAM_NAME_TAG, value)) => name = Some(parse_contig(value, number)?),
+                    Some((SAM_LENGTH_TAG, value)) => length = Some(parse_length(value, number)?),
+                    Some((SAM_ALIASES_TAG, value)) => {
+                        for alias in value.split(',') {
+                            aliases.push(parse_contig(alias, number)?);
+                        }
+                    }
+                    Some((SAM_TOPOLOGY_TAG, value)) => topology = parse_topology(value, number)?,
+                    _ => {}
+                }
+            }
+
+            let name = name.ok_or(ParseError::MissingField {
+                line: number,
+                field: "`SN` tag",
+            })?;
+            let length = length.ok_or(ParseError::MissingField {
+                line: number,
+                field: "`LN` tag",
+            })?;
+
+            assembly.push(
+                Entry::new(name, length)
+                    .with_aliases(aliases)
+                    .with_topology(topology),
+            )?;
+        }
+
+        Ok(assembly)
+    }
+
+    /// Gets the number of contigs in the assembly.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether or not the assembly contains any contigs.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets an iterator over the entries in canonical order.
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Gets the entry for a contig by its name or any of its aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    ///
+    /// let dict = "@SQ\tSN:chrM\tLN:16569\tAN:MT\n";
+    /// let assembly = Assembly::read_sam_header(dict.as_bytes())?;
+    ///
+    /// assert_eq!(assembly.get("MT").unwrap().length(), 16_569);
+    /// assert!(assembly.get("chr1").is_none());
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(&self, name: &str) -> Option<&Entry> {
+        self.index_of(name).map(|i| &self.entries[i])
+    }
+
+    /// Gets the index of a contig within the canonical order by its name or
+    /// any of its aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    ///
+    /// let fai = "chr1\t1000\nchr2\t500\n";
+    /// let assembly = Assembly::read_fai(fai.as_bytes())?;
+    ///
+    /// assert_eq!(assembly.index_of("chr2"), Some(1));
+    /// assert_eq!(assembly.index_of("chr3"), None);
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    /// Gets the length of a contig by its name or any of its aliases.
+    pub fn length(&self, name: &str) -> Option<u64> {
+        self.get(name).map(Entry::length)
+    }
+
+    /// Validates that a coordinate lies on a known contig and within that
+    /// contig's bounds.
+    ///
+    /// In-base positions are valid from `1` to the length of the contig, and
+    /// interbase positions are valid from `0` to the length of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let assembly = Assembly::read_fai("seq0\t100\n".as_bytes())?;
+    ///
+    /// let coordinate = "seq0:+:100".parse::<Coordinate<Interbase>>()?;
+    /// assert!(assembly.validate_coordinate(&coordinate).is_ok());
+    ///
+    /// let coordinate = "seq0:+:101".parse::<Coordinate<Base>>()?;
+    /// assert!(assembly.validate_coordinate(&coordinate).is_err());
+    ///
+    /// let coordinate = "seq1:+:1".parse::<Coordinate<Base>>()?;
+    /// assert!(assembly.validate_coordinate(&coordinate).is_err());
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_coordinate<S: System>(&self, coordinate: &Coordinate<S>) -> Result<()>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.validate_position(coordinate.contig(), coordinate.position())
+    }
+
+    /// Validates that an interval lies on a known contig and within that
+    /// contig's bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let assembly = Assembly::read_fai("seq0\t100\n".as_bytes())?;
+    ///
+    /// let interval = "seq0:-:100-1".parse::<Interval<Base>>()?;
+    /// assert!(assembly.validate_interval(&interval).is_ok());
+    ///
+    /// let interval = "seq0:-:101-1".parse::<Interval<Base>>()?;
+    /// assert!(assembly.validate_interval(&interval).is_err());
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_interval<S: System>(&self, interval: &Interval<S>) -> Result<()>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.validate_position(interval.contig(), interval.upper())
+    }
+
+    /// Validates that a position lies within the bounds of a contig.
+    fn validate_position<S: System>(&self, contig: &Contig, position: &Position<S>) -> Result<()>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let length = self
+            .length(contig.as_str())
+            .ok_or_else(|| Error::UnknownContig(contig.clone()))?;
+
+        if position.get().to_u64() > length {
+            return Err(Error::OutOfBounds {
+                contig: contig.clone(),
+                position: position.get().to_u64(),
+                length,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Compares two contigs by their canonical order within the assembly.
+    ///
+    /// Contigs that are not present within the assembly are ordered after all
+    /// known contigs and by name amongst themselves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use core::cmp::Ordering;
+    ///
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Contig;
+    ///
+    /// let assembly = Assembly::read_fai("chr2\t100\nchr10\t100\n".as_bytes())?;
+    ///
+    /// let chr2 = Contig::new_unchecked("chr2");
+    /// let chr10 = Contig::new_unchecked("chr10");
+    /// let unknown = Contig::new_unchecked("chr1");
+    ///
+    /// assert_eq!(assembly.compare_contigs(&chr2, &chr10), Ordering::Less);
+    /// assert_eq!(assembly.compare_contigs(&chr10, &unknown), Ordering::Less);
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn compare_contigs(&self, a: &Contig, b: &Contig) -> Ordering {
+        match (self.index_of(a.as_str()), self.index_of(b.as_str())) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => a.cmp(b),
+        }
+    }
+
+    /// Compares two coordinates using the canonical contig order of the
+    /// assembly.
+    ///
+    /// Coordinates are ordered by contig (see
+    /// [`compare_contigs()`](Self::compare_contigs)), then by position, and
+    /// then by strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let assembly = Assembly::read_fai("chr2\t100\nchr10\t100\n".as_bytes())?;
+    ///
+    /// let mut coordinates = ["chr10:+:1", "chr2:-:50", "chr2:+:10"]
+    ///     .into_iter()
+    ///     .map(|value| value.parse::<Coordinate<Base>>())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// coordinates.sort_by(|a, b| assembly.compare_coordinates(a, b));
+    ///
+    /// let coordinates = coordinates
+    ///     .iter()
+    ///     .map(|coordinate| coordinate.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(coordinates, vec!["chr2:+:10", "chr2:-:50", "chr10:+:1"]);
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn compare_coordinates<S: System>(&self, a: &Coordinate<S>, b: &Coordinate<S>) -> Ordering
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.compare_contigs(a.contig(), b.contig())
+            .then_with(|| a.position().cmp(b.position()))
+            .then_with(|| a.strand().cmp(&b.strand()))
+    }
+
+    /// Compares two intervals using the canonical contig order of the
+    /// assembly.
+    ///
+    /// Intervals are ordered by contig (see
+    /// [`compare_contigs()`](Self::compare_contigs)), then by their
+    /// numerically lower position, then by their numerically upper position,
+    /// and then by strand.
+    pub fn compare_intervals<S: System>(&self, a: &Interval<S>, b: &Interval<S>) -> Ordering
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.compare_contigs(a.contig(), b.contig())
+            .then_with(|| a.lower().cmp(b.lower()))
+            .then_with(|| a.upper().cmp(b.upper()))
+            .then_with(|| a.strand().cmp(&b.strand()))
+    }
+
+    /// Sorts the contigs of the assembly into karyotypic order.
+    ///
+    /// Karyotypic order places numbered chromosomes first (in numerical
+    /// order), followed by the `X`, `Y`, and mitochondrial (`M`/`MT`)
+    /// chromosomes, followed by all other contigs in lexicographical order.
+    /// Any `chr` prefix is ignored for the purposes of ordering. The sort is
+    /// stable.
+    ///
+    /// This is useful when an assembly is loaded from a source that orders
+    /// its contigs lexicographically (e.g., `chr1`, `chr10`, `chr11`, ...).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    ///
+    /// let fai = "chrUn_1\t10\nchrM\t10\nchr10\t10\nchrX\t10\nchr2\t10\nchr1\t10\n";
+    /// let mut assembly = Assembly::read_fai(fai.as_bytes())?;
+    /// assembly.sort_karyotypic();
+    ///
+    /// let names = assembly
+    ///     .iter()
+    ///     .map(|entry| entry.contig().as_str())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     names,
+    ///     vec!["chr1", "chr2", "chr10", "chrX", "chrM", "chrUn_1"]
+    /// );
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sort_karyotypic(&mut self) {
+        self.entries
+            .sort_by(|a, b| karyotypic_key(&a.contig).cmp(&karyotypic_key(&b.contig)));
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            for name in core::iter::once(&entry.contig).chain(entry.aliases.iter()) {
+                self.names.insert(name.shared().clone(), index);
+            }
+        }
+    }
+}
+
+/// Gets a key that sorts contig names into karyotypic order.
+fn karyotypic_key(contig: &Contig) -> (u8, u64, &str) {
+    let name = contig.as_str();
+    let stripped = name
+        .strip_prefix("chr")
+        .or_else(|| name.strip_prefix("Chr"))
+        .or_else(|| name.strip_prefix("CHR"))
+        .unwrap_or(name);
+
+    if let Ok(number) = stripped.parse::<u64>() {
+        return (0, number, name);
+    }
+
+    match stripped {
+        "X" => (1, 0, name),
+        "Y" => (2, 0, name),
+        "M" | "MT" => (3, 0, name),
+        _ => (4, 0, name),
+    }
+}
+
+/// Parses a contig name from a field on a particular line.
+#[cfg(feature = "std")]
+fn parse_contig(value: &str, line: usize) -> ParseResult<Contig> {
+    Contig::try_new(value).map_err(|inner| ParseError::Contig { line, inner })
+}
+
+/// Parses a contig length from a field on a particular line.
+#[cfg(feature = "std")]
+fn parse_length(value: &str, line: usize) -> ParseResult<u64> {
+    value.parse::<u64>().map_err(|_| ParseError::Length {
+        line,
+        value: value.to_string(),
+    })
+}
+
+/// Parses a contig topology from a field on a particular line.
+#[cfg(feature = "std")]
+fn parse_topology(value: &str, line: usize) -> ParseResult<Topology> {
+    match value {
+        "linear" => Ok(Topology::Linear),
+        "circular" => Ok(Topology::Circular),
+        _ => Err(ParseError::Topology {
+            line,
+            value: value.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fai() {
+        let fai = "chr1\t248956422\t112\t70\t71\n\nchr2\t242193529\t252513167\t70\t71\n";
+        let assembly = Assembly::read_fai(fai.as_bytes()).unwrap();
+
+        assert_eq!(assembly.len(), 2);
+        assert_eq!(assembly.index_of("chr1"), Some(0));
+        assert_eq!(assembly.length("chr2"), Some(242_193_529));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn fai_errors() {
+        let err = Assembly::read_fai("chr1\t10\nchr2\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: line 2: missing contig length"
+        );
+
+        let err = Assembly::read_fai("chr1\tten\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: line 1: invalid contig length `ten`"
+        );
+
+        let err = Assembly::read_fai("\t10\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: line 1: invalid contig name: contig name cannot be empty"
+        );
+
+        let err = Assembly::read_fai("chr1\t10\nchr1\t20\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate contig name or alias: `chr1`");
+
+        let err =
+            Assembly::read_sam_header("@SQ\tSN:chrM\tLN:1\tTP:round\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: line 1: invalid contig topology `round`"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sam_header_topology() {
+        let header = [
+            "@SQ\tSN:chr1\tLN:248956422",
+            "@SQ\tSN:chrM\tLN:16569\tTP:circular",
+            "@SQ\tSN:chrY\tLN:57227415\tTP:linear",
+        ]
+        .map(|line| format!("{line}\n"))
+        .concat();
+        let assembly = Assembly::read_sam_header(header.as_bytes()).unwrap();
+
+        assert_eq!(assembly.get("chr1").unwrap().topology(), Topology::Linear);
+        assert_eq!(assembly.get("chrM").unwrap().topology(), Topology::Circular);
+        assert_eq!(assembly.get("chrY").unwrap().topology(), Topology::Linear);
+        assert_eq!(Topology::Circular.to_string(), "circular");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sam_header() {
+        let header = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:chr1\tLN:248956422\tAN:1,NC_000001.11\n@\
+                      SQ\tLN:16569\tSN:chrM\tAN:MT\n@PG\tID:bwa\nread0\t0\tchr1\t1\n@SQ\tSN:\
+                      ignored\tLN:1\n";
+        let assembly = Assembly::read_sam_header(header.as_bytes()).unwrap();
+
+        assert_eq!(assembly.len(), 2);
+        assert_eq!(assembly.index_of("NC_000001.11"), Some(0));
+        assert_eq!(assembly.index_of("MT"), Some(1));
+        assert_eq!(assembly.get("1").unwrap().aliases().len(), 2);
+        assert!(assembly.get("ignored").is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sam_header_errors() {
+        let err = Assembly::read_sam_header("@SQ\tSN:chr1\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "parse error: line 1: missing `LN` tag");
+
+        let err = Assembly::read_sam_header("@HD\tVN:1.6\n@SQ\tLN:10\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "parse error: line 2: missing `SN` tag");
+
+        let err =
+            Assembly::read_sam_header("@SQ\tSN:chr1\tLN:1\tAN:chr1\n".as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "duplicate contig name or alias: `chr1`");
+    }
+
+    #[test]
+    fn failed_push_leaves_assembly_unchanged() {
+        let mut assembly =
+            Assembly::try_new([Entry::new(Contig::new_unchecked("chr1"), 10)]).unwrap();
+
+        let entry = Entry::new(Contig::new_unchecked("chr2"), 10)
+            .with_aliases([Contig::new_unchecked("2"), Contig::new_unchecked("chr1")]);
+        assert!(assembly.push(entry).is_err());
+
+        assert_eq!(assembly.len(), 1);
+        assert!(assembly.get("2").is_none());
+    }
+
+    #[test]
+    fn validation() {
+        let assembly = Assembly::try_new([Entry::new(Contig::new_unchecked("seq0"), 100)]).unwrap();
+
+        let interval = "seq0:+:0-100".parse::<Interval<Interbase>>().unwrap();
+        assert!(assembly.validate_interval(&interval).is_ok());
+
+        let interval = "seq0:+:0-101".parse::<Interval<Interbase>>().unwrap();
+        let err = assembly.validate_interval(&interval).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "position `101` is out of bounds for contig `seq0` of length `100`"
+        );
+
+        let interval = "seq1:+:1-10".parse::<Interval<Base>>().unwrap();
+        let err = assembly.validate_interval(&interval).unwrap_err();
+        assert_eq!(err.to_string(), "unknown contig: `seq1`");
+    }
+
+    #[test]
+    fn karyotypic_sort_updates_lookups() {
+        let mut assembly = Assembly::try_new([
+            Entry::new(Contig::new_unchecked("chrY"), 1),
+            Entry::new(Contig::new_unchecked("chr11"), 1),
+            Entry::new(Contig::new_unchecked("chr3"), 1).with_aliases([Contig::new_unchecked("3")]),
+        ])
+        .unwrap();
+        assembly.sort_karyotypic();
+
+        assert_eq!(assembly.index_of("chr3"), Some(0));
+        assert_eq!(assembly.index_of("3"), Some(0));
+        assert_eq!(assembly.index_of("chr11"), Some(1));
+        assert_eq!(assembly.index_of("chrY"), Some(2));
+    }
+}
@@ -0,0 +1,1158 @@
+//! BED records.
+//!
+//! The [BED format](https://genome.ucsc.edu/FAQ/FAQformat.html#format1)
+//! describes intervals using zero-based, half-open coordinates, which map
+//! exactly onto [`Interval<Interbase>`]. This module reads and writes BED3
+//! through BED12 lines (including `BEDn+m` files with extra columns) as typed
+//! [`Record`]s. Records are generic over the [`Width`] of their positions, so
+//! files describing contigs longer than [`u32::MAX`] can be read as
+//! `Record<u64>`.
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::contig;
+#[cfg(feature = "std")]
+use crate::contig::ContigPool;
+use crate::position::Number;
+use crate::position::Width;
+use crate::system::Interbase;
+
+/// The delimiter between columns.
+const DELIMITER: char = '\t';
+
+/// The delimiter between values within a list column.
+const LIST_DELIMITER: char = ',';
+
+/// The value denoting a missing optional column.
+const MISSING: &str = ".";
+
+/// The minimum number of standard columns in a record.
+const MIN_COLUMNS: usize = 3;
+
+/// The maximum number of standard columns in a record.
+const MAX_COLUMNS: usize = 12;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing a BED record.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// Too few columns were present.
+    #[error("expected at least {expected} columns, found {found}")]
+    MissingColumns {
+        /// The number of columns expected.
+        expected: usize,
+
+        /// The number of columns found.
+        found: usize,
+    },
+
+    /// An unsupported number of standard columns was requested.
+    #[error("unsupported number of standard columns: {0}")]
+    UnsupportedColumns(usize),
+
+    /// An invalid contig name was encountered.
+    #[error("invalid contig name: {0}")]
+    Contig(contig::Error),
+
+    /// An invalid value was encountered in a column.
+    #[error("invalid {column} `{value}`")]
+    Invalid {
+        /// The name of the column.
+        column: &'static str,
+
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+
+    /// The start of the record is after its end.
+    #[error("start `{start}` is greater than end `{end}`")]
+    NegativelySized {
+        /// The start position.
+        start: u64,
+
+        /// The end position.
+        end: u64,
+    },
+
+    /// The block columns had differing lengths.
+    #[error(
+        "block count `{count}` does not match the number of block sizes ({sizes}) and block \
+         starts ({starts})"
+    )]
+    BlockCount {
+        /// The declared number of blocks.
+        count: usize,
+
+        /// The number of block sizes.
+        sizes: usize,
+
+        /// The number of block starts.
+        starts: usize,
+    },
+
+    /// A thick range or block was not contained within the record.
+    #[error("{kind} `{lower}-{upper}` is not contained within the record")]
+    Uncontained {
+        /// The kind of sub-interval.
+        kind: &'static str,
+
+        /// The numerically lower position of the sub-interval.
+        lower: u64,
+
+        /// The numerically upper position of the sub-interval.
+        upper: u64,
+    },
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to reading BED records.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// An I/O error.
+    #[cfg(feature = "std")]
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A parse error on a particular line.
+    #[error("line {line}: {inner}")]
+    Parse {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The inner error.
+        inner: ParseError,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Records
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A BED record.
+///
+/// The strand of the record is carried by its interval: records without a
+/// strand column (or with a `.` strand) are [`Strand::Unstranded`]. Optional
+/// columns containing `.` are represented as [`None`].
+///
+/// The number of standard columns and any extra columns are retained so that
+/// a record is written back exactly as it was read.
+///
+/// The positions of a record are represented using `W` (see [`Width`]), which
+/// defaults to [`Number`].
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Strand;
+/// use omics_coordinate::bed::Record;
+///
+/// let line = "chr1\t100\t200\tgene0\t960\t-\t120\t180\t0\t2\t20,30\t0,70";
+/// let record = line.parse::<Record>()?;
+///
+/// assert_eq!(record.interval().to_string(), "chr1:-:200-100");
+/// assert_eq!(record.name(), Some("gene0"));
+/// assert_eq!(record.score(), Some(960));
+/// assert_eq!(record.strand(), Strand::Negative);
+/// assert_eq!(record.thick().unwrap().to_string(), "chr1:-:180-120");
+///
+/// let blocks = record
+///     .blocks()
+///     .unwrap()
+///     .iter()
+///     .map(|block| block.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(blocks, vec!["chr1:-:120-100", "chr1:-:200-170"]);
+///
+/// assert_eq!(record.to_string(), line);
+///
+/// let record = "chr1\t4500000000\t4500000100".parse::<Record<u64>>()?;
+/// assert_eq!(record.interval().count_entities(), 100);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record<W: Width = Number> {
+    /// The interval.
+    interval: Interval<Interbase<W>>,
+
+    /// The name (column 4).
+    name: Option<String>,
+
+    /// The score (column 5).
+    score: Option<u32>,
+
+    /// The thick range (columns 7 and 8).
+    thick: Option<Interval<Interbase<W>>>,
+
+    /// The item color (column 9).
+    item_rgb: Option<String>,
+
+    /// The blocks (columns 10 through 12) in ascending genomic order.
+    blocks: Option<Vec<Interval<Interbase<W>>>>,
+
+    /// The number of standard columns.
+    columns: usize,
+
+    /// Any columns following the standard columns.
+    extra: Vec<String>,
+
+    /// Whether the block lists were written with trailing commas.
+    trailing_commas: bool,
+}
+
+impl<W: Width> Record<W> {
+    /// Creates a new record for an interval.
+    ///
+    /// The record is a BED6 record if the interval is stranded and a BED3
+    /// record otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::bed::Record;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let interval = "chr1:.:0-10".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(Record::new(interval).to_string(), "chr1\t0\t10");
+    ///
+    /// let interval = "chr1:-:10-0".parse::<Interval<Interbase>>()?;
+    /// let record = Record::new(interval).with_name("feature0");
+    /// assert_eq!(record.to_string(), "chr1\t0\t10\tfeature0\t.\t-");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(interval: Interval<Interbase<W>>) -> Self {
+        let columns = if interval.strand().is_stranded() {
+            6
+        } else {
+            MIN_COLUMNS
+        };
+
+        Self {
+            interval,
+            name: None,
+            score: None,
+            thick: None,
+            item_rgb: None,
+            blocks: None,
+            columns,
+            extra: Default::default(),
+            trailing_commas: false,
+        }
+    }
+
+    /// Consumes `self` and returns a record with the provided name.
+    #[must_use = "this method returns a new record"]
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self.columns = self.columns.max(4);
+        self
+    }
+
+    /// Consumes `self` and returns a record with the provided score.
+    #[must_use = "this method returns a new record"]
+    pub fn with_score(mut self, score: u32) -> Self {
+        self.score = Some(score);
+        self.columns = self.columns.max(5);
+        self
+    }
+
+    /// Gets the interval.
+    pub fn interval(&self) -> &Interval<Interbase<W>> {
+        &self.interval
+    }
+
+    /// Consumes `self` and returns the interval.
+    pub fn into_interval(self) -> Interval<Interbase<W>> {
+        self.interval
+    }
+
+    /// Gets the contig.
+    pub fn contig(&self) -> &Contig {
+        self.interval.contig()
+    }
+
+    /// Gets the strand.
+    pub fn strand(&self) -> Strand {
+        self.interval.strand()
+    }
+
+    /// Gets the name (if it exists).
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the score (if it exists).
+    pub fn score(&self) -> Option<u32> {
+        self.score
+    }
+
+    /// Gets the thick range (if it exists).
+    ///
+    /// The thick range is a sub-interval of the record's interval.
+    pub fn thick(&self) -> Option<&Interval<Interbase<W>>> {
+        self.thick.as_ref()
+    }
+
+    /// Gets the item color (if it exists).
+    pub fn item_rgb(&self) -> Option<&str> {
+        self.item_rgb.as_deref()
+    }
+
+    /// Gets the blocks (if they exist).
+    ///
+    /// Each block is a sub-interval of the record's interval, and blocks are
+    /// returned in ascending genomic order (as they are listed in the record).
+    pub fn blocks(&self) -> Option<&[Interval<Interbase<W>>]> {
+        self.blocks.as_deref()
+    }
+
+    /// Gets the number of standard columns (from `3` to `12`).
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Gets any columns following the standard columns.
+    pub fn extra(&self) -> &[String] {
+        &self.extra
+    }
+
+    /// Attempts to parse a record from a line.
+    ///
+    /// If `columns` is provided, exactly that many leading columns are parsed
+    /// as standard BED columns and the remainder are retained as extra columns
+    /// (as in a `BEDn+m` file). Otherwise, the number of standard columns is
+    /// inferred from the line.
+    pub(crate) fn parse_with(
+        line: &str,
+        columns: Option<usize>,
+        contig: impl FnOnce(&str) -> contig::Result<Contig>,
+    ) -> ParseResult<Self> {
+        let fields = line.split(DELIMITER).collect::<Vec<_>>();
+
+        let columns = match columns {
+            Some(columns) => {
+                if !is_supported(columns) {
+                    return Err(ParseError::UnsupportedColumns(columns));
+                }
+
+                if fields.len() < columns {
+                    return Err(ParseError::MissingColumns {
+                        expected: columns,
+                        found: fields.len(),
+                    });
+                }
+
+                columns
+            }
+            None => match fields.len() {
+                found if found < MIN_COLUMNS => {
+                    return Err(ParseError::MissingColumns {
+                        expected: MIN_COLUMNS,
+                        found,
+                    });
+                }
+                7 => 6,
+                10 | 11 => 9,
+                found => found.min(MAX_COLUMNS),
+            },
+        };
+
+        let contig = contig(fields[0]).map_err(ParseError::Contig)?;
+        let lower = parse_number::<W>(fields[1], "start")?;
+        let upper = parse_number::<W>(fields[2], "end")?;
+
+        if lower > upper {
+            return Err(ParseError::NegativelySized {
+                start: lower.to_u64(),
+                end: upper.to_u64(),
+            });
+        }
+
+        let strand = match fields.get(5).filter(|_| columns >= 6) {
+            Some(value) => value.parse::<Strand>().map_err(|_| ParseError::Invalid {
+                column: "strand",
+                value: value.to_string(),
+            })?,
+            None => Strand::Unstranded,
+        };
+
+        let interval =
+            Interval::from_bounds(contig, strand, Position::new(lower), Position::new(upper));
+
+        let field = |index: usize| {
+            fields
+                .get(index)
+                .copied()
+                .filter(|value| index < columns && *value != MISSING)
+        };
+
+        let name = field(3).map(String::from);
+        let score = field(4)
+            .map(|value| {
+                value.parse::<u32>().map_err(|_| ParseError::Invalid {
+                    column: "score",
+                    value: value.to_string(),
+                })
+            })
+            .transpose()?;
+
+        let thick = match (field(6), field(7)) {
+            (Some(start), Some(end)) => {
+                let start = parse_number(start, "thick start")?;
+                let end = parse_number(end, "thick end")?;
+                Some(sub_interval(&interval, "thick range", start, end)?)
+            }
+            (None, None) => None,
+            // NOTE: a thick range that is only partially missing cannot be
+            // represented (or written back), so it is rejected.
+            (Some(_), None) => return Err(missing("thick end")),
+            (None, Some(_)) => return Err(missing("thick start")),
+        };
+
+        let item_rgb = field(8).map(String::from);
+
+        let mut trailing_commas = false;
+        let blocks = match (field(9), field(10), field(11)) {
+            (Some(count), Some(sizes), Some(starts)) => {
+                trailing_commas = sizes.ends_with(LIST_DELIMITER);
+
+                let count = count.parse::<usize>().map_err(|_| ParseError::Invalid {
+                    column: "block count",
+                    value: count.to_string(),
+                })?;
+                let sizes = parse_list(sizes, "block sizes")?;
+                let starts = parse_list(starts, "block starts")?;
+
+                if sizes.len() != count || starts.len() != count {
+                    return Err(ParseError::BlockCount {
+                        count,
+                        sizes: sizes.len(),
+                        starts: starts.len(),
+                    });
+                }
+
+                let blocks = starts
+                    .into_iter()
+                    .zip(sizes)
+                    .map(|(start, size)| {
+                        let start = lower.checked_add(start);
+                        let end = start.and_then(|start| start.checked_add(size));
+
+                        match (start, end) {
+                            (Some(start), Some(end)) => {
+                                sub_interval(&interval, "block", start, end)
+                            }
+                            _ => Err(ParseError::Uncontained {
+                                kind: "block",
+                                lower: start.unwrap_or(W::MAX).to_u64(),
+                                upper: W::MAX.to_u64(),
+                            }),
+                        }
+                    })
+                    .collect::<ParseResult<Vec<_>>>()?;
+
+                Some(blocks)
+            }
+            (None, None, None) => None,
+            (None, ..) => return Err(missing("block count")),
+            (_, None, _) => return Err(missing("block sizes")),
+            (_, _, None) => return Err(missing("block starts")),
+        };
+
+        let extra = fields[columns..]
+            .iter()
+            .map(|value| value.to_string())
+            .collect();
+
+        Ok(Self {
+            interval,
+            name,
+            score,
+            thick,
+            item_rgb,
+            blocks,
+            columns,
+            extra,
+            trailing_commas,
+        })
+    }
+}
+
+/// Creates an error for a missing value within a group of columns in which
+/// other values are present.
+fn missing(column: &'static str) -> ParseError {
+    ParseError::Invalid {
+        column,
+        value: MISSING.to_string(),
+    }
+}
+
+/// Returns whether a line is a `track` or `browser` line.
+///
+/// The keyword must be followed by whitespace or end the line so that records
+/// on contigs such as `track1` are not mistaken for these lines.
+#[cfg(feature = "std")]
+fn is_header(line: &str) -> bool {
+    ["track", "browser"].into_iter().any(|keyword| {
+        line.strip_prefix(keyword)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+    })
+}
+
+/// Returns whether a number of standard columns is supported.
+fn is_supported(columns: usize) -> bool {
+    matches!(columns, 3..=6 | 8 | 9 | 12)
+}
+
+/// Parses a position from a column.
+fn parse_number<W: Width>(value: &str, column: &'static str) -> ParseResult<W> {
+    value.parse::<W>().map_err(|_| ParseError::Invalid {
+        column,
+        value: value.to_string(),
+    })
+}
+
+/// Parses a comma-delimited list of positions from a column.
+///
+/// A single trailing comma is permitted.
+fn parse_list<W: Width>(value: &str, column: &'static str) -> ParseResult<Vec<W>> {
+    let value = value.strip_suffix(LIST_DELIMITER).unwrap_or(value);
+
+    if value.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    value
+        .split(LIST_DELIMITER)
+        .map(|value| parse_number(value, column))
+        .collect()
+}
+
+/// Creates a sub-interval of a record's interval from its bounds.
+fn sub_interval<W: Width>(
+    interval: &Interval<Interbase<W>>,
+    kind: &'static str,
+    lower: W,
+    upper: W,
+) -> ParseResult<Interval<Interbase<W>>> {
+    if lower > upper || lower < interval.lower().get() || upper > interval.upper().get() {
+        return Err(ParseError::Uncontained {
+            kind,
+            lower: lower.to_u64(),
+            upper: upper.to_u64(),
+        });
+    }
+
+    Ok(Interval::from_bounds(
+        interval.contig().clone(),
+        interval.strand(),
+        Position::new(lower),
+        Position::new(upper),
+    ))
+}
+
+/// Writes a comma-delimited list.
+fn write_list<W: Width>(
+    f: &mut core::fmt::Formatter<'_>,
+    values: impl Iterator<Item = W>,
+    trailing: bool,
+) -> core::fmt::Result {
+    for (i, value) in values.enumerate() {
+        if i > 0 {
+            write!(f, "{LIST_DELIMITER}")?;
+        }
+
+        write!(f, "{value}")?;
+    }
+
+    if trailing {
+        write!(f, "{LIST_DELIMITER}")?;
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<W: Width> core::fmt::Display for Record<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let lower = self.interval.lower().get();
+
+        write!(
+            f,
+            "{}{DELIMITER}{}{DELIMITER}{}",
+            self.interval.contig(),
+            lower,
+            self.interval.upper().get()
+        )?;
+
+        if self.columns >= 4 {
+            write!(f, "{DELIMITER}{}", self.name.as_deref().unwrap_or(MISSING))?;
+        }
+
+        if self.columns >= 5 {
+            match self.score {
+                Some(score) => write!(f, "{DELIMITER}{score}")?,
+                None => write!(f, "{DELIMITER}{MISSING}")?,
+            }
+        }
+
+        if self.columns >= 6 {
+            write!(f, "{DELIMITER}{}", self.interval.strand())?;
+        }
+
+        if self.columns >= 8 {
+            match &self.thick {
+                Some(thick) => write!(
+                    f,
+                    "{DELIMITER}{}{DELIMITER}{}",
+                    thick.lower().get(),
+                    thick.upper().get()
+                )?,
+                None => write!(f, "{DELIMITER}{MISSING}{DELIMITER}{MISSING}")?,
+            }
+        }
+
+        if self.columns >= 9 {
+            write!(
+                f,
+                "{DELIMITER}{}",
+                self.item_rgb.as_deref().unwrap_or(MISSING)
+            )?;
+        }
+
+        if self.columns >= 12 {
+            match &self.blocks {
+                Some(blocks) => {
+                    write!(f, "{DELIMITER}{}{DELIMITER}", blocks.len())?;
+                    write_list(
+                        f,
+                        blocks.iter().map(|block| block.count_entities()),
+                        self.trailing_commas,
+                    )?;
+                    write!(f, "{DELIMITER}")?;
+                    write_list(
+                        f,
+                        blocks.iter().map(|block| block.lower().get() - lower),
+                        self.trailing_commas,
+                    )?;
+                }
+                None => write!(
+                    f,
+                    "{DELIMITER}{MISSING}{DELIMITER}{MISSING}{DELIMITER}{MISSING}"
+                )?,
+            }
+        }
+
+        for value in &self.extra {
+            write!(f, "{DELIMITER}{value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Width> core::str::FromStr for Record<W> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        Self::parse_with(s, None, |name| Contig::try_new(name))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Reading and writing
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A streaming reader of BED records.
+///
+/// Blank lines, comment lines (lines starting with `#`), and `track` and
+/// `browser` lines (lines starting with either keyword followed by whitespace)
+/// are skipped. Contigs are interned within a [`ContigPool`], so
+/// records on the same contig share a single allocation.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::bed::Reader;
+///
+/// let data = "track name=example\nchr1\t0\t10\nchr1\t20\t30\n";
+/// let records = Reader::new(data.as_bytes()).collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[1].interval().to_string(), "chr1:.:20-30");
+///
+/// let err = Reader::new("chr1\t10\t0\n".as_bytes())
+///     .next()
+///     .unwrap()
+///     .unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "line 1: start `10` is greater than end `0`"
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Reader<R, W: Width = Number> {
+    /// The inner reader.
+    inner: R,
+
+    /// The number of standard columns (if fixed).
+    columns: Option<usize>,
+
+    /// The pool of contigs seen so far.
+    pool: ContigPool,
+
+    /// The current line number.
+    line: usize,
+
+    /// A buffer for the current line.
+    buffer: String,
+
+    /// The width of the positions within the records.
+    width: PhantomData<W>,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> Reader<R> {
+    /// Creates a new reader that infers the number of standard columns from
+    /// each line.
+    ///
+    /// The reader produces records with positions of the default [`Width`]
+    /// (see [`Reader::with_width()`] to read records of another width).
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            columns: None,
+            pool: Default::default(),
+            line: 0,
+            buffer: Default::default(),
+            width: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead, W: Width> Reader<R, W> {
+    /// Consumes `self` and returns a reader that produces records with
+    /// positions of another [`Width`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::bed::Reader;
+    ///
+    /// let data = "chr1\t4999999990\t5000000000\n";
+    ///
+    /// let record = Reader::new(data.as_bytes())
+    ///     .with_width::<u64>()
+    ///     .next()
+    ///     .unwrap()?;
+    /// assert_eq!(
+    ///     record.interval().to_string(),
+    ///     "chr1:.:4999999990-5000000000"
+    /// );
+    ///
+    /// assert!(Reader::new(data.as_bytes()).next().unwrap().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use = "this method returns a new reader"]
+    pub fn with_width<V: Width>(self) -> Reader<R, V> {
+        Reader {
+            inner: self.inner,
+            columns: self.columns,
+            pool: self.pool,
+            line: self.line,
+            buffer: self.buffer,
+            width: PhantomData,
+        }
+    }
+
+    /// Consumes `self` and returns a reader that parses exactly `columns`
+    /// standard columns from each line, retaining the remainder as extra
+    /// columns (as in a `BEDn+m` file).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::bed::Reader;
+    ///
+    /// // A narrowPeak (BED6+4) line.
+    /// let data = "chr1\t9\t20\tpeak0\t0\t.\t18.2\t-1\t4.6\t5\n";
+    /// let record = Reader::new(data.as_bytes())
+    ///     .with_columns(6)
+    ///     .next()
+    ///     .unwrap()?;
+    ///
+    /// assert_eq!(record.columns(), 6);
+    /// assert_eq!(record.extra(), &["18.2", "-1", "4.6", "5"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use = "this method returns a new reader"]
+    pub fn with_columns(mut self, columns: usize) -> Self {
+        self.columns = Some(columns);
+        self
+    }
+
+    /// Reads the next record.
+    ///
+    /// Returns [`None`] at the end of the input.
+    pub fn read_record(&mut self) -> Option<Result<Record<W>>> {
+        loop {
+            self.buffer.clear();
+
+            match self.inner.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(err) => return Some(Err(Error::Io(err))),
+            }
+
+            self.line += 1;
+
+            let line = self.buffer.trim_end_matches(['\n', '\r']);
+
+            if line.is_empty() || line.starts_with('#') || is_header(line) {
+                continue;
+            }
+
+            let pool = &mut self.pool;
+            return Some(
+                Record::parse_with(line, self.columns, |name| pool.contig(name)).map_err(|inner| {
+                    Error::Parse {
+                        line: self.line,
+                        inner,
+                    }
+                }),
+            );
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead, W: Width> Iterator for Reader<R, W> {
+    type Item = Result<Record<W>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record()
+    }
+}
+
+/// A writer of BED records.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::bed::Reader;
+/// use omics_coordinate::bed::Writer;
+///
+/// let data = "chr1\t0\t10\ta\t0\t+\nchr2\t5\t6\tb\t.\t.\n";
+///
+/// let mut writer = Writer::new(Vec::new());
+/// for record in Reader::new(data.as_bytes()) {
+///     writer.write_record(&record?)?;
+/// }
+///
+/// assert_eq!(String::from_utf8(writer.into_inner())?, data);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Writer<T> {
+    /// The inner writer.
+    inner: T,
+}
+
+#[cfg(feature = "std")]
+impl<T: Write> Writer<T> {
+    /// Creates a new writer.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Writes a record as a single line.
+    pub fn write_record<W: Width>(&mut self, record: &Record<W>) -> std::io::Result<()> {
+        writeln!(self.inner, "{record}")
+    }
+
+    /// Consumes `self` and returns the inner writer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn bed3() {
+        let record = "chr1\t0\t100".parse::<Record>().unwrap();
+        assert_eq!(record.columns(), 3);
+        assert_eq!(record.strand(), Strand::Unstranded);
+        assert_eq!(record.interval().count_entities(), 100);
+        assert_eq!(record.name(), None);
+        assert!(record.blocks().is_none());
+    }
+
+    #[test]
+    fn missing_values() {
+        let line = "chr1\t0\t100\t.\t.\t.\t.\t.\t.";
+        let record = line.parse::<Record>().unwrap();
+
+        assert_eq!(record.columns(), 9);
+        assert_eq!(record.name(), None);
+        assert_eq!(record.score(), None);
+        assert_eq!(record.strand(), Strand::Unstranded);
+        assert!(record.thick().is_none());
+        assert_eq!(record.item_rgb(), None);
+        assert_eq!(record.to_string(), line);
+    }
+
+    #[test]
+    fn bed12() {
+        let line =
+            "chr2\t1000\t5000\ttx0\t0\t+\t1200\t4900\t255,0,0\t3\t500,400,200,\t0,1500,3800,";
+        let record = line.parse::<Record>().unwrap();
+
+        let blocks = record
+            .blocks()
+            .unwrap()
+            .iter()
+            .map(|block| block.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            blocks,
+            vec!["chr2:+:1000-1500", "chr2:+:2500-2900", "chr2:+:4800-5000"]
+        );
+
+        for block in record.blocks().unwrap() {
+            assert!(
+                record
+                    .interval()
+                    .contains_coordinate(&block.clone().into_start())
+            );
+            assert!(
+                record
+                    .interval()
+                    .contains_coordinate(&block.clone().into_end())
+            );
+        }
+
+        assert_eq!(record.item_rgb(), Some("255,0,0"));
+        assert_eq!(record.to_string(), line);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn extra_columns() {
+        let line = "chr1\t0\t10\tname\t5\t-\textra0\textra1";
+        let record = Reader::new(line.as_bytes())
+            .with_columns(6)
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.extra(), &["extra0", "extra1"]);
+        assert_eq!(record.to_string(), line);
+
+        // A BED6+1 line is inferred without an explicit number of columns.
+        let line = "chr1\t0\t10\tname\t5\t-\textra0";
+        let record = line.parse::<Record>().unwrap();
+        assert_eq!(record.columns(), 6);
+        assert_eq!(record.to_string(), line);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "chr1\t0".parse::<Record>().unwrap_err();
+        assert_eq!(err.to_string(), "expected at least 3 columns, found 2");
+
+        let err = "\t0\t1".parse::<Record>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid contig name: contig name cannot be empty"
+        );
+
+        let err = "chr1\t0\t1\tn\t0\t*".parse::<Record>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid strand `*`");
+
+        let err = "chr1\t0\t1\tn\thigh".parse::<Record>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid score `high`");
+
+        let err = "chr1\t10\t20\tn\t0\t+\t5\t15"
+            .parse::<Record>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "thick range `5-15` is not contained within the record"
+        );
+
+        let err = "chr1\t10\t20\tn\t0\t+\t15\t."
+            .parse::<Record>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid thick end `.`");
+
+        let err = "chr1\t10\t20\tn\t0\t+\t.\t15"
+            .parse::<Record>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid thick start `.`");
+
+        let err = "chr1\t10\t20\tn\t0\t+\t10\t20\t0\t1\t.\t0"
+            .parse::<Record>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "invalid block sizes `.`");
+
+        let err = "chr1\t10\t20\tn\t0\t+\t10\t20\t0\t2\t5\t0"
+            .parse::<Record>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "block count `2` does not match the number of block sizes (1) and block starts (1)"
+        );
+
+        let err = "chr1\t10\t20\tn\t0\t+\t10\t20\t0\t1\t11\t0"
+            .parse::<Record>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "block `10-21` is not contained within the record"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_errors_are_line_numbered() {
+        let data = "# header\nchr1\t0\t10\n\nchr1\tzero\t10\n";
+        let mut reader = Reader::new(data.as_bytes());
+
+        assert!(reader.next().unwrap().is_ok());
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "line 4: invalid start `zero`");
+        assert!(reader.next().is_none());
+
+        let err = Reader::new("chr1\t0\t10\n".as_bytes())
+            .with_columns(7)
+            .next()
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: unsupported number of standard columns: 7"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_skips_track_and_browser_lines() {
+        let data = [
+            "browser position chr1:1-100",
+            "track",
+            "track\tname=example",
+            "track1\t0\t10",
+            "browser_scaffold\t5\t15",
+            "trackless\t20\t30",
+        ]
+        .map(|line| format!("{line}\n"))
+        .concat();
+
+        let contigs = Reader::new(data.as_bytes())
+            .map(|record| record.unwrap().interval().contig().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(contigs, ["track1", "browser_scaffold", "trackless"]);
+    }
+
+    #[test]
+    fn wide_records() {
+        let line = [
+            "chr1A",
+            "4500000000",
+            "4500001000",
+            "n",
+            "0",
+            "-",
+            "4500000100",
+            "4500000900",
+            "0",
+            "2",
+            "100,200",
+            "0,800",
+        ]
+        .join("\t");
+        let record = line.parse::<Record<u64>>().unwrap();
+
+        assert_eq!(
+            record.interval().to_string(),
+            "chr1A:-:4500001000-4500000000"
+        );
+        assert_eq!(record.interval().count_entities(), 1_000);
+        assert_eq!(
+            record.blocks().unwrap()[1].to_string(),
+            "chr1A:-:4500001000-4500000800"
+        );
+        assert_eq!(record.to_string(), line);
+
+        let err = line.parse::<Record>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid start `4500000000`");
+
+        let err = "chr1A\t5000000000\t4500000000"
+            .parse::<Record<u64>>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "start `5000000000` is greater than end `4500000000`"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn round_trip() {
+        let lines = [
+            "chr1\t0\t10",
+            "chr1\t5\t15\ta",
+            "chr1\t5\t15\ta\t0",
+            "chr2\t0\t1\tb\t1\t-",
+            "chr2\t0\t100\tc\t0\t-\t10\t90\t0",
+            "chr3\t0\t100\td\t0\t+\t0\t0\t0\t2\t10,20\t0,80",
+        ];
+        let data = lines.map(|line| format!("{line}\n")).concat();
+
+        let mut writer = Writer::new(Vec::new());
+        for record in Reader::new(data.as_bytes()) {
+            writer.write_record(&record.unwrap()).unwrap();
+        }
+
+        assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), data);
+    }
+}
@@ -0,0 +1,947 @@
+//! Circular contigs.
+//!
+//! Some molecules—such as mitochondrial genomes, chloroplast genomes, and
+//! bacterial plasmids—are circular: the last position on the contig is
+//! directly followed by the first position. Features on these molecules (for
+//! example, the mitochondrial D-loop) may wrap across the origin, which cannot
+//! be represented with a linear [`Interval`].
+//!
+//! A [`CircularContig`] pairs a contig with its length so that coordinates can
+//! be moved modulo the length of the contig and so that
+//! [`CircularInterval`]s—which may wrap around the origin—can be created.
+//!
+//! On a circular contig, an interval _wraps_ when its end lies before its start
+//! in the direction of the strand (e.g., `chrM:+:16000-100` covers positions
+//! `16000` through `16569` followed by positions `1` through `100`). Because
+//! interbase positions `0` and the length of the contig refer to the same
+//! space on a circular contig, either may be used.
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::CoordinateRef;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::assembly::Entry;
+use crate::assembly::Topology;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to a circular contig.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A linear contig error.
+    ///
+    /// This error occurs when a circular contig is requested for a contig
+    /// whose topology is linear.
+    #[error("contig `{0}` is not circular")]
+    Linear(Contig),
+
+    /// A mismatched contig error.
+    ///
+    /// This error occurs when a coordinate does not lie upon the circular
+    /// contig it is being used with.
+    #[error("mismatched contigs: expected `{expected}`, found `{found}`")]
+    MismatchedContigs {
+        /// The circular contig.
+        expected: Contig,
+
+        /// The contig of the coordinate.
+        found: Contig,
+    },
+
+    /// A mismatched strand error.
+    ///
+    /// This error occurs when the start and end of an interval are not located
+    /// on the same strand.
+    #[error("mismatched strands for coordinates: `{start}` and `{end}`")]
+    MismatchedStrands {
+        /// The strand of the start coordinate.
+        start: Strand,
+
+        /// The strand of the end coordinate.
+        end: Strand,
+    },
+
+    /// An out of bounds error.
+    ///
+    /// This error occurs when a position lies beyond the end of its contig.
+    #[error("position `{position}` is out of bounds for contig `{contig}` of length `{length}`")]
+    OutOfBounds {
+        /// The contig.
+        contig: Contig,
+
+        /// The position.
+        position: u64,
+
+        /// The length of the contig.
+        length: u64,
+    },
+
+    /// A width error.
+    ///
+    /// This error occurs when the length of a circular contig cannot be
+    /// represented by the positions being used with it.
+    #[error(
+        "contig `{contig}` of length `{length}` cannot be represented with {bits}-bit positions"
+    )]
+    Width {
+        /// The contig.
+        contig: Contig,
+
+        /// The length of the contig.
+        length: u64,
+
+        /// The number of bits in the position representation.
+        bits: u32,
+    },
+
+    /// A zero length error.
+    ///
+    /// This error occurs when a circular contig is created with a length of
+    /// zero.
+    #[error("circular contig `{0}` must have a non-zero length")]
+    ZeroLength(Contig),
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// Gets the first position within a coordinate system (`1` for in-base
+/// positions and `0` for interbase positions).
+fn origin<S: System>() -> Position<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    // SAFETY: one is a valid position in every coordinate system.
+    let one = Position::<S>::try_from_number(S::Number::ONE).unwrap();
+    one.checked_sub(S::Number::ONE).unwrap_or(one)
+}
+
+/// Moves a position numerically upward by `magnitude`, wrapping around a
+/// circular contig of the provided length.
+///
+/// The position must not exceed `length`, and `magnitude` must not exceed
+/// `length`.
+fn advance<S: System>(
+    position: &Position<S>,
+    magnitude: S::Number,
+    length: S::Number,
+) -> Position<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    // SAFETY: if the magnitude fits before the end of the contig, the addition
+    // cannot overflow. Otherwise, `length - magnitude < position`, so the
+    // subtraction lands on a valid position that is at least one.
+    if magnitude <= length - position.get() {
+        position.checked_add(magnitude).unwrap()
+    } else {
+        position.checked_sub(length - magnitude).unwrap()
+    }
+}
+
+/// Moves a position numerically downward by `magnitude`, wrapping around a
+/// circular contig of the provided length.
+///
+/// The position must not exceed `length`, and `magnitude` must not exceed
+/// `length`.
+fn retreat<S: System>(
+    position: &Position<S>,
+    magnitude: S::Number,
+    length: S::Number,
+) -> Position<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    // SAFETY: if the subtraction would pass the origin, then `position <=
+    // magnitude`, so the addition lands at or before the end of the contig.
+    position
+        .checked_sub(magnitude)
+        .unwrap_or_else(|| position.checked_add(length - magnitude).unwrap())
+}
+
+/// Moves a position by `magnitude` in the direction of a strand, wrapping
+/// around a circular contig of the provided length.
+fn step<S: System>(
+    position: &Position<S>,
+    strand: Strand,
+    magnitude: S::Number,
+    length: S::Number,
+) -> Position<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    match strand {
+        Strand::Positive | Strand::Unstranded => advance(position, magnitude, length),
+        Strand::Negative => retreat(position, magnitude, length),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Circular contigs
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A circular contig.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::Assembly;
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::circular::CircularContig;
+/// use omics_coordinate::system::Base;
+///
+/// let header = "@SQ\tSN:chrM\tLN:16569\tTP:circular\n";
+/// let assembly = Assembly::read_sam_header(header.as_bytes())?;
+/// let chrm = CircularContig::try_from(assembly.get("chrM").unwrap())?;
+///
+/// let coordinate = "chrM:+:16560".parse::<Coordinate<Base>>()?;
+/// let moved = chrm.move_forward(coordinate, 20)?;
+/// assert_eq!(moved.to_string(), "chrM:+:11");
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CircularContig {
+    /// The contig.
+    contig: Contig,
+
+    /// The length of the contig.
+    length: u64,
+}
+
+impl CircularContig {
+    /// Attempts to create a new circular contig.
+    ///
+    /// The length of the contig must be non-zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::circular::CircularContig;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("pUC19"), 2_686)?;
+    /// assert_eq!(contig.length(), 2_686);
+    ///
+    /// assert!(CircularContig::try_new(Contig::new_unchecked("empty"), 0).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(contig: Contig, length: u64) -> Result<Self> {
+        if length == 0 {
+            return Err(Error::ZeroLength(contig));
+        }
+
+        Ok(Self { contig, length })
+    }
+
+    /// Gets the contig.
+    pub fn contig(&self) -> &Contig {
+        &self.contig
+    }
+
+    /// Gets the length of the contig.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Gets the length of the contig in the representation of a coordinate
+    /// system's positions.
+    fn length_as<S: System>(&self) -> Result<S::Number> {
+        S::Number::from_u64(self.length).ok_or_else(|| Error::Width {
+            contig: self.contig.clone(),
+            length: self.length,
+            bits: S::Number::BITS,
+        })
+    }
+
+    /// Ensures that a coordinate lies upon this contig and within its bounds.
+    fn check<S: System>(&self, coordinate: &Coordinate<S>) -> Result<()>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        if coordinate.contig() != &self.contig {
+            return Err(Error::MismatchedContigs {
+                expected: self.contig.clone(),
+                found: coordinate.contig().clone(),
+            });
+        }
+
+        if coordinate.position().get().to_u64() > self.length {
+            return Err(Error::OutOfBounds {
+                contig: self.contig.clone(),
+                position: coordinate.position().get().to_u64(),
+                length: self.length,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Attempts to move a coordinate forward by `magnitude`, wrapping around
+    /// the origin of the contig.
+    ///
+    /// As with [`Coordinate::move_forward()`], this method is dependent on the
+    /// strand of the coordinate. Because the contig is circular, the move
+    /// never overflows: it is performed modulo the length of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let coordinate = "seq0:+:95".parse::<Coordinate<Interbase>>()?;
+    /// let moved = contig.move_forward(coordinate, 10)?;
+    /// assert_eq!(moved.to_string(), "seq0:+:5");
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let coordinate = "seq0:-:5".parse::<Coordinate<Base>>()?;
+    /// let moved = contig.move_forward(coordinate, 10)?;
+    /// assert_eq!(moved.to_string(), "seq0:-:95");
+    ///
+    /// let coordinate = "seq0:+:5".parse::<Coordinate<Base>>()?;
+    /// let moved = contig.move_forward(coordinate, 1_000)?;
+    /// assert_eq!(moved.to_string(), "seq0:+:5");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn move_forward<S: System>(
+        &self,
+        coordinate: Coordinate<S>,
+        magnitude: S::Number,
+    ) -> Result<Coordinate<S>>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.check(&coordinate)?;
+
+        let length = self.length_as::<S>()?;
+        let (contig, strand, position) = coordinate.into_parts();
+        let position = step(&position, strand, magnitude % length, length);
+
+        Ok(Coordinate::new(contig, strand, position))
+    }
+
+    /// Attempts to move a coordinate backward by `magnitude`, wrapping around
+    /// the origin of the contig.
+    ///
+    /// As with [`Coordinate::move_backward()`], this method is dependent on
+    /// the strand of the coordinate. Because the contig is circular, the move
+    /// never overflows: it is performed modulo the length of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    ///
+    /// let coordinate = "seq0:+:5".parse::<Coordinate<Base>>()?;
+    /// let moved = contig.move_backward(coordinate, 10)?;
+    /// assert_eq!(moved.to_string(), "seq0:+:95");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn move_backward<S: System>(
+        &self,
+        coordinate: Coordinate<S>,
+        magnitude: S::Number,
+    ) -> Result<Coordinate<S>>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.check(&coordinate)?;
+
+        let length = self.length_as::<S>()?;
+        let (contig, strand, position) = coordinate.into_parts();
+        let position = step(&position, strand.complement(), magnitude % length, length);
+
+        Ok(Coordinate::new(contig, strand, position))
+    }
+
+    /// Attempts to create an interval on this contig that may wrap around the
+    /// origin.
+    ///
+    /// Both coordinates must lie upon this contig, be located on the same
+    /// strand, and lie within the bounds of the contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let chrm = CircularContig::try_new(Contig::new_unchecked("chrM"), 16_569)?;
+    ///
+    /// // The mitochondrial D-loop spans the origin.
+    /// let start = "chrM:+:16024".parse::<Coordinate<Base>>()?;
+    /// let end = "chrM:+:576".parse::<Coordinate<Base>>()?;
+    /// let dloop = chrm.interval(start, end)?;
+    ///
+    /// assert!(dloop.is_wrapping());
+    /// assert_eq!(dloop.count_entities(), 1_122);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn interval<S: System>(
+        &self,
+        start: Coordinate<S>,
+        end: Coordinate<S>,
+    ) -> Result<CircularInterval<S>>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        self.length_as::<S>()?;
+        self.check(&start)?;
+        self.check(&end)?;
+
+        if start.strand() != end.strand() {
+            return Err(Error::MismatchedStrands {
+                start: start.strand(),
+                end: end.strand(),
+            });
+        }
+
+        let (_, strand, start) = start.into_parts();
+        let (_, _, end) = end.into_parts();
+
+        Ok(CircularInterval {
+            contig: self.clone(),
+            strand,
+            start,
+            end,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Circular intervals
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An interval on a circular contig.
+///
+/// Unlike an [`Interval`], a circular interval may wrap around the origin of
+/// its contig. Circular intervals are created with
+/// [`CircularContig::interval()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircularInterval<S: System> {
+    /// The circular contig.
+    contig: CircularContig,
+
+    /// The strand.
+    strand: Strand,
+
+    /// The start position.
+    start: Position<S>,
+
+    /// The end position.
+    end: Position<S>,
+}
+
+impl<S: System> CircularInterval<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Gets the contig.
+    pub fn contig(&self) -> &Contig {
+        &self.contig.contig
+    }
+
+    /// Gets the length of the contig.
+    pub fn length(&self) -> u64 {
+        self.contig.length
+    }
+
+    /// Gets the length of the contig in the representation of the positions
+    /// of the interval.
+    fn length_number(&self) -> S::Number {
+        // SAFETY: the length was checked to be representable when the
+        // interval was created.
+        self.contig.length_as::<S>().unwrap()
+    }
+
+    /// Gets the strand.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Gets the start coordinate by reference.
+    pub fn start(&self) -> CoordinateRef<'_, S> {
+        CoordinateRef::new(&self.contig.contig, self.strand, &self.start)
+    }
+
+    /// Gets the end coordinate by reference.
+    pub fn end(&self) -> CoordinateRef<'_, S> {
+        CoordinateRef::new(&self.contig.contig, self.strand, &self.end)
+    }
+
+    /// Returns whether or not the interval wraps around the origin of the
+    /// contig.
+    ///
+    /// An interval wraps when its end lies before its start in the direction
+    /// of its strand.
+    pub fn is_wrapping(&self) -> bool {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.start > self.end,
+            Strand::Negative => self.start < self.end,
+        }
+    }
+
+    /// Splits the interval into its linear pieces in the direction of the
+    /// strand.
+    ///
+    /// An interval that does not wrap is a single linear interval. An interval
+    /// that wraps is split at the origin into the piece running from its start
+    /// to the end of the contig (or, on the negative strand, to the beginning
+    /// of the contig) and the piece continuing on the other side of the
+    /// origin to its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let start = "seq0:+:90".parse::<Coordinate<Interbase>>()?;
+    /// let end = "seq0:+:10".parse::<Coordinate<Interbase>>()?;
+    /// let (first, second) = contig.interval(start, end)?.split();
+    ///
+    /// assert_eq!(first.to_string(), "seq0:+:90-100");
+    /// assert_eq!(second.unwrap().to_string(), "seq0:+:0-10");
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let start = "seq0:-:10".parse::<Coordinate<Base>>()?;
+    /// let end = "seq0:-:91".parse::<Coordinate<Base>>()?;
+    /// let (first, second) = contig.interval(start, end)?.split();
+    ///
+    /// assert_eq!(first.to_string(), "seq0:-:10-1");
+    /// assert_eq!(second.unwrap().to_string(), "seq0:-:100-91");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn split(&self) -> (Interval<S>, Option<Interval<S>>) {
+        let contig = || self.contig.contig.clone();
+
+        if !self.is_wrapping() {
+            let (lower, upper) = match self.strand {
+                Strand::Positive | Strand::Unstranded => (self.start.clone(), self.end.clone()),
+                Strand::Negative => (self.end.clone(), self.start.clone()),
+            };
+
+            return (
+                Interval::from_bounds(contig(), self.strand, lower, upper),
+                None,
+            );
+        }
+
+        // SAFETY: the length of a circular contig is always non-zero.
+        let terminus = Position::<S>::try_from_number(self.length_number()).unwrap();
+        let origin = origin::<S>();
+
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => (
+                Interval::from_bounds(contig(), self.strand, self.start.clone(), terminus),
+                Some(Interval::from_bounds(
+                    contig(),
+                    self.strand,
+                    origin,
+                    self.end.clone(),
+                )),
+            ),
+            Strand::Negative => (
+                Interval::from_bounds(contig(), self.strand, origin, self.start.clone()),
+                Some(Interval::from_bounds(
+                    contig(),
+                    self.strand,
+                    self.end.clone(),
+                    terminus,
+                )),
+            ),
+        }
+    }
+
+    /// Gets the number of entities contained within the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let start = "seq0:+:90".parse::<Coordinate<Interbase>>()?;
+    /// let end = "seq0:+:10".parse::<Coordinate<Interbase>>()?;
+    /// assert_eq!(contig.interval(start, end)?.count_entities(), 20);
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// // An in-base interval ending directly before its start covers the
+    /// // entire contig.
+    /// let start = "seq0:+:51".parse::<Coordinate<Base>>()?;
+    /// let end = "seq0:+:50".parse::<Coordinate<Base>>()?;
+    /// assert_eq!(contig.interval(start, end)?.count_entities(), 100);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn count_entities(&self) -> S::Number {
+        let (first, second) = self.split();
+        first.count_entities()
+            + second
+                .map(|piece| piece.count_entities())
+                .unwrap_or(S::Number::ZERO)
+    }
+
+    /// Returns whether or not a coordinate is contained within the interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    /// let start = "seq0:+:90".parse::<Coordinate<Base>>()?;
+    /// let end = "seq0:+:10".parse::<Coordinate<Base>>()?;
+    /// let interval = contig.interval(start, end)?;
+    ///
+    /// assert!(interval.contains_coordinate(&"seq0:+:95".parse::<Coordinate<Base>>()?));
+    /// assert!(interval.contains_coordinate(&"seq0:+:5".parse::<Coordinate<Base>>()?));
+    /// assert!(!interval.contains_coordinate(&"seq0:+:50".parse::<Coordinate<Base>>()?));
+    /// assert!(!interval.contains_coordinate(&"seq0:-:5".parse::<Coordinate<Base>>()?));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contains_coordinate(&self, coordinate: &Coordinate<S>) -> bool {
+        let (first, second) = self.split();
+
+        first.contains_coordinate(coordinate)
+            || second
+                .map(|piece| piece.contains_coordinate(coordinate))
+                .unwrap_or(false)
+    }
+
+    /// Gets the offset of a coordinate from the start of the interval in the
+    /// direction of the strand.
+    ///
+    /// If the coordinate is not contained within the interval, [`None`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    /// let start = "seq0:-:10".parse::<Coordinate<Base>>()?;
+    /// let end = "seq0:-:91".parse::<Coordinate<Base>>()?;
+    /// let interval = contig.interval(start, end)?;
+    ///
+    /// let coordinate = "seq0:-:100".parse::<Coordinate<Base>>()?;
+    /// assert_eq!(interval.coordinate_offset(&coordinate), Some(10));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn coordinate_offset(&self, coordinate: &Coordinate<S>) -> Option<S::Number> {
+        if !self.contains_coordinate(coordinate) {
+            return None;
+        }
+
+        let start = self.start.get();
+        let position = coordinate.position().get();
+        let length = self.length_number();
+
+        Some(match self.strand {
+            Strand::Positive | Strand::Unstranded if position >= start => position - start,
+            Strand::Positive | Strand::Unstranded => length - start + position,
+            Strand::Negative if position <= start => start - position,
+            Strand::Negative => start + (length - position),
+        })
+    }
+
+    /// Gets the coordinate at an offset from the start of the interval in the
+    /// direction of the strand.
+    ///
+    /// If the offset lies beyond the end of the interval, [`None`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::circular::CircularContig;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let contig = CircularContig::try_new(Contig::new_unchecked("seq0"), 100)?;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let start = "seq0:+:90".parse::<Coordinate<Interbase>>()?;
+    /// let end = "seq0:+:10".parse::<Coordinate<Interbase>>()?;
+    /// let interval = contig.interval(start, end)?;
+    ///
+    /// let expected = "seq0:+:5".parse::<Coordinate<Interbase>>()?;
+    /// assert_eq!(interval.coordinate_at_offset(15), Some(expected));
+    /// assert!(interval.coordinate_at_offset(21).is_none());
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let start = "seq0:+:90".parse::<Coordinate<Base>>()?;
+    /// let end = "seq0:+:10".parse::<Coordinate<Base>>()?;
+    /// let interval = contig.interval(start, end)?;
+    ///
+    /// let expected = "seq0:+:1".parse::<Coordinate<Base>>()?;
+    /// assert_eq!(interval.coordinate_at_offset(11), Some(expected));
+    /// assert!(interval.coordinate_at_offset(21).is_none());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn coordinate_at_offset(&self, offset: S::Number) -> Option<Coordinate<S>> {
+        let length = self.length_number();
+
+        if offset > length {
+            return None;
+        }
+
+        let position = step(&self.start, self.strand, offset, length);
+        let coordinate = Coordinate::new(self.contig.contig.clone(), self.strand, position);
+
+        // NOTE: the round trip guards against offsets that land back within
+        // the interval after travelling all the way around the contig.
+        match self.coordinate_offset(&coordinate) == Some(offset) {
+            true => Some(coordinate),
+            false => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl TryFrom<&Entry> for CircularContig {
+    type Error = Error;
+
+    fn try_from(entry: &Entry) -> Result<Self> {
+        if entry.topology() != Topology::Circular {
+            return Err(Error::Linear(entry.contig().clone()));
+        }
+
+        Self::try_new(entry.contig().clone(), entry.length())
+    }
+}
+
+impl<S: System> core::fmt::Display for CircularInterval<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}-{}",
+            self.contig.contig, self.strand, self.start, self.end
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    fn contig() -> CircularContig {
+        CircularContig::try_new(Contig::new_unchecked("seq0"), 10).unwrap()
+    }
+
+    #[test]
+    fn movement_round_trips() {
+        let contig = contig();
+
+        for strand in ["+", "-"] {
+            for position in 1..=10 {
+                let coordinate = Coordinate::<Base>::try_new("seq0", strand, position).unwrap();
+
+                for magnitude in 0..=25 {
+                    let moved = contig.move_forward(coordinate.clone(), magnitude).unwrap();
+                    assert!((1..=10).contains(&moved.position().get()));
+                    assert_eq!(contig.move_backward(moved, magnitude).unwrap(), coordinate);
+                }
+            }
+        }
+
+        let coordinate = "seq0:+:0".parse::<Coordinate<Interbase>>().unwrap();
+        let moved = contig.move_backward(coordinate, 1).unwrap();
+        assert_eq!(moved.position().get(), 9);
+    }
+
+    #[test]
+    fn errors() {
+        let contig = contig();
+
+        let err = contig
+            .move_forward("seq1:+:1".parse::<Coordinate<Base>>().unwrap(), 1)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "mismatched contigs: expected `seq0`, found `seq1`"
+        );
+
+        let err = contig
+            .interval(
+                "seq0:+:1".parse::<Coordinate<Base>>().unwrap(),
+                "seq0:+:11".parse::<Coordinate<Base>>().unwrap(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "position `11` is out of bounds for contig `seq0` of length `10`"
+        );
+
+        let err = contig
+            .interval(
+                "seq0:+:1".parse::<Coordinate<Base>>().unwrap(),
+                "seq0:-:1".parse::<Coordinate<Base>>().unwrap(),
+            )
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::MismatchedStrands {
+                start: Strand::Positive,
+                end: Strand::Negative
+            }
+        );
+
+        let entry = Entry::new(Contig::new_unchecked("chr1"), 10);
+        let err = CircularContig::try_from(&entry).unwrap_err();
+        assert_eq!(err, Error::Linear(Contig::new_unchecked("chr1")));
+    }
+
+    #[test]
+    fn offsets_round_trip() {
+        let contig = contig();
+
+        for (start, end) in [(3, 7), (8, 2), (6, 5), (1, 10), (4, 4)] {
+            for strand in ["+", "-"] {
+                let interval = contig
+                    .interval(
+                        Coordinate::<Base>::try_new("seq0", strand, start).unwrap(),
+                        Coordinate::<Base>::try_new("seq0", strand, end).unwrap(),
+                    )
+                    .unwrap();
+
+                let count = interval.count_entities();
+                let contained = (1..=10)
+                    .filter(|position| {
+                        let coordinate =
+                            Coordinate::<Base>::try_new("seq0", strand, *position).unwrap();
+                        interval.contains_coordinate(&coordinate)
+                    })
+                    .count();
+                assert_eq!(count as usize, contained);
+
+                for offset in 0..count {
+                    let coordinate = interval.coordinate_at_offset(offset).unwrap();
+                    assert_eq!(interval.coordinate_offset(&coordinate), Some(offset));
+                }
+
+                assert!(interval.coordinate_at_offset(count).is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn interbase_origin() {
+        let contig = contig();
+
+        // Interbase positions `0` and `10` are the same space on the contig.
+        let interval = contig
+            .interval(
+                "seq0:+:10".parse::<Coordinate<Interbase>>().unwrap(),
+                "seq0:+:3".parse::<Coordinate<Interbase>>().unwrap(),
+            )
+            .unwrap();
+        assert!(interval.is_wrapping());
+        assert_eq!(interval.count_entities(), 3);
+        assert_eq!(interval.to_string(), "seq0:+:10-3");
+
+        let interval = contig
+            .interval(
+                "seq0:-:3".parse::<Coordinate<Interbase>>().unwrap(),
+                "seq0:-:8".parse::<Coordinate<Interbase>>().unwrap(),
+            )
+            .unwrap();
+        assert_eq!(interval.count_entities(), 5);
+        assert_eq!(
+            interval.coordinate_at_offset(5).unwrap().position().get(),
+            8
+        );
+        assert!(interval.coordinate_at_offset(6).is_none());
+    }
+}
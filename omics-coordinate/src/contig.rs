@@ -1,9 +1,17 @@
 //! Contiguous molecules.
 
-use std::sync::Arc;
+use alloc::string::String;
+use alloc::sync::Arc;
 
 use thiserror::Error;
 
+pub mod alias;
+pub mod pool;
+
+pub use alias::AliasTable;
+pub use pool::ContigPool;
+pub use pool::Symbol;
+
 ////////////////////////////////////////////////////////////////////////////////////////
 // Errors
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -16,8 +24,8 @@ pub enum Error {
     Empty,
 }
 
-/// A [`Result`](std::result::Result) with an [`Error`](enum@Error).
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
 
 ////////////////////////////////////////////////////////////////////////////////////////
 // Contig
@@ -29,14 +37,30 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// `O(1)` (an atomic reference count increment rather than a heap
 /// allocation).
 ///
+/// A fingerprint of the name is computed once when a contig is created.
+/// Hashing a contig writes only this fingerprint, and equality first compares
+/// the underlying pointers and then the fingerprints, so both are integer
+/// operations. The names themselves are only compared when two separately
+/// allocated contigs have the same fingerprint. Contigs retrieved from a
+/// [`ContigPool`] share an allocation per name, so comparing them never
+/// touches the names at all.
+///
+/// Ordering still compares the names lexicographically.
+///
 /// Notably, the internal representation of [`Contig`] may change in the future
 /// (though the interface to this type will remain stable with respect to
 /// [semantic versioning](https://semver.org/)).
 ///
 /// For a more in-depth discussion on this, please see [this section of the
 /// docs](crate#contigs).
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Contig(Arc<str>);
+#[derive(Clone)]
+pub struct Contig {
+    /// The name of the contig.
+    name: Arc<str>,
+
+    /// The fingerprint of the name.
+    fingerprint: u64,
+}
 
 impl Contig {
     /// Attempts to create a new contig.
@@ -61,7 +85,7 @@ impl Contig {
         if s.is_empty() {
             return Err(Error::Empty);
         }
-        Ok(Self(Arc::from(s)))
+        Ok(Self::from_arc(Arc::from(s)))
     }
 
     /// Creates a new contig without validating that the name is non-empty.
@@ -81,26 +105,84 @@ impl Contig {
     /// assert_eq!(contig.as_str(), "chr1");
     /// ```
     pub fn new_unchecked(value: impl Into<String>) -> Self {
-        Self(Arc::from(value.into()))
+        Self::from_arc(Arc::from(value.into()))
+    }
+
+    /// Creates a new contig from a shared name.
+    fn from_arc(name: Arc<str>) -> Self {
+        let fingerprint = fingerprint(&name);
+        Self { name, fingerprint }
     }
 
     /// Returns a reference to the contig name as a string slice.
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.name
+    }
+
+    /// Gets the shared allocation holding the contig name.
+    pub(crate) fn shared(&self) -> &Arc<str> {
+        &self.name
     }
 }
 
+/// Computes the fingerprint of a contig name.
+///
+/// This is the 64-bit FNV-1a hash of the name, which is cheap to compute for
+/// the short names typical of contigs and stable across platforms.
+fn fingerprint(name: &str) -> u64 {
+    name.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////
 // Trait implementations
 ////////////////////////////////////////////////////////////////////////////////////////
 
-impl std::fmt::Display for Contig {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+impl core::fmt::Debug for Contig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Contig").field(&self.name).finish()
+    }
+}
+
+impl core::fmt::Display for Contig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl PartialEq for Contig {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.name, &other.name)
+            || (self.fingerprint == other.fingerprint && self.name == other.name)
+    }
+}
+
+impl Eq for Contig {}
+
+impl core::hash::Hash for Contig {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.fingerprint);
+    }
+}
+
+impl PartialOrd for Contig {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
     }
 }
 
-impl std::str::FromStr for Contig {
+impl Ord for Contig {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        if Arc::ptr_eq(&self.name, &other.name) {
+            return core::cmp::Ordering::Equal;
+        }
+
+        self.name.cmp(&other.name)
+    }
+}
+
+impl core::str::FromStr for Contig {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
@@ -124,16 +206,41 @@ impl TryFrom<String> for Contig {
     }
 }
 
-impl std::ops::Deref for Contig {
+impl core::ops::Deref for Contig {
     type Target = str;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.name
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Contig {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Contig {
+    /// Deserializes a contig, validating its name as [`Contig::try_new()`]
+    /// does.
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Self::try_new(name).map_err(serde::de::Error::custom)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
     use super::*;
 
     #[test]
@@ -171,7 +278,49 @@ mod tests {
         let b = a.clone();
         assert_eq!(a, b);
         // Both point to the same underlying `str`.
-        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+        assert!(core::ptr::eq(a.as_str(), b.as_str()));
+    }
+
+    #[test]
+    fn equality_and_hashing() {
+        use core::hash::Hash;
+        use core::hash::Hasher;
+
+        /// A hasher that records the values written to it.
+        #[derive(Default)]
+        struct Recorder(Vec<u8>);
+
+        impl Hasher for Recorder {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.extend_from_slice(bytes);
+            }
+        }
+
+        let hash = |contig: &Contig| {
+            let mut recorder = Recorder::default();
+            contig.hash(&mut recorder);
+            recorder.0
+        };
+
+        // Separately allocated contigs with the same name are equal and hash
+        // the same way.
+        let a = Contig::new_unchecked("chr1");
+        let b = Contig::new_unchecked(String::from("chr1"));
+        assert!(!core::ptr::eq(a.as_str(), b.as_str()));
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+
+        // Hashing only writes the fingerprint.
+        assert_eq!(hash(&a).len(), 8);
+
+        let c = Contig::new_unchecked("chr2");
+        assert_ne!(a, c);
+        assert_ne!(hash(&a), hash(&c));
+        assert!(a < c);
     }
 
     #[test]
@@ -203,4 +352,15 @@ mod tests {
         let err = Contig::try_from(String::from("")).expect_err("empty should fail");
         assert_eq!(err, Error::Empty);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let contig = Contig::try_new("chr1").unwrap();
+        let json = serde_json::to_string(&contig).unwrap();
+        assert_eq!(json, r#""chr1""#);
+        assert_eq!(serde_json::from_str::<Contig>(&json).unwrap(), contig);
+
+        assert!(serde_json::from_str::<Contig>(r#""""#).is_err());
+    }
 }
@@ -0,0 +1,671 @@
+//! Aliases between contig naming schemes.
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::System;
+use crate::interval;
+use crate::position;
+
+/// The character that begins a header or comment line.
+#[cfg(feature = "std")]
+const COMMENT_PREFIX: char = '#';
+
+/// The delimiter between columns.
+#[cfg(feature = "std")]
+const DELIMITER: char = '\t';
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing an alias table.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A row had no name in the first (canonical) column.
+    #[error("line {line}: missing canonical contig name")]
+    MissingCanonical {
+        /// The line number (starting at one).
+        line: usize,
+    },
+
+    /// A row had more columns than the header.
+    #[error("line {line}: expected at most {expected} columns, found {found}")]
+    Columns {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The number of columns in the header.
+        expected: usize,
+
+        /// The number of columns in the row.
+        found: usize,
+    },
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to an alias table.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// A conflicting alias error.
+    ///
+    /// This error occurs when the same name is listed for more than one
+    /// contig.
+    #[error("contig name `{0}` is an alias for more than one contig")]
+    Conflict(Contig),
+
+    /// An I/O error.
+    #[cfg(feature = "std")]
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A missing alias error.
+    ///
+    /// This error occurs when a contig is known but has no name within the
+    /// requested naming scheme.
+    #[error("contig `{contig}` has no name in the `{scheme}` naming scheme")]
+    MissingAlias {
+        /// The contig.
+        contig: Contig,
+
+        /// The naming scheme.
+        scheme: String,
+    },
+
+    /// A parse error.
+    #[error("parse error: {0}")]
+    Parse(#[from] ParseError),
+
+    /// An unknown naming scheme error.
+    #[error("unknown naming scheme: `{0}`")]
+    UnknownScheme(String),
+
+    /// An unmapped contig error.
+    ///
+    /// This error occurs when a contig name is not present within the table.
+    #[error("contig `{0}` has no alias mapping")]
+    Unmapped(Contig),
+
+    /// A too many names error.
+    ///
+    /// This error occurs when a contig is inserted with more names than the
+    /// table has naming schemes.
+    #[error("expected at most {expected} names, found {found}")]
+    TooManyNames {
+        /// The number of naming schemes.
+        expected: usize,
+
+        /// The number of names.
+        found: usize,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Alias table
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A table of contig aliases across naming schemes.
+///
+/// Each row of the table describes a single contig and each column describes
+/// a single naming scheme (e.g., UCSC, Ensembl, or RefSeq). The name in the
+/// first column is the _canonical_ name of the contig.
+///
+/// Because [`Contig`]s compare by their raw names, `chr1:+:100` and `1:+:100`
+/// are different coordinates. An alias table resolves this by canonicalizing
+/// (or translating) contigs, coordinates, and intervals into a single naming
+/// scheme before they are compared.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::contig::AliasTable;
+/// use omics_coordinate::system::Base;
+///
+/// let tsv = "# ucsc\tensembl\trefseq\nchr1\t1\tNC_000001.11\nchrM\tMT\tNC_012920.1\n";
+/// let table = AliasTable::read_tsv(tsv.as_bytes())?;
+///
+/// let coordinate = "1:+:100".parse::<Coordinate<Base>>()?;
+/// let coordinate = table.canonicalize_coordinate(coordinate)?;
+/// assert_eq!(coordinate.to_string(), "chr1:+:100");
+///
+/// let coordinate = table.translate_coordinate(coordinate, "refseq")?;
+/// assert_eq!(coordinate.to_string(), "NC_000001.11:+:100");
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AliasTable {
+    /// The names of the naming schemes (one per column).
+    ///
+    /// This is empty if the table was loaded without a header.
+    schemes: Vec<String>,
+
+    /// The names for each contig (one per column).
+    rows: Vec<Vec<Option<Contig>>>,
+
+    /// A lookup from each name to the index of its row.
+    names: crate::Map<Arc<str>, usize>,
+}
+
+impl AliasTable {
+    /// Creates an empty alias table with the provided naming schemes (one per
+    /// column).
+    ///
+    /// Contigs are added to the table with [`insert()`](Self::insert). To
+    /// create a table without named schemes, use [`AliasTable::default()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::contig::AliasTable;
+    ///
+    /// let mut table = AliasTable::new(["ucsc", "ensembl"]);
+    /// table.insert(
+    ///     Contig::new_unchecked("chr1"),
+    ///     [Some(Contig::new_unchecked("1"))],
+    /// )?;
+    /// table.insert(Contig::new_unchecked("chrUn_KI270302v1"), [None])?;
+    ///
+    /// assert_eq!(table.schemes(), &["ucsc", "ensembl"]);
+    /// assert_eq!(table.len(), 2);
+    ///
+    /// let contig = table.translate(&Contig::new_unchecked("chr1"), "ensembl")?;
+    /// assert_eq!(contig.as_str(), "1");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(schemes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            schemes: schemes.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Attempts to add a contig to the table.
+    ///
+    /// The canonical name is followed by the names of the contig within each
+    /// subsequent naming scheme, where [`None`] denotes that the contig has no
+    /// name within that naming scheme. If the table has named schemes and more
+    /// names are provided than there are schemes, an [`Error::TooManyNames`]
+    /// is returned. If any of the names is already an alias for another
+    /// contig, an [`Error::Conflict`] is returned and the table is left
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::contig::AliasTable;
+    ///
+    /// let mut table = AliasTable::default();
+    /// table.insert(
+    ///     Contig::new_unchecked("chrM"),
+    ///     [Some(Contig::new_unchecked("MT"))],
+    /// )?;
+    ///
+    /// let contig = table.canonicalize(&Contig::new_unchecked("MT"))?;
+    /// assert_eq!(contig.as_str(), "chrM");
+    ///
+    /// let err = table
+    ///     .insert(
+    ///         Contig::new_unchecked("chrMT"),
+    ///         [Some(Contig::new_unchecked("MT"))],
+    ///     )
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "contig name `MT` is an alias for more than one contig"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert(
+        &mut self,
+        canonical: Contig,
+        aliases: impl IntoIterator<Item = Option<Contig>>,
+    ) -> Result<()> {
+        let row = core::iter::once(Some(canonical))
+            .chain(aliases)
+            .collect::<Vec<_>>();
+
+        if !self.schemes.is_empty() && row.len() > self.schemes.len() {
+            return Err(Error::TooManyNames {
+                expected: self.schemes.len(),
+                found: row.len(),
+            });
+        }
+
+        self.push(row)
+    }
+
+    /// Attempts to read an alias table from a chromAlias-style TSV.
+    ///
+    /// Each line contains the names of a single contig separated by tabs,
+    /// starting with its canonical name. Empty columns denote that a contig has
+    /// no name within that naming scheme. If the first line begins with `#`,
+    /// it is treated as a header naming each column's naming scheme; any
+    /// other lines beginning with `#` are treated as comments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::contig::AliasTable;
+    ///
+    /// let tsv = "# ucsc\tensembl\nchr1\t1\nchrUn_KI270302v1\t\n";
+    /// let table = AliasTable::read_tsv(tsv.as_bytes())?;
+    ///
+    /// assert_eq!(table.schemes(), &["ucsc", "ensembl"]);
+    /// assert_eq!(table.len(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_tsv(reader: impl BufRead) -> Result<Self> {
+        let mut table = Self::default();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let number = i + 1;
+
+            if let Some(header) = line.strip_prefix(COMMENT_PREFIX) {
+                if i == 0 {
+                    table.schemes = header
+                        .split(DELIMITER)
+                        .map(|scheme| scheme.trim().to_string())
+                        .collect();
+                }
+
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let columns = line.split(DELIMITER).collect::<Vec<_>>();
+
+            if !table.schemes.is_empty() && columns.len() > table.schemes.len() {
+                return Err(ParseError::Columns {
+                    line: number,
+                    expected: table.schemes.len(),
+                    found: columns.len(),
+                }
+                .into());
+            }
+
+            // NOTE: empty names are treated as missing, so every contig
+            // created here is guaranteed to have a non-empty name.
+            let row = columns
+                .into_iter()
+                .map(|name| match name.trim() {
+                    "" => None,
+                    name => Some(Contig::new_unchecked(name)),
+                })
+                .collect::<Vec<_>>();
+
+            if !matches!(row.first(), Some(Some(_))) {
+                return Err(ParseError::MissingCanonical { line: number }.into());
+            }
+
+            table.push(row)?;
+        }
+
+        Ok(table)
+    }
+
+    /// Attempts to add a row of names to the table.
+    ///
+    /// The table is left unchanged if any of the names conflict.
+    fn push(&mut self, row: Vec<Option<Contig>>) -> Result<()> {
+        let index = self.rows.len();
+
+        if let Some(name) = row
+            .iter()
+            .flatten()
+            .find(|name| self.names.contains_key(name.as_str()))
+        {
+            return Err(Error::Conflict(name.clone()));
+        }
+
+        for name in row.iter().flatten() {
+            self.names.insert(name.shared().clone(), index);
+        }
+
+        self.rows.push(row);
+        Ok(())
+    }
+
+    /// Gets the names of the naming schemes (one per column).
+    ///
+    /// This is empty if the table was loaded without a header.
+    pub fn schemes(&self) -> &[String] {
+        &self.schemes
+    }
+
+    /// Gets the number of contigs in the table.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns whether or not the table contains any contigs.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Gets the row for a contig name.
+    fn row(&self, contig: &Contig) -> Result<&[Option<Contig>]> {
+        self.names
+            .get(contig.as_str())
+            .map(|index| self.rows[*index].as_slice())
+            .ok_or_else(|| Error::Unmapped(contig.clone()))
+    }
+
+    /// Canonicalizes a contig name.
+    ///
+    /// If the name is not present in the table, an [`Error::Unmapped`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::contig::AliasTable;
+    ///
+    /// let table = AliasTable::read_tsv("chr1\t1\tNC_000001.11\n".as_bytes())?;
+    ///
+    /// let contig = table.canonicalize(&Contig::new_unchecked("NC_000001.11"))?;
+    /// assert_eq!(contig.as_str(), "chr1");
+    ///
+    /// assert!(table.canonicalize(&Contig::new_unchecked("2")).is_err());
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonicalize(&self, contig: &Contig) -> Result<Contig> {
+        // SAFETY: every row is checked to have a canonical name when it is
+        // added to the table.
+        Ok(self.row(contig)?[0].clone().unwrap())
+    }
+
+    /// Translates a contig name into the provided naming scheme.
+    ///
+    /// If the naming scheme is not present in the table, an
+    /// [`Error::UnknownScheme`] is returned. If the name is not present in the
+    /// table, an [`Error::Unmapped`] is returned. If the contig has no name in
+    /// the naming scheme, an [`Error::MissingAlias`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::contig::AliasTable;
+    ///
+    /// let tsv = "# ucsc\tensembl\nchr1\t1\nchrUn_KI270302v1\t\n";
+    /// let table = AliasTable::read_tsv(tsv.as_bytes())?;
+    ///
+    /// let contig = table.translate(&Contig::new_unchecked("chr1"), "ensembl")?;
+    /// assert_eq!(contig.as_str(), "1");
+    ///
+    /// let contig = Contig::new_unchecked("chrUn_KI270302v1");
+    /// assert!(table.translate(&contig, "ensembl").is_err());
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn translate(&self, contig: &Contig, scheme: &str) -> Result<Contig> {
+        let column = self
+            .schemes
+            .iter()
+            .position(|s| s == scheme)
+            .ok_or_else(|| Error::UnknownScheme(scheme.to_string()))?;
+
+        self.row(contig)?
+            .get(column)
+            .cloned()
+            .flatten()
+            .ok_or_else(|| Error::MissingAlias {
+                contig: contig.clone(),
+                scheme: scheme.to_string(),
+            })
+    }
+
+    /// Canonicalizes the contig of a coordinate.
+    ///
+    /// See [`canonicalize()`](Self::canonicalize) for more details.
+    pub fn canonicalize_coordinate<S: System>(
+        &self,
+        coordinate: Coordinate<S>,
+    ) -> Result<Coordinate<S>>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let contig = self.canonicalize(coordinate.contig())?;
+        Ok(coordinate.with_contig(contig))
+    }
+
+    /// Translates the contig of a coordinate into the provided naming scheme.
+    ///
+    /// See [`translate()`](Self::translate) for more details.
+    pub fn translate_coordinate<S: System>(
+        &self,
+        coordinate: Coordinate<S>,
+        scheme: &str,
+    ) -> Result<Coordinate<S>>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let contig = self.translate(coordinate.contig(), scheme)?;
+        Ok(coordinate.with_contig(contig))
+    }
+
+    /// Canonicalizes the contig of an interval.
+    ///
+    /// See [`canonicalize()`](Self::canonicalize) for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::contig::AliasTable;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let table = AliasTable::read_tsv("chrM\tMT\n".as_bytes())?;
+    ///
+    /// let interval = "MT:-:100-0".parse::<Interval<Interbase>>()?;
+    /// let interval = table.canonicalize_interval(interval)?;
+    /// assert_eq!(interval.to_string(), "chrM:-:100-0");
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonicalize_interval<S: System>(&self, interval: Interval<S>) -> Result<Interval<S>>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let contig = self.canonicalize(interval.contig())?;
+        Ok(interval.with_contig(contig))
+    }
+
+    /// Translates the contig of an interval into the provided naming scheme.
+    ///
+    /// See [`translate()`](Self::translate) for more details.
+    pub fn translate_interval<S: System>(
+        &self,
+        interval: Interval<S>,
+        scheme: &str,
+    ) -> Result<Interval<S>>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let contig = self.translate(interval.contig(), scheme)?;
+        Ok(interval.with_contig(contig))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    #[cfg(feature = "std")]
+    const TSV: &str = "# ucsc\tassembly\tensembl\trefseq\nchr1\t1\t1\tNC_000001.11\n# a \
+                       comment\n\nchrM\tMT\tMT\tNC_012920.1\nchrUn_KI270302v1\t\t\n";
+
+    /// Builds the table described by `TSV` without reading it.
+    fn table() -> AliasTable {
+        let mut table = AliasTable::new(["ucsc", "assembly", "ensembl", "refseq"]);
+
+        for (canonical, aliases) in [
+            ("chr1", [Some("1"), Some("1"), Some("NC_000001.11")]),
+            ("chrM", [Some("MT"), Some("MT"), Some("NC_012920.1")]),
+            ("chrUn_KI270302v1", [None, None, None]),
+        ] {
+            let aliases = aliases.map(|alias| alias.map(Contig::new_unchecked));
+            table
+                .insert(Contig::new_unchecked(canonical), aliases)
+                .unwrap();
+        }
+
+        table
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read() {
+        let table = AliasTable::read_tsv(TSV.as_bytes()).unwrap();
+
+        assert_eq!(table.schemes(), &["ucsc", "assembly", "ensembl", "refseq"]);
+        assert_eq!(table.len(), 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_errors() {
+        let err = AliasTable::read_tsv("# a\tb\nchr1\t1\t2\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: line 2: expected at most 2 columns, found 3"
+        );
+
+        let err = AliasTable::read_tsv("\t1\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error: line 1: missing canonical contig name"
+        );
+
+        let err = AliasTable::read_tsv("chr1\t1\nchr2\t1\n".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "contig name `1` is an alias for more than one contig"
+        );
+    }
+
+    #[test]
+    fn insert() {
+        let mut table = AliasTable::new(["ucsc", "ensembl"]);
+        table
+            .insert(
+                Contig::new_unchecked("chr1"),
+                [Some(Contig::new_unchecked("1"))],
+            )
+            .unwrap();
+
+        // The same name may be listed more than once for a single contig.
+        table
+            .insert(
+                Contig::new_unchecked("chrM"),
+                [Some(Contig::new_unchecked("chrM"))],
+            )
+            .unwrap();
+        assert_eq!(table.len(), 2);
+
+        let err = table
+            .insert(Contig::new_unchecked("chr2"), [None, None])
+            .unwrap_err();
+        assert_eq!(err.to_string(), "expected at most 2 names, found 3");
+
+        let err = table
+            .insert(
+                Contig::new_unchecked("chr2"),
+                [Some(Contig::new_unchecked("1"))],
+            )
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "contig name `1` is an alias for more than one contig"
+        );
+
+        // A failed insertion leaves the table unchanged.
+        assert_eq!(table.len(), 2);
+        assert!(table.canonicalize(&Contig::new_unchecked("chr2")).is_err());
+    }
+
+    #[test]
+    fn canonicalize_and_translate() {
+        let table = table();
+
+        let contig = Contig::new_unchecked("MT");
+        assert_eq!(table.canonicalize(&contig).unwrap().as_str(), "chrM");
+        assert_eq!(
+            table.translate(&contig, "refseq").unwrap().as_str(),
+            "NC_012920.1"
+        );
+
+        let err = table.translate(&contig, "genbank").unwrap_err();
+        assert_eq!(err.to_string(), "unknown naming scheme: `genbank`");
+
+        let contig = Contig::new_unchecked("chrUn_KI270302v1");
+        let err = table.translate(&contig, "refseq").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "contig `chrUn_KI270302v1` has no name in the `refseq` naming scheme"
+        );
+
+        let err = table
+            .canonicalize(&Contig::new_unchecked("chr2"))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "contig `chr2` has no alias mapping");
+    }
+
+    #[test]
+    fn coordinates_and_intervals() {
+        let table = table();
+
+        let coordinate = "NC_000001.11:-:5".parse::<Coordinate<Interbase>>().unwrap();
+        let coordinate = table.translate_coordinate(coordinate, "ensembl").unwrap();
+        assert_eq!(coordinate.to_string(), "1:-:5");
+
+        let interval = "chrM:+:1-10".parse::<Interval<Base>>().unwrap();
+        let interval = table.translate_interval(interval, "assembly").unwrap();
+        assert_eq!(interval.to_string(), "MT:+:1-10");
+
+        let interval = table.canonicalize_interval(interval).unwrap();
+        assert_eq!(interval.to_string(), "chrM:+:1-10");
+    }
+}
@@ -0,0 +1,286 @@
+//! Interned contigs.
+
+use alloc::vec::Vec;
+
+use string_interner::StringInterner;
+use string_interner::Symbol as _;
+use string_interner::backend::StringBackend;
+use string_interner::symbol::SymbolU32;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::System;
+use crate::contig;
+use crate::coordinate;
+use crate::interval;
+use crate::position;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Symbols
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An interned contig name.
+///
+/// A symbol is a small, [`Copy`] handle to a contig name stored within a
+/// [`ContigPool`]. Equality, ordering, and hashing of symbols are integer
+/// operations. Symbols are only meaningful with respect to the pool that
+/// created them, and they are ordered by the time at which their contig name
+/// was first interned.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(SymbolU32);
+
+impl Symbol {
+    /// Gets the index of the symbol within its pool.
+    ///
+    /// Indices are dense: the `n`th distinct contig name interned into a pool
+    /// receives index `n - 1`.
+    pub fn index(&self) -> usize {
+        self.0.to_usize()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Pool
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A pool of interned contigs.
+///
+/// Each distinct contig name is stored exactly once. Interning a name returns
+/// a [`Symbol`] for cheap comparisons, and a pooled [`Contig`] can be
+/// retrieved for any symbol. Because pooled contigs share the same underlying
+/// allocation, creating a contig from a pool (including when parsing
+/// coordinates and intervals with
+/// [`parse_coordinate()`](Self::parse_coordinate) and
+/// [`parse_interval()`](Self::parse_interval)) never allocates for a name
+/// that has been seen before.
+///
+/// Sharing an allocation also means that pooled contigs with the same name
+/// are equal by pointer comparison alone, while pooled contigs with different
+/// names are told apart by their fingerprints (see [`Contig`]). Comparing the
+/// contigs of pooled coordinates and intervals is thus an integer operation.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::contig::ContigPool;
+///
+/// let mut pool = ContigPool::new();
+///
+/// let a = pool.intern("chr1")?;
+/// let b = pool.intern("chr2")?;
+/// assert_ne!(a, b);
+/// assert_eq!(pool.intern("chr1")?, a);
+///
+/// assert_eq!(pool.resolve(a).unwrap().as_str(), "chr1");
+/// assert_eq!(pool.len(), 2);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ContigPool {
+    /// The interner mapping names to symbols.
+    ///
+    /// The string backend hands out dense, sequential symbols, which allows
+    /// them to be used as indices into `contigs`.
+    interner: StringInterner<StringBackend<SymbolU32>>,
+
+    /// The pooled contigs indexed by symbol.
+    contigs: Vec<Contig>,
+}
+
+impl ContigPool {
+    /// Creates a new, empty contig pool.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of distinct contigs in the pool.
+    pub fn len(&self) -> usize {
+        self.contigs.len()
+    }
+
+    /// Returns whether or not the pool contains any contigs.
+    pub fn is_empty(&self) -> bool {
+        self.contigs.is_empty()
+    }
+
+    /// Attempts to intern a contig name, returning its symbol.
+    ///
+    /// Returns an error if the contig name is empty.
+    pub fn intern(&mut self, name: &str) -> contig::Result<Symbol> {
+        if name.is_empty() {
+            return Err(contig::Error::Empty);
+        }
+
+        let symbol = self.interner.get_or_intern(name);
+
+        if symbol.to_usize() == self.contigs.len() {
+            self.contigs.push(Contig::new_unchecked(name));
+        }
+
+        Ok(Symbol(symbol))
+    }
+
+    /// Gets the symbol for a contig name if it has already been interned.
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.interner.get(name).map(Symbol)
+    }
+
+    /// Resolves a symbol to its pooled contig.
+    ///
+    /// Returns [`None`] if the symbol was not created by this pool.
+    pub fn resolve(&self, symbol: Symbol) -> Option<&Contig> {
+        self.contigs.get(symbol.index())
+    }
+
+    /// Attempts to get a pooled contig for a contig name, interning the name if
+    /// it has not been seen before.
+    ///
+    /// Returns an error if the contig name is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::contig::ContigPool;
+    ///
+    /// let mut pool = ContigPool::new();
+    ///
+    /// let a = pool.contig("chr1")?;
+    /// let b = pool.contig("chr1")?;
+    ///
+    /// // Both contigs share the same underlying allocation, so comparing them
+    /// // does not compare their names.
+    /// assert!(core::ptr::eq(a.as_str(), b.as_str()));
+    /// assert_eq!(a, b);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contig(&mut self, name: &str) -> contig::Result<Contig> {
+        let symbol = self.intern(name)?;
+        Ok(self.contigs[symbol.index()].clone())
+    }
+
+    /// Attempts to parse a coordinate using a pooled contig.
+    ///
+    /// This accepts the same syntax as
+    /// [`Coordinate::from_str()`](core::str::FromStr::from_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::contig::ContigPool;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let mut pool = ContigPool::new();
+    ///
+    /// let a = pool.parse_coordinate::<Interbase>("seq0:+:1")?;
+    /// let b = pool.parse_coordinate::<Interbase>("seq0:-:5")?;
+    /// assert!(core::ptr::eq(a.contig().as_str(), b.contig().as_str()));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_coordinate<S: System>(&mut self, s: &str) -> coordinate::Result<Coordinate<S>>
+    where
+        Position<S>: position::r#trait::Position<S>,
+    {
+        Coordinate::parse_with(s, |name| self.contig(name))
+    }
+
+    /// Attempts to parse an interval using a pooled contig.
+    ///
+    /// This accepts the same syntax as
+    /// [`Interval::from_str()`](core::str::FromStr::from_str).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::contig::ContigPool;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let mut pool = ContigPool::new();
+    ///
+    /// let interval = pool.parse_interval::<Base>("seq0:+:1-10")?;
+    /// assert_eq!(interval.to_string(), "seq0:+:1-10");
+    /// assert_eq!(pool.len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_interval<S: System>(&mut self, s: &str) -> interval::Result<Interval<S>>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        Interval::parse_with(s, |name| self.contig(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    #[test]
+    fn interning() {
+        let mut pool = ContigPool::new();
+        assert!(pool.is_empty());
+
+        let a = pool.intern("chr1").unwrap();
+        let b = pool.intern("chr2").unwrap();
+        let c = pool.intern("chr1").unwrap();
+
+        assert_eq!(a, c);
+        assert_ne!(a, b);
+        assert_eq!(a.index(), 0);
+        assert_eq!(b.index(), 1);
+        assert_eq!(pool.get("chr2"), Some(b));
+        assert_eq!(pool.get("chr3"), None);
+        assert_eq!(pool.resolve(b).unwrap().as_str(), "chr2");
+        assert_eq!(pool.len(), 2);
+
+        let err = pool.intern("").unwrap_err();
+        assert_eq!(err, contig::Error::Empty);
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn parsing() {
+        let mut pool = ContigPool::new();
+
+        let coordinate = pool.parse_coordinate::<Base>("seq0:+:1").unwrap();
+        assert_eq!(coordinate, "seq0:+:1".parse::<Coordinate<Base>>().unwrap());
+
+        let interval = pool.parse_interval::<Interbase>("seq0:-:10-0").unwrap();
+        assert_eq!(
+            interval,
+            "seq0:-:10-0".parse::<Interval<Interbase>>().unwrap()
+        );
+        assert!(core::ptr::eq(
+            coordinate.contig().as_str(),
+            interval.contig().as_str()
+        ));
+
+        assert_eq!(pool.len(), 1);
+
+        let err = pool.parse_coordinate::<Base>(":+:1").unwrap_err();
+        assert_eq!(err, ":+:1".parse::<Coordinate<Base>>().unwrap_err(),);
+
+        // Contig names from rejected input are not interned.
+        let err = pool.parse_coordinate::<Base>("seq1:+:0").unwrap_err();
+        assert_eq!(err, "seq1:+:0".parse::<Coordinate<Base>>().unwrap_err(),);
+
+        let err = pool.parse_coordinate::<Base>("seq1:?:1").unwrap_err();
+        assert_eq!(err, "seq1:?:1".parse::<Coordinate<Base>>().unwrap_err(),);
+
+        let err = pool.parse_interval::<Base>("seq1:+:10-1").unwrap_err();
+        assert_eq!(err, "seq1:+:10-1".parse::<Interval<Base>>().unwrap_err(),);
+
+        let err = pool.parse_interval::<Base>("seq1:+:1-x").unwrap_err();
+        assert_eq!(err, "seq1:+:1-x".parse::<Interval<Base>>().unwrap_err(),);
+
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.get("seq1"), None);
+    }
+}
@@ -1,5 +1,9 @@
 //! Coordinates.
 
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::string::ToString;
+
 use omics_core::VARIANT_SEPARATOR;
 use thiserror::Error;
 
@@ -9,7 +13,7 @@ use crate::Strand;
 use crate::System;
 use crate::contig;
 use crate::position;
-use crate::position::Number;
+use crate::position::Width;
 use crate::strand;
 
 pub mod base;
@@ -32,8 +36,8 @@ pub enum ParseError {
     },
 }
 
-/// A [`Result`](std::result::Result) with a [`ParseError`].
-pub type ParseResult<T> = std::result::Result<T, ParseError>;
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
 
 /// An error related to a coordinate.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -55,8 +59,8 @@ pub enum Error {
     Position(#[from] position::Error),
 }
 
-/// A [`Result`](std::result::Result) with an [`Error`](enum@Error).
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
 
 ////////////////////////////////////////////////////////////////////////////////////////
 // The `Coordinate` trait
@@ -68,13 +72,13 @@ pub mod r#trait {
 
     /// Requirements to be a coordinate.
     pub trait Coordinate<S: System>:
-        std::fmt::Display
-        + std::fmt::Debug
+        core::fmt::Display
+        + core::fmt::Debug
         + PartialEq
         + Eq
         + PartialOrd
         + Ord
-        + std::str::FromStr<Err = Error>
+        + core::str::FromStr<Err = Error>
     where
         Self: Sized,
     {
@@ -82,7 +86,7 @@ pub mod r#trait {
         fn try_new(
             contig: impl TryInto<Contig, Error = contig::Error>,
             strand: impl TryInto<Strand, Error = strand::Error>,
-            position: Number,
+            position: S::Number,
         ) -> Result<Self>;
     }
 }
@@ -140,8 +144,8 @@ impl<'a, S: System> CoordinateRef<'a, S> {
     }
 }
 
-impl<S: System> std::fmt::Display for CoordinateRef<'_, S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<S: System> core::fmt::Display for CoordinateRef<'_, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}:{}:{}", self.contig, self.strand, self.position)
     }
 }
@@ -204,7 +208,7 @@ where
     pub fn try_new(
         contig: impl TryInto<Contig, Error = contig::Error>,
         strand: impl TryInto<Strand, Error = strand::Error>,
-        position: Number,
+        position: S::Number,
     ) -> Result<Self>
     where
         Self: r#trait::Coordinate<S>,
@@ -367,13 +371,13 @@ where
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn move_forward(&mut self, magnitude: Number) -> bool {
-        if magnitude == 0 {
+    pub fn move_forward(&mut self, magnitude: S::Number) -> bool {
+        if magnitude == S::Number::ZERO {
             return true;
         }
 
         let result = match self.strand {
-            Strand::Positive => self.position.checked_add(magnitude),
+            Strand::Positive | Strand::Unstranded => self.position.checked_add(magnitude),
             Strand::Negative => self.position.checked_sub(magnitude),
         };
 
@@ -426,13 +430,13 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[must_use = "this method returns a new coordinate"]
-    pub fn into_move_forward(self, magnitude: Number) -> Option<Coordinate<S>> {
-        if magnitude == 0 {
+    pub fn into_move_forward(self, magnitude: S::Number) -> Option<Coordinate<S>> {
+        if magnitude == S::Number::ZERO {
             return Some(self);
         }
 
         match self.strand {
-            Strand::Positive => self.position.checked_add(magnitude),
+            Strand::Positive | Strand::Unstranded => self.position.checked_add(magnitude),
             Strand::Negative => self.position.checked_sub(magnitude),
         }
         .map(|position| Self::new(self.contig, self.strand, position))
@@ -478,13 +482,13 @@ where
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn move_backward(&mut self, magnitude: Number) -> bool {
-        if magnitude == 0 {
+    pub fn move_backward(&mut self, magnitude: S::Number) -> bool {
+        if magnitude == S::Number::ZERO {
             return true;
         }
 
         let result = match self.strand {
-            Strand::Positive => self.position.checked_sub(magnitude),
+            Strand::Positive | Strand::Unstranded => self.position.checked_sub(magnitude),
             Strand::Negative => self.position.checked_add(magnitude),
         };
 
@@ -541,13 +545,13 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[must_use = "this method returns a new coordinate"]
-    pub fn into_move_backward(self, magnitude: Number) -> Option<Coordinate<S>> {
-        if magnitude == 0 {
+    pub fn into_move_backward(self, magnitude: S::Number) -> Option<Coordinate<S>> {
+        if magnitude == S::Number::ZERO {
             return Some(self);
         }
 
         match self.strand {
-            Strand::Positive => self.position.checked_sub(magnitude),
+            Strand::Positive | Strand::Unstranded => self.position.checked_sub(magnitude),
             Strand::Negative => self.position.checked_add(magnitude),
         }
         .map(|position| Self::new(self.contig, self.strand, position))
@@ -590,14 +594,105 @@ where
         let (contig, strand, position) = self.into_parts();
         Coordinate::new(contig, strand.complement(), position)
     }
+
+    /// Parses a coordinate, creating its contig with the provided function.
+    pub(crate) fn parse_with(
+        s: &str,
+        contig: impl FnOnce(&str) -> contig::Result<Contig>,
+    ) -> Result<Self> {
+        let (prefix, position) =
+            s.rsplit_once(VARIANT_SEPARATOR)
+                .ok_or_else(|| ParseError::Format {
+                    value: s.to_owned(),
+                })?;
+        let (name, strand) =
+            prefix
+                .rsplit_once(VARIANT_SEPARATOR)
+                .ok_or_else(|| ParseError::Format {
+                    value: s.to_owned(),
+                })?;
+
+        let strand = strand.parse::<Strand>().map_err(Error::Strand)?;
+        let position = position.parse::<Position<S>>().map_err(Error::Position)?;
+
+        // NOTE: the contig is only created once everything else is known to be
+        // valid so that rejected input never reaches the contig function.
+        let contig = contig(name).map_err(|_| {
+            Error::Parse(ParseError::Format {
+                value: s.to_string(),
+            })
+        })?;
+
+        Ok(Self::new(contig, strand, position))
+    }
+
+    /// Consumes `self` and returns the same coordinate without a strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::Strand;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let coordinate = Coordinate::<Base>::try_new("seq0", "-", 10)?;
+    /// let unstranded = coordinate.into_unstranded();
+    /// assert_eq!(unstranded.strand(), Strand::Unstranded);
+    /// assert_eq!(unstranded.position().get(), 10);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use = "this method returns a new coordinate"]
+    pub fn into_unstranded(self) -> Coordinate<S> {
+        let (contig, _, position) = self.into_parts();
+        Coordinate::new(contig, Strand::Unstranded, position)
+    }
+
+    /// Consumes `self` and attempts to convert the coordinate into a
+    /// coordinate of the same coordinate system with another [`Width`].
+    ///
+    /// If the position cannot be represented within the target width, a
+    /// [`position::Error::Width`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let coordinate = Coordinate::<Interbase>::try_new("seq0", "+", 10)?;
+    /// let wide = coordinate.try_into_width::<u64>()?;
+    /// assert_eq!(wide.to_string(), "seq0:+:10");
+    ///
+    /// let coordinate = "seq0:+:5000000000".parse::<Coordinate<Interbase<u64>>>()?;
+    /// assert!(coordinate.try_into_width::<u32>().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_into_width<W: Width>(self) -> Result<Coordinate<S::WithWidth<W>>> {
+        let (contig, strand, position) = self.into_parts();
+
+        Ok(Coordinate {
+            system: Default::default(),
+            contig,
+            strand,
+            position: position.try_into_width()?,
+        })
+    }
+
+    /// Consumes `self` and returns the same coordinate on a different contig.
+    pub(crate) fn with_contig(self, contig: Contig) -> Coordinate<S> {
+        let (_, strand, position) = self.into_parts();
+        Coordinate::new(contig, strand, position)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////
 // Trait implementations
 ////////////////////////////////////////////////////////////////////////////////////////
 
-impl<S: System> std::fmt::Display for Coordinate<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<S: System> core::fmt::Display for Coordinate<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if !f.alternate() {
             write!(f, "{}:{}:{}", self.contig, self.strand, self.position)
         } else {
@@ -634,35 +729,41 @@ where
     }
 }
 
-impl<S: System> std::str::FromStr for Coordinate<S>
+impl<S: System> core::str::FromStr for Coordinate<S>
 where
     Position<S>: position::r#trait::Position<S>,
 {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let (prefix, position) =
-            s.rsplit_once(VARIANT_SEPARATOR)
-                .ok_or_else(|| ParseError::Format {
-                    value: s.to_owned(),
-                })?;
-        let (contig, strand) =
-            prefix
-                .rsplit_once(VARIANT_SEPARATOR)
-                .ok_or_else(|| ParseError::Format {
-                    value: s.to_owned(),
-                })?;
-
-        let contig = contig.parse::<Contig>().map_err(|_| {
-            Error::Parse(ParseError::Format {
-                value: s.to_string(),
-            })
-        })?;
+        Self::parse_with(s, |name| Contig::try_new(name))
+    }
+}
 
-        let strand = strand.parse::<Strand>().map_err(Error::Strand)?;
-        let position = position.parse::<Position<S>>().map_err(Error::Position)?;
+#[cfg(feature = "serde")]
+impl<S: System> serde::Serialize for Coordinate<S> {
+    /// Serializes a coordinate in its `contig:strand:position` string form.
+    fn serialize<Se: serde::Serializer>(
+        &self,
+        serializer: Se,
+    ) -> core::result::Result<Se::Ok, Se::Error> {
+        serializer.collect_str(self)
+    }
+}
 
-        Ok(Self::new(contig, strand, position))
+#[cfg(feature = "serde")]
+impl<'de, S: System> serde::Deserialize<'de> for Coordinate<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Deserializes a coordinate from its `contig:strand:position` string
+    /// form, validating it as parsing does.
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -680,4 +781,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn unstranded_moves_like_positive() {
+        let mut coordinate = "seq0:.:10".parse::<Coordinate<Interbase>>().unwrap();
+        assert_eq!(coordinate.strand(), Strand::Unstranded);
+
+        assert!(coordinate.move_forward(5));
+        assert_eq!(coordinate.position().get(), 15);
+        assert!(coordinate.move_backward(15));
+        assert_eq!(coordinate.position().get(), 0);
+        assert!(!coordinate.move_backward(1));
+
+        let coordinate = coordinate.swap_strand();
+        assert_eq!(coordinate.strand(), Strand::Unstranded);
+        assert_eq!(coordinate.to_string(), "seq0:.:0");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let coordinate = Coordinate::<Interbase>::try_new("seq0", "-", 0).unwrap();
+        let json = serde_json::to_string(&coordinate).unwrap();
+        assert_eq!(json, r#""seq0:-:0""#);
+        assert_eq!(
+            serde_json::from_str::<Coordinate<Interbase>>(&json).unwrap(),
+            coordinate
+        );
+
+        assert!(serde_json::from_str::<Coordinate<crate::system::Base>>(&json).is_err());
+        assert!(serde_json::from_str::<Coordinate<Interbase>>(r#""seq0:0""#).is_err());
+    }
 }
@@ -3,8 +3,7 @@
 use crate::Strand;
 use crate::contig;
 use crate::coordinate::Error;
-use crate::position;
-use crate::position::base::Position;
+use crate::position::Width;
 use crate::strand;
 use crate::system::Base;
 use crate::system::Interbase;
@@ -12,7 +11,7 @@ use crate::system::Interbase;
 /// A base coordinate.
 pub type Coordinate = crate::Coordinate<Base>;
 
-impl Coordinate {
+impl<W: Width> crate::Coordinate<Base<W>> {
     /// Consumes `self` and attempts to convert the coordinate to the next
     /// interbase coordinate.
     ///
@@ -21,18 +20,20 @@ impl Coordinate {
     /// the coordinate forward within the _same_ coordinate system, you're
     /// almost certainly looking for
     /// [`move_forward()`](crate::Coordinate::move_forward).
-    pub fn nudge_forward(self) -> Option<crate::Coordinate<Interbase>> {
+    pub fn nudge_forward(self) -> Option<crate::Coordinate<Interbase<W>>> {
         let (contig, strand, position) = self.into_parts();
 
         let position = match strand {
-            Strand::Positive => crate::Position::<Interbase>::new(position.get()),
+            Strand::Positive | Strand::Unstranded => {
+                crate::Position::<Interbase<W>>::new(position.get())
+            }
             Strand::Negative => position
                 .get()
-                .checked_sub(1)
-                .map(crate::Position::<Interbase>::new)?,
+                .checked_sub(W::ONE)
+                .map(crate::Position::<Interbase<W>>::new)?,
         };
 
-        Some(crate::Coordinate::<Interbase>::new(
+        Some(crate::Coordinate::<Interbase<W>>::new(
             contig, strand, position,
         ))
     }
@@ -45,35 +46,35 @@ impl Coordinate {
     /// the coordinate backward within the _same_ coordinate system, you're
     /// almost certainly looking for
     /// [`move_backward()`](crate::Coordinate::move_backward).
-    pub fn nudge_backward(self) -> Option<crate::Coordinate<Interbase>> {
+    pub fn nudge_backward(self) -> Option<crate::Coordinate<Interbase<W>>> {
         let (contig, strand, position) = self.into_parts();
 
         let position = match strand {
-            Strand::Positive => position
+            Strand::Positive | Strand::Unstranded => position
                 .get()
-                .checked_sub(1)
-                .map(crate::Position::<Interbase>::new)?,
-            Strand::Negative => crate::Position::<Interbase>::new(position.get()),
+                .checked_sub(W::ONE)
+                .map(crate::Position::<Interbase<W>>::new)?,
+            Strand::Negative => crate::Position::<Interbase<W>>::new(position.get()),
         };
 
-        Some(crate::Coordinate::<Interbase>::new(
+        Some(crate::Coordinate::<Interbase<W>>::new(
             contig, strand, position,
         ))
     }
 }
 
-impl crate::coordinate::r#trait::Coordinate<Base> for Coordinate {
+impl<W: Width> crate::coordinate::r#trait::Coordinate<Base<W>> for crate::Coordinate<Base<W>> {
     fn try_new(
         contig: impl TryInto<crate::Contig, Error = contig::Error>,
         strand: impl TryInto<crate::Strand, Error = strand::Error>,
-        position: position::Number,
+        position: W,
     ) -> super::Result<Self> {
         let contig = contig.try_into().map_err(Error::Contig)?;
         let strand = strand.try_into().map_err(Error::Strand)?;
-        let position = Position::try_new(position).map_err(Error::Position)?;
+        let position = crate::Position::<Base<W>>::try_new(position).map_err(Error::Position)?;
 
         Ok(Self {
-            system: Base,
+            system: Base::default(),
             contig,
             strand,
             position,
@@ -3,8 +3,7 @@
 use crate::Strand;
 use crate::contig;
 use crate::coordinate::Error;
-use crate::position;
-use crate::position::interbase::Position;
+use crate::position::Width;
 use crate::strand;
 use crate::system::Base;
 use crate::system::Interbase;
@@ -12,7 +11,7 @@ use crate::system::Interbase;
 /// An interbase coordinate.
 pub type Coordinate = crate::Coordinate<Interbase>;
 
-impl Coordinate {
+impl<W: Width> crate::Coordinate<Interbase<W>> {
     /// Consumes `self` and attempts converts the coordinate to the next in-base
     /// coordinate.
     ///
@@ -21,15 +20,15 @@ impl Coordinate {
     /// the coordinate forward within the _same_ coordinate system, you're
     /// almost certainly looking for
     /// [`move_forward()`](crate::Coordinate::move_forward).
-    pub fn nudge_forward(self) -> Option<crate::Coordinate<Base>> {
+    pub fn nudge_forward(self) -> Option<crate::Coordinate<Base<W>>> {
         let (contig, strand, position) = self.into_parts();
 
         let position = match strand {
-            Strand::Positive => position
+            Strand::Positive | Strand::Unstranded => position
                 .get()
-                .checked_add(1)
-                .and_then(|value| crate::Position::<Base>::try_new(value).ok()),
-            Strand::Negative => crate::Position::<Base>::try_new(position.get()).ok(),
+                .checked_add(W::ONE)
+                .and_then(|value| crate::Position::<Base<W>>::try_new(value).ok()),
+            Strand::Negative => crate::Position::<Base<W>>::try_new(position.get()).ok(),
         }?;
 
         Some(crate::Coordinate::new(contig, strand, position))
@@ -43,33 +42,37 @@ impl Coordinate {
     /// the coordinate backward within the _same_ coordinate system, you're
     /// almost certainly looking for
     /// [`move_backward()`](crate::Coordinate::move_backward).
-    pub fn nudge_backward(self) -> Option<crate::Coordinate<Base>> {
+    pub fn nudge_backward(self) -> Option<crate::Coordinate<Base<W>>> {
         let (contig, strand, position) = self.into_parts();
 
         let position = match strand {
-            Strand::Positive => crate::Position::<Base>::try_new(position.get()).ok(),
+            Strand::Positive | Strand::Unstranded => {
+                crate::Position::<Base<W>>::try_new(position.get()).ok()
+            }
             Strand::Negative => position
                 .get()
-                .checked_add(1)
-                .and_then(|value| crate::Position::<Base>::try_new(value).ok()),
+                .checked_add(W::ONE)
+                .and_then(|value| crate::Position::<Base<W>>::try_new(value).ok()),
         }?;
 
         Some(crate::Coordinate::new(contig, strand, position))
     }
 }
 
-impl crate::coordinate::r#trait::Coordinate<Interbase> for Coordinate {
+impl<W: Width> crate::coordinate::r#trait::Coordinate<Interbase<W>>
+    for crate::Coordinate<Interbase<W>>
+{
     fn try_new(
         contig: impl TryInto<crate::Contig, Error = contig::Error>,
         strand: impl TryInto<crate::Strand, Error = strand::Error>,
-        position: position::Number,
+        position: W,
     ) -> super::Result<Self> {
         let contig = contig.try_into().map_err(Error::Contig)?;
         let strand = strand.try_into().map_err(Error::Strand)?;
-        let position = Position::new(position);
+        let position = crate::Position::<Interbase<W>>::new(position);
 
         Ok(Self {
-            system: Interbase,
+            system: Interbase::default(),
             contig,
             strand,
             position,
@@ -0,0 +1,666 @@
+//! Run-length encoded coverage over intervals.
+//!
+//! Coverage (or depth) is the number of intervals that contain each entity
+//! along a contig. Intervals (e.g., aligned reads or annotated features) are
+//! collected with an [`Accumulator`], which is then finished into a
+//! [`Coverage`]: a depth track for each contig stored as a sorted list of
+//! maximal runs of entities sharing the same, non-zero depth. Entities not
+//! covered by any run have a depth of zero.
+//!
+//! Coverage is computed per contig and without regard to strand: intervals on
+//! either strand (or on no strand) of the same contig all contribute to the
+//! same track. To compute stranded coverage, accumulate the intervals of each
+//! strand separately.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::cmp::min;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+use crate::system::Base;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Segments
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A maximal run of entities sharing the same, non-zero depth.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Segment<W> {
+    /// The interbase position at which the run starts.
+    start: W,
+
+    /// The interbase position at which the run ends.
+    end: W,
+
+    /// The depth of every entity within the run.
+    depth: u64,
+}
+
+/// Compresses a list of depth changes into a sorted list of segments.
+///
+/// Each event is an interbase position paired with the change in depth at that
+/// position. Directly adjacent segments with equal depths are coalesced.
+fn compress<W: Width>(mut events: Vec<(W, i64)>) -> Vec<Segment<W>> {
+    events.sort_unstable();
+
+    let mut segments: Vec<Segment<W>> = Vec::new();
+    let mut depth = 0i64;
+    let mut previous = W::ZERO;
+
+    for (position, change) in events {
+        if depth > 0 && position > previous {
+            // NOTE: every interval (or previously compressed segment) that
+            // has started and not yet ended contributes a positive amount to
+            // the depth, so it is never negative.
+            let depth = depth as u64;
+
+            match segments.last_mut() {
+                Some(last) if last.end == previous && last.depth == depth => last.end = position,
+                _ => segments.push(Segment {
+                    start: previous,
+                    end: position,
+                    depth,
+                }),
+            }
+        }
+
+        depth += change;
+        previous = position;
+    }
+
+    segments
+}
+
+/// The number of buffered depth changes on a single contig above which they
+/// are compressed into segments.
+const COMPRESSION_THRESHOLD: usize = 1 << 16;
+
+/// The depth track of a single contig while intervals are being accumulated.
+#[derive(Clone, Debug)]
+struct Track<W> {
+    /// The segments compressed so far.
+    segments: Vec<Segment<W>>,
+
+    /// The changes in depth not yet compressed into the segments.
+    events: Vec<(W, i64)>,
+}
+
+impl<W> Default for Track<W> {
+    fn default() -> Self {
+        Self {
+            segments: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+}
+
+impl<W: Width> Track<W> {
+    /// Adds a non-empty, half-open range of interbase positions to the track.
+    ///
+    /// Once the buffered changes outnumber both the threshold and the
+    /// segments, they are compressed into the segments. This bounds the
+    /// buffer by the size of the track rather than by the number of added
+    /// ranges while keeping the amortized cost of each addition logarithmic.
+    fn push(&mut self, start: W, end: W) {
+        self.events.push((start, 1));
+        self.events.push((end, -1));
+
+        if self.events.len() >= max(COMPRESSION_THRESHOLD, self.segments.len()) {
+            self.compress();
+        }
+    }
+
+    /// Compresses the buffered changes into the segments.
+    fn compress(&mut self) {
+        let mut events = core::mem::take(&mut self.events);
+
+        events.extend(self.segments.iter().flat_map(|segment| {
+            // NOTE: a depth is bounded by the number of added ranges, which
+            // cannot exceed `i64::MAX`.
+            let depth = segment.depth as i64;
+            [(segment.start, depth), (segment.end, -depth)]
+        }));
+
+        self.segments = compress(events);
+    }
+
+    /// Consumes `self` and returns the compressed segments.
+    fn finish(mut self) -> Vec<Segment<W>> {
+        self.compress();
+        self.segments
+    }
+}
+
+/// Gets the number of entities shared with a half-open range of interbase
+/// positions and the depth of each segment that overlaps the range.
+fn overlapping<W: Width>(
+    segments: &[Segment<W>],
+    start: W,
+    end: W,
+) -> impl Iterator<Item = (u64, u64)> + '_ {
+    let first = segments.partition_point(|segment| segment.end <= start);
+
+    segments[first..]
+        .iter()
+        .take_while(move |segment| segment.start < end)
+        .map(move |segment| {
+            let length = min(end, segment.end) - max(start, segment.start);
+            (length.to_u64(), segment.depth)
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Accumulators
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An accumulator of intervals into a [`Coverage`].
+///
+/// Intervals may be added in any order. Zero-sized interbase intervals contain
+/// no entities and are therefore ignored.
+///
+/// The depth changes of each contig are buffered and periodically compressed
+/// into runs, so memory use grows with the number of runs in the resulting
+/// coverage rather than with the number of added intervals.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::coverage::Accumulator;
+/// use omics_coordinate::system::Interbase;
+///
+/// let mut accumulator = Accumulator::<Interbase>::new();
+/// accumulator.add(&"seq0:+:0-10".parse::<Interval<Interbase>>()?);
+/// accumulator.add(&"seq0:-:15-5".parse::<Interval<Interbase>>()?);
+///
+/// let coverage = accumulator.finish();
+/// let runs = coverage
+///     .runs()
+///     .map(|run| run.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     runs,
+///     vec!["seq0\t0\t5\t1", "seq0\t5\t10\t2", "seq0\t10\t15\t1"]
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Accumulator<S: System> {
+    /// The depth track for each contig.
+    tracks: BTreeMap<Contig, Track<S::Number>>,
+}
+
+impl<S: System> Default for Accumulator<S> {
+    fn default() -> Self {
+        Self {
+            tracks: Default::default(),
+        }
+    }
+}
+
+impl<S: System> Accumulator<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a new, empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an interval to the accumulator.
+    pub fn add(&mut self, interval: &Interval<S>) {
+        let (start, end) = interval.entity_range();
+
+        if start >= end {
+            return;
+        }
+
+        self.tracks
+            .entry(interval.contig().clone())
+            .or_default()
+            .push(start, end);
+    }
+
+    /// Consumes `self` and computes the coverage of the accumulated
+    /// intervals.
+    pub fn finish(self) -> Coverage<S> {
+        let tracks = self
+            .tracks
+            .into_iter()
+            .map(|(contig, track)| (contig, track.finish()))
+            .filter(|(_, segments)| !segments.is_empty())
+            .collect();
+
+        Coverage {
+            tracks,
+            system: PhantomData,
+        }
+    }
+}
+
+impl<S: System> Extend<Interval<S>> for Accumulator<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn extend<T: IntoIterator<Item = Interval<S>>>(&mut self, iter: T) {
+        for interval in iter {
+            self.add(&interval);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Runs
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A maximal run of entities sharing the same, non-zero depth.
+///
+/// Because coverage is computed without regard to strand, the interval of a
+/// run is always [`Strand::Unstranded`]. A run is displayed as a
+/// [BedGraph](https://genome.ucsc.edu/goldenPath/help/bedgraph.html) line:
+/// the contig, the zero-based, half-open bounds of the run, and the depth,
+/// separated by tabs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Run<S: System> {
+    /// The interval spanned by the run.
+    interval: Interval<S>,
+
+    /// The depth of every entity within the run.
+    depth: u64,
+}
+
+impl<S: System> Run<S> {
+    /// Gets the interval spanned by the run.
+    pub fn interval(&self) -> &Interval<S> {
+        &self.interval
+    }
+
+    /// Gets the depth of every entity within the run.
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+
+    /// Consumes `self` and returns the interval and the depth of the run.
+    pub fn into_parts(self) -> (Interval<S>, u64) {
+        (self.interval, self.depth)
+    }
+}
+
+impl<S: System> core::fmt::Display for Run<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (start, end) = self.interval.entity_range();
+        write!(
+            f,
+            "{}\t{start}\t{end}\t{}",
+            self.interval.contig(),
+            self.depth
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Coverage
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The run-length encoded coverage of a collection of intervals.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::coverage::Coverage;
+/// use omics_coordinate::system::Base;
+///
+/// let coverage = ["seq0:+:1-10", "seq0:+:6-20", "seq0:+:16-20"]
+///     .into_iter()
+///     .map(|value| value.parse::<Interval<Base>>())
+///     .collect::<Result<Coverage<Base>, _>>()?;
+///
+/// assert_eq!(coverage.depth(&Coordinate::try_new("seq0", "+", 7)?), 2);
+/// assert_eq!(coverage.depth(&Coordinate::try_new("seq0", "+", 21)?), 0);
+///
+/// let interval = "seq0:+:1-20".parse::<Interval<Base>>()?;
+/// assert_eq!(coverage.mean_depth(&interval), Some(1.5));
+/// assert_eq!(coverage.fraction_at_least(&interval, 2), Some(0.5));
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Coverage<S: System> {
+    /// The depth track for each contig.
+    tracks: BTreeMap<Contig, Vec<Segment<S::Number>>>,
+
+    /// The coordinate system.
+    system: PhantomData<S>,
+}
+
+impl<S: System> Default for Coverage<S> {
+    fn default() -> Self {
+        Self {
+            tracks: Default::default(),
+            system: PhantomData,
+        }
+    }
+}
+
+impl<S: System> Coverage<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a new coverage in which every entity has a depth of zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of runs within the coverage.
+    pub fn len(&self) -> usize {
+        self.tracks.values().map(Vec::len).sum()
+    }
+
+    /// Returns whether or not any entity has a non-zero depth.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+
+    /// Gets the depth of the entity at an in-base coordinate.
+    ///
+    /// The strand of the coordinate is ignored.
+    pub fn depth(&self, coordinate: &Coordinate<Base<S::Number>>) -> u64 {
+        let offset = coordinate.position().get() - S::Number::ONE;
+
+        overlapping(
+            self.track(coordinate.contig()),
+            offset,
+            offset + S::Number::ONE,
+        )
+        .next()
+        .map(|(_, depth)| depth)
+        .unwrap_or_default()
+    }
+
+    /// Gets the mean depth of the entities within an interval.
+    ///
+    /// The strand of the interval is ignored. If the interval contains no
+    /// entities, [`None`] is returned.
+    pub fn mean_depth(&self, interval: &Interval<S>) -> Option<f64> {
+        let (start, end) = interval.entity_range();
+        let count = self.count(start, end)?;
+
+        let total = overlapping(self.track(interval.contig()), start, end)
+            .map(|(length, depth)| u128::from(length) * u128::from(depth))
+            .sum::<u128>();
+
+        Some(total as f64 / count as f64)
+    }
+
+    /// Gets the fraction of the entities within an interval that have a depth
+    /// at or above a threshold.
+    ///
+    /// The strand of the interval is ignored. If the interval contains no
+    /// entities, [`None`] is returned.
+    pub fn fraction_at_least(&self, interval: &Interval<S>, threshold: u64) -> Option<f64> {
+        let (start, end) = interval.entity_range();
+        let count = self.count(start, end)?;
+
+        if threshold == 0 {
+            return Some(1.0);
+        }
+
+        let covered = overlapping(self.track(interval.contig()), start, end)
+            .filter(|(_, depth)| *depth >= threshold)
+            .map(|(length, _)| length)
+            .sum::<u64>();
+
+        Some(covered as f64 / count as f64)
+    }
+
+    /// Gets an iterator over the runs of entities with a non-zero depth.
+    ///
+    /// Runs are ordered lexicographically by contig and then by position,
+    /// which is the order expected of a BedGraph file. Entities with a depth
+    /// of zero are not included.
+    pub fn runs(&self) -> impl Iterator<Item = Run<S>> + '_ {
+        self.tracks.iter().flat_map(|(contig, segments)| {
+            segments.iter().map(|segment| {
+                // SAFETY: every segment contains at least one entity, so this
+                // will always unwrap.
+                let interval = <Interval<S> as interval::r#trait::Interval<S>>::from_entity_bounds(
+                    contig.clone(),
+                    Strand::Unstranded,
+                    segment.start,
+                    segment.end,
+                )
+                .unwrap();
+
+                Run {
+                    interval,
+                    depth: segment.depth,
+                }
+            })
+        })
+    }
+
+    /// Writes the runs of entities with a non-zero depth as BedGraph lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::coverage::Coverage;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let coverage = ["seq1:+:0-10", "seq0:+:5-10", "seq0:+:5-20"]
+    ///     .into_iter()
+    ///     .map(|value| value.parse::<Interval<Interbase>>())
+    ///     .collect::<Result<Coverage<Interbase>, _>>()?;
+    ///
+    /// let mut buffer = Vec::new();
+    /// coverage.write_bedgraph(&mut buffer)?;
+    ///
+    /// assert_eq!(
+    ///     String::from_utf8(buffer)?,
+    ///     "seq0\t5\t10\t2\nseq0\t10\t20\t1\nseq1\t0\t10\t1\n"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_bedgraph<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        for run in self.runs() {
+            writeln!(writer, "{run}")?;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the number of entities within a half-open range of interbase
+    /// positions, or [`None`] if the range is empty.
+    fn count(&self, start: S::Number, end: S::Number) -> Option<u64> {
+        (start < end).then(|| (end - start).to_u64())
+    }
+
+    /// Gets the segments of the depth track for a contig.
+    fn track(&self, contig: &Contig) -> &[Segment<S::Number>] {
+        self.tracks.get(contig).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<S: System> FromIterator<Interval<S>> for Coverage<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn from_iter<T: IntoIterator<Item = Interval<S>>>(iter: T) -> Self {
+        let mut accumulator = Accumulator::new();
+        accumulator.extend(iter);
+        accumulator.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::*;
+    use crate::system::Interbase;
+    use crate::testing::Lcg;
+
+    fn coverage(values: &[&str]) -> Coverage<Interbase> {
+        values
+            .iter()
+            .map(|value| value.parse::<Interval<Interbase>>().unwrap())
+            .collect()
+    }
+
+    fn interval(value: &str) -> Interval<Interbase> {
+        value.parse().unwrap()
+    }
+
+    fn runs(coverage: &Coverage<Interbase>) -> Vec<(String, u64)> {
+        coverage
+            .runs()
+            .map(|run| (run.interval().to_string(), run.depth()))
+            .collect()
+    }
+
+    #[test]
+    fn coalesces_adjacent_runs_with_equal_depths() {
+        let coverage = coverage(&[
+            "seq0:+:0-10",
+            "seq0:+:10-20",
+            "seq0:-:30-20",
+            "seq0:+:25-40",
+        ]);
+
+        assert_eq!(
+            runs(&coverage),
+            vec![
+                (String::from("seq0:.:0-25"), 1),
+                (String::from("seq0:.:25-30"), 2),
+                (String::from("seq0:.:30-40"), 1),
+            ]
+        );
+        assert_eq!(coverage.len(), 3);
+    }
+
+    #[test]
+    fn ignores_zero_sized_intervals() {
+        let coverage = coverage(&["seq0:+:5-5", "seq1:+:0-0"]);
+        assert!(coverage.is_empty());
+        assert_eq!(coverage.runs().count(), 0);
+    }
+
+    #[test]
+    fn computes_summaries_over_gaps_and_unknown_contigs() {
+        let coverage = coverage(&["seq0:+:0-10", "seq0:+:0-10", "seq0:+:20-30"]);
+
+        assert_eq!(coverage.mean_depth(&interval("seq0:+:0-40")), Some(0.75));
+        assert_eq!(coverage.mean_depth(&interval("seq0:-:15-5")), Some(1.0));
+        assert_eq!(coverage.mean_depth(&interval("seq1:+:0-10")), Some(0.0));
+        assert_eq!(coverage.mean_depth(&interval("seq0:+:5-5")), None);
+
+        assert_eq!(
+            coverage.fraction_at_least(&interval("seq0:+:0-40"), 1),
+            Some(0.5)
+        );
+        assert_eq!(
+            coverage.fraction_at_least(&interval("seq0:+:0-40"), 2),
+            Some(0.25)
+        );
+        assert_eq!(
+            coverage.fraction_at_least(&interval("seq0:+:0-40"), 3),
+            Some(0.0)
+        );
+        assert_eq!(
+            coverage.fraction_at_least(&interval("seq1:+:0-10"), 0),
+            Some(1.0)
+        );
+        assert_eq!(coverage.fraction_at_least(&interval("seq0:+:5-5"), 0), None);
+    }
+
+    #[test]
+    fn matches_a_naive_per_entity_count() {
+        let mut lcg = Lcg::new(42);
+        let mut next = |bound: u64| lcg.below(bound);
+
+        let mut depths = [0u64; 100];
+        let mut accumulator = Accumulator::<Interbase>::new();
+
+        for _ in 0..50 {
+            let start = next(100);
+            let end = start + next(100 - start) + 1;
+            depths[start as usize..end as usize]
+                .iter_mut()
+                .for_each(|depth| *depth += 1);
+            accumulator.add(&interval(&format!("seq0:+:{start}-{end}")));
+        }
+
+        let coverage = accumulator.finish();
+
+        for (offset, expected) in depths.iter().enumerate() {
+            let coordinate = Coordinate::<Base>::try_new("seq0", "+", offset as u32 + 1).unwrap();
+            assert_eq!(coverage.depth(&coordinate), *expected);
+        }
+
+        let expected = depths.iter().sum::<u64>() as f64 / 100.0;
+        assert_eq!(
+            coverage.mean_depth(&interval("seq0:+:0-100")),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn compresses_buffered_changes_as_intervals_are_added() {
+        let mut accumulator = Accumulator::<Interbase>::new();
+        let first = interval("seq0:+:0-10");
+        let second = interval("seq0:+:5-15");
+
+        for _ in 0..COMPRESSION_THRESHOLD {
+            accumulator.add(&first);
+            accumulator.add(&second);
+        }
+
+        let track = accumulator.tracks.get(first.contig()).unwrap();
+        assert!(track.events.len() < COMPRESSION_THRESHOLD);
+        assert_eq!(track.segments.len(), 3);
+
+        let depth = COMPRESSION_THRESHOLD as u64;
+        let coverage = accumulator.finish();
+        assert_eq!(
+            runs(&coverage),
+            vec![
+                (String::from("seq0:.:0-5"), depth),
+                (String::from("seq0:.:5-10"), depth * 2),
+                (String::from("seq0:.:10-15"), depth),
+            ]
+        );
+    }
+}
@@ -0,0 +1,1825 @@
+//! GFF3 and GTF records.
+//!
+//! The [GFF3](https://github.com/The-Sequence-Ontology/Specifications/blob/master/gff3.md)
+//! and [GTF](https://useast.ensembl.org/info/website/upload/gff.html) formats
+//! describe features using one-based, fully-closed coordinates, which map
+//! exactly onto [`Interval<Base>`]. The two formats share the same eight
+//! leading columns and differ only in the syntax of their attributes column.
+//! This module reads and writes both as typed [`Record`]s and assembles
+//! records into gene → transcript → exon/CDS hierarchies (see [`assemble()`]).
+//! Records are generic over the [`Width`] of their positions, so files
+//! describing contigs longer than [`u32::MAX`] can be read as `Record<u64>`.
+
+use alloc::collections::BTreeMap;
+use alloc::collections::BTreeSet;
+use alloc::collections::btree_map::Entry;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::contig;
+#[cfg(feature = "std")]
+use crate::contig::ContigPool;
+use crate::position::Number;
+use crate::position::Width;
+use crate::system::Base;
+
+/// The delimiter between columns.
+const DELIMITER: char = '\t';
+
+/// The value denoting a missing column.
+const MISSING: &str = ".";
+
+/// The number of columns in a record.
+const COLUMNS: usize = 9;
+
+/// The GFF3 attribute containing the identifier of a feature.
+const GFF3_ID: &str = "ID";
+
+/// The GFF3 attribute containing the identifiers of the parents of a feature.
+const GFF3_PARENT: &str = "Parent";
+
+/// The GTF attribute containing the identifier of a gene.
+const GTF_GENE_ID: &str = "gene_id";
+
+/// The GTF attribute containing the identifier of a transcript.
+const GTF_TRANSCRIPT_ID: &str = "transcript_id";
+
+/// The types of records that are parts of a transcript rather than
+/// transcripts themselves, in both their Sequence Ontology (GFF3) and GTF
+/// spellings.
+const TRANSCRIPT_PARTS: &[&str] = &[
+    "exon",
+    "CDS",
+    "intron",
+    "UTR",
+    "five_prime_UTR",
+    "three_prime_UTR",
+    "five_prime_utr",
+    "three_prime_utr",
+    "start_codon",
+    "stop_codon",
+    "Selenocysteine",
+];
+
+/// The characters that are percent-encoded within GFF3 attributes in addition
+/// to those that are percent-encoded within every column.
+const ATTRIBUTE_RESERVED: &[char] = &[';', '=', '&', ','];
+
+/// The directive that ends the records of a GFF3 file.
+#[cfg(feature = "std")]
+const FASTA_DIRECTIVE: &str = "##FASTA";
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing a GFF3 or GTF record.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// Too few columns were present.
+    #[error("expected {COLUMNS} columns, found {0}")]
+    MissingColumns(usize),
+
+    /// An invalid contig name was encountered.
+    #[error("invalid contig name: {0}")]
+    Contig(contig::Error),
+
+    /// An invalid value was encountered in a column.
+    #[error("invalid {column} `{value}`")]
+    Invalid {
+        /// The name of the column.
+        column: &'static str,
+
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+
+    /// The start of the record is after its end.
+    #[error("start `{start}` is greater than end `{end}`")]
+    NegativelySized {
+        /// The start position.
+        start: u64,
+
+        /// The end position.
+        end: u64,
+    },
+
+    /// An invalid attribute was encountered.
+    #[error("invalid attribute `{0}`")]
+    Attribute(String),
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to reading GFF3 or GTF records.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// An I/O error.
+    #[cfg(feature = "std")]
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A parse error on a particular line.
+    #[error("line {line}: {inner}")]
+    Parse {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The inner error.
+        inner: ParseError,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// An error related to assembling records into a hierarchy.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum HierarchyError {
+    /// A duplicate identifier error.
+    ///
+    /// This error occurs when more than one gene or transcript shares the
+    /// same identifier.
+    #[error("duplicate identifier: `{0}`")]
+    Duplicate(String),
+
+    /// A nested feature error.
+    ///
+    /// This error occurs when a feature's parent is neither a gene nor a
+    /// transcript.
+    #[error("parent `{0}` is neither a gene nor a transcript")]
+    Nested(String),
+
+    /// An unknown parent error.
+    ///
+    /// This error occurs when a feature's parent is not present within the
+    /// records.
+    #[error("unknown parent: `{0}`")]
+    UnknownParent(String),
+
+    /// An inconsistent children error.
+    ///
+    /// This error occurs when a GTF gene or transcript (or the implicit
+    /// transcript of a gene) must be synthesized from child records that lie
+    /// on different contigs or strands.
+    #[error("children of `{0}` lie on different contigs or strands")]
+    Inconsistent(String),
+}
+
+/// A [`Result`](core::result::Result) with a [`HierarchyError`].
+pub type HierarchyResult<T> = core::result::Result<T, HierarchyError>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Formats and phases
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The format of a record.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// GFF3, whose attributes are written as `key=value` pairs.
+    Gff3,
+
+    /// GTF (also known as GFF2.5), whose attributes are written as `key
+    /// "value";` pairs.
+    Gtf,
+}
+
+/// The phase of a coding feature.
+///
+/// The phase is the number of entities that must be removed from the start of
+/// the feature (in the direction of its strand) to reach the first complete
+/// codon.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Phase {
+    /// The feature begins with a complete codon.
+    Zero,
+
+    /// One entity precedes the first complete codon.
+    One,
+
+    /// Two entities precede the first complete codon.
+    Two,
+}
+
+impl core::fmt::Display for Phase {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Phase::Zero => write!(f, "0"),
+            Phase::One => write!(f, "1"),
+            Phase::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl core::str::FromStr for Phase {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        match s {
+            "0" => Ok(Phase::Zero),
+            "1" => Ok(Phase::One),
+            "2" => Ok(Phase::Two),
+            _ => Err(ParseError::Invalid {
+                column: "phase",
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Attributes
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The attributes of a record.
+///
+/// Attributes are kept in the order in which they were first encountered. A
+/// key may have more than one value: GFF3 separates values with commas
+/// (e.g., `Parent=tx0,tx1`), while GTF repeats the key (e.g., `tag "basic";
+/// tag "CCDS";`).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attributes(Vec<(String, Vec<String>)>);
+
+impl Attributes {
+    /// Gets the first value of an attribute (if it exists).
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.get_all(key).first().map(String::as_str)
+    }
+
+    /// Gets all values of an attribute.
+    ///
+    /// If the attribute does not exist, an empty slice is returned.
+    pub fn get_all(&self, key: &str) -> &[String] {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, values)| values.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Appends a value to an attribute, adding the attribute if it does not
+    /// yet exist.
+    pub fn push(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+
+        match self.0.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, values)) => values.push(value.into()),
+            None => self.0.push((key, vec![value.into()])),
+        }
+    }
+
+    /// Gets an iterator over the attributes and their values.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.0
+            .iter()
+            .map(|(key, values)| (key.as_str(), values.as_slice()))
+    }
+
+    /// Gets the number of attributes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether or not there are any attributes.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Attempts to parse GFF3 attributes.
+    fn parse_gff3(s: &str) -> ParseResult<Self> {
+        let mut attributes = Self::default();
+
+        for pair in s.split(';').filter(|pair| !pair.trim().is_empty()) {
+            let (key, values) = pair
+                .split_once('=')
+                .ok_or_else(|| ParseError::Attribute(pair.to_string()))?;
+            let key = percent_decode(key.trim())?;
+
+            for value in values.split(',') {
+                attributes.push(key.clone(), percent_decode(value)?);
+            }
+        }
+
+        Ok(attributes)
+    }
+
+    /// Attempts to parse GTF attributes.
+    fn parse_gtf(s: &str) -> ParseResult<Self> {
+        let mut attributes = Self::default();
+        let mut rest = s.trim_start_matches([' ', ';']);
+
+        while !rest.is_empty() {
+            let (key, remainder) = rest
+                .split_once(' ')
+                .ok_or_else(|| ParseError::Attribute(rest.to_string()))?;
+            let remainder = remainder.trim_start();
+
+            let (value, remainder) = match remainder.strip_prefix('"') {
+                Some(quoted) => {
+                    let (value, remainder) =
+                        unquote(quoted).ok_or_else(|| ParseError::Attribute(rest.to_string()))?;
+                    let remainder = remainder.trim_start();
+
+                    if !remainder.is_empty() && !remainder.starts_with(';') {
+                        return Err(ParseError::Attribute(rest.to_string()));
+                    }
+
+                    (value, remainder)
+                }
+                None => {
+                    let (value, remainder) = remainder.split_once(';').unwrap_or((remainder, ""));
+                    (value.trim_end().to_string(), remainder)
+                }
+            };
+
+            attributes.push(key, value);
+            rest = remainder.trim_start_matches([' ', ';']);
+        }
+
+        Ok(attributes)
+    }
+}
+
+/// Decodes the percent-encoded characters within a GFF3 attribute.
+fn percent_decode(s: &str) -> ParseResult<String> {
+    if !s.contains('%') {
+        return Ok(s.to_string());
+    }
+
+    let invalid = || ParseError::Attribute(s.to_string());
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+
+    while let Some(byte) = iter.next() {
+        if byte != b'%' {
+            bytes.push(byte);
+            continue;
+        }
+
+        let hex = [
+            iter.next().ok_or_else(invalid)?,
+            iter.next().ok_or_else(invalid)?,
+        ];
+        let hex = core::str::from_utf8(&hex).map_err(|_| invalid())?;
+        bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+    }
+
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+/// Writes a GFF3 column or attribute, percent-encoding the percent sign,
+/// control characters (including tabs and newlines), and any of the
+/// `reserved` characters.
+fn write_percent_encoded(
+    f: &mut core::fmt::Formatter<'_>,
+    s: &str,
+    reserved: &[char],
+) -> core::fmt::Result {
+    for c in s.chars() {
+        match c {
+            c if c == '%' || c.is_ascii_control() || reserved.contains(&c) => {
+                write!(f, "%{:02X}", c as u32)?
+            }
+            c => write!(f, "{c}")?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads a quoted GTF attribute value following its opening quote.
+///
+/// Backslash escapes are resolved. Returns the value and the remainder
+/// following the closing quote, or [`None`] if the value is not closed.
+fn unquote(s: &str) -> Option<(String, &str)> {
+    let mut value = String::new();
+    let mut chars = s.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &s[i + 1..])),
+            '\\' => match chars.next()?.1 {
+                't' => value.push('\t'),
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                c => value.push(c),
+            },
+            c => value.push(c),
+        }
+    }
+
+    None
+}
+
+/// Writes a quoted GTF attribute value, escaping quotes, backslashes, tabs,
+/// and newlines with a backslash.
+fn write_quoted(f: &mut core::fmt::Formatter<'_>, s: &str) -> core::fmt::Result {
+    write!(f, "\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\t' => write!(f, "\\t")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            c => write!(f, "{c}")?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Records
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A GFF3 or GTF record.
+///
+/// The strand of the record is carried by its interval: records with a `.`
+/// strand are [`Strand::Unstranded`]. The GFF3 `?` strand (a relevant but
+/// unknown strand) is also parsed as [`Strand::Unstranded`], so the
+/// distinction is not retained and such records are written back with a `.`
+/// strand. Optional columns containing `.` are represented as [`None`].
+///
+/// GFF3 records percent-encode the percent sign and control characters
+/// (such as tabs and newlines) within their seqid, source, and type columns,
+/// along with the reserved characters of their attributes. GTF attribute
+/// values are quoted, with quotes, backslashes, tabs, and newlines escaped by
+/// a backslash. Both forms are decoded when parsing, so records are written
+/// back exactly as they were read.
+///
+/// The positions of a record are represented using `W` (see [`Width`]), which
+/// defaults to [`Number`].
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Strand;
+/// use omics_coordinate::gff::Format;
+/// use omics_coordinate::gff::Phase;
+/// use omics_coordinate::gff::Record;
+///
+/// let line = "chr1\tHAVANA\tCDS\t65565\t65573\t.\t-\t0\tID=cds0;Parent=tx0,tx1";
+/// let record: Record = Record::parse(line, Format::Gff3)?;
+///
+/// assert_eq!(record.interval().to_string(), "chr1:-:65573-65565");
+/// assert_eq!(record.ty(), "CDS");
+/// assert_eq!(record.strand(), Strand::Negative);
+/// assert_eq!(record.phase(), Some(Phase::Zero));
+/// assert_eq!(record.id(), Some("cds0"));
+/// assert_eq!(record.parents(), &["tx0", "tx1"]);
+///
+/// assert_eq!(record.to_string(), line);
+///
+/// let line = "chr1\t.\tgene\t4500000001\t4500000100\t.\t+\t.\tID=g0";
+/// let record = Record::<u64>::parse(line, Format::Gff3)?;
+/// assert_eq!(record.interval().count_entities(), 100);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record<W: Width = Number> {
+    /// The format.
+    format: Format,
+
+    /// The interval (columns 1, 4, 5, and 7).
+    interval: Interval<Base<W>>,
+
+    /// The source (column 2).
+    source: String,
+
+    /// The feature type (column 3).
+    ty: String,
+
+    /// The score (column 6).
+    score: Option<f64>,
+
+    /// The phase (column 8).
+    phase: Option<Phase>,
+
+    /// The attributes (column 9).
+    attributes: Attributes,
+}
+
+impl<W: Width> Record<W> {
+    /// Creates a new record for an interval without a score, phase, or
+    /// attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::gff::Format;
+    /// use omics_coordinate::gff::Record;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let interval = "chr1:+:11869-14409".parse::<Interval<Base>>()?;
+    /// let record = Record::new(Format::Gtf, interval, "HAVANA", "gene")
+    ///     .with_attribute("gene_id", "ENSG00000223972.5")
+    ///     .with_attribute("gene_name", "DDX11L1");
+    ///
+    /// assert_eq!(
+    ///     record.to_string(),
+    ///     "chr1\tHAVANA\tgene\t11869\t14409\t.\t+\t.\tgene_id \"ENSG00000223972.5\"; gene_name \
+    ///      \"DDX11L1\";"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        format: Format,
+        interval: Interval<Base<W>>,
+        source: impl Into<String>,
+        ty: impl Into<String>,
+    ) -> Self {
+        Self {
+            format,
+            interval,
+            source: source.into(),
+            ty: ty.into(),
+            score: None,
+            phase: None,
+            attributes: Default::default(),
+        }
+    }
+
+    /// Consumes `self` and returns a record with the provided score.
+    #[must_use = "this method returns a new record"]
+    pub fn with_score(mut self, score: f64) -> Self {
+        self.score = Some(score);
+        self
+    }
+
+    /// Consumes `self` and returns a record with the provided phase.
+    #[must_use = "this method returns a new record"]
+    pub fn with_phase(mut self, phase: Phase) -> Self {
+        self.phase = Some(phase);
+        self
+    }
+
+    /// Consumes `self` and returns a record with a value appended to an
+    /// attribute.
+    #[must_use = "this method returns a new record"]
+    pub fn with_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes.push(key, value);
+        self
+    }
+
+    /// Gets the format.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Gets the interval.
+    pub fn interval(&self) -> &Interval<Base<W>> {
+        &self.interval
+    }
+
+    /// Consumes `self` and returns the interval.
+    pub fn into_interval(self) -> Interval<Base<W>> {
+        self.interval
+    }
+
+    /// Gets the contig (also known as the sequence identifier).
+    pub fn contig(&self) -> &Contig {
+        self.interval.contig()
+    }
+
+    /// Gets the strand.
+    pub fn strand(&self) -> Strand {
+        self.interval.strand()
+    }
+
+    /// Gets the source.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Gets the feature type (e.g., `gene`, `exon`, or `CDS`).
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /// Gets the score (if it exists).
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// Gets the phase (if it exists).
+    pub fn phase(&self) -> Option<Phase> {
+        self.phase
+    }
+
+    /// Gets the attributes.
+    pub fn attributes(&self) -> &Attributes {
+        &self.attributes
+    }
+
+    /// Gets the identifier of the feature (if it exists).
+    ///
+    /// For GFF3 records, this is the `ID` attribute. For GTF records, this is
+    /// the `gene_id` attribute of `gene` records and the `transcript_id`
+    /// attribute of `transcript` records—other GTF records have no
+    /// identifier.
+    pub fn id(&self) -> Option<&str> {
+        match self.format {
+            Format::Gff3 => self.attributes.get(GFF3_ID),
+            Format::Gtf => match self.ty.as_str() {
+                "gene" => self.attributes.get(GTF_GENE_ID),
+                "transcript" => self.attributes.get(GTF_TRANSCRIPT_ID),
+                _ => None,
+            },
+        }
+    }
+
+    /// Gets the identifiers of the parents of the feature.
+    ///
+    /// For GFF3 records, these are the values of the `Parent` attribute. For
+    /// GTF records, `gene` records have no parents, `transcript` records are
+    /// children of their `gene_id`, and all other records are children of
+    /// their `transcript_id`.
+    pub fn parents(&self) -> &[String] {
+        match self.format {
+            Format::Gff3 => self.attributes.get_all(GFF3_PARENT),
+            Format::Gtf => match self.ty.as_str() {
+                "gene" => &[],
+                "transcript" => self.attributes.get_all(GTF_GENE_ID),
+                _ => self.attributes.get_all(GTF_TRANSCRIPT_ID),
+            },
+        }
+    }
+
+    /// Attempts to parse a record from a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::gff::Format;
+    /// use omics_coordinate::gff::Record;
+    ///
+    /// let line = "chr1\tHAVANA\texon\t11869\t12227\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\"; \
+    ///             tag \"basic\"; tag \"CCDS\";";
+    /// let record: Record = Record::parse(line, Format::Gtf)?;
+    ///
+    /// assert_eq!(record.id(), None);
+    /// assert_eq!(record.parents(), &["t0"]);
+    /// assert_eq!(record.attributes().get_all("tag"), &["basic", "CCDS"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse(line: &str, format: Format) -> ParseResult<Self> {
+        Self::parse_with(line, format, |name| Contig::try_new(name))
+    }
+
+    /// Attempts to parse a record from a line, creating its contig with the
+    /// provided function.
+    pub(crate) fn parse_with(
+        line: &str,
+        format: Format,
+        contig: impl FnOnce(&str) -> contig::Result<Contig>,
+    ) -> ParseResult<Self> {
+        let fields = line.splitn(COLUMNS, DELIMITER).collect::<Vec<_>>();
+
+        if fields.len() < COLUMNS {
+            return Err(ParseError::MissingColumns(fields.len()));
+        }
+
+        // NOTE: the seqid, source, and type columns of GFF3 records may
+        // contain percent-encoded characters.
+        let column = |index: usize, name: &'static str| match format {
+            Format::Gff3 => percent_decode(fields[index]).map_err(|_| ParseError::Invalid {
+                column: name,
+                value: fields[index].to_string(),
+            }),
+            Format::Gtf => Ok(fields[index].to_string()),
+        };
+
+        let name = column(0, "seqid")?;
+        let source = column(1, "source")?;
+        let ty = column(2, "type")?;
+
+        let contig = contig(&name).map_err(ParseError::Contig)?;
+        let start = parse_position::<W>(fields[3], "start")?;
+        let end = parse_position(fields[4], "end")?;
+
+        if start > end {
+            return Err(ParseError::NegativelySized {
+                start: start.get().to_u64(),
+                end: end.get().to_u64(),
+            });
+        }
+
+        let strand = match fields[6] {
+            "+" => Strand::Positive,
+            "-" => Strand::Negative,
+            "." | "?" => Strand::Unstranded,
+            value => {
+                return Err(ParseError::Invalid {
+                    column: "strand",
+                    value: value.to_string(),
+                });
+            }
+        };
+
+        let score = optional(fields[5])
+            .map(|value| {
+                value.parse::<f64>().map_err(|_| ParseError::Invalid {
+                    column: "score",
+                    value: value.to_string(),
+                })
+            })
+            .transpose()?;
+
+        let phase = optional(fields[7])
+            .map(|value| value.parse::<Phase>())
+            .transpose()?;
+
+        let attributes = match optional(fields[8].trim_end()) {
+            Some(value) => match format {
+                Format::Gff3 => Attributes::parse_gff3(value)?,
+                Format::Gtf => Attributes::parse_gtf(value)?,
+            },
+            None => Attributes::default(),
+        };
+
+        Ok(Self {
+            format,
+            interval: Interval::from_bounds(contig, strand, start, end),
+            source,
+            ty,
+            score,
+            phase,
+            attributes,
+        })
+    }
+}
+
+/// Parses an in-base position from a column.
+fn parse_position<W: Width>(value: &str, column: &'static str) -> ParseResult<Position<Base<W>>> {
+    value
+        .parse::<W>()
+        .ok()
+        .and_then(|number| Position::<Base<W>>::try_new(number).ok())
+        .ok_or_else(|| ParseError::Invalid {
+            column,
+            value: value.to_string(),
+        })
+}
+
+/// Gets the value of an optional column.
+fn optional(value: &str) -> Option<&str> {
+    Some(value).filter(|value| *value != MISSING && !value.is_empty())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Hierarchies
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A transcript and its child features.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Transcript<W: Width = Number> {
+    /// The transcript record.
+    record: Record<W>,
+
+    /// The exon records.
+    exons: Vec<Record<W>>,
+
+    /// The CDS records.
+    cds: Vec<Record<W>>,
+
+    /// Any other child records (e.g., UTRs and start or stop codons).
+    features: Vec<Record<W>>,
+}
+
+impl<W: Width> Transcript<W> {
+    /// Creates a new transcript without any child features.
+    fn new(record: Record<W>) -> Self {
+        Self {
+            record,
+            exons: Vec::new(),
+            cds: Vec::new(),
+            features: Vec::new(),
+        }
+    }
+
+    /// Creates the implicit transcript of a gene from the first of the
+    /// transcript parts whose parent is the gene itself.
+    fn implicit(part: &Record<W>, gene: &str) -> Self {
+        let record = Record::new(
+            part.format,
+            part.interval.clone(),
+            &part.source,
+            "transcript",
+        );
+
+        let record = match part.format {
+            Format::Gff3 => record.with_attribute(GFF3_PARENT, gene),
+            Format::Gtf => record.with_attribute(GTF_GENE_ID, gene),
+        };
+
+        Self::new(record)
+    }
+
+    /// Gets the transcript record.
+    pub fn record(&self) -> &Record<W> {
+        &self.record
+    }
+
+    /// Gets the exon records.
+    pub fn exons(&self) -> &[Record<W>] {
+        &self.exons
+    }
+
+    /// Gets the CDS records.
+    pub fn cds(&self) -> &[Record<W>] {
+        &self.cds
+    }
+
+    /// Gets any other child records (e.g., UTRs and start or stop codons).
+    pub fn features(&self) -> &[Record<W>] {
+        &self.features
+    }
+}
+
+/// A gene and its transcripts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gene<W: Width = Number> {
+    /// The gene record.
+    record: Record<W>,
+
+    /// The transcripts.
+    transcripts: Vec<Transcript<W>>,
+}
+
+impl<W: Width> Gene<W> {
+    /// Gets the gene record.
+    pub fn record(&self) -> &Record<W> {
+        &self.record
+    }
+
+    /// Gets the transcripts.
+    pub fn transcripts(&self) -> &[Transcript<W>] {
+        &self.transcripts
+    }
+}
+
+/// Assembles records into gene → transcript → exon/CDS hierarchies.
+///
+/// Records are linked by their [identifiers](Record::id) and
+/// [parents](Record::parents):
+///
+/// * Records without parents are genes (this includes top-level records such as
+///   GFF3 `region` records, which simply have no transcripts).
+/// * Records whose parent is a gene are transcripts, unless they are parts of a
+///   transcript by their type (exons, CDS, introns, UTRs, and start and stop
+///   codons).
+/// * Records whose parent is a transcript are sorted into the exons, CDS, and
+///   other features of that transcript by their type.
+///
+/// Transcript parts whose parent is a gene (as in GFF3 files for prokaryotes,
+/// where a `CDS` is commonly the direct child of its `gene`) are gathered
+/// into a single implicit transcript for that gene. The implicit transcript
+/// has a `transcript` type, no identifier, spans all of its parts, takes the
+/// source of its first part, and follows the other transcripts of the gene.
+///
+/// Records may appear in any order. A record with multiple parents (e.g., an
+/// exon shared by two transcripts) is added to each of its parents. Genes,
+/// transcripts, and features retain the order in which they were provided.
+///
+/// GTF files commonly omit `gene` and `transcript` records. When a GTF
+/// record refers to a gene or transcript (through its `gene_id` or
+/// `transcript_id` attribute) that is not present, a record for that gene or
+/// transcript is synthesized: it spans all of its children, takes the source
+/// of its first child, and is placed just before that child. Synthesized
+/// transcripts also inherit the `gene_id` of their first child.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::gff::Format;
+/// use omics_coordinate::gff::Reader;
+/// use omics_coordinate::gff::assemble;
+///
+/// let data = "\
+/// ##gff-version 3
+/// chr1\t.\tgene\t1000\t9000\t.\t+\t.\tID=gene0;Name=EDEN
+/// chr1\t.\tmRNA\t1050\t9000\t.\t+\t.\tID=tx0;Parent=gene0
+/// chr1\t.\texon\t1050\t1500\t.\t+\t.\tParent=tx0
+/// chr1\t.\texon\t5000\t5500\t.\t+\t.\tParent=tx0
+/// chr1\t.\tCDS\t1201\t1500\t.\t+\t0\tID=cds0;Parent=tx0
+/// chr1\t.\tCDS\t5000\t5500\t.\t+\t0\tID=cds0;Parent=tx0
+/// ";
+///
+/// let records = Reader::new(data.as_bytes(), Format::Gff3).collect::<Result<Vec<_>, _>>()?;
+/// let genes = assemble(records)?;
+///
+/// assert_eq!(genes.len(), 1);
+/// assert_eq!(genes[0].record().attributes().get("Name"), Some("EDEN"));
+///
+/// let transcript = &genes[0].transcripts()[0];
+/// assert_eq!(transcript.record().id(), Some("tx0"));
+/// assert_eq!(transcript.exons().len(), 2);
+/// assert_eq!(transcript.cds().len(), 2);
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn assemble<W: Width>(
+    records: impl IntoIterator<Item = Record<W>>,
+) -> HierarchyResult<Vec<Gene<W>>> {
+    // NOTE: transcripts are synthesized first so that any synthesized genes
+    // also span the synthesized transcripts.
+    let records = synthesize(records.into_iter().collect(), "transcript")?;
+    let records = synthesize(records, "gene")?;
+
+    let (genes, rest): (Vec<_>, Vec<_>) = records
+        .into_iter()
+        .partition(|record| record.parents().is_empty());
+
+    let mut gene_ids = BTreeMap::new();
+
+    for (i, gene) in genes.iter().enumerate() {
+        if let Some(id) = gene.id() {
+            if gene_ids.insert(id.to_string(), i).is_some() {
+                return Err(HierarchyError::Duplicate(id.to_string()));
+            }
+        }
+    }
+
+    let mut genes = genes
+        .into_iter()
+        .map(|record| Gene {
+            record,
+            transcripts: Vec::new(),
+        })
+        .collect::<Vec<_>>();
+
+    // The location of every transcript by its identifier, as the indices of
+    // its gene and of the transcript within each of its genes.
+    let mut transcript_ids = BTreeMap::<String, Vec<(usize, usize)>>::new();
+
+    for record in rest.iter().filter(|record| !is_transcript_part(record)) {
+        let mut locations = Vec::new();
+
+        for gene in record
+            .parents()
+            .iter()
+            .filter_map(|parent| gene_ids.get(parent))
+        {
+            locations.push((*gene, genes[*gene].transcripts.len()));
+            genes[*gene]
+                .transcripts
+                .push(Transcript::new(record.clone()));
+        }
+
+        // NOTE: records without a gene as a parent are not transcripts, but
+        // features of a transcript.
+        if locations.is_empty() {
+            continue;
+        }
+
+        if let Some(id) = record.id() {
+            if transcript_ids.insert(id.to_string(), locations).is_some() {
+                return Err(HierarchyError::Duplicate(id.to_string()));
+            }
+        }
+    }
+
+    // The index of the implicit transcript within each gene that holds the
+    // transcript parts whose parent is the gene itself.
+    let mut implicit = BTreeMap::<usize, usize>::new();
+
+    for record in &rest {
+        for parent in record.parents() {
+            let locations = match (transcript_ids.get(parent), gene_ids.get(parent)) {
+                (Some(locations), _) => locations.clone(),
+                (None, Some(_)) if !is_transcript_part(record) => continue,
+                (None, Some(gene)) => {
+                    let transcripts = &mut genes[*gene].transcripts;
+
+                    match implicit.entry(*gene) {
+                        Entry::Vacant(entry) => {
+                            entry.insert(transcripts.len());
+                            transcripts.push(Transcript::implicit(record, parent));
+                        }
+                        Entry::Occupied(entry) => {
+                            let transcript = &mut transcripts[*entry.get()].record;
+                            transcript.interval = transcript
+                                .interval
+                                .span(&record.interval)
+                                .map_err(|_| HierarchyError::Inconsistent(parent.clone()))?;
+                        }
+                    }
+
+                    vec![(*gene, implicit[gene])]
+                }
+                (None, None) => {
+                    if rest.iter().any(|other| other.id() == Some(parent.as_str())) {
+                        return Err(HierarchyError::Nested(parent.clone()));
+                    }
+
+                    return Err(HierarchyError::UnknownParent(parent.clone()));
+                }
+            };
+
+            for (gene, transcript) in locations {
+                let transcript = &mut genes[gene].transcripts[transcript];
+
+                match record.ty() {
+                    "exon" => transcript.exons.push(record.clone()),
+                    "CDS" => transcript.cds.push(record.clone()),
+                    _ => transcript.features.push(record.clone()),
+                }
+            }
+        }
+    }
+
+    Ok(genes)
+}
+
+/// Returns whether a record is a part of a transcript (such as an exon, a CDS,
+/// a UTR, or a start or stop codon) rather than a transcript itself.
+fn is_transcript_part<W: Width>(record: &Record<W>) -> bool {
+    TRANSCRIPT_PARTS.contains(&record.ty.as_str())
+}
+
+/// Synthesizes the GTF records of a type (either `gene` or `transcript`) that
+/// are referred to by other GTF records but are not present themselves.
+fn synthesize<W: Width>(records: Vec<Record<W>>, ty: &str) -> HierarchyResult<Vec<Record<W>>> {
+    let present = records
+        .iter()
+        .filter(|record| record.format == Format::Gtf && record.ty == ty)
+        .filter_map(|record| record.id())
+        .collect::<BTreeSet<_>>();
+
+    // The synthesized records by their identifiers, along with the index of
+    // their first child.
+    let mut synthesized = BTreeMap::<&str, (usize, Record<W>)>::new();
+
+    for (i, record) in records.iter().enumerate() {
+        let is_child = match record.ty.as_str() {
+            "gene" => false,
+            "transcript" => ty == "gene",
+            _ => ty == "transcript",
+        };
+
+        if record.format != Format::Gtf || !is_child {
+            continue;
+        }
+
+        for parent in record.parents() {
+            if present.contains(parent.as_str()) {
+                continue;
+            }
+
+            match synthesized.entry(parent) {
+                Entry::Vacant(entry) => {
+                    let mut synthesized =
+                        Record::new(Format::Gtf, record.interval.clone(), &record.source, ty);
+
+                    if ty == "transcript" {
+                        for gene in record.attributes.get_all(GTF_GENE_ID) {
+                            synthesized.attributes.push(GTF_GENE_ID, gene);
+                        }
+
+                        synthesized.attributes.push(GTF_TRANSCRIPT_ID, parent);
+                    } else {
+                        synthesized.attributes.push(GTF_GENE_ID, parent);
+                    }
+
+                    entry.insert((i, synthesized));
+                }
+                Entry::Occupied(mut entry) => {
+                    let (_, synthesized) = entry.get_mut();
+                    synthesized.interval = synthesized
+                        .interval
+                        .span(&record.interval)
+                        .map_err(|_| HierarchyError::Inconsistent(parent.clone()))?;
+                }
+            }
+        }
+    }
+
+    if synthesized.is_empty() {
+        return Ok(records);
+    }
+
+    let mut synthesized = synthesized.into_values().collect::<Vec<_>>();
+    synthesized.sort_by_key(|(first, _)| *first);
+
+    let mut result = Vec::with_capacity(records.len() + synthesized.len());
+    let mut synthesized = synthesized.into_iter().peekable();
+
+    for (i, record) in records.into_iter().enumerate() {
+        while let Some((_, parent)) = synthesized.next_if(|(first, _)| *first == i) {
+            result.push(parent);
+        }
+
+        result.push(record);
+    }
+
+    Ok(result)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<W: Width> core::fmt::Display for Record<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.format {
+            Format::Gff3 => {
+                for (i, value) in [self.interval.contig().as_str(), &self.source, &self.ty]
+                    .into_iter()
+                    .enumerate()
+                {
+                    if i > 0 {
+                        write!(f, "{DELIMITER}")?;
+                    }
+
+                    write_percent_encoded(f, value, &[])?;
+                }
+            }
+            Format::Gtf => write!(
+                f,
+                "{}{DELIMITER}{}{DELIMITER}{}",
+                self.interval.contig(),
+                self.source,
+                self.ty
+            )?,
+        }
+
+        write!(
+            f,
+            "{DELIMITER}{}{DELIMITER}{}",
+            self.interval.lower().get(),
+            self.interval.upper().get(),
+        )?;
+
+        match self.score {
+            Some(score) => write!(f, "{DELIMITER}{score}")?,
+            None => write!(f, "{DELIMITER}{MISSING}")?,
+        }
+
+        write!(f, "{DELIMITER}{}", self.interval.strand())?;
+
+        match self.phase {
+            Some(phase) => write!(f, "{DELIMITER}{phase}")?,
+            None => write!(f, "{DELIMITER}{MISSING}")?,
+        }
+
+        write!(f, "{DELIMITER}")?;
+
+        if self.attributes.is_empty() {
+            return write!(f, "{MISSING}");
+        }
+
+        match self.format {
+            Format::Gff3 => {
+                for (i, (key, values)) in self.attributes.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ";")?;
+                    }
+
+                    write_percent_encoded(f, key, ATTRIBUTE_RESERVED)?;
+                    write!(f, "=")?;
+
+                    for (j, value) in values.iter().enumerate() {
+                        if j > 0 {
+                            write!(f, ",")?;
+                        }
+
+                        write_percent_encoded(f, value, ATTRIBUTE_RESERVED)?;
+                    }
+                }
+            }
+            Format::Gtf => {
+                // NOTE: repeated keys are written once per value, and every
+                // value is quoted.
+                let pairs = self
+                    .attributes
+                    .iter()
+                    .flat_map(|(key, values)| values.iter().map(move |value| (key, value)));
+
+                for (i, (key, value)) in pairs.enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+
+                    write!(f, "{key} ")?;
+                    write_quoted(f, value)?;
+                    write!(f, ";")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Reading and writing
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A streaming reader of GFF3 or GTF records.
+///
+/// Blank lines, comment lines, and directive lines (lines starting with `#`)
+/// are skipped. Reading stops at a GFF3 `##FASTA` directive, as the remainder
+/// of the file contains sequences rather than records. Contigs are interned
+/// within a [`ContigPool`], so records on the same contig share a single
+/// allocation.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::gff::Format;
+/// use omics_coordinate::gff::Reader;
+///
+/// let data =
+///     "#!genome-build GRCh38\nchr1\tHAVANA\tgene\t11869\t14409\t.\t+\t.\tgene_id \"g0\";\n";
+/// let records = Reader::new(data.as_bytes(), Format::Gtf).collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(records.len(), 1);
+/// assert_eq!(records[0].interval().to_string(), "chr1:+:11869-14409");
+/// assert_eq!(records[0].id(), Some("g0"));
+///
+/// let err = Reader::new(
+///     "chr1\t.\tgene\t20\t10\t.\t+\t.\t.\n".as_bytes(),
+///     Format::Gff3,
+/// )
+/// .next()
+/// .unwrap()
+/// .unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "line 1: start `20` is greater than end `10`"
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Reader<R, W: Width = Number> {
+    /// The inner reader.
+    inner: R,
+
+    /// The format.
+    format: Format,
+
+    /// The pool of contigs seen so far.
+    pool: ContigPool,
+
+    /// The current line number.
+    line: usize,
+
+    /// A buffer for the current line.
+    buffer: String,
+
+    /// Whether the end of the records has been reached.
+    done: bool,
+
+    /// The width of the positions within the records.
+    width: PhantomData<W>,
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead> Reader<R> {
+    /// Creates a new reader.
+    ///
+    /// The reader produces records with positions of the default [`Width`]
+    /// (see [`Reader::with_width()`] to read records of another width).
+    pub fn new(inner: R, format: Format) -> Self {
+        Self {
+            inner,
+            format,
+            pool: Default::default(),
+            line: 0,
+            buffer: Default::default(),
+            done: false,
+            width: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead, W: Width> Reader<R, W> {
+    /// Consumes `self` and returns a reader that produces records with
+    /// positions of another [`Width`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::gff::Format;
+    /// use omics_coordinate::gff::Reader;
+    ///
+    /// let data = "chr1\t.\tgene\t4999999991\t5000000000\t.\t+\t.\tID=g0\n";
+    ///
+    /// let record = Reader::new(data.as_bytes(), Format::Gff3)
+    ///     .with_width::<u64>()
+    ///     .next()
+    ///     .unwrap()?;
+    /// assert_eq!(
+    ///     record.interval().to_string(),
+    ///     "chr1:+:4999999991-5000000000"
+    /// );
+    ///
+    /// assert!(
+    ///     Reader::new(data.as_bytes(), Format::Gff3)
+    ///         .next()
+    ///         .unwrap()
+    ///         .is_err()
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use = "this method returns a new reader"]
+    pub fn with_width<V: Width>(self) -> Reader<R, V> {
+        Reader {
+            inner: self.inner,
+            format: self.format,
+            pool: self.pool,
+            line: self.line,
+            buffer: self.buffer,
+            done: self.done,
+            width: PhantomData,
+        }
+    }
+
+    /// Reads the next record.
+    ///
+    /// Returns [`None`] at the end of the input or at a `##FASTA` directive.
+    pub fn read_record(&mut self) -> Option<Result<Record<W>>> {
+        while !self.done {
+            self.buffer.clear();
+
+            match self.inner.read_line(&mut self.buffer) {
+                Ok(0) => self.done = true,
+                Ok(_) => {}
+                Err(err) => return Some(Err(Error::Io(err))),
+            }
+
+            self.line += 1;
+
+            let line = self.buffer.trim_end_matches(['\n', '\r']);
+
+            if line.starts_with(FASTA_DIRECTIVE) {
+                self.done = true;
+            }
+
+            if self.done || line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let pool = &mut self.pool;
+            return Some(
+                Record::parse_with(line, self.format, |name| pool.contig(name)).map_err(|inner| {
+                    Error::Parse {
+                        line: self.line,
+                        inner,
+                    }
+                }),
+            );
+        }
+
+        None
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: BufRead, W: Width> Iterator for Reader<R, W> {
+    type Item = Result<Record<W>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record()
+    }
+}
+
+/// A writer of GFF3 or GTF records.
+///
+/// Records are written in their own format, so GFF3 and GTF records should
+/// not be mixed within the same output.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::gff::Format;
+/// use omics_coordinate::gff::Reader;
+/// use omics_coordinate::gff::Writer;
+///
+/// let data = "##gff-version 3\nchr1\t.\tgene\t1\t10\t0.5\t-\t.\tID=g0;Note=a%3Bb\n";
+///
+/// let mut writer = Writer::new(Vec::new());
+/// writer.write_directive("gff-version 3")?;
+/// for record in Reader::new(data.as_bytes(), Format::Gff3) {
+///     writer.write_record(&record?)?;
+/// }
+///
+/// assert_eq!(String::from_utf8(writer.into_inner())?, data);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Writer<T> {
+    /// The inner writer.
+    inner: T,
+}
+
+#[cfg(feature = "std")]
+impl<T: Write> Writer<T> {
+    /// Creates a new writer.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Writes a directive line (e.g., `gff-version 3`), which is prefixed
+    /// with `##`.
+    pub fn write_directive(&mut self, directive: &str) -> std::io::Result<()> {
+        writeln!(self.inner, "##{directive}")
+    }
+
+    /// Writes a record as a single line.
+    pub fn write_record<W: Width>(&mut self, record: &Record<W>) -> std::io::Result<()> {
+        writeln!(self.inner, "{record}")
+    }
+
+    /// Consumes `self` and returns the inner writer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gff3(line: &str) -> Record {
+        Record::parse(line, Format::Gff3).unwrap()
+    }
+
+    fn gtf(line: &str) -> Record {
+        Record::parse(line, Format::Gtf).unwrap()
+    }
+
+    #[test]
+    fn missing_values() {
+        let line = "chr1\t.\tregion\t1\t248956422\t.\t.\t.\t.";
+        let record = gff3(line);
+
+        assert_eq!(record.source(), ".");
+        assert_eq!(record.score(), None);
+        assert_eq!(record.strand(), Strand::Unstranded);
+        assert_eq!(record.phase(), None);
+        assert!(record.attributes().is_empty());
+        assert_eq!(record.interval().count_entities(), 248_956_422);
+        assert_eq!(record.to_string(), line);
+    }
+
+    #[test]
+    fn gff3_attributes() {
+        let record = gff3("ctg1\t.\tgene\t1\t9\t.\t+\t.\tID=g0;Note=a%3Db%2Cc%25;Dbxref=x:1,y:2;");
+
+        assert_eq!(record.id(), Some("g0"));
+        assert_eq!(record.attributes().get("Note"), Some("a=b,c%"));
+        assert_eq!(record.attributes().get_all("Dbxref"), &["x:1", "y:2"]);
+        assert!(record.parents().is_empty());
+        assert_eq!(
+            record.to_string(),
+            "ctg1\t.\tgene\t1\t9\t.\t+\t.\tID=g0;Note=a%3Db%2Cc%25;Dbxref=x:1,y:2"
+        );
+    }
+
+    #[test]
+    fn gtf_attributes() {
+        let record = gtf(
+            "chr1\tHAVANA\ttranscript\t11869\t14409\t.\t+\t.\tgene_id \"g0\"; transcript_id \
+             \"t0\"; level 2; note \"a; b\";",
+        );
+
+        assert_eq!(record.id(), Some("t0"));
+        assert_eq!(record.parents(), &["g0"]);
+        assert_eq!(record.attributes().get("level"), Some("2"));
+        assert_eq!(record.attributes().get("note"), Some("a; b"));
+        assert_eq!(
+            record.to_string(),
+            "chr1\tHAVANA\ttranscript\t11869\t14409\t.\t+\t.\tgene_id \"g0\"; transcript_id \
+             \"t0\"; level \"2\"; note \"a; b\";"
+        );
+
+        let record = gtf("chr1\tHAVANA\tgene\t1\t10\t.\t-\t.\tgene_id \"g0\"");
+        assert_eq!(record.id(), Some("g0"));
+        assert!(record.parents().is_empty());
+    }
+
+    #[test]
+    fn special_characters_round_trip() {
+        let interval = "chr\t1%:+:1-10".parse::<Interval<Base>>().unwrap();
+
+        let record = Record::new(Format::Gff3, interval, "a\nsource", "gene%\x01")
+            .with_attribute("Note", "tab\there; a=b, c%");
+        let line = record.to_string();
+        assert_eq!(
+            line,
+            "chr%091%25\ta%0Asource\tgene%25%01\t1\t10\t.\t+\t.\tNote=tab%09here%3B a%3Db%2C c%25"
+        );
+        assert_eq!(Record::parse(&line, Format::Gff3).unwrap(), record);
+
+        let interval = "chr1:+:1-10".parse::<Interval<Base>>().unwrap();
+        let record = Record::new(Format::Gtf, interval, "src", "gene")
+            .with_attribute("gene_id", "g0")
+            .with_attribute("note", "a \"quoted\" \\ value\twith\nbreaks");
+        let line = record.to_string();
+        assert!(line.ends_with(r#"note "a \"quoted\" \\ value\twith\nbreaks";"#));
+        assert_eq!(Record::parse(&line, Format::Gtf).unwrap(), record);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = Record::<Number>::parse;
+
+        let err = parse("chr1\t.\tgene\t1\t10", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "expected 9 columns, found 5");
+
+        let err = parse("chr1\t.\tgene\t0\t10\t.\t+\t.\t.", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid start `0`");
+
+        let err = parse("chr1\t.\tgene\t1\t10\thigh\t+\t.\t.", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid score `high`");
+
+        let err = parse("chr1\t.\tgene\t1\t10\t.\t*\t.\t.", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid strand `*`");
+
+        let err = parse("chr1\t.\tCDS\t1\t10\t.\t+\t3\t.", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid phase `3`");
+
+        let err = parse("chr1\t.\tgene\t1\t10\t.\t+\t.\tID", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid attribute `ID`");
+
+        let err = parse("chr1\t.\tgene\t1\t10\t.\t+\t.\tID=a%2", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid attribute `a%2`");
+
+        let err = parse("chr1\t.\tgene\t1\t10\t.\t+\t.\tgene_id \"g0", Format::Gtf).unwrap_err();
+        assert_eq!(err.to_string(), "invalid attribute `gene_id \"g0`");
+
+        let err = parse("chr%1\t.\tgene\t1\t10\t.\t+\t.\t.", Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid seqid `chr%1`");
+    }
+
+    #[test]
+    fn wide_positions() {
+        let line = "chr1\t.\tgene\t4999999991\t5000000000\t.\t-\t.\tID=g0";
+        let record = Record::<u64>::parse(line, Format::Gff3).unwrap();
+        assert_eq!(record.interval().start().position().get(), 5_000_000_000);
+        assert_eq!(record.to_string(), line);
+
+        let err = Record::<Number>::parse(line, Format::Gff3).unwrap_err();
+        assert_eq!(err.to_string(), "invalid start `4999999991`");
+
+        let err = Record::<u64>::parse("chr1\t.\tgene\t5000000000\t1\t.\t+\t.\t.", Format::Gff3)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "start `5000000000` is greater than end `1`"
+        );
+
+        let genes = assemble(vec![record.clone()]).unwrap();
+        assert_eq!(genes[0].record(), &record);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_errors_are_line_numbered() {
+        let data = [
+            "##gff-version 3",
+            "chr1\t.\tgene\t1\t10\t.\t+\t.\tID=g0",
+            "",
+            "chr1\t.\tgene\tone\t10\t.\t+\t.\t.",
+            "##FASTA",
+            ">chr1",
+            "ACGT",
+        ]
+        .join("\n");
+        let mut reader = Reader::new(data.as_bytes(), Format::Gff3);
+
+        assert!(reader.next().unwrap().is_ok());
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.to_string(), "line 4: invalid start `one`");
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn gtf_hierarchy() {
+        let data = [
+            "chr1\t.\texon\t10\t20\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\t.\tgene\t1\t100\t.\t-\t.\tgene_id \"g0\";",
+            "chr1\t.\ttranscript\t10\t100\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\t.\tCDS\t15\t20\t.\t-\t2\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\t.\tstop_codon\t12\t14\t.\t-\t0\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\t.\ttranscript\t50\t100\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t1\";",
+            "chr2\t.\tgene\t1\t100\t.\t+\t.\tgene_id \"g1\";",
+        ];
+        let genes = assemble(data.iter().map(|line| gtf(line))).unwrap();
+
+        assert_eq!(genes.len(), 2);
+        assert_eq!(genes[0].record().id(), Some("g0"));
+        assert_eq!(genes[0].transcripts().len(), 2);
+        assert!(genes[1].transcripts().is_empty());
+
+        let transcript = &genes[0].transcripts()[0];
+        assert_eq!(transcript.record().id(), Some("t0"));
+        assert_eq!(transcript.exons().len(), 1);
+        assert_eq!(transcript.cds()[0].phase(), Some(Phase::Two));
+        assert_eq!(transcript.features()[0].ty(), "stop_codon");
+        assert!(genes[0].transcripts()[1].exons().is_empty());
+    }
+
+    #[test]
+    fn gtf_hierarchy_without_genes_or_transcripts() {
+        let data = [
+            "chr1\tsrc\texon\t50\t60\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\tsrc\texon\t10\t20\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\tsrc\tCDS\t15\t20\t.\t+\t0\tgene_id \"g0\"; transcript_id \"t0\";",
+            "chr1\tsrc\texon\t70\t80\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t1\";",
+            "chr2\t.\ttranscript\t1\t50\t.\t-\t.\tgene_id \"g1\"; transcript_id \"t2\";",
+            "chr2\t.\texon\t1\t50\t.\t-\t.\tgene_id \"g1\"; transcript_id \"t2\";",
+        ];
+        let genes = assemble(data.iter().map(|line| gtf(line))).unwrap();
+
+        assert_eq!(genes.len(), 2);
+        assert_eq!(
+            genes[0].record().to_string(),
+            "chr1\tsrc\tgene\t10\t80\t.\t+\t.\tgene_id \"g0\";"
+        );
+        assert_eq!(genes[0].transcripts().len(), 2);
+        assert_eq!(genes[1].record().interval().to_string(), "chr2:-:50-1");
+
+        let transcript = &genes[0].transcripts()[0];
+        assert_eq!(
+            transcript.record().to_string(),
+            "chr1\tsrc\ttranscript\t10\t60\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";"
+        );
+        assert_eq!(transcript.exons().len(), 2);
+        assert_eq!(transcript.cds().len(), 1);
+        assert_eq!(genes[0].transcripts()[1].record().id(), Some("t1"));
+        assert_eq!(genes[1].transcripts()[0].exons().len(), 1);
+
+        let err = assemble([
+            gtf("chr1\t.\texon\t1\t10\t.\t+\t.\tgene_id \"g0\"; transcript_id \"t0\";"),
+            gtf("chr1\t.\texon\t20\t30\t.\t-\t.\tgene_id \"g0\"; transcript_id \"t0\";"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "children of `t0` lie on different contigs or strands"
+        );
+    }
+
+    #[test]
+    fn unknown_strands_are_written_as_unstranded() {
+        let record = gff3("chr1\t.\tgene\t1\t10\t.\t?\t.\tID=g0");
+
+        assert_eq!(record.strand(), Strand::Unstranded);
+        assert_eq!(record.to_string(), "chr1\t.\tgene\t1\t10\t.\t.\t.\tID=g0");
+    }
+
+    #[test]
+    fn gff3_hierarchy() {
+        // An exon shared by two transcripts is added to both.
+        let genes = assemble([
+            gff3("chr1\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+            gff3("chr1\t.\tmRNA\t1\t100\t.\t+\t.\tID=t0;Parent=g0"),
+            gff3("chr1\t.\tmRNA\t1\t50\t.\t+\t.\tID=t1;Parent=g0"),
+            gff3("chr1\t.\texon\t1\t50\t.\t+\t.\tID=e0;Parent=t0,t1"),
+        ])
+        .unwrap();
+        assert_eq!(genes[0].transcripts()[0].exons().len(), 1);
+        assert_eq!(genes[0].transcripts()[1].exons().len(), 1);
+
+        let err = assemble([
+            gff3("chr1\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+            gff3("chr1\t.\texon\t1\t50\t.\t+\t.\tParent=t0"),
+        ])
+        .unwrap_err();
+        assert_eq!(err.to_string(), "unknown parent: `t0`");
+
+        let err = assemble([
+            gff3("chr1\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+            gff3("chr1\t.\tmRNA\t1\t100\t.\t+\t.\tID=t0;Parent=g0"),
+            gff3("chr1\t.\texon\t1\t50\t.\t+\t.\tID=e0;Parent=t0"),
+            gff3("chr1\t.\tmotif\t1\t5\t.\t+\t.\tParent=e0"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parent `e0` is neither a gene nor a transcript"
+        );
+
+        let err = assemble([
+            gff3("chr1\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+            gff3("chr2\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+        ])
+        .unwrap_err();
+        assert_eq!(err.to_string(), "duplicate identifier: `g0`");
+    }
+
+    #[test]
+    fn gff3_hierarchy_without_transcripts() {
+        // As is common for prokaryotes, coding sequences are direct children
+        // of their genes.
+        let genes = assemble([
+            gff3("NC_000913.3\tRefSeq\tgene\t337\t2799\t.\t+\t.\tID=gene-thrA"),
+            gff3("NC_000913.3\tRefSeq\tCDS\t337\t2799\t.\t+\t0\tID=cds-thrA;Parent=gene-thrA"),
+            gff3("NC_000913.3\tRefSeq\tgene\t2801\t3733\t.\t+\t.\tID=gene-thrB"),
+            gff3("NC_000913.3\tRefSeq\tCDS\t2801\t3733\t.\t+\t0\tID=cds-thrB;Parent=gene-thrB"),
+            gff3("NC_000913.3\tRefSeq\tgene\t4000\t4100\t.\t-\t.\tID=gene-rrsA"),
+            gff3("NC_000913.3\tRefSeq\trRNA\t4000\t4100\t.\t-\t.\tID=rna-rrsA;Parent=gene-rrsA"),
+            gff3("NC_000913.3\tRefSeq\texon\t4000\t4100\t.\t-\t.\tParent=rna-rrsA"),
+        ])
+        .unwrap();
+
+        assert_eq!(genes.len(), 3);
+
+        let transcripts = genes[0].transcripts();
+        assert_eq!(transcripts.len(), 1);
+        assert_eq!(
+            transcripts[0].record().to_string(),
+            "NC_000913.3\tRefSeq\ttranscript\t337\t2799\t.\t+\t.\tParent=gene-thrA"
+        );
+        assert_eq!(transcripts[0].record().id(), None);
+        assert!(transcripts[0].exons().is_empty());
+        assert_eq!(transcripts[0].cds()[0].id(), Some("cds-thrA"));
+
+        assert_eq!(genes[1].transcripts()[0].cds().len(), 1);
+
+        let transcript = &genes[2].transcripts()[0];
+        assert_eq!(transcript.record().ty(), "rRNA");
+        assert_eq!(transcript.exons().len(), 1);
+
+        // Parts whose parents mix a gene and a transcript are added to both
+        // the implicit transcript of the gene and the named transcript.
+        let genes = assemble([
+            gff3("chr1\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+            gff3("chr1\t.\tmRNA\t10\t100\t.\t+\t.\tID=t0;Parent=g0"),
+            gff3("chr1\t.\texon\t10\t20\t.\t+\t.\tParent=g0,t0"),
+            gff3("chr1\t.\tfive_prime_UTR\t5\t9\t.\t+\t.\tParent=g0"),
+        ])
+        .unwrap();
+
+        let transcripts = genes[0].transcripts();
+        assert_eq!(transcripts.len(), 2);
+        assert_eq!(transcripts[0].record().id(), Some("t0"));
+        assert_eq!(transcripts[0].exons().len(), 1);
+        assert_eq!(
+            transcripts[1].record().interval().to_string(),
+            "chr1:+:5-20"
+        );
+        assert_eq!(transcripts[1].exons().len(), 1);
+        assert_eq!(transcripts[1].features()[0].ty(), "five_prime_UTR");
+
+        let err = assemble([
+            gff3("chr1\t.\tgene\t1\t100\t.\t+\t.\tID=g0"),
+            gff3("chr1\t.\tCDS\t1\t10\t.\t+\t0\tParent=g0"),
+            gff3("chr1\t.\tCDS\t20\t30\t.\t-\t0\tParent=g0"),
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "children of `g0` lie on different contigs or strands"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn round_trip() {
+        let lines = [
+            "chr1\tensembl\tgene\t1\t10\t.\t+\t.\tID=g0;Name=A",
+            "chr1\tensembl\tCDS\t2\t9\t12.5\t-\t1\tParent=t0,t1",
+            "chr2\t.\tregion\t1\t1\t.\t.\t.\t.",
+        ];
+        let data = lines.map(|line| format!("{line}\n")).concat();
+
+        let mut writer = Writer::new(Vec::new());
+        for record in Reader::new(data.as_bytes(), Format::Gff3) {
+            writer.write_record(&record.unwrap()).unwrap();
+        }
+
+        assert_eq!(String::from_utf8(writer.into_inner()).unwrap(), data);
+    }
+}
@@ -0,0 +1,816 @@
+//! Indexed collections of intervals.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to an interval index.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An unsorted input error.
+    ///
+    /// This error occurs when an index is built in bulk from input that is
+    /// not sorted by the numerically lower position of each interval within
+    /// a contig and strand.
+    #[error(
+        "unsorted input on `{contig}:{strand}`: an interval starting at `{current}` follows an \
+         interval starting at `{previous}`"
+    )]
+    Unsorted {
+        /// The contig upon which the unsorted intervals are located.
+        contig: Contig,
+
+        /// The strand upon which the unsorted intervals are located.
+        strand: Strand,
+
+        /// The numerically lower position of the previous interval.
+        previous: u64,
+
+        /// The numerically lower position of the current interval.
+        current: u64,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Partitions
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// Entries grouped by their contig and strand.
+type Groups<S, V> = BTreeMap<(Contig, Strand), Vec<(Interval<S>, V)>>;
+
+/// The intervals and payloads on a single contig and strand.
+///
+/// Entries are sorted by their numerically lower position. Over this sorted
+/// array, an implicit, balanced binary tree is laid out where the root of any
+/// range of entries is the midpoint of that range. Each node is augmented
+/// with the maximum numerically upper position within its subtree, which
+/// allows whole subtrees to be skipped during queries.
+#[derive(Clone, Debug)]
+struct Partition<S: System, V> {
+    /// The entries sorted by their numerically lower position.
+    entries: Vec<(Interval<S>, V)>,
+
+    /// The maximum numerically upper position within the subtree rooted at
+    /// each entry.
+    max_upper: Vec<S::Number>,
+
+    /// The indices of the entries sorted by their numerically upper position.
+    by_upper: Vec<usize>,
+}
+
+impl<S: System, V> Partition<S, V>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a new partition from entries that are sorted by their
+    /// numerically lower position.
+    fn new(entries: Vec<(Interval<S>, V)>) -> Self {
+        let mut by_upper = (0..entries.len()).collect::<Vec<_>>();
+        by_upper.sort_by_key(|i| entries[*i].0.upper().get());
+
+        let mut partition = Self {
+            max_upper: vec![S::Number::ZERO; entries.len()],
+            entries,
+            by_upper,
+        };
+
+        partition.augment(0, partition.entries.len());
+        partition
+    }
+
+    /// Computes the maximum numerically upper position for each node in the
+    /// subtree covering the entries within `[lo, hi)`.
+    fn augment(&mut self, lo: usize, hi: usize) -> S::Number {
+        if lo >= hi {
+            return S::Number::ZERO;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let value = self.entries[mid]
+            .0
+            .upper()
+            .get()
+            .max(self.augment(lo, mid))
+            .max(self.augment(mid + 1, hi));
+
+        self.max_upper[mid] = value;
+        value
+    }
+
+    /// Visits, in sorted order, every entry whose numerically upper position
+    /// is at least `min_upper` and whose numerically lower position is at
+    /// most `max_lower`.
+    fn visit<'a>(
+        &'a self,
+        lo: usize,
+        hi: usize,
+        min_upper: S::Number,
+        max_lower: S::Number,
+        f: &mut impl FnMut(&'a (Interval<S>, V)),
+    ) {
+        if lo >= hi {
+            return;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+
+        // No entry in this subtree reaches far enough to be of interest.
+        if self.max_upper[mid] < min_upper {
+            return;
+        }
+
+        self.visit(lo, mid, min_upper, max_lower, f);
+
+        let entry = &self.entries[mid];
+
+        // This entry, as well as all entries to the right of it, start too
+        // far along to be of interest.
+        if entry.0.lower().get() > max_lower {
+            return;
+        }
+
+        f(entry);
+        self.visit(mid + 1, hi, min_upper, max_lower, f);
+    }
+
+    /// Collects, in sorted order, every entry within the bounds that also
+    /// satisfies `predicate`.
+    fn collect(
+        &self,
+        min_upper: S::Number,
+        max_lower: S::Number,
+        predicate: impl Fn(&Interval<S>) -> bool,
+    ) -> Vec<(&Interval<S>, &V)> {
+        let mut results = Vec::new();
+
+        self.visit(0, self.entries.len(), min_upper, max_lower, &mut |entry| {
+            if predicate(&entry.0) {
+                results.push((&entry.0, &entry.1));
+            }
+        });
+
+        results
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Interval index
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An index of intervals, each associated with a payload, that supports fast
+/// overlap, containment, and nearest neighbor queries.
+///
+/// Intervals are partitioned by their contig and strand—queries only ever
+/// return intervals located on the same contig and strand as the query
+/// interval. Within each partition, queries run in `O(log n + k)` time, where
+/// `n` is the number of intervals in the partition and `k` is the number of
+/// intervals returned.
+///
+/// The index is immutable once built. It can be built in bulk from sorted
+/// input using [`IntervalIndex::try_from_sorted()`] or from input in any
+/// order by collecting an iterator.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::IntervalIndex;
+/// use omics_coordinate::system::Interbase;
+///
+/// let index = [
+///     ("seq0:+:10-20", "a"),
+///     ("seq0:+:15-25", "b"),
+///     ("seq0:-:20-10", "c"),
+///     ("seq1:+:10-20", "d"),
+/// ]
+/// .into_iter()
+/// .map(|(interval, value)| Ok((interval.parse::<Interval<Interbase>>()?, value)))
+/// .collect::<Result<IntervalIndex<Interbase, _>, Box<dyn std::error::Error>>>()?;
+///
+/// let query = "seq0:+:18-30".parse::<Interval<Interbase>>()?;
+/// let values = index
+///     .find_overlapping(&query)
+///     .into_iter()
+///     .map(|(_, value)| *value)
+///     .collect::<Vec<_>>();
+/// assert_eq!(values, vec!["a", "b"]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntervalIndex<S: System, V> {
+    /// The partitions of the index.
+    partitions: BTreeMap<(Contig, Strand), Partition<S, V>>,
+
+    /// The total number of entries across all partitions.
+    len: usize,
+}
+
+impl<S: System, V> IntervalIndex<S, V>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Attempts to build an index in bulk from sorted input.
+    ///
+    /// Within each contig and strand, the intervals must be sorted by their
+    /// numerically lower position (i.e., the start position on the positive
+    /// strand and the end position on the negative strand). Intervals from
+    /// different contigs and strands may be interleaved in any order. If the
+    /// input is not sorted, an [`Error::Unsorted`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let intervals = ["seq0:+:1-10", "seq0:+:5-15", "seq0:+:20-30"]
+    ///     .into_iter()
+    ///     .map(|interval| interval.parse::<Interval<Base>>())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// let index = IntervalIndex::try_from_sorted(intervals.into_iter().zip(0..))?;
+    /// assert_eq!(index.len(), 3);
+    ///
+    /// let intervals = ["seq0:+:5-15", "seq0:+:1-10"]
+    ///     .into_iter()
+    ///     .map(|interval| interval.parse::<Interval<Base>>())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert!(IntervalIndex::try_from_sorted(intervals.into_iter().zip(0..)).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_sorted(entries: impl IntoIterator<Item = (Interval<S>, V)>) -> Result<Self> {
+        let mut groups = Groups::<S, V>::new();
+
+        for (interval, value) in entries {
+            let group = groups
+                .entry((interval.contig().clone(), interval.strand()))
+                .or_default();
+
+            if let Some((previous, _)) = group.last() {
+                if previous.lower() > interval.lower() {
+                    return Err(Error::Unsorted {
+                        contig: interval.contig().clone(),
+                        strand: interval.strand(),
+                        previous: previous.lower().get().to_u64(),
+                        current: interval.lower().get().to_u64(),
+                    });
+                }
+            }
+
+            group.push((interval, value));
+        }
+
+        Ok(Self::from_groups(groups))
+    }
+
+    /// Builds an index from groups of sorted entries.
+    fn from_groups(groups: Groups<S, V>) -> Self {
+        let len = groups.values().map(Vec::len).sum();
+        let partitions = groups
+            .into_iter()
+            .map(|(key, entries)| (key, Partition::new(entries)))
+            .collect();
+
+        Self { partitions, len }
+    }
+
+    /// Gets the number of intervals in the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let index = IntervalIndex::<Interbase, ()>::try_from_sorted([])?;
+    /// assert_eq!(index.len(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether or not the index contains any intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let index = IntervalIndex::<Interbase, ()>::try_from_sorted([])?;
+    /// assert!(index.is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets an iterator over all intervals and payloads in the index.
+    ///
+    /// Entries are yielded partition by partition (ordered by contig and then
+    /// strand) and, within each partition, sorted by their numerically lower
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let index = ["seq1:+:0-10", "seq0:+:5-10", "seq0:+:0-10"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Interbase>>()?, ())))
+    ///     .collect::<Result<IntervalIndex<Interbase, _>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let intervals = index
+    ///     .iter()
+    ///     .map(|(interval, _)| interval.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec!["seq0:+:0-10", "seq0:+:5-10", "seq1:+:0-10"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&Interval<S>, &V)> {
+        self.partitions.values().flat_map(|partition| {
+            partition
+                .entries
+                .iter()
+                .map(|(interval, value)| (interval, value))
+        })
+    }
+
+    /// Gets the partition for the contig and strand of an interval.
+    fn partition(&self, interval: &Interval<S>) -> Option<&Partition<S, V>> {
+        self.partitions
+            .get(&(interval.contig().clone(), interval.strand()))
+    }
+
+    /// Finds all intervals that overlap the query interval.
+    ///
+    /// Overlap is defined as in [`Interval::overlaps()`]. Results are sorted
+    /// by their numerically lower position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let index = ["seq0:-:20-10", "seq0:-:30-21", "seq0:+:10-20"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Base>>()?, ())))
+    ///     .collect::<Result<IntervalIndex<Base, _>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let query = "seq0:-:25-15".parse::<Interval<Base>>()?;
+    /// assert_eq!(index.find_overlapping(&query).len(), 2);
+    ///
+    /// let query = "seq0:-:40-31".parse::<Interval<Base>>()?;
+    /// assert!(index.find_overlapping(&query).is_empty());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn find_overlapping(&self, query: &Interval<S>) -> Vec<(&Interval<S>, &V)> {
+        let Some(partition) = self.partition(query) else {
+            return Vec::new();
+        };
+
+        partition.collect(query.lower().get(), query.upper().get(), |interval| {
+            <Interval<S> as interval::r#trait::Interval<S>>::overlaps_unchecked(interval, query)
+        })
+    }
+
+    /// Finds all intervals that fully contain the query interval.
+    ///
+    /// An interval contains the query interval when both the start and end
+    /// positions of the query interval fall within it (as defined by
+    /// [`Interval::contains_coordinate()`]). Results are sorted by their
+    /// numerically lower position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let index = ["seq0:+:0-100", "seq0:+:10-20", "seq0:+:15-50"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Interbase>>()?, ())))
+    ///     .collect::<Result<IntervalIndex<Interbase, _>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let query = "seq0:+:15-20".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(index.find_containing(&query).len(), 3);
+    ///
+    /// let query = "seq0:+:5-20".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(index.find_containing(&query).len(), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn find_containing(&self, query: &Interval<S>) -> Vec<(&Interval<S>, &V)> {
+        let Some(partition) = self.partition(query) else {
+            return Vec::new();
+        };
+
+        let (lower, upper) = (query.lower(), query.upper());
+        partition.collect(upper.get(), lower.get(), |interval| {
+            interval.lower() <= lower && interval.upper() >= upper
+        })
+    }
+
+    /// Finds all intervals that are fully contained within the query interval.
+    ///
+    /// An interval is contained within the query interval when both its start
+    /// and end positions fall within the query interval (as defined by
+    /// [`Interval::contains_coordinate()`]). Results are sorted by their
+    /// numerically lower position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let index = ["seq0:+:0-100", "seq0:+:10-20", "seq0:+:15-50"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Interbase>>()?, ())))
+    ///     .collect::<Result<IntervalIndex<Interbase, _>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let query = "seq0:+:10-50".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(index.find_contained(&query).len(), 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn find_contained(&self, query: &Interval<S>) -> Vec<(&Interval<S>, &V)> {
+        let Some(partition) = self.partition(query) else {
+            return Vec::new();
+        };
+
+        let (lower, upper) = (query.lower(), query.upper());
+        partition.collect(lower.get(), upper.get(), |interval| {
+            interval.lower() >= lower && interval.upper() <= upper
+        })
+    }
+
+    /// Finds the intervals nearest to the query interval.
+    ///
+    /// Nearness is measured using [`Interval::distance()`]. All intervals tied
+    /// for the smallest distance are returned, sorted by their numerically
+    /// lower position. Intervals that overlap or that are directly adjacent to
+    /// the query interval have a distance of zero (`0`). If no intervals exist
+    /// on the query interval's contig and strand, an empty [`Vec`] is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalIndex;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let index = [
+    ///     ("seq0:+:0-10", "a"),
+    ///     ("seq0:+:30-40", "b"),
+    ///     ("seq0:+:45-50", "c"),
+    /// ]
+    /// .into_iter()
+    /// .map(|(interval, value)| Ok((interval.parse::<Interval<Interbase>>()?, value)))
+    /// .collect::<Result<IntervalIndex<Interbase, _>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let query = "seq0:+:25-28".parse::<Interval<Interbase>>()?;
+    /// let nearest = index.find_nearest(&query);
+    /// assert_eq!(nearest.len(), 1);
+    /// assert_eq!(*nearest[0].1, "b");
+    /// assert_eq!(query.distance(nearest[0].0)?, 2);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn find_nearest(&self, query: &Interval<S>) -> Vec<(&Interval<S>, &V)> {
+        let Some(partition) = self.partition(query) else {
+            return Vec::new();
+        };
+
+        let distance = |interval: &Interval<S>| {
+            // SAFETY: every interval in the partition is located on the same
+            // contig and strand as the query, so this will always unwrap.
+            query.distance(interval).unwrap()
+        };
+
+        // NOTE: intervals that overlap or are directly adjacent to the query
+        // are always the nearest. Adjacent intervals can be up to one position
+        // beyond the query, so the bounds are widened accordingly.
+        let (lower, upper) = (query.lower().get(), query.upper().get());
+        let touching = partition.collect(
+            lower.saturating_sub(S::Number::ONE),
+            upper.saturating_add(S::Number::ONE),
+            |interval| distance(interval) == S::Number::ZERO,
+        );
+
+        if !touching.is_empty() {
+            return touching;
+        }
+
+        // Otherwise, every interval is located either entirely before or
+        // entirely after the query. The nearest interval before the query has
+        // the largest upper position, and the nearest interval after the query
+        // has the smallest lower position.
+        let i = partition
+            .by_upper
+            .partition_point(|i| partition.entries[*i].0.upper().get() < lower);
+        let before = partition.by_upper[..i]
+            .last()
+            .map(|i| partition.entries[*i].0.upper().get())
+            .map(|value| {
+                let start = partition.by_upper[..i]
+                    .partition_point(|i| partition.entries[*i].0.upper().get() < value);
+                &partition.by_upper[start..i]
+            })
+            .unwrap_or_default();
+
+        let i = partition
+            .entries
+            .partition_point(|(interval, _)| interval.lower().get() <= upper);
+        let after = partition
+            .entries
+            .get(i)
+            .map(|(interval, _)| interval.lower().get())
+            .map(|value| {
+                let end = partition
+                    .entries
+                    .partition_point(|(interval, _)| interval.lower().get() <= value);
+                i..end
+            })
+            .unwrap_or_default();
+
+        let before_distance = before.first().map(|i| distance(&partition.entries[*i].0));
+        let after_distance = after
+            .clone()
+            .next()
+            .map(|i| distance(&partition.entries[i].0));
+
+        let nearest = match (before_distance, after_distance) {
+            (Some(b), Some(a)) => b.min(a),
+            (Some(b), None) => b,
+            (None, Some(a)) => a,
+            (None, None) => return Vec::new(),
+        };
+
+        let mut results = Vec::new();
+
+        if before_distance == Some(nearest) {
+            let mut indices = before.to_vec();
+            indices.sort_unstable();
+            results.extend(indices.into_iter().map(|i| &partition.entries[i]));
+        }
+
+        if after_distance == Some(nearest) {
+            results.extend(after.map(|i| &partition.entries[i]));
+        }
+
+        results
+            .into_iter()
+            .map(|(interval, value)| (interval, value))
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<S: System, V> FromIterator<(Interval<S>, V)> for IntervalIndex<S, V>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn from_iter<T: IntoIterator<Item = (Interval<S>, V)>>(iter: T) -> Self {
+        let mut groups = Groups::<S, V>::new();
+
+        for (interval, value) in iter {
+            groups
+                .entry((interval.contig().clone(), interval.strand()))
+                .or_default()
+                .push((interval, value));
+        }
+
+        for entries in groups.values_mut() {
+            // NOTE: the sort is stable so that intervals sharing a lower
+            // position retain their input order.
+            entries.sort_by(|(a, _), (b, _)| a.lower().cmp(b.lower()));
+        }
+
+        Self::from_groups(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    /// Builds an index of numbered intervals from their string forms.
+    fn build<S: System>(intervals: &[&str]) -> IntervalIndex<S, usize>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        intervals
+            .iter()
+            .map(|value| value.parse::<Interval<S>>().unwrap())
+            .zip(0..)
+            .collect()
+    }
+
+    /// Gets the payloads from a set of query results.
+    fn values<S: System>(results: Vec<(&Interval<S>, &usize)>) -> Vec<usize> {
+        results.into_iter().map(|(_, value)| *value).collect()
+    }
+
+    #[test]
+    fn unsorted() {
+        let intervals = ["seq0:-:20-10", "seq0:+:5-10", "seq0:-:15-5", "seq0:-:30-6"]
+            .map(|value| value.parse::<Interval<Interbase>>().unwrap());
+
+        let err = IntervalIndex::try_from_sorted(intervals.into_iter().zip(0..)).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Unsorted {
+                contig: Contig::new_unchecked("seq0"),
+                strand: Strand::Negative,
+                previous: 10,
+                current: 5,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unsorted input on `seq0:-`: an interval starting at `5` follows an interval starting \
+             at `10`"
+        );
+    }
+
+    #[test]
+    fn partitions_by_contig_and_strand() {
+        let index = build::<Interbase>(&["seq0:+:0-10", "seq0:-:10-0", "seq1:+:0-10"]);
+        assert_eq!(index.len(), 3);
+
+        let query = "seq0:+:5-6".parse::<Interval<Interbase>>().unwrap();
+        assert_eq!(values(index.find_overlapping(&query)), vec![0]);
+
+        let query = "seq0:-:6-5".parse::<Interval<Interbase>>().unwrap();
+        assert_eq!(values(index.find_overlapping(&query)), vec![1]);
+
+        let query = "seq2:+:5-6".parse::<Interval<Interbase>>().unwrap();
+        assert!(index.find_overlapping(&query).is_empty());
+        assert!(index.find_nearest(&query).is_empty());
+    }
+
+    #[test]
+    fn overlapping_matches_brute_force() {
+        // A deterministic spread of intervals with a variety of lengths.
+        let bounds = (0..500u32)
+            .map(|i| {
+                let start = (i * 7919) % 1000;
+                (start, start + (i * 104_729) % 50)
+            })
+            .collect::<Vec<_>>();
+
+        let intervals = bounds
+            .iter()
+            .map(|(start, end)| format!("seq0:+:{start}-{end}"))
+            .collect::<Vec<_>>();
+        let interbase =
+            build::<Interbase>(&intervals.iter().map(String::as_str).collect::<Vec<_>>());
+
+        let intervals = bounds
+            .iter()
+            .map(|(start, end)| format!("seq0:+:{}-{}", start + 1, end + 1))
+            .collect::<Vec<_>>();
+        let base = build::<Base>(&intervals.iter().map(String::as_str).collect::<Vec<_>>());
+
+        for start in (0..1100).step_by(13) {
+            for length in [0, 1, 5, 40] {
+                let query = format!("seq0:+:{}-{}", start, start + length)
+                    .parse::<Interval<Interbase>>()
+                    .unwrap();
+                let mut expected = interbase
+                    .iter()
+                    .filter(|(interval, _)| interval.overlaps(&query).unwrap())
+                    .map(|(_, value)| *value)
+                    .collect::<Vec<_>>();
+                let mut actual = values(interbase.find_overlapping(&query));
+                expected.sort_unstable();
+                actual.sort_unstable();
+                assert_eq!(actual, expected);
+
+                let nearest = interbase
+                    .iter()
+                    .map(|(interval, _)| interval.distance(&query).unwrap())
+                    .min()
+                    .unwrap();
+                let mut expected = interbase
+                    .iter()
+                    .filter(|(interval, _)| interval.distance(&query).unwrap() == nearest)
+                    .map(|(_, value)| *value)
+                    .collect::<Vec<_>>();
+                let mut actual = values(interbase.find_nearest(&query));
+                expected.sort_unstable();
+                actual.sort_unstable();
+                assert_eq!(actual, expected);
+
+                let query = format!("seq0:+:{}-{}", start + 1, start + 1 + length)
+                    .parse::<Interval<Base>>()
+                    .unwrap();
+                let mut expected = base
+                    .iter()
+                    .filter(|(interval, _)| interval.overlaps(&query).unwrap())
+                    .map(|(_, value)| *value)
+                    .collect::<Vec<_>>();
+                let mut actual = values(base.find_overlapping(&query));
+                expected.sort_unstable();
+                actual.sort_unstable();
+                assert_eq!(actual, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn containment() {
+        let index = build::<Base>(&["seq0:-:100-1", "seq0:-:20-10", "seq0:-:50-15"]);
+
+        let query = "seq0:-:20-15".parse::<Interval<Base>>().unwrap();
+        assert_eq!(values(index.find_containing(&query)), vec![0, 1, 2]);
+        assert_eq!(values(index.find_contained(&query)), Vec::<usize>::new());
+
+        let query = "seq0:-:50-10".parse::<Interval<Base>>().unwrap();
+        assert_eq!(values(index.find_containing(&query)), vec![0]);
+        assert_eq!(values(index.find_contained(&query)), vec![1, 2]);
+    }
+
+    #[test]
+    fn nearest() {
+        let index = build::<Base>(&[
+            "seq0:+:1-10",
+            "seq0:+:1-12",
+            "seq0:+:30-40",
+            "seq0:+:30-35",
+            "seq0:+:50-60",
+        ]);
+
+        // Equidistant intervals on both sides are all returned.
+        let query = "seq0:+:21-21".parse::<Interval<Base>>().unwrap();
+        assert_eq!(values(index.find_nearest(&query)), vec![1, 2, 3]);
+
+        // Only the closer side is returned.
+        let query = "seq0:+:25-25".parse::<Interval<Base>>().unwrap();
+        assert_eq!(values(index.find_nearest(&query)), vec![2, 3]);
+
+        // Adjacent intervals are considered touching.
+        let query = "seq0:+:41-49".parse::<Interval<Base>>().unwrap();
+        assert_eq!(values(index.find_nearest(&query)), vec![2, 4]);
+
+        // Queries beyond either end of the partition find the extremes.
+        let query = "seq0:+:100-200".parse::<Interval<Base>>().unwrap();
+        assert_eq!(values(index.find_nearest(&query)), vec![4]);
+
+        // Zero-sized interbase intervals within an interval are touching it.
+        let index = build::<Interbase>(&["seq0:+:10-20", "seq0:+:30-40"]);
+        let query = "seq0:+:15-15".parse::<Interval<Interbase>>().unwrap();
+        assert_eq!(values(index.find_nearest(&query)), vec![0]);
+    }
+}
@@ -1,7 +1,10 @@
 //! Intervals.
 
-use std::cmp::max;
-use std::cmp::min;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::string::ToString;
+use core::cmp::max;
+use core::cmp::min;
 
 use omics_core::VARIANT_SEPARATOR;
 use thiserror::Error;
@@ -10,16 +13,18 @@ use crate::Contig;
 use crate::Position;
 use crate::Strand;
 use crate::System;
+use crate::contig;
 use crate::coordinate;
 use crate::coordinate::Coordinate;
 use crate::coordinate::CoordinateRef;
 use crate::position;
-use crate::position::Number;
+use crate::position::Width;
 use crate::strand;
 use crate::system::Base;
 
 pub mod base;
 pub mod interbase;
+pub mod iter;
 
 /// The separator between an interval's start and end positions.
 const INTERVAL_SEPARATOR: &str = "-";
@@ -67,24 +72,57 @@ pub enum ClampError {
     )]
     Disjoint {
         /// The start position of the interval being clamped.
-        original_start: Number,
+        original_start: u64,
 
         /// The end position of the interval being clamped.
-        original_end: Number,
+        original_end: u64,
 
         /// The start position of the interval doing the clamping.
-        operand_start: Number,
+        operand_start: u64,
 
         /// The end position of the interval doing the clamping.
-        operand_end: Number,
+        operand_end: u64,
 
         /// The strand shared by both intervals.
         strand: Strand,
     },
 }
 
-/// A [`Result`](std::result::Result) with a [`ClampError`].
-pub type ClampResult<T> = std::result::Result<T, ClampError>;
+/// A [`Result`](core::result::Result) with a [`ClampError`].
+pub type ClampResult<T> = core::result::Result<T, ClampError>;
+
+/// An error that occurs when comparing two intervals.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ComparisonError {
+    /// A mismatched contig error.
+    ///
+    /// This error occurs when one attempts to compare an interval with another
+    /// interval that is not located on the same contig.
+    #[error("mismatched contigs: `{original}` and `{operand}`")]
+    MismatchedContigs {
+        /// The contig of the interval being operated on.
+        original: Contig,
+
+        /// The contig of the interval being compared against.
+        operand: Contig,
+    },
+
+    /// A mismatched strand error.
+    ///
+    /// This error occurs when one attempts to compare an interval with another
+    /// interval that is not located on the same strand.
+    #[error("mismatched strand: `{original}` and `{operand}`")]
+    MismatchedStrand {
+        /// The strand of the interval being operated on.
+        original: Strand,
+
+        /// The strand of the interval being compared against.
+        operand: Strand,
+    },
+}
+
+/// A [`Result`](core::result::Result) with a [`ComparisonError`].
+pub type ComparisonResult<T> = core::result::Result<T, ComparisonError>;
 
 /// An error related to the creation of a nonsensical interval.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -126,16 +164,16 @@ pub enum NonsensicalError {
     #[error("negatively sized interval: start is `{start}`, end is `{end}`, strand is `{strand}`")]
     NegativelySized {
         /// The start position.
-        start: Number,
+        start: u64,
         /// The end position.
-        end: Number,
+        end: u64,
         /// The strand.
         strand: Strand,
     },
 }
 
-/// A [`Result`](std::result::Result) with a [`NonsensicalError`].
-pub type NonsensicalResult<T> = std::result::Result<T, NonsensicalError>;
+/// A [`Result`](core::result::Result) with a [`NonsensicalError`].
+pub type NonsensicalResult<T> = core::result::Result<T, NonsensicalError>;
 
 /// An error related to parsing an interval.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -148,8 +186,64 @@ pub enum ParseError {
     },
 }
 
-/// A [`Result`](std::result::Result) with a [`ParseError`].
-pub type ParseResult<T> = std::result::Result<T, ParseError>;
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error that occurs when resizing an interval.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ResizeError {
+    /// An overflow error.
+    ///
+    /// This error occurs when the numerically upper bound of a resized
+    /// interval would exceed the largest representable position.
+    #[error("moving position `{position}` up by `{magnitude}` overflows")]
+    Overflow {
+        /// The position being moved.
+        position: u64,
+
+        /// The magnitude of the move.
+        magnitude: u64,
+    },
+
+    /// An underflow error.
+    ///
+    /// This error occurs when the numerically lower bound of a resized
+    /// interval would precede the first position of the coordinate system.
+    #[error("moving position `{position}` down by `{magnitude}` underflows")]
+    Underflow {
+        /// The position being moved.
+        position: u64,
+
+        /// The magnitude of the move.
+        magnitude: u64,
+    },
+
+    /// An out of bounds error.
+    ///
+    /// This error occurs when a contig length is provided for clamping but the
+    /// interval being resized already extends beyond the end of the contig.
+    #[error("position `{position}` is out of bounds for contig `{contig}` of length `{length}`")]
+    OutOfBounds {
+        /// The contig.
+        contig: Contig,
+
+        /// The position.
+        position: u64,
+
+        /// The length of the contig.
+        length: u64,
+    },
+
+    /// An empty interval error.
+    ///
+    /// This error occurs when the resized interval would not contain any
+    /// entities.
+    #[error("resized interval contains no entities")]
+    Empty,
+}
+
+/// A [`Result`](core::result::Result) with a [`ResizeError`].
+pub type ResizeResult<T> = core::result::Result<T, ResizeError>;
 
 /// An error related to an interval.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -158,6 +252,10 @@ pub enum Error {
     #[error("clamp error: {0}")]
     Clamp(#[from] ClampError),
 
+    /// A comparison error.
+    #[error("comparison error: {0}")]
+    Comparison(#[from] ComparisonError),
+
     /// A coordinate error.
     #[error("coordinate error: {0}")]
     Coordinate(#[from] coordinate::Error),
@@ -178,13 +276,17 @@ pub enum Error {
     #[error("position error: {0}")]
     Position(#[from] position::Error),
 
+    /// A resize error.
+    #[error("resize error: {0}")]
+    Resize(#[from] ResizeError),
+
     /// A strand error.
     #[error("strand error: {0}")]
     Strand(#[from] strand::Error),
 }
 
-/// A [`Result`](std::result::Result) with an [`Error`](enum@Error).
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
 
 ////////////////////////////////////////////////////////////////////////////////////////
 // The `Coordinate` trait
@@ -200,10 +302,49 @@ pub mod r#trait {
     pub trait Interval<S: System> {
         /// Returns whether or not the entity at the in-base coordinate is
         /// contained within this interval.
-        fn contains_entity(&self, coordinate: &Coordinate<Base>) -> bool;
+        fn contains_entity(&self, coordinate: &Coordinate<Base<S::Number>>) -> bool;
 
         /// Gets the number of member contained within the interval.
-        fn count_entities(&self) -> Number;
+        fn count_entities(&self) -> S::Number;
+
+        /// Returns whether or not this interval shares at least one entity
+        /// with another interval.
+        ///
+        /// Both intervals are assumed to be located on the same contig and
+        /// strand—no check is performed.
+        fn overlaps_unchecked(&self, other: &Self) -> bool;
+
+        /// Gets the numerically lower and upper positions of the gap between
+        /// this interval and another interval.
+        ///
+        /// Both intervals are assumed to be located on the same contig and
+        /// strand—no check is performed. If no entities lie between the two
+        /// intervals (i.e., they overlap or they are directly adjacent to one
+        /// another), [`None`] is returned.
+        fn gap_unchecked(&self, other: &Self) -> Option<(Position<S>, Position<S>)>;
+
+        /// Gets the entities contained within the interval as a half-open
+        /// range of interbase positions.
+        ///
+        /// This gives every coordinate system a common representation of the
+        /// entities within an interval (e.g., the in-base interval `[1, 10]`
+        /// and the interbase interval `[0, 10]` are both represented as
+        /// `(0, 10)`).
+        fn entity_bounds(&self) -> (S::Number, S::Number);
+
+        /// Creates an interval containing the entities within a half-open
+        /// range of interbase positions.
+        ///
+        /// If the range contains no entities (i.e., `start >= end`), [`None`]
+        /// is returned.
+        fn from_entity_bounds(
+            contig: Contig,
+            strand: Strand,
+            start: S::Number,
+            end: S::Number,
+        ) -> Option<Self>
+        where
+            Self: Sized;
     }
 }
 
@@ -303,26 +444,7 @@ where
             }));
         }
 
-        match start_strand {
-            Strand::Positive => {
-                if start_position > end_position {
-                    return Err(Error::Nonsensical(NonsensicalError::NegativelySized {
-                        start: start_position.get(),
-                        end: end_position.get(),
-                        strand: start_strand,
-                    }));
-                }
-            }
-            Strand::Negative => {
-                if end_position > start_position {
-                    return Err(Error::Nonsensical(NonsensicalError::NegativelySized {
-                        start: start_position.get(),
-                        end: end_position.get(),
-                        strand: start_strand,
-                    }));
-                }
-            }
-        }
+        check_size(start_strand, &start_position, &end_position)?;
 
         Ok(Interval {
             contig: start_contig,
@@ -519,6 +641,37 @@ where
         )
     }
 
+    /// Consumes `self` and attempts to convert the interval into an interval
+    /// of the same coordinate system with another [`Width`].
+    ///
+    /// If either position cannot be represented within the target width, a
+    /// [`position::Error::Width`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let interval = "seq0:+:1-1000".parse::<Interval<Base>>()?;
+    /// let wide = interval.try_into_width::<u64>()?;
+    /// assert_eq!(wide.to_string(), "seq0:+:1-1000");
+    ///
+    /// let interval = "seq0:-:5000000000-10".parse::<Interval<Interbase<u64>>>()?;
+    /// assert!(interval.try_into_width::<u32>().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_into_width<W: Width>(self) -> Result<Interval<S::WithWidth<W>>> {
+        Ok(Interval {
+            contig: self.contig,
+            strand: self.strand,
+            start: self.start.try_into_width()?,
+            end: self.end.try_into_width()?,
+        })
+    }
+
     /// Returns a reference to the contig.
     ///
     /// # Examples
@@ -670,7 +823,7 @@ where
         }
 
         match self.strand() {
-            Strand::Positive => {
+            Strand::Positive | Strand::Unstranded => {
                 self.start().position().get() <= coordinate.position().get()
                     && self.end().position().get() >= coordinate.position().get()
             }
@@ -741,7 +894,7 @@ where
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn contains_entity(&self, coordinate: &Coordinate<Base>) -> bool {
+    pub fn contains_entity(&self, coordinate: &Coordinate<Base<S::Number>>) -> bool {
         <Self as r#trait::Interval<S>>::contains_entity(self, coordinate)
     }
 
@@ -797,10 +950,102 @@ where
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn count_entities(&self) -> Number {
+    pub fn count_entities(&self) -> S::Number {
         <Self as r#trait::Interval<S>>::count_entities(self)
     }
 
+    /// Gets an iterator over the entities within the interval as in-base
+    /// coordinates.
+    ///
+    /// Entities are yielded in the direction of the strand: ascending on the
+    /// positive strand (and for unstranded intervals) and descending on the
+    /// negative strand. The iterator is double-ended and knows its exact
+    /// length, which makes it convenient to zip against a slice of the
+    /// underlying sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-13".parse::<Interval<Interbase>>()?;
+    /// let entities = interval
+    ///     .entities()
+    ///     .map(|c| c.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(entities, vec!["seq0:+:11", "seq0:+:12", "seq0:+:13"]);
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:13-11".parse::<Interval<Base>>()?;
+    /// assert_eq!(interval.entities().len(), 3);
+    ///
+    /// let bases = interval
+    ///     .entities()
+    ///     .zip(b"ACG")
+    ///     .map(|(c, base)| format!("{}={}", c.position(), *base as char))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(bases, vec!["13=A", "12=C", "11=G"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn entities(&self) -> iter::Entities<S::Number> {
+        let (lower, upper) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+        iter::Entities::new(self.contig.clone(), self.strand, lower, upper)
+    }
+
+    /// Gets an iterator over the boundaries of the entities within the
+    /// interval as interbase coordinates.
+    ///
+    /// An interval containing `n` entities has `n + 1` boundaries (so a
+    /// zero-sized interbase interval has exactly one boundary). Boundaries are
+    /// yielded in the direction of the strand, and the iterator is
+    /// double-ended and knows its exact length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:-:12-10".parse::<Interval<Interbase>>()?;
+    /// let boundaries = interval
+    ///     .boundaries()
+    ///     .map(|c| c.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(boundaries, vec!["seq0:-:12", "seq0:-:11", "seq0:-:10"]);
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:+:1-2".parse::<Interval<Base>>()?;
+    /// let boundaries = interval
+    ///     .boundaries()
+    ///     .map(|c| c.position().get())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(boundaries, vec![0, 1, 2]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn boundaries(&self) -> iter::Boundaries<S::Number> {
+        let (lower, upper) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+        iter::Boundaries::new(self.contig.clone(), self.strand, lower, upper)
+    }
+
     /// Consumes `self` and clamps an interval by another interval.
     ///
     /// Clamping is an operation whereby the ends of an interval are restricted
@@ -940,21 +1185,23 @@ where
         let operand_end_value = operand_end.get();
 
         let (new_start, new_end) = match start_strand {
-            Strand::Positive => (max(start, operand_start), min(end, operand_end)),
+            Strand::Positive | Strand::Unstranded => {
+                (max(start, operand_start), min(end, operand_end))
+            }
             Strand::Negative => (min(start, operand_start), max(end, operand_end)),
         };
 
         let disjoint = match start_strand {
-            Strand::Positive => new_start > new_end,
+            Strand::Positive | Strand::Unstranded => new_start > new_end,
             Strand::Negative => new_start < new_end,
         };
 
         if disjoint {
             return Err(Error::Clamp(ClampError::Disjoint {
-                original_start,
-                original_end,
-                operand_start: operand_start_value,
-                operand_end: operand_end_value,
+                original_start: original_start.to_u64(),
+                original_end: original_end.to_u64(),
+                operand_start: operand_start_value.to_u64(),
+                operand_end: operand_end_value.to_u64(),
                 strand: start_strand,
             }));
         }
@@ -1016,7 +1263,7 @@ where
     ///
     /// Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn coordinate_offset(&self, coordinate: &Coordinate<S>) -> Option<Number> {
+    pub fn coordinate_offset(&self, coordinate: &Coordinate<S>) -> Option<S::Number> {
         if !self.contains_coordinate(coordinate) {
             return None;
         }
@@ -1099,7 +1346,7 @@ where
     ///
     /// Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn coordinate_at_offset(&self, offset: Number) -> Option<Coordinate<S>> {
+    pub fn coordinate_at_offset(&self, offset: S::Number) -> Option<Coordinate<S>> {
         let coordinate = self.start().into_owned().into_move_forward(offset)?;
 
         match self.contains_coordinate(&coordinate) {
@@ -1149,10 +1396,22 @@ where
     /// let recomplemented = complemented.reverse_complement();
     /// assert_eq!(recomplemented, original);
     ///
+    /// //============//
+    /// // Unstranded //
+    /// //============//
+    ///
+    /// let original = "seq0:.:10-20".parse::<Interval<Base>>()?;
+    /// assert_eq!(original.clone().reverse_complement(), original);
+    ///
     /// Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[must_use = "this method returns a new interval"]
     pub fn reverse_complement(self) -> super::Interval<S> {
+        // NOTE: an unstranded interval has no orientation to reverse.
+        if self.strand == Strand::Unstranded {
+            return self;
+        }
+
         Self {
             contig: self.contig,
             strand: self.strand.complement(),
@@ -1160,85 +1419,995 @@ where
             end: self.start,
         }
     }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////
-// Trait implementations
-////////////////////////////////////////////////////////////////////////////////////////
 
-impl<S: System> std::fmt::Display for Interval<S>
-where
-    Interval<S>: r#trait::Interval<S>,
-    Position<S>: position::r#trait::Position<S>,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}:{}:{}-{}",
-            self.contig(),
-            self.strand(),
-            self.start().position(),
-            self.end().position(),
-        )
+    /// Returns whether or not this interval shares at least one entity with
+    /// another interval.
+    ///
+    /// Overlap is determined by the entities contained within each interval
+    /// (see [`contains_entity()`](Self::contains_entity)) rather than the
+    /// coordinates themselves. As such, two interbase intervals that only
+    /// share a boundary do not overlap, and zero-sized interbase intervals
+    /// never overlap anything.
+    ///
+    /// Both intervals must be located on the same contig and strand—otherwise,
+    /// a [`ComparisonError`] is returned. Notably, this means an unstranded
+    /// interval is never implicitly compared with a stranded one: to compare
+    /// them regardless of strand, first convert the stranded interval with
+    /// [`into_unstranded()`](Self::into_unstranded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Interbase>>()?;
+    /// assert!(interval.overlaps(&"seq0:+:19-30".parse::<Interval<Interbase>>()?)?);
+    /// assert!(!interval.overlaps(&"seq0:+:20-30".parse::<Interval<Interbase>>()?)?);
+    ///
+    /// // Unstranded intervals must be compared explicitly.
+    /// let unstranded = "seq0:.:15-25".parse::<Interval<Interbase>>()?;
+    /// assert!(interval.overlaps(&unstranded).is_err());
+    /// assert!(interval.clone().into_unstranded().overlaps(&unstranded)?);
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:20-10".parse::<Interval<Base>>()?;
+    /// assert!(interval.overlaps(&"seq0:-:10-1".parse::<Interval<Base>>()?)?);
+    /// assert!(!interval.overlaps(&"seq0:-:9-1".parse::<Interval<Base>>()?)?);
+    ///
+    /// // Intervals on different strands cannot be compared.
+    /// assert!(
+    ///     interval
+    ///         .overlaps(&"seq0:+:1-10".parse::<Interval<Base>>()?)
+    ///         .is_err()
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn overlaps(&self, other: &Interval<S>) -> ComparisonResult<bool> {
+        self.check_comparable(other)?;
+        Ok(<Self as r#trait::Interval<S>>::overlaps_unchecked(
+            self, other,
+        ))
     }
-}
 
-impl<S: System> std::str::FromStr for Interval<S>
-where
-    Interval<S>: r#trait::Interval<S>,
-    Position<S>: position::r#trait::Position<S>,
-{
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self> {
-        let (prefix, positions) =
-            s.rsplit_once(VARIANT_SEPARATOR)
-                .ok_or_else(|| ParseError::Format {
-                    value: s.to_owned(),
-                })?;
-        let (contig, strand) =
-            prefix
-                .rsplit_once(VARIANT_SEPARATOR)
-                .ok_or_else(|| ParseError::Format {
-                    value: s.to_owned(),
-                })?;
-
-        let contig = contig.parse::<Contig>().map_err(|_| {
-            Error::Parse(ParseError::Format {
-                value: s.to_string(),
-            })
-        })?;
-
-        let strand = strand.parse::<Strand>().map_err(Error::Strand)?;
-        let (start, end) = positions
-            .split_once(INTERVAL_SEPARATOR)
-            .filter(|(_, end)| !end.contains(INTERVAL_SEPARATOR))
-            .ok_or_else(|| ParseError::Format {
-                value: s.to_owned(),
-            })?;
+    /// Gets the intersection of this interval and another interval.
+    ///
+    /// The intersection is the interval covering the entities shared by both
+    /// intervals. If the intervals do not overlap (as defined by
+    /// [`overlaps()`](Self::overlaps)), [`None`] is returned. The resulting
+    /// interval is oriented on the same strand as the operands.
+    ///
+    /// Both intervals must be located on the same contig and strand—otherwise,
+    /// a [`ComparisonError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Interbase>>()?;
+    /// let other = "seq0:+:15-30".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(
+    ///     interval.intersect(&other)?,
+    ///     Some("seq0:+:15-20".parse::<Interval<Interbase>>()?)
+    /// );
+    ///
+    /// let other = "seq0:+:20-30".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(interval.intersect(&other)?, None);
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:20-10".parse::<Interval<Base>>()?;
+    /// let other = "seq0:-:30-15".parse::<Interval<Base>>()?;
+    /// assert_eq!(
+    ///     interval.intersect(&other)?,
+    ///     Some("seq0:-:20-15".parse::<Interval<Base>>()?)
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn intersect(&self, other: &Interval<S>) -> ComparisonResult<Option<Interval<S>>> {
+        if !self.overlaps(other)? {
+            return Ok(None);
+        }
 
-        let start = start.parse::<Position<S>>().map_err(Error::Position)?;
-        let end = end.parse::<Position<S>>().map_err(Error::Position)?;
+        let lower = max(self.lower(), other.lower()).clone();
+        let upper = min(self.upper(), other.upper()).clone();
 
-        Interval::try_new(
-            Coordinate::new(contig.clone(), strand, start),
-            Coordinate::new(contig, strand, end),
-        )
+        Ok(Some(Self::from_bounds(
+            self.contig.clone(),
+            self.strand,
+            lower,
+            upper,
+        )))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::position::Error as PositionError;
-    use crate::position::Number;
-    use crate::position::ParseError as PositionParseError;
-    use crate::strand::Error as StrandError;
-    use crate::strand::ParseError as StrandParseError;
-    use crate::system::Base;
-    use crate::system::Interbase;
+    /// Gets the span of this interval and another interval.
+    ///
+    /// The span is the smallest interval that covers both intervals,
+    /// including any gap that lies between them. The resulting interval is
+    /// oriented on the same strand as the operands.
+    ///
+    /// Both intervals must be located on the same contig and strand—otherwise,
+    /// a [`ComparisonError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Interbase>>()?;
+    /// let other = "seq0:+:30-40".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(
+    ///     interval.span(&other)?,
+    ///     "seq0:+:10-40".parse::<Interval<Interbase>>()?
+    /// );
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:20-10".parse::<Interval<Base>>()?;
+    /// let other = "seq0:-:40-30".parse::<Interval<Base>>()?;
+    /// assert_eq!(
+    ///     interval.span(&other)?,
+    ///     "seq0:-:40-10".parse::<Interval<Base>>()?
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn span(&self, other: &Interval<S>) -> ComparisonResult<Interval<S>> {
+        self.check_comparable(other)?;
+
+        let lower = min(self.lower(), other.lower()).clone();
+        let upper = max(self.upper(), other.upper()).clone();
+
+        Ok(Self::from_bounds(
+            self.contig.clone(),
+            self.strand,
+            lower,
+            upper,
+        ))
+    }
 
-    #[test]
+    /// Gets the union of this interval and another interval.
+    ///
+    /// The union is only representable as a single interval when no entities
+    /// lie between the two intervals (i.e., they either overlap or are
+    /// directly adjacent to one another). In that case, the union is equal to
+    /// the [`span()`](Self::span) of the two intervals. Otherwise, [`None`] is
+    /// returned.
+    ///
+    /// Both intervals must be located on the same contig and strand—otherwise,
+    /// a [`ComparisonError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Interbase>>()?;
+    /// let other = "seq0:+:20-30".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(
+    ///     interval.union(&other)?,
+    ///     Some("seq0:+:10-30".parse::<Interval<Interbase>>()?)
+    /// );
+    ///
+    /// let other = "seq0:+:21-30".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(interval.union(&other)?, None);
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Base>>()?;
+    /// let other = "seq0:+:21-30".parse::<Interval<Base>>()?;
+    /// assert_eq!(
+    ///     interval.union(&other)?,
+    ///     Some("seq0:+:10-30".parse::<Interval<Base>>()?)
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn union(&self, other: &Interval<S>) -> ComparisonResult<Option<Interval<S>>> {
+        if self.gap_between(other)?.is_some() {
+            return Ok(None);
+        }
+
+        self.span(other).map(Some)
+    }
+
+    /// Gets the gap between this interval and another interval.
+    ///
+    /// The gap is the interval covering the entities that lie strictly between
+    /// the two intervals. If no such entities exist (i.e., the intervals
+    /// overlap or are directly adjacent to one another), [`None`] is returned.
+    /// The resulting interval is oriented on the same strand as the operands.
+    ///
+    /// Both intervals must be located on the same contig and strand—otherwise,
+    /// a [`ComparisonError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Interbase>>()?;
+    /// let other = "seq0:+:25-30".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(
+    ///     interval.gap_between(&other)?,
+    ///     Some("seq0:+:20-25".parse::<Interval<Interbase>>()?)
+    /// );
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:30-25".parse::<Interval<Base>>()?;
+    /// let other = "seq0:-:20-10".parse::<Interval<Base>>()?;
+    /// assert_eq!(
+    ///     interval.gap_between(&other)?,
+    ///     Some("seq0:-:24-21".parse::<Interval<Base>>()?)
+    /// );
+    ///
+    /// let other = "seq0:-:24-10".parse::<Interval<Base>>()?;
+    /// assert_eq!(interval.gap_between(&other)?, None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn gap_between(&self, other: &Interval<S>) -> ComparisonResult<Option<Interval<S>>> {
+        self.check_comparable(other)?;
+
+        Ok(
+            <Self as r#trait::Interval<S>>::gap_unchecked(self, other).map(|(lower, upper)| {
+                Self::from_bounds(self.contig.clone(), self.strand, lower, upper)
+            }),
+        )
+    }
+
+    /// Gets the distance between this interval and another interval.
+    ///
+    /// The distance is the number of entities that lie strictly between the
+    /// two intervals. Intervals that overlap or that are directly adjacent to
+    /// one another have a distance of zero (`0`).
+    ///
+    /// Both intervals must be located on the same contig and strand—otherwise,
+    /// a [`ComparisonError`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(
+    ///     interval.distance(&"seq0:+:25-30".parse::<Interval<Interbase>>()?)?,
+    ///     5
+    /// );
+    /// assert_eq!(
+    ///     interval.distance(&"seq0:+:20-30".parse::<Interval<Interbase>>()?)?,
+    ///     0
+    /// );
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Base>>()?;
+    /// assert_eq!(
+    ///     interval.distance(&"seq0:+:25-30".parse::<Interval<Base>>()?)?,
+    ///     4
+    /// );
+    /// assert_eq!(
+    ///     interval.distance(&"seq0:+:21-30".parse::<Interval<Base>>()?)?,
+    ///     0
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn distance(&self, other: &Interval<S>) -> ComparisonResult<S::Number> {
+        Ok(self
+            .gap_between(other)?
+            .map(|gap| gap.count_entities())
+            .unwrap_or_default())
+    }
+
+    /// Consumes `self` and returns the same span of entities without a strand.
+    ///
+    /// The resulting interval is [`Strand::Unstranded`] and, as such, is
+    /// oriented from its numerically lower position to its numerically upper
+    /// position. This is the explicit way to compare a stranded interval with
+    /// an unstranded one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let interval = "seq0:-:20-10".parse::<Interval<Base>>()?;
+    /// assert_eq!(interval.into_unstranded().to_string(), "seq0:.:10-20");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use = "this method returns a new interval"]
+    pub fn into_unstranded(self) -> Interval<S> {
+        let (lower, upper) = match self.strand {
+            Strand::Positive | Strand::Unstranded => (self.start, self.end),
+            Strand::Negative => (self.end, self.start),
+        };
+
+        Self::from_bounds(self.contig, Strand::Unstranded, lower, upper)
+    }
+
+    /// Gets the interval of `magnitude` entities directly upstream of this
+    /// interval.
+    ///
+    /// This method is dependent on the strand of the interval:
+    ///
+    /// - on the [`Strand::Positive`] (and for unstranded intervals), the flank
+    ///   lies at numerically lower positions than the interval, and
+    /// - on the [`Strand::Negative`], the flank lies at numerically higher
+    ///   positions than the interval.
+    ///
+    /// If `length` is provided, it is treated as the length of the contig and
+    /// the flank is clamped to the bounds of the contig (an
+    /// [`OutOfBounds`](ResizeError::OutOfBounds) error is returned if the
+    /// interval itself does not lie within the contig). Otherwise, a flank
+    /// that would extend past the first or last representable position results
+    /// in an [`Underflow`](ResizeError::Underflow) or
+    /// [`Overflow`](ResizeError::Overflow) error. If the flank would contain
+    /// no entities, an [`Empty`](ResizeError::Empty) error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:100-200".parse::<Interval<Interbase>>()?;
+    /// let flank = interval.flank_upstream(50, None)?;
+    /// assert_eq!(flank.to_string(), "seq0:+:50-100");
+    ///
+    /// let flank = interval.flank_upstream(500, Some(1000))?;
+    /// assert_eq!(flank.to_string(), "seq0:+:0-100");
+    ///
+    /// assert!(interval.flank_upstream(500, None).is_err());
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:200-101".parse::<Interval<Base>>()?;
+    /// let flank = interval.flank_upstream(50, None)?;
+    /// assert_eq!(flank.to_string(), "seq0:-:250-201");
+    ///
+    /// let flank = interval.flank_upstream(50, Some(220))?;
+    /// assert_eq!(flank.to_string(), "seq0:-:220-201");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn flank_upstream(
+        &self,
+        magnitude: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<Interval<S>> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.flank_lower(magnitude, length),
+            Strand::Negative => self.flank_upper(magnitude, length),
+        }
+    }
+
+    /// Gets the interval of `magnitude` entities directly downstream of this
+    /// interval.
+    ///
+    /// This method is dependent on the strand of the interval:
+    ///
+    /// - on the [`Strand::Positive`] (and for unstranded intervals), the flank
+    ///   lies at numerically higher positions than the interval, and
+    /// - on the [`Strand::Negative`], the flank lies at numerically lower
+    ///   positions than the interval.
+    ///
+    /// Clamping and errors are handled in the same manner as
+    /// [`flank_upstream()`](Self::flank_upstream).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:-:200-100".parse::<Interval<Interbase>>()?;
+    /// let flank = interval.flank_downstream(50, None)?;
+    /// assert_eq!(flank.to_string(), "seq0:-:100-50");
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:+:101-200".parse::<Interval<Base>>()?;
+    /// let flank = interval.flank_downstream(50, None)?;
+    /// assert_eq!(flank.to_string(), "seq0:+:201-250");
+    ///
+    /// // A flank that lies entirely beyond the contig contains no entities.
+    /// assert!(interval.flank_downstream(50, Some(200)).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn flank_downstream(
+        &self,
+        magnitude: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<Interval<S>> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.flank_upper(magnitude, length),
+            Strand::Negative => self.flank_lower(magnitude, length),
+        }
+    }
+
+    /// Extends the interval by `upstream` entities before its start and by
+    /// `downstream` entities after its end.
+    ///
+    /// This method is dependent on the strand of the interval: on the
+    /// [`Strand::Negative`], upstream lies at numerically higher positions and
+    /// downstream lies at numerically lower positions.
+    ///
+    /// Clamping and errors are handled in the same manner as
+    /// [`flank_upstream()`](Self::flank_upstream).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::interval::ResizeError;
+    /// use omics_coordinate::position::Number;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:-:200-100".parse::<Interval<Interbase>>()?;
+    /// let extended = interval.extend(10, 20, None)?;
+    /// assert_eq!(extended.to_string(), "seq0:-:210-80");
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:+:10-20".parse::<Interval<Base>>()?;
+    /// assert_eq!(
+    ///     interval.extend(20, 0, None).unwrap_err(),
+    ///     ResizeError::Underflow {
+    ///         position: 10,
+    ///         magnitude: 20
+    ///     }
+    /// );
+    ///
+    /// let extended = interval.extend(20, Number::MAX, Some(1000))?;
+    /// assert_eq!(extended.to_string(), "seq0:+:1-1000");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn extend(
+        &self,
+        upstream: S::Number,
+        downstream: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<Interval<S>> {
+        self.check_within(length)?;
+
+        if upstream == S::Number::ZERO && downstream == S::Number::ZERO {
+            return Ok(self.clone());
+        }
+
+        let (below, above) = self.orient(upstream, downstream);
+        let (start, end) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+
+        let start = self.lower_entity_bound(start, below, length)?;
+        let end = self.raise_entity_bound(end, above, length)?;
+
+        self.resized(start, end)
+    }
+
+    /// Extends the interval by `magnitude` entities on both sides.
+    ///
+    /// This is equivalent to calling [`extend()`](Self::extend) with the same
+    /// magnitude upstream and downstream (akin to `bedtools slop -b`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let interval = "seq0:+:100-200".parse::<Interval<Interbase>>()?;
+    /// assert_eq!(interval.slop(10, None)?.to_string(), "seq0:+:90-210");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn slop(
+        &self,
+        magnitude: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<Interval<S>> {
+        self.extend(magnitude, magnitude, length)
+    }
+
+    /// Shrinks the interval by `upstream` entities at its start and by
+    /// `downstream` entities at its end.
+    ///
+    /// This method is dependent on the strand of the interval in the same
+    /// manner as [`extend()`](Self::extend). If the shrunken interval would
+    /// not contain any entities, an [`Empty`](ResizeError::Empty) error is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let interval = "seq0:-:200-101".parse::<Interval<Base>>()?;
+    /// let shrunk = interval.shrink(10, 20)?;
+    /// assert_eq!(shrunk.to_string(), "seq0:-:190-121");
+    ///
+    /// assert!(interval.shrink(50, 49).is_ok());
+    /// assert!(interval.shrink(50, 50).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn shrink(&self, upstream: S::Number, downstream: S::Number) -> ResizeResult<Interval<S>> {
+        if upstream == S::Number::ZERO && downstream == S::Number::ZERO {
+            return Ok(self.clone());
+        }
+
+        let (below, above) = self.orient(upstream, downstream);
+        let (start, end) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+
+        self.resized(start.saturating_add(below), end.saturating_sub(above))
+    }
+
+    /// Resizes the interval to contain `size` entities while keeping it
+    /// centered on the same location.
+    ///
+    /// When the change in size is odd, the extra entity is added to (or
+    /// removed from) the downstream end of the interval. When growing, the
+    /// interval is clamped and errors are reported in the same manner as
+    /// [`extend()`](Self::extend), so a clamped interval may contain fewer
+    /// than `size` entities. A `size` of zero results in an
+    /// [`Empty`](ResizeError::Empty) error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// //===========//
+    /// // Interbase //
+    /// //===========//
+    ///
+    /// let interval = "seq0:+:100-110".parse::<Interval<Interbase>>()?;
+    /// let resized = interval.resize_around_center(4, None)?;
+    /// assert_eq!(resized.to_string(), "seq0:+:103-107");
+    ///
+    /// let resized = interval.resize_around_center(15, None)?;
+    /// assert_eq!(resized.to_string(), "seq0:+:98-113");
+    ///
+    /// //======//
+    /// // Base //
+    /// //======//
+    ///
+    /// let interval = "seq0:-:110-101".parse::<Interval<Base>>()?;
+    /// let resized = interval.resize_around_center(15, None)?;
+    /// assert_eq!(resized.to_string(), "seq0:-:112-98");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resize_around_center(
+        &self,
+        size: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<Interval<S>> {
+        if size == S::Number::ZERO {
+            return Err(ResizeError::Empty);
+        }
+
+        self.check_within(length)?;
+
+        let (start, end) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+        let count = end - start;
+
+        let two = S::Number::from(2u8);
+
+        if size >= count {
+            let difference = size - count;
+            self.extend(difference / two, difference - difference / two, length)
+        } else {
+            let difference = count - size;
+            self.shrink(difference / two, difference - difference / two)
+        }
+    }
+
+    /// Orients upstream and downstream magnitudes into numerically lower and
+    /// upper magnitudes based on the strand of the interval.
+    fn orient(&self, upstream: S::Number, downstream: S::Number) -> (S::Number, S::Number) {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => (upstream, downstream),
+            Strand::Negative => (downstream, upstream),
+        }
+    }
+
+    /// Ensures that the interval lies within a contig of the provided length
+    /// (if one is provided).
+    fn check_within(&self, length: Option<S::Number>) -> ResizeResult<()> {
+        match length {
+            Some(length) if self.upper().get() > length => Err(ResizeError::OutOfBounds {
+                contig: self.contig.clone(),
+                position: self.upper().get().to_u64(),
+                length: length.to_u64(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Moves a numerically lower entity bound down by `magnitude`, clamping to
+    /// the start of the contig if a length is provided.
+    fn lower_entity_bound(
+        &self,
+        bound: S::Number,
+        magnitude: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<S::Number> {
+        match bound.checked_sub(magnitude) {
+            Some(bound) => Ok(bound),
+            None if length.is_some() => Ok(S::Number::ZERO),
+            None => Err(ResizeError::Underflow {
+                position: self.lower().get().to_u64(),
+                magnitude: magnitude.to_u64(),
+            }),
+        }
+    }
+
+    /// Moves a numerically upper entity bound up by `magnitude`, clamping to
+    /// the end of the contig if a length is provided.
+    fn raise_entity_bound(
+        &self,
+        bound: S::Number,
+        magnitude: S::Number,
+        length: Option<S::Number>,
+    ) -> ResizeResult<S::Number> {
+        match (bound.checked_add(magnitude), length) {
+            (Some(bound), Some(length)) => Ok(min(bound, length)),
+            (Some(bound), None) => Ok(bound),
+            (None, Some(length)) => Ok(length),
+            (None, None) => Err(ResizeError::Overflow {
+                position: self.upper().get().to_u64(),
+                magnitude: magnitude.to_u64(),
+            }),
+        }
+    }
+
+    /// Gets the flank of `magnitude` entities at numerically lower positions
+    /// than the interval.
+    fn flank_lower(&self, magnitude: S::Number, length: Option<S::Number>) -> ResizeResult<Self> {
+        self.check_within(length)?;
+
+        let (start, _) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+        let lower = self.lower_entity_bound(start, magnitude, length)?;
+
+        self.resized(lower, start)
+    }
+
+    /// Gets the flank of `magnitude` entities at numerically higher positions
+    /// than the interval.
+    fn flank_upper(&self, magnitude: S::Number, length: Option<S::Number>) -> ResizeResult<Self> {
+        self.check_within(length)?;
+
+        let (_, end) = <Self as r#trait::Interval<S>>::entity_bounds(self);
+        let upper = self.raise_entity_bound(end, magnitude, length)?;
+
+        self.resized(end, upper)
+    }
+
+    /// Creates an interval on the same contig and strand as this interval
+    /// containing the entities within a half-open range of interbase
+    /// positions.
+    fn resized(&self, start: S::Number, end: S::Number) -> ResizeResult<Self> {
+        <Self as r#trait::Interval<S>>::from_entity_bounds(
+            self.contig.clone(),
+            self.strand,
+            start,
+            end,
+        )
+        .ok_or(ResizeError::Empty)
+    }
+
+    /// Ensures that another interval is located on the same contig and strand
+    /// as this interval.
+    fn check_comparable(&self, other: &Interval<S>) -> ComparisonResult<()> {
+        if self.contig != other.contig {
+            return Err(ComparisonError::MismatchedContigs {
+                original: self.contig.clone(),
+                operand: other.contig.clone(),
+            });
+        }
+
+        if self.strand != other.strand {
+            return Err(ComparisonError::MismatchedStrand {
+                original: self.strand,
+                operand: other.strand,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Parses an interval, creating its contig with the provided function.
+    pub(crate) fn parse_with(
+        s: &str,
+        contig: impl FnOnce(&str) -> contig::Result<Contig>,
+    ) -> Result<Self> {
+        let (prefix, positions) =
+            s.rsplit_once(VARIANT_SEPARATOR)
+                .ok_or_else(|| ParseError::Format {
+                    value: s.to_owned(),
+                })?;
+        let (name, strand) =
+            prefix
+                .rsplit_once(VARIANT_SEPARATOR)
+                .ok_or_else(|| ParseError::Format {
+                    value: s.to_owned(),
+                })?;
+
+        let strand = strand.parse::<Strand>().map_err(Error::Strand)?;
+        let (start, end) = positions
+            .split_once(INTERVAL_SEPARATOR)
+            .filter(|(_, end)| !end.contains(INTERVAL_SEPARATOR))
+            .ok_or_else(|| ParseError::Format {
+                value: s.to_owned(),
+            })?;
+
+        let start = start.parse::<Position<S>>().map_err(Error::Position)?;
+        let end = end.parse::<Position<S>>().map_err(Error::Position)?;
+        check_size(strand, &start, &end)?;
+
+        // NOTE: the contig is only created once everything else is known to be
+        // valid so that rejected input never reaches the contig function.
+        let contig = contig(name).map_err(|_| {
+            Error::Parse(ParseError::Format {
+                value: s.to_string(),
+            })
+        })?;
+
+        Ok(Self {
+            contig,
+            strand,
+            start,
+            end,
+        })
+    }
+}
+
+/// Checks that the end of an interval does not lie before its start with
+/// respect to its strand.
+fn check_size<S: System>(strand: Strand, start: &Position<S>, end: &Position<S>) -> Result<()>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    let negatively_sized = match strand {
+        Strand::Positive | Strand::Unstranded => start > end,
+        Strand::Negative => end > start,
+    };
+
+    if negatively_sized {
+        return Err(Error::Nonsensical(NonsensicalError::NegativelySized {
+            start: start.get().to_u64(),
+            end: end.get().to_u64(),
+            strand,
+        }));
+    }
+
+    Ok(())
+}
+
+impl<S: System> Interval<S>
+where
+    Interval<S>: r#trait::Interval<S>,
+{
+    /// Gets the entities contained within the interval as a half-open range of
+    /// interbase positions.
+    ///
+    /// This is shorthand for
+    /// [`entity_bounds()`](r#trait::Interval::entity_bounds) that avoids
+    /// spelling out the fully qualified trait path at each call site.
+    pub(crate) fn entity_range(&self) -> (S::Number, S::Number) {
+        <Self as r#trait::Interval<S>>::entity_bounds(self)
+    }
+}
+
+impl<S: System> Interval<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates an interval from its numerically lower and upper positions,
+    /// orienting the start and end based on the strand.
+    ///
+    /// The caller is responsible for ensuring that `lower <= upper`.
+    pub(crate) fn from_bounds(
+        contig: Contig,
+        strand: Strand,
+        lower: Position<S>,
+        upper: Position<S>,
+    ) -> Self {
+        let (start, end) = match strand {
+            Strand::Positive | Strand::Unstranded => (lower, upper),
+            Strand::Negative => (upper, lower),
+        };
+
+        Self {
+            contig,
+            strand,
+            start,
+            end,
+        }
+    }
+
+    /// Consumes `self` and returns the same interval on a different contig.
+    pub(crate) fn with_contig(self, contig: Contig) -> Self {
+        Self { contig, ..self }
+    }
+
+    /// Gets the numerically lower of the start and end positions.
+    pub(crate) fn lower(&self) -> &Position<S> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => &self.start,
+            Strand::Negative => &self.end,
+        }
+    }
+
+    /// Gets the numerically upper of the start and end positions.
+    pub(crate) fn upper(&self) -> &Position<S> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => &self.end,
+            Strand::Negative => &self.start,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<S: System> core::fmt::Display for Interval<S>
+where
+    Interval<S>: r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}-{}",
+            self.contig(),
+            self.strand(),
+            self.start().position(),
+            self.end().position(),
+        )
+    }
+}
+
+impl<S: System> core::str::FromStr for Interval<S>
+where
+    Interval<S>: r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse_with(s, |name| Contig::try_new(name))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<S: System> serde::Serialize for Interval<S>
+where
+    Interval<S>: r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Serializes an interval in its `contig:strand:start-end` string form.
+    fn serialize<Se: serde::Serializer>(
+        &self,
+        serializer: Se,
+    ) -> core::result::Result<Se::Ok, Se::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: System> serde::Deserialize<'de> for Interval<S>
+where
+    Interval<S>: r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Deserializes an interval from its `contig:strand:start-end` string
+    /// form, validating it as [`Interval::try_new()`] does.
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::position::Error as PositionError;
+    use crate::position::Number;
+    use crate::position::ParseError as PositionParseError;
+    use crate::strand::Error as StrandError;
+    use crate::strand::ParseError as StrandParseError;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    #[test]
     fn valid() {
         let start = "seq0:+:0".parse::<Coordinate<Interbase>>().unwrap();
         let end = "seq0:+:9".parse::<Coordinate<Interbase>>().unwrap();
@@ -1358,8 +2527,8 @@ mod tests {
     #[test]
     fn interval_storage_is_smaller_than_two_coordinates() {
         assert!(
-            std::mem::size_of::<Interval<Interbase>>()
-                < 2 * std::mem::size_of::<Coordinate<Interbase>>()
+            core::mem::size_of::<Interval<Interbase>>()
+                < 2 * core::mem::size_of::<Coordinate<Interbase>>()
         );
     }
 
@@ -1373,8 +2542,8 @@ mod tests {
         let end_ref: CoordinateRef<'_, Interbase> = interval.end();
         let start_position = interval.start().position();
 
-        assert!(std::ptr::eq(start_ref.contig(), interval.contig()));
-        assert!(std::ptr::eq(end_ref.contig(), interval.contig()));
+        assert!(core::ptr::eq(start_ref.contig(), interval.contig()));
+        assert!(core::ptr::eq(end_ref.contig(), interval.contig()));
         assert_eq!(start_ref, interval.start());
         assert_eq!(end_ref, interval.end());
         assert_eq!(start_ref.into_owned(), start);
@@ -1551,6 +2720,95 @@ mod tests {
         );
     }
 
+    #[test]
+    fn comparison_mismatches() {
+        let interval = "seq0:+:10-20".parse::<Interval<Interbase>>().unwrap();
+
+        let other = "seq1:+:10-20".parse::<Interval<Interbase>>().unwrap();
+        let err = interval.overlaps(&other).unwrap_err();
+        assert_eq!(
+            err,
+            ComparisonError::MismatchedContigs {
+                original: Contig::new_unchecked("seq0"),
+                operand: Contig::new_unchecked("seq1"),
+            }
+        );
+        assert_eq!(err.to_string(), "mismatched contigs: `seq0` and `seq1`");
+        assert!(interval.intersect(&other).is_err());
+        assert!(interval.span(&other).is_err());
+        assert!(interval.union(&other).is_err());
+        assert!(interval.gap_between(&other).is_err());
+        assert!(interval.distance(&other).is_err());
+
+        let other = "seq0:-:20-10".parse::<Interval<Interbase>>().unwrap();
+        let err = interval.overlaps(&other).unwrap_err();
+        assert_eq!(
+            err,
+            ComparisonError::MismatchedStrand {
+                original: Strand::Positive,
+                operand: Strand::Negative,
+            }
+        );
+        assert_eq!(
+            Error::from(err).to_string(),
+            "comparison error: mismatched strand: `+` and `-`"
+        );
+    }
+
+    #[test]
+    fn comparisons_are_symmetric() {
+        let intervals = [
+            "seq0:-:20-10",
+            "seq0:-:15-5",
+            "seq0:-:30-20",
+            "seq0:-:40-35",
+            "seq0:-:15-15",
+        ]
+        .map(|value| value.parse::<Interval<Interbase>>().unwrap());
+
+        for a in &intervals {
+            for b in &intervals {
+                assert_eq!(a.overlaps(b), b.overlaps(a));
+                assert_eq!(a.intersect(b), b.intersect(a));
+                assert_eq!(a.span(b), b.span(a));
+                assert_eq!(a.union(b), b.union(a));
+                assert_eq!(a.gap_between(b), b.gap_between(a));
+                assert_eq!(a.distance(b), b.distance(a));
+            }
+        }
+    }
+
+    #[test]
+    fn negative_strand_comparisons() {
+        let interval = "seq0:-:20-10".parse::<Interval<Interbase>>().unwrap();
+        let other = "seq0:-:40-30".parse::<Interval<Interbase>>().unwrap();
+
+        assert!(!interval.overlaps(&other).unwrap());
+        assert_eq!(interval.intersect(&other).unwrap(), None);
+        assert_eq!(
+            interval.span(&other).unwrap(),
+            "seq0:-:40-10".parse::<Interval<Interbase>>().unwrap()
+        );
+        assert_eq!(interval.union(&other).unwrap(), None);
+        assert_eq!(
+            interval.gap_between(&other).unwrap(),
+            Some("seq0:-:30-20".parse::<Interval<Interbase>>().unwrap())
+        );
+        assert_eq!(interval.distance(&other).unwrap(), 10);
+
+        let other = "seq0:-:25-15".parse::<Interval<Interbase>>().unwrap();
+        assert!(interval.overlaps(&other).unwrap());
+        assert_eq!(
+            interval.intersect(&other).unwrap(),
+            Some("seq0:-:20-15".parse::<Interval<Interbase>>().unwrap())
+        );
+        assert_eq!(
+            interval.union(&other).unwrap(),
+            Some("seq0:-:25-10".parse::<Interval<Interbase>>().unwrap())
+        );
+        assert_eq!(interval.distance(&other).unwrap(), 0);
+    }
+
     #[test]
     fn positive_strand_offset() {
         let interval = "seq0:+:1000-2000".parse::<Interval<Interbase>>().unwrap();
@@ -1772,4 +3030,143 @@ mod tests {
 
         assert_eq!(interval.to_string(), "seq0:-:10-0");
     }
+
+    #[test]
+    fn unstranded() {
+        let interval = "seq0:.:10-20".parse::<Interval<Base>>().unwrap();
+        assert_eq!(interval.strand(), Strand::Unstranded);
+        assert_eq!(interval.count_entities(), 11);
+        assert_eq!(interval.to_string(), "seq0:.:10-20");
+        assert!(interval.contains_coordinate(&"seq0:.:15".parse().unwrap()));
+        assert!(!interval.contains_coordinate(&"seq0:+:15".parse().unwrap()));
+
+        let err = "seq0:.:20-10".parse::<Interval<Base>>().unwrap_err();
+        assert_eq!(
+            err,
+            Error::Nonsensical(NonsensicalError::NegativelySized {
+                start: 20,
+                end: 10,
+                strand: Strand::Unstranded
+            })
+        );
+
+        let stranded = "seq0:-:25-15".parse::<Interval<Base>>().unwrap();
+        let err = interval.overlaps(&stranded).unwrap_err();
+        assert_eq!(
+            err,
+            ComparisonError::MismatchedStrand {
+                original: Strand::Unstranded,
+                operand: Strand::Negative,
+            }
+        );
+
+        let stranded = stranded.into_unstranded();
+        assert!(interval.overlaps(&stranded).unwrap());
+        assert_eq!(
+            interval.intersect(&stranded).unwrap().unwrap().to_string(),
+            "seq0:.:15-20"
+        );
+    }
+
+    #[test]
+    fn resize() {
+        let interval = "seq0:+:10-20".parse::<Interval<Base>>().unwrap();
+        let upstream = interval.flank_upstream(9, None).unwrap();
+        assert_eq!(upstream.to_string(), "seq0:+:1-9");
+        assert_eq!(
+            interval.flank_upstream(10, None).unwrap_err(),
+            ResizeError::Underflow {
+                position: 10,
+                magnitude: 10
+            }
+        );
+        assert_eq!(
+            interval.flank_upstream(0, None).unwrap_err(),
+            ResizeError::Empty
+        );
+        assert_eq!(
+            interval.flank_downstream(5, Some(15)).unwrap_err(),
+            ResizeError::OutOfBounds {
+                contig: Contig::new_unchecked("seq0"),
+                position: 20,
+                length: 15
+            }
+        );
+
+        // Flanks on the negative strand mirror those on the positive strand.
+        let reversed = interval.clone().reverse_complement();
+        assert_eq!(
+            reversed.flank_upstream(5, None).unwrap(),
+            interval
+                .flank_downstream(5, None)
+                .unwrap()
+                .reverse_complement()
+        );
+        assert_eq!(
+            reversed.flank_downstream(5, None).unwrap(),
+            interval
+                .flank_upstream(5, None)
+                .unwrap()
+                .reverse_complement()
+        );
+
+        let interval = format!("seq0:+:10-{}", Number::MAX - 1)
+            .parse::<Interval<Interbase>>()
+            .unwrap();
+        assert_eq!(
+            interval.extend(0, 2, None).unwrap_err(),
+            ResizeError::Overflow {
+                position: u64::from(Number::MAX - 1),
+                magnitude: 2
+            }
+        );
+        assert_eq!(
+            interval.extend(0, 1, None).unwrap().upper().get(),
+            Number::MAX
+        );
+
+        // Zero-sized interbase intervals are left untouched by no-op resizes
+        // but can be extended.
+        let interval = "seq0:+:10-10".parse::<Interval<Interbase>>().unwrap();
+        assert_eq!(interval.slop(0, None).unwrap(), interval);
+        assert_eq!(interval.shrink(0, 0).unwrap(), interval);
+        assert_eq!(interval.slop(5, None).unwrap().to_string(), "seq0:+:5-15");
+
+        let interval = "seq0:.:11-20".parse::<Interval<Base>>().unwrap();
+        assert_eq!(
+            interval.shrink(Number::MAX, 0).unwrap_err(),
+            ResizeError::Empty
+        );
+        assert_eq!(
+            interval.resize_around_center(1, None).unwrap().to_string(),
+            "seq0:.:15-15"
+        );
+        assert_eq!(
+            interval.resize_around_center(0, None).unwrap_err(),
+            ResizeError::Empty
+        );
+        assert_eq!(
+            interval
+                .resize_around_center(100, Some(50))
+                .unwrap()
+                .to_string(),
+            "seq0:.:1-50"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let interval = "seq0:-:10-1".parse::<Interval<Base>>().unwrap();
+        let json = serde_json::to_string(&interval).unwrap();
+        assert_eq!(json, r#""seq0:-:10-1""#);
+        assert_eq!(
+            serde_json::from_str::<Interval<Base>>(&json).unwrap(),
+            interval
+        );
+
+        // Intervals are validated just as they are when constructed.
+        assert!(serde_json::from_str::<Interval<Base>>(r#""seq0:+:10-1""#).is_err());
+        assert!(serde_json::from_str::<Interval<Base>>(r#""seq0:+:0-1""#).is_err());
+    }
 }
@@ -1,8 +1,14 @@
 //! Base intervals.
 
-use crate::base::Coordinate;
+use core::cmp::max;
+use core::cmp::min;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Position;
+use crate::Strand;
 use crate::interval::r#trait;
-use crate::position::Number;
+use crate::position::Width;
 use crate::system::Base;
 use crate::system::Interbase;
 
@@ -16,7 +22,7 @@ use crate::system::Interbase;
 /// the interval `[start, end]`.
 pub type Interval = crate::Interval<Base>;
 
-impl Interval {
+impl<W: Width> crate::Interval<Base<W>> {
     /// Consumes `self` and returns the equivalent interbase interval.
     ///
     /// # Examples
@@ -33,7 +39,7 @@ impl Interval {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn into_equivalent_interbase(self) -> crate::interval::Interval<Interbase> {
+    pub fn into_equivalent_interbase(self) -> crate::interval::Interval<Interbase<W>> {
         let (start, end) = self.into_coordinates();
 
         // SAFETY: given the rules of how interbase and base coordinate systems
@@ -43,7 +49,7 @@ impl Interval {
 
         // SAFETY: since this was previously a valid interbase interval, as long
         // as the two nudges above succeed, this should always unwrap.
-        crate::interval::Interval::<Interbase>::try_new(start, end).unwrap()
+        crate::interval::Interval::<Interbase<W>>::try_new(start, end).unwrap()
     }
 }
 
@@ -51,8 +57,8 @@ impl Interval {
 // Trait implementations
 ////////////////////////////////////////////////////////////////////////////////////////
 
-impl r#trait::Interval<Base> for Interval {
-    fn contains_entity(&self, coordinate: &Coordinate) -> bool {
+impl<W: Width> r#trait::Interval<Base<W>> for crate::Interval<Base<W>> {
+    fn contains_entity(&self, coordinate: &Coordinate<Base<W>>) -> bool {
         // NOTE: for in-base positions whether or not the entity is contained in
         // the interval matches the implementation of whether or not the
         // coordinate is contained within the interval, as entities and
@@ -61,11 +67,55 @@ impl r#trait::Interval<Base> for Interval {
     }
 
     /// Gets the number of entities within the interval.
-    fn count_entities(&self) -> Number {
+    fn count_entities(&self) -> W {
         self.start()
             .position()
             .distance_unchecked(self.end().position())
-            + 1
+            + W::ONE
+    }
+
+    fn overlaps_unchecked(&self, other: &Self) -> bool {
+        max(self.lower(), other.lower()) <= min(self.upper(), other.upper())
+    }
+
+    fn gap_unchecked(&self, other: &Self) -> Option<(Position<Base<W>>, Position<Base<W>>)> {
+        // NOTE: in-base intervals are closed on both ends, so the gap starts
+        // at the entity _after_ the first interval and stops at the entity
+        // _before_ the second interval.
+        let lower = min(self.upper(), other.upper()).get().checked_add(W::ONE)?;
+        let upper = max(self.lower(), other.lower()).get().checked_sub(W::ONE)?;
+
+        if lower > upper {
+            return None;
+        }
+
+        // SAFETY: `lower` is at least two and `upper` is at least `lower`, so
+        // neither value can be zero.
+        Some((
+            Position::<Base<W>>::try_new(lower).unwrap(),
+            Position::<Base<W>>::try_new(upper).unwrap(),
+        ))
+    }
+
+    fn entity_bounds(&self) -> (W, W) {
+        // NOTE: the lower position of an in-base interval is always at least
+        // one, so this cannot underflow.
+        (self.lower().get() - W::ONE, self.upper().get())
+    }
+
+    fn from_entity_bounds(contig: Contig, strand: Strand, start: W, end: W) -> Option<Self> {
+        if start >= end {
+            return None;
+        }
+
+        // SAFETY: `start` is strictly less than `end`, so `start + 1` cannot
+        // overflow and neither value can be zero.
+        Some(Self::from_bounds(
+            contig,
+            strand,
+            Position::<Base<W>>::try_new(start + W::ONE).unwrap(),
+            Position::<Base<W>>::try_new(end).unwrap(),
+        ))
     }
 }
 
@@ -73,6 +123,7 @@ impl r#trait::Interval<Base> for Interval {
 mod tests {
     use super::*;
     use crate::Coordinate;
+    use crate::position::Number;
     use crate::system::Base;
 
     fn create_coordinate(contig: &str, strand: &str, position: Number) -> crate::Coordinate<Base> {
@@ -206,4 +257,79 @@ mod tests {
         // the negative strand.
         assert!(!interval.contains_entity(&create_coordinate("seq0", "+", 15)));
     }
+
+    #[test]
+    fn overlaps() {
+        let interval = create_interval("seq0", "+", 10, 20);
+
+        // Intervals sharing only their boundary entity overlap.
+        assert!(
+            interval
+                .overlaps(&create_interval("seq0", "+", 20, 30))
+                .unwrap()
+        );
+        assert!(
+            interval
+                .overlaps(&create_interval("seq0", "+", 1, 10))
+                .unwrap()
+        );
+
+        // Directly adjacent intervals do not overlap.
+        assert!(
+            !interval
+                .overlaps(&create_interval("seq0", "+", 21, 30))
+                .unwrap()
+        );
+        assert!(
+            !interval
+                .overlaps(&create_interval("seq0", "+", 1, 9))
+                .unwrap()
+        );
+
+        // Contained intervals overlap.
+        assert!(
+            interval
+                .overlaps(&create_interval("seq0", "+", 15, 15))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn gap_between() {
+        let interval = create_interval("seq0", "+", 10, 20);
+
+        assert_eq!(
+            interval
+                .gap_between(&create_interval("seq0", "+", 22, 30))
+                .unwrap(),
+            Some(create_interval("seq0", "+", 21, 21))
+        );
+        assert_eq!(
+            interval
+                .gap_between(&create_interval("seq0", "+", 21, 30))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            interval
+                .distance(&create_interval("seq0", "+", 1, 5))
+                .unwrap(),
+            4
+        );
+
+        // Gaps at the extremes of the number line do not overflow.
+        let interval = create_interval("seq0", "+", 1, 1);
+        assert_eq!(
+            interval
+                .gap_between(&create_interval("seq0", "+", 2, Number::MAX))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            interval
+                .gap_between(&create_interval("seq0", "+", Number::MAX, Number::MAX))
+                .unwrap(),
+            Some(create_interval("seq0", "+", 2, Number::MAX - 1))
+        );
+    }
 }
@@ -1,10 +1,14 @@
 //! Interbase intervals.
 
+use core::cmp::max;
+use core::cmp::min;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Position;
 use crate::Strand;
-use crate::base;
-use crate::interbase::Coordinate;
-use crate::interval::Number;
 use crate::interval::r#trait;
+use crate::position::Width;
 use crate::system::Base;
 use crate::system::Interbase;
 
@@ -29,7 +33,7 @@ use crate::system::Interbase;
 /// representations, no such gymnastics are required.
 pub type Interval = crate::Interval<Interbase>;
 
-impl Interval {
+impl<W: Width> crate::Interval<Interbase<W>> {
     /// Checks whether the interbase interval contains the entity _after_ the
     /// specified interbase coordinate.
     ///
@@ -72,7 +76,7 @@ impl Interval {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn contains_next_entity(&self, coordinate: Coordinate) -> Option<bool> {
+    pub fn contains_next_entity(&self, coordinate: Coordinate<Interbase<W>>) -> Option<bool> {
         let coordinate = coordinate.nudge_forward()?;
         Some(self.contains_entity(&coordinate))
     }
@@ -119,7 +123,7 @@ impl Interval {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn contains_prev_entity(&self, coordinate: Coordinate) -> Option<bool> {
+    pub fn contains_prev_entity(&self, coordinate: Coordinate<Interbase<W>>) -> Option<bool> {
         let coordinate = coordinate.nudge_backward()?;
         Some(self.contains_entity(&coordinate))
     }
@@ -142,13 +146,13 @@ impl Interval {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn into_equivalent_base(self) -> Option<crate::interval::Interval<Base>> {
+    pub fn into_equivalent_base(self) -> Option<crate::interval::Interval<Base<W>>> {
         let (start, end) = self.into_coordinates();
 
         let start = start.nudge_forward()?;
         let end = end.nudge_backward()?;
 
-        crate::interval::Interval::<Base>::try_new(start, end).ok()
+        crate::interval::Interval::<Base<W>>::try_new(start, end).ok()
     }
 }
 
@@ -156,8 +160,8 @@ impl Interval {
 // Trait implementations
 ////////////////////////////////////////////////////////////////////////////////////////
 
-impl r#trait::Interval<Interbase> for Interval {
-    fn contains_entity(&self, coordinate: &base::Coordinate) -> bool {
+impl<W: Width> r#trait::Interval<Interbase<W>> for crate::Interval<Interbase<W>> {
+    fn contains_entity(&self, coordinate: &Coordinate<Base<W>>) -> bool {
         if self.contig() != coordinate.contig() {
             return false;
         }
@@ -167,7 +171,7 @@ impl r#trait::Interval<Interbase> for Interval {
         }
 
         match self.strand() {
-            Strand::Positive => {
+            Strand::Positive | Strand::Unstranded => {
                 self.start().position().get() < coordinate.position().get()
                     && self.end().position().get() >= coordinate.position().get()
             }
@@ -179,17 +183,57 @@ impl r#trait::Interval<Interbase> for Interval {
     }
 
     /// Gets the number of entities within the interval.
-    fn count_entities(&self) -> Number {
+    fn count_entities(&self) -> W {
         self.start()
             .position()
             .distance_unchecked(self.end().position())
     }
+
+    fn overlaps_unchecked(&self, other: &Self) -> bool {
+        // NOTE: the comparison here is strict, as two interbase intervals
+        // that only share a boundary do not share any entities.
+        max(self.lower(), other.lower()) < min(self.upper(), other.upper())
+    }
+
+    fn gap_unchecked(
+        &self,
+        other: &Self,
+    ) -> Option<(Position<Interbase<W>>, Position<Interbase<W>>)> {
+        let lower = *min(self.upper(), other.upper());
+        let upper = *max(self.lower(), other.lower());
+
+        if lower >= upper {
+            return None;
+        }
+
+        Some((lower, upper))
+    }
+
+    fn entity_bounds(&self) -> (W, W) {
+        (self.lower().get(), self.upper().get())
+    }
+
+    fn from_entity_bounds(contig: Contig, strand: Strand, start: W, end: W) -> Option<Self> {
+        if start >= end {
+            return None;
+        }
+
+        Some(Self::from_bounds(
+            contig,
+            strand,
+            Position::<Interbase<W>>::new(start),
+            Position::<Interbase<W>>::new(end),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::format;
+
     use super::*;
     use crate::Coordinate;
+    use crate::position::Number;
     use crate::system::Base;
 
     fn create_coordinate(
@@ -648,4 +692,65 @@ mod tests {
                 .is_none()
         );
     }
+
+    #[test]
+    fn overlaps() {
+        let interval = create_interval("seq0", "+", 10, 20);
+
+        // Intervals sharing only a boundary do not overlap.
+        assert!(
+            !interval
+                .overlaps(&create_interval("seq0", "+", 20, 30))
+                .unwrap()
+        );
+        assert!(
+            !interval
+                .overlaps(&create_interval("seq0", "+", 0, 10))
+                .unwrap()
+        );
+
+        // Intervals sharing a single entity overlap.
+        assert!(
+            interval
+                .overlaps(&create_interval("seq0", "+", 19, 30))
+                .unwrap()
+        );
+
+        // Zero-sized intervals contain no entities and never overlap.
+        assert!(
+            !interval
+                .overlaps(&create_interval("seq0", "+", 15, 15))
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn gap_between() {
+        let interval = create_interval("seq0", "+", 10, 20);
+
+        assert_eq!(
+            interval
+                .gap_between(&create_interval("seq0", "+", 21, 30))
+                .unwrap(),
+            Some(create_interval("seq0", "+", 20, 21))
+        );
+        assert_eq!(
+            interval
+                .gap_between(&create_interval("seq0", "+", 20, 30))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            interval
+                .union(&create_interval("seq0", "+", 20, 30))
+                .unwrap(),
+            Some(create_interval("seq0", "+", 10, 30))
+        );
+        assert_eq!(
+            interval
+                .distance(&create_interval("seq0", "+", 0, 5))
+                .unwrap(),
+            5
+        );
+    }
 }
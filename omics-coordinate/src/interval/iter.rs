@@ -0,0 +1,329 @@
+//! Iterators over the contents of an interval.
+
+use core::iter::FusedIterator;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Position;
+use crate::Strand;
+use crate::position::Number;
+use crate::position::Width;
+use crate::system::Base;
+use crate::system::Interbase;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Entities
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the entities within an interval as in-base coordinates.
+///
+/// Entities are yielded in the direction of the strand: ascending on the
+/// positive strand (and for unstranded intervals) and descending on the
+/// negative strand.
+///
+/// This is created by [`Interval::entities()`](crate::Interval::entities).
+#[derive(Clone, Debug)]
+pub struct Entities<W: Width = Number> {
+    /// The contig.
+    contig: Contig,
+
+    /// The strand.
+    strand: Strand,
+
+    /// The numerically lowest remaining entity as an interbase position.
+    lower: W,
+
+    /// The position just past the numerically highest remaining entity as an
+    /// interbase position.
+    upper: W,
+}
+
+impl<W: Width> Entities<W> {
+    /// Creates an iterator over the entities within a half-open range of
+    /// interbase positions.
+    pub(crate) fn new(contig: Contig, strand: Strand, lower: W, upper: W) -> Self {
+        Self {
+            contig,
+            strand,
+            lower,
+            upper: upper.max(lower),
+        }
+    }
+
+    /// Creates the coordinate for the entity starting at an interbase
+    /// position.
+    fn coordinate(&self, position: W) -> Coordinate<Base<W>> {
+        // SAFETY: every remaining entity starts before `upper`, so adding one
+        // cannot overflow and is always a valid (non-zero) in-base position.
+        let position = Position::<Base<W>>::try_new(position + W::ONE).unwrap();
+        Coordinate::new(self.contig.clone(), self.strand, position)
+    }
+
+    /// Takes the numerically lowest remaining entity.
+    fn take_lower(&mut self) -> Option<Coordinate<Base<W>>> {
+        if self.lower >= self.upper {
+            return None;
+        }
+
+        self.lower += W::ONE;
+        Some(self.coordinate(self.lower - W::ONE))
+    }
+
+    /// Takes the numerically highest remaining entity.
+    fn take_upper(&mut self) -> Option<Coordinate<Base<W>>> {
+        if self.lower >= self.upper {
+            return None;
+        }
+
+        self.upper -= W::ONE;
+        Some(self.coordinate(self.upper))
+    }
+}
+
+impl<W: Width> Iterator for Entities<W> {
+    type Item = Coordinate<Base<W>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.take_lower(),
+            Strand::Negative => self.take_upper(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.upper - self.lower).to_usize().unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<W: Width> DoubleEndedIterator for Entities<W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.take_upper(),
+            Strand::Negative => self.take_lower(),
+        }
+    }
+}
+
+impl<W: Width> ExactSizeIterator for Entities<W> {}
+
+impl<W: Width> FusedIterator for Entities<W> {}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Boundaries
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the boundaries of the entities within an interval as
+/// interbase coordinates.
+///
+/// An interval containing `n` entities has `n + 1` boundaries. Boundaries are
+/// yielded in the direction of the strand: ascending on the positive strand
+/// (and for unstranded intervals) and descending on the negative strand.
+///
+/// This is created by [`Interval::boundaries()`](crate::Interval::boundaries).
+#[derive(Clone, Debug)]
+pub struct Boundaries<W: Width = Number> {
+    /// The contig.
+    contig: Contig,
+
+    /// The strand.
+    strand: Strand,
+
+    /// The numerically lowest remaining boundary.
+    lower: W,
+
+    /// The numerically highest remaining boundary.
+    upper: W,
+
+    /// Whether or not the iterator has been exhausted.
+    ///
+    /// Because both ends of the range are inclusive, this is tracked
+    /// separately so that boundaries at [`Width::MAX`] can be represented.
+    done: bool,
+}
+
+impl<W: Width> Boundaries<W> {
+    /// Creates an iterator over the boundaries between `lower` and `upper`
+    /// (inclusive).
+    pub(crate) fn new(contig: Contig, strand: Strand, lower: W, upper: W) -> Self {
+        Self {
+            contig,
+            strand,
+            lower,
+            upper: upper.max(lower),
+            done: false,
+        }
+    }
+
+    /// Creates the coordinate for a boundary.
+    fn coordinate(&self, position: W) -> Coordinate<Interbase<W>> {
+        Coordinate::new(
+            self.contig.clone(),
+            self.strand,
+            Position::<Interbase<W>>::new(position),
+        )
+    }
+
+    /// Takes the numerically lowest remaining boundary.
+    fn take_lower(&mut self) -> Option<Coordinate<Interbase<W>>> {
+        if self.done {
+            return None;
+        }
+
+        let position = self.lower;
+
+        match self.lower == self.upper {
+            true => self.done = true,
+            false => self.lower += W::ONE,
+        }
+
+        Some(self.coordinate(position))
+    }
+
+    /// Takes the numerically highest remaining boundary.
+    fn take_upper(&mut self) -> Option<Coordinate<Interbase<W>>> {
+        if self.done {
+            return None;
+        }
+
+        let position = self.upper;
+
+        match self.lower == self.upper {
+            true => self.done = true,
+            false => self.upper -= W::ONE,
+        }
+
+        Some(self.coordinate(position))
+    }
+}
+
+impl<W: Width> Iterator for Boundaries<W> {
+    type Item = Coordinate<Interbase<W>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.take_lower(),
+            Strand::Negative => self.take_upper(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.done {
+            true => 0,
+            false => (self.upper - self.lower)
+                .to_usize()
+                .and_then(|remaining| remaining.checked_add(1))
+                .unwrap_or(usize::MAX),
+        };
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<W: Width> DoubleEndedIterator for Boundaries<W> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.strand {
+            Strand::Positive | Strand::Unstranded => self.take_upper(),
+            Strand::Negative => self.take_lower(),
+        }
+    }
+}
+
+impl<W: Width> ExactSizeIterator for Boundaries<W> {}
+
+impl<W: Width> FusedIterator for Boundaries<W> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use crate::Interval;
+    use crate::position::Number;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    #[test]
+    fn entities_in_strand_order() {
+        let interval = "seq0:-:5-3".parse::<Interval<Base>>().unwrap();
+        let positions = interval
+            .entities()
+            .map(|c| c.position().get())
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![5, 4, 3]);
+
+        let positions = interval
+            .entities()
+            .rev()
+            .map(|c| c.position().get())
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![3, 4, 5]);
+
+        // Interbase intervals yield the in-base entities between their
+        // boundaries.
+        let interval = "seq0:+:0-3".parse::<Interval<Interbase>>().unwrap();
+        let coordinates = interval
+            .entities()
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(coordinates, vec!["seq0:+:1", "seq0:+:2", "seq0:+:3"]);
+
+        let interval = "seq0:+:3-3".parse::<Interval<Interbase>>().unwrap();
+        assert_eq!(interval.entities().len(), 0);
+        assert_eq!(interval.entities().next(), None);
+    }
+
+    #[test]
+    fn meeting_in_the_middle() {
+        let interval = "seq0:+:1-4".parse::<Interval<Base>>().unwrap();
+        let mut entities = interval.entities();
+
+        assert_eq!(entities.len(), 4);
+        assert_eq!(entities.next().unwrap().position().get(), 1);
+        assert_eq!(entities.next_back().unwrap().position().get(), 4);
+        assert_eq!(entities.len(), 2);
+        assert_eq!(entities.next_back().unwrap().position().get(), 3);
+        assert_eq!(entities.next().unwrap().position().get(), 2);
+        assert_eq!(entities.len(), 0);
+        assert!(entities.next().is_none());
+        assert!(entities.next_back().is_none());
+    }
+
+    #[test]
+    fn boundaries() {
+        let interval = "seq0:-:3-1".parse::<Interval<Base>>().unwrap();
+        let boundaries = interval.boundaries();
+        assert_eq!(boundaries.len(), 4);
+
+        let positions = boundaries.map(|c| c.position().get()).collect::<Vec<_>>();
+        assert_eq!(positions, vec![3, 2, 1, 0]);
+
+        let interval = "seq0:+:7-7".parse::<Interval<Interbase>>().unwrap();
+        let mut boundaries = interval.boundaries();
+        assert_eq!(boundaries.len(), 1);
+        assert_eq!(boundaries.next_back().unwrap().to_string(), "seq0:+:7");
+        assert_eq!(boundaries.len(), 0);
+        assert!(boundaries.next().is_none());
+    }
+
+    #[test]
+    fn extremes() {
+        let interval = format!("seq0:+:{}-{}", Number::MAX - 1, Number::MAX)
+            .parse::<Interval<Interbase>>()
+            .unwrap();
+
+        let positions = interval
+            .boundaries()
+            .map(|c| c.position().get())
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![Number::MAX - 1, Number::MAX]);
+
+        let positions = interval
+            .entities()
+            .map(|c| c.position().get())
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![Number::MAX]);
+    }
+}
@@ -78,6 +78,20 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 //!
+//! ### `no_std` Support
+//!
+//! The crate depends on the standard library through the `std` feature, which
+//! is enabled by default. Disabling default features builds the crate for
+//! `no_std` targets that provide a global allocator (`alloc`). Everything
+//! except the following remains available without `std`:
+//!
+//! * Reading assemblies, alias tables, BED files, GFF3/GTF files, and chain
+//!   files from a [`BufRead`](std::io::BufRead) and writing BED, BedGraph, and
+//!   GFF3/GTF files to a [`Write`](std::io::Write).
+//! * Using a [`HashMap`](std::collections::HashMap) as a
+//!   [`LengthSource`](crate::assembly::LengthSource) (a
+//!   [`BTreeMap`](alloc::collections::BTreeMap) may be used instead).
+//!
 //! # Background
 //!
 //! Coordinate systems can be surprisingly hard to find comprehensive,
@@ -373,6 +387,10 @@
 //!   the the original strand of DNA from which a position on RNA is derived is
 //!   lost during any conversion from one to the other. If it is of interest,
 //!   you may keep track of this kind of thing on your own at conversion time.
+//! * Coordinates whose strand is not known (for example, records with a `.`
+//!   strand in BED, GFF, or VCF files) use [`Strand::Unstranded`]. Unstranded
+//!   coordinates and intervals are oriented like those on the positive strand,
+//!   but they are never implicitly compared with stranded ones.
 //!
 //! ## Intervals
 //!
@@ -453,20 +471,53 @@
 //! [t2t-genome]: https://www.ncbi.nlm.nih.gov/assembly/GCF_009914755.1/
 //! [t2t-publication]: https://www.science.org/doi/10.1126/science.abj6987
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+/// The map used to look up entries by contig name.
+#[cfg(feature = "std")]
+type Map<K, V> = std::collections::HashMap<K, V>;
+
+/// The map used to look up entries by contig name.
+///
+/// Hash maps are unavailable without `std`, so an ordered map is used instead.
+#[cfg(not(feature = "std"))]
+type Map<K, V> = alloc::collections::BTreeMap<K, V>;
+
+pub mod assembly;
+pub mod bed;
+pub mod circular;
 pub mod contig;
 pub mod coordinate;
+pub mod coverage;
+pub mod gff;
+pub mod index;
 pub mod interval;
+pub mod liftover;
 pub mod math;
+pub mod packed;
 pub mod position;
+pub mod region;
+pub mod set;
 pub mod strand;
+pub mod sweep;
 pub mod system;
+pub mod transcript;
+pub mod window;
+
+#[cfg(test)]
+mod testing;
 
+pub use assembly::Assembly;
 pub use contig::Contig;
 pub use coordinate::Coordinate;
 pub use coordinate::CoordinateRef;
 pub use coordinate::base;
 pub use coordinate::interbase;
+pub use index::IntervalIndex;
 pub use interval::Interval;
 pub use position::Position;
+pub use set::IntervalSet;
 pub use strand::Strand;
 pub use system::System;
@@ -0,0 +1,1548 @@
+//! Liftover of coordinates and intervals between assemblies.
+//!
+//! A [chain file](https://genome.ucsc.edu/goldenPath/help/chain.html)
+//! describes how one assembly (the _source_) aligns onto another (the
+//! _destination_) as a series of chains. Each chain pairs a region of a source
+//! contig with a region of a destination contig and breaks that pairing into
+//! gapless, aligned blocks. A [`Liftover`] indexes the blocks of every chain
+//! by their location within the source assembly so that coordinates and
+//! intervals can be mapped onto the destination assembly.
+//!
+//! Note that the UCSC documentation refers to the source assembly as the
+//! "target" and the destination assembly as the "query".
+//!
+//! Liftover never guesses. Mapping a coordinate reports whether it is
+//! unmapped, maps to a single location, or maps to multiple locations (see
+//! [`CoordinateMapping`]). Mapping an interval additionally reports when only
+//! some of its entities map or when its entities are split across chains (see
+//! [`IntervalMapping`]). When a chain aligns the source to the opposite strand
+//! of the destination, mapped coordinates and intervals are moved to the
+//! complementary strand.
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::IntervalIndex;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::contig;
+use crate::contig::ContigPool;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+use crate::system::Base;
+use crate::system::Interbase;
+
+/// The keyword that begins a chain header line.
+const HEADER_KEYWORD: &str = "chain";
+
+/// The names of the fields within a chain header line.
+const HEADER_FIELDS: [&str; 13] = [
+    "`chain` keyword",
+    "score",
+    "source name",
+    "source size",
+    "source strand",
+    "source start",
+    "source end",
+    "destination name",
+    "destination size",
+    "destination strand",
+    "destination start",
+    "destination end",
+    "chain id",
+];
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing a chain file.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// A required field was missing from a chain header line.
+    #[error("line {line}: missing {field}")]
+    MissingField {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// A description of the missing field.
+        field: &'static str,
+    },
+
+    /// An invalid contig name was encountered.
+    #[error("line {line}: invalid contig name: {inner}")]
+    Contig {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The inner error.
+        inner: contig::Error,
+    },
+
+    /// An invalid value was encountered in a field.
+    #[error("line {line}: invalid {field} `{value}`")]
+    Invalid {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// A description of the field.
+        field: &'static str,
+
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+
+    /// The bounds of a chain do not fit within its sequence.
+    #[error("line {line}: {sequence} bounds `{start}-{end}` do not fit within length `{length}`")]
+    Bounds {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The sequence (source or destination) being described.
+        sequence: &'static str,
+
+        /// The start of the chain.
+        start: u64,
+
+        /// The end of the chain.
+        end: u64,
+
+        /// The length of the sequence.
+        length: u64,
+    },
+
+    /// An alignment data line had an unexpected number of fields.
+    #[error("line {line}: expected 1 or 3 alignment fields, found {found}")]
+    Alignment {
+        /// The line number (starting at one).
+        line: usize,
+
+        /// The number of fields found.
+        found: usize,
+    },
+
+    /// An alignment data line was found outside of a chain.
+    #[error("line {line}: alignment data outside of a chain")]
+    MissingHeader {
+        /// The line number (starting at one).
+        line: usize,
+    },
+
+    /// The blocks of a chain do not end where its header says they do.
+    #[error("line {line}: {sequence} blocks end at `{found}`, but the chain ends at `{expected}`")]
+    Mismatched {
+        /// The line number of the chain header (starting at one).
+        line: usize,
+
+        /// The sequence (source or destination) being described.
+        sequence: &'static str,
+
+        /// The end declared within the chain header.
+        expected: u64,
+
+        /// The end reached by the blocks of the chain.
+        found: u64,
+    },
+
+    /// A chain was not terminated by a final, single-field alignment line.
+    #[error("line {line}: chain is not terminated")]
+    Unterminated {
+        /// The line number of the chain header (starting at one).
+        line: usize,
+    },
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to liftover.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// An I/O error.
+    #[cfg(feature = "std")]
+    #[error("i/o error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// A parse error.
+    #[error("parse error: {0}")]
+    Parse(#[from] ParseError),
+
+    /// A width error.
+    ///
+    /// This error occurs when a mapped position cannot be represented by the
+    /// positions being mapped.
+    #[error("mapped position `{value}` cannot be represented as a {bits}-bit position")]
+    Width {
+        /// The mapped position.
+        value: u64,
+
+        /// The number of bits in the position representation.
+        bits: u32,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// The `Coordinate` trait
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// Traits related to liftover.
+pub mod r#trait {
+    use super::*;
+
+    /// Requirements for a coordinate to be lifted over.
+    pub trait Coordinate<S: System> {
+        /// Gets the location of the coordinate as a half-open range of
+        /// interbase positions.
+        ///
+        /// This is the entity at an in-base coordinate (e.g., `(9, 10)` for
+        /// in-base position `10`) and the empty range at an interbase
+        /// coordinate (e.g., `(10, 10)` for interbase position `10`).
+        fn bounds(&self) -> (S::Number, S::Number);
+    }
+}
+
+impl<W: Width> r#trait::Coordinate<Base<W>> for Coordinate<Base<W>> {
+    fn bounds(&self) -> (W, W) {
+        let position = self.position().get();
+        (position - W::ONE, position)
+    }
+}
+
+impl<W: Width> r#trait::Coordinate<Interbase<W>> for Coordinate<Interbase<W>> {
+    fn bounds(&self) -> (W, W) {
+        let position = self.position().get();
+        (position, position)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Chains
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A gapless, aligned block within a chain.
+///
+/// Both positions are numerically lower interbase positions on the positive
+/// strand of their respective contigs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Block {
+    /// The start of the block within the source contig.
+    source: u64,
+
+    /// The start of the block within the destination contig.
+    destination: u64,
+
+    /// The number of entities within the block.
+    size: u64,
+}
+
+/// A chain aligning a region of a source contig to a region of a destination
+/// contig.
+///
+/// The regions are reported as interbase intervals on the strands given in
+/// the chain header (the source strand is almost always positive).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chain {
+    /// The chain identifier.
+    id: u64,
+
+    /// The alignment score.
+    score: u64,
+
+    /// The aligned region of the source contig.
+    source: Interval<Interbase<u64>>,
+
+    /// The length of the source contig.
+    source_length: u64,
+
+    /// The aligned region of the destination contig.
+    destination: Interval<Interbase<u64>>,
+
+    /// The length of the destination contig.
+    destination_length: u64,
+
+    /// The aligned blocks.
+    blocks: Vec<Block>,
+}
+
+impl Chain {
+    /// Gets the chain identifier.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Gets the alignment score.
+    pub fn score(&self) -> u64 {
+        self.score
+    }
+
+    /// Gets the aligned region of the source contig.
+    pub fn source(&self) -> &Interval<Interbase<u64>> {
+        &self.source
+    }
+
+    /// Gets the length of the source contig.
+    pub fn source_length(&self) -> u64 {
+        self.source_length
+    }
+
+    /// Gets the aligned region of the destination contig.
+    pub fn destination(&self) -> &Interval<Interbase<u64>> {
+        &self.destination
+    }
+
+    /// Gets the length of the destination contig.
+    pub fn destination_length(&self) -> u64 {
+        self.destination_length
+    }
+
+    /// Returns whether or not the chain aligns the source to the opposite
+    /// strand of the destination.
+    pub fn is_reversed(&self) -> bool {
+        self.source.strand() != self.destination.strand()
+    }
+
+    /// Maps a half-open range of interbase positions on the positive strand
+    /// of the source contig that lies within a block onto the positive strand
+    /// of the destination contig.
+    fn project(&self, block: &Block, start: u64, end: u64) -> (u64, u64) {
+        let (start, end) = (start - block.source, end - block.source);
+
+        if self.is_reversed() {
+            let upper = block.destination + block.size;
+            (upper - end, upper - start)
+        } else {
+            (block.destination + start, block.destination + end)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Mappings
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The result of lifting over a coordinate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CoordinateMapping<S: System> {
+    /// The coordinate does not lie within any aligned block.
+    Unmapped,
+
+    /// The coordinate maps to exactly one coordinate.
+    Mapped(Coordinate<S>),
+
+    /// The coordinate maps to more than one coordinate.
+    ///
+    /// This occurs when the coordinate lies within more than one chain or
+    /// when an interbase coordinate lies on the boundary between two blocks
+    /// that are not adjacent within the destination. The coordinates are
+    /// sorted.
+    Multiple(Vec<Coordinate<S>>),
+}
+
+/// A part of an interval that maps onto the destination assembly through a
+/// single aligned block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Segment<S: System> {
+    /// The part of the interval within the source assembly.
+    source: Interval<S>,
+
+    /// The mapped part of the interval within the destination assembly.
+    destination: Interval<S>,
+
+    /// The identifier of the chain.
+    chain: u64,
+}
+
+impl<S: System> Segment<S> {
+    /// Gets the part of the interval within the source assembly.
+    pub fn source(&self) -> &Interval<S> {
+        &self.source
+    }
+
+    /// Gets the mapped part of the interval within the destination assembly.
+    pub fn destination(&self) -> &Interval<S> {
+        &self.destination
+    }
+
+    /// Gets the identifier of the chain through which the segment maps.
+    pub fn chain(&self) -> u64 {
+        self.chain
+    }
+}
+
+/// The result of lifting over an interval.
+///
+/// Segments are ordered by the location of their source within the interval
+/// (in the direction of the interval's strand).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntervalMapping<S: System> {
+    /// No entity of the interval lies within an aligned block.
+    Unmapped,
+
+    /// Every entity of the interval maps through a single chain.
+    ///
+    /// The mapped interval spans from the first to the last mapped entity, so
+    /// it also contains any entities that are only present within the
+    /// destination assembly.
+    Mapped(Interval<S>),
+
+    /// Some, but not all, entities of the interval map through a single
+    /// chain.
+    Partial {
+        /// The interval spanning from the first to the last mapped entity.
+        interval: Interval<S>,
+
+        /// The mapped segments.
+        segments: Vec<Segment<S>>,
+    },
+
+    /// The entities of the interval map through more than one chain.
+    Split(Vec<Segment<S>>),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Liftover
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An index of chains for lifting over coordinates and intervals from a
+/// source assembly to a destination assembly.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::liftover::CoordinateMapping;
+/// use omics_coordinate::liftover::IntervalMapping;
+/// use omics_coordinate::liftover::Liftover;
+/// use omics_coordinate::system::Base;
+///
+/// // Source entities `1-100` map to destination entities `1001-1100` and source
+/// // entities `111-150` map to destination entities `1101-1140`.
+/// let chain = "chain 100 chr1 1000 + 0 150 chr1 5000 + 1000 1140 1\n100\t10\t0\n40\n";
+/// let liftover = chain.parse::<Liftover>()?;
+///
+/// let coordinate = "chr1:+:10".parse::<Coordinate<Base>>()?;
+/// assert_eq!(
+///     liftover.map_coordinate(&coordinate)?,
+///     CoordinateMapping::Mapped("chr1:+:1010".parse()?)
+/// );
+///
+/// let coordinate = "chr1:+:105".parse::<Coordinate<Base>>()?;
+/// assert_eq!(
+///     liftover.map_coordinate(&coordinate)?,
+///     CoordinateMapping::Unmapped
+/// );
+///
+/// let interval = "chr1:+:1-50".parse::<Interval<Base>>()?;
+/// assert_eq!(
+///     liftover.map_interval(&interval)?,
+///     IntervalMapping::Mapped("chr1:+:1001-1050".parse()?)
+/// );
+///
+/// let interval = "chr1:+:91-120".parse::<Interval<Base>>()?;
+/// match liftover.map_interval(&interval)? {
+///     IntervalMapping::Partial { interval, segments } => {
+///         assert_eq!(interval.to_string(), "chr1:+:1091-1110");
+///         assert_eq!(segments.len(), 2);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Liftover {
+    /// The chains.
+    chains: Vec<Chain>,
+
+    /// The blocks of every chain on the positive strand of their source
+    /// contig, each associated with the index of its chain and the index of
+    /// the block within that chain.
+    index: IntervalIndex<Interbase<u64>, (usize, usize)>,
+}
+
+impl Liftover {
+    /// Attempts to read a liftover from a chain file.
+    ///
+    /// Blank lines and comment lines (lines starting with `#`) are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::liftover::Liftover;
+    ///
+    /// let data = "chain 50 chr1 100 + 0 50 1 100 - 10 60 7\n50\n";
+    /// let liftover = Liftover::read_chain(data.as_bytes())?;
+    ///
+    /// let chain = liftover.chains().next().unwrap();
+    /// assert_eq!(chain.id(), 7);
+    /// assert_eq!(chain.source().to_string(), "chr1:+:0-50");
+    /// assert_eq!(chain.destination().to_string(), "1:-:90-40");
+    /// assert!(chain.is_reversed());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn read_chain(reader: impl BufRead) -> Result<Self> {
+        let mut parser = Parser::default();
+
+        for (i, line) in reader.lines().enumerate() {
+            parser.parse_line(&line?, i + 1)?;
+        }
+
+        Ok(parser.finish()?)
+    }
+
+    /// Gets the number of chains.
+    pub fn len(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Returns whether or not there are any chains.
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+
+    /// Gets an iterator over the chains in the order in which they were
+    /// provided.
+    pub fn chains(&self) -> impl Iterator<Item = &Chain> {
+        self.chains.iter()
+    }
+
+    /// Lifts over a coordinate.
+    ///
+    /// An in-base coordinate maps when its entity lies within an aligned
+    /// block. An interbase coordinate maps when it lies within or on the
+    /// boundary of an aligned block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::liftover::CoordinateMapping;
+    /// use omics_coordinate::liftover::Liftover;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let liftover = "chain 10 chr1 100 + 0 100 chr1 100 - 0 100 1\n100\n".parse::<Liftover>()?;
+    ///
+    /// let coordinate = "chr1:+:1".parse::<Coordinate<Base>>()?;
+    /// assert_eq!(
+    ///     liftover.map_coordinate(&coordinate)?,
+    ///     CoordinateMapping::Mapped("chr1:-:100".parse()?)
+    /// );
+    ///
+    /// let coordinate = "chr1:-:0".parse::<Coordinate<Interbase>>()?;
+    /// assert_eq!(
+    ///     liftover.map_coordinate(&coordinate)?,
+    ///     CoordinateMapping::Mapped("chr1:+:100".parse()?)
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn map_coordinate<S: System>(
+        &self,
+        coordinate: &Coordinate<S>,
+    ) -> Result<CoordinateMapping<S>>
+    where
+        Coordinate<S>: r#trait::Coordinate<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let (start, end) = <Coordinate<S> as r#trait::Coordinate<S>>::bounds(coordinate);
+
+        let mut coordinates = self
+            .locate(coordinate.contig(), start.to_u64(), end.to_u64())
+            .into_iter()
+            .map(|located| {
+                Ok(Coordinate::new(
+                    located.chain.destination.contig().clone(),
+                    orient(coordinate.strand(), located.chain),
+                    mapped_position::<S>(located.destination.1)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        coordinates.sort_by(|a, b| {
+            (a.contig(), a.strand(), a.position().get()).cmp(&(
+                b.contig(),
+                b.strand(),
+                b.position().get(),
+            ))
+        });
+        coordinates.dedup();
+
+        Ok(match coordinates.len() {
+            0 => CoordinateMapping::Unmapped,
+            // SAFETY: we just checked that there is exactly one coordinate, so
+            // this will always unwrap.
+            1 => CoordinateMapping::Mapped(coordinates.pop().unwrap()),
+            _ => CoordinateMapping::Multiple(coordinates),
+        })
+    }
+
+    /// Lifts over an interval.
+    ///
+    /// A zero-sized interbase interval contains no entities, so it maps as
+    /// its start coordinate does (see
+    /// [`map_coordinate()`](Self::map_coordinate)), with multiple mapped
+    /// coordinates reported as a [`IntervalMapping::Split`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::liftover::IntervalMapping;
+    /// use omics_coordinate::liftover::Liftover;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let data = [
+    ///     "chain 10 chr1 100 + 0 50 chr1 100 + 0 50 1",
+    ///     "50",
+    ///     "",
+    ///     "chain 10 chr1 100 + 50 100 chr2 50 - 0 50 2",
+    ///     "50",
+    /// ]
+    /// .join("\n");
+    /// let liftover = data.parse::<Liftover>()?;
+    ///
+    /// let interval = "chr1:+:40-60".parse::<Interval<Interbase>>()?;
+    /// match liftover.map_interval(&interval)? {
+    ///     IntervalMapping::Split(segments) => {
+    ///         let destinations = segments
+    ///             .iter()
+    ///             .map(|segment| segment.destination().to_string())
+    ///             .collect::<Vec<_>>();
+    ///         assert_eq!(destinations, vec!["chr1:+:40-50", "chr2:-:50-40"]);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn map_interval<S: System>(&self, interval: &Interval<S>) -> Result<IntervalMapping<S>>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let (start, end) = <Interval<S> as interval::r#trait::Interval<S>>::entity_bounds(interval);
+
+        if start == end {
+            return self.map_empty_interval(interval);
+        }
+
+        let mut segments = self
+            .locate(interval.contig(), start.to_u64(), end.to_u64())
+            .into_iter()
+            .map(|located| {
+                let (start, end) = located.source;
+                let (lower, upper) = located.destination;
+
+                Ok(Segment {
+                    source: entity_interval(
+                        interval.contig().clone(),
+                        interval.strand(),
+                        start,
+                        end,
+                    )?,
+                    destination: entity_interval(
+                        located.chain.destination.contig().clone(),
+                        orient(interval.strand(), located.chain),
+                        lower,
+                        upper,
+                    )?,
+                    chain: located.chain.id,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        segments
+            .sort_by_key(|segment| (segment.source.lower().get(), segment.source.upper().get()));
+
+        if interval.strand() == Strand::Negative {
+            segments.reverse();
+        }
+
+        let Some(first) = segments.first() else {
+            return Ok(IntervalMapping::Unmapped);
+        };
+
+        if segments.iter().any(|segment| segment.chain != first.chain) {
+            return Ok(IntervalMapping::Split(segments));
+        }
+
+        // SAFETY: all segments are located on the same contig and strand, and
+        // there is at least one segment, so these will always unwrap.
+        let spanned = segments
+            .iter()
+            .map(|segment| segment.destination.clone())
+            .reduce(|a, b| a.span(&b).unwrap())
+            .unwrap();
+
+        let mapped = segments
+            .iter()
+            .map(|segment| segment.source.count_entities().to_u64())
+            .sum::<u64>();
+
+        if mapped == (end - start).to_u64() {
+            Ok(IntervalMapping::Mapped(spanned))
+        } else {
+            Ok(IntervalMapping::Partial {
+                interval: spanned,
+                segments,
+            })
+        }
+    }
+
+    /// Lifts over an interval that contains no entities.
+    fn map_empty_interval<S: System>(&self, interval: &Interval<S>) -> Result<IntervalMapping<S>>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let (start, _) = <Interval<S> as interval::r#trait::Interval<S>>::entity_bounds(interval);
+        let start = start.to_u64();
+
+        let mut segments = self
+            .locate(interval.contig(), start, start)
+            .into_iter()
+            .map(|located| {
+                let source = mapped_position::<S>(start)?;
+                let destination = mapped_position::<S>(located.destination.0)?;
+
+                Ok(Segment {
+                    source: Interval::from_bounds(
+                        interval.contig().clone(),
+                        interval.strand(),
+                        source.clone(),
+                        source,
+                    ),
+                    destination: Interval::from_bounds(
+                        located.chain.destination.contig().clone(),
+                        orient(interval.strand(), located.chain),
+                        destination.clone(),
+                        destination,
+                    ),
+                    chain: located.chain.id,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        segments.sort_by(|a, b| {
+            (
+                a.destination.contig(),
+                a.destination.strand(),
+                a.destination.lower().get(),
+            )
+                .cmp(&(
+                    b.destination.contig(),
+                    b.destination.strand(),
+                    b.destination.lower().get(),
+                ))
+        });
+        segments.dedup_by(|a, b| a.destination == b.destination);
+
+        Ok(match segments.len() {
+            0 => IntervalMapping::Unmapped,
+            // SAFETY: we just checked that there is exactly one segment, so
+            // this will always unwrap.
+            1 => IntervalMapping::Mapped(segments.pop().unwrap().destination),
+            _ => IntervalMapping::Split(segments),
+        })
+    }
+
+    /// Locates the blocks that contain a half-open range of interbase
+    /// positions on the positive strand of a source contig.
+    ///
+    /// For an empty range, every block that contains the position within or
+    /// on its boundary is located. Otherwise, every block that shares at
+    /// least one entity with the range is located.
+    fn locate(&self, contig: &Contig, start: u64, end: u64) -> Vec<Located<'_>> {
+        let query = Interval::from_bounds(
+            contig.clone(),
+            Strand::Positive,
+            Position::<Interbase<u64>>::new(start),
+            Position::<Interbase<u64>>::new(end),
+        );
+
+        let located = if start == end {
+            self.index.find_containing(&query)
+        } else {
+            self.index.find_overlapping(&query)
+        };
+
+        located
+            .into_iter()
+            .map(|(_, (chain, block))| {
+                let chain = &self.chains[*chain];
+                let block = &chain.blocks[*block];
+
+                let start = start.max(block.source);
+                let end = end.min(block.source + block.size);
+
+                Located {
+                    chain,
+                    source: (start, end),
+                    destination: chain.project(block, start, end),
+                }
+            })
+            .collect()
+    }
+}
+
+/// A part of a range of interbase positions that lies within an aligned block.
+#[derive(Debug)]
+struct Located<'a> {
+    /// The chain containing the block.
+    chain: &'a Chain,
+
+    /// The part of the range within the block on the positive strand of the
+    /// source contig.
+    source: (u64, u64),
+
+    /// The part of the range mapped onto the positive strand of the
+    /// destination contig.
+    destination: (u64, u64),
+}
+
+/// Gets the strand of a mapped coordinate or interval.
+fn orient(strand: Strand, chain: &Chain) -> Strand {
+    if chain.is_reversed() {
+        strand.complement()
+    } else {
+        strand
+    }
+}
+
+/// Attempts to create a position from a mapped value.
+fn mapped_position<S: System>(value: u64) -> Result<Position<S>>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    let width = S::Number::from_u64(value).ok_or(Error::Width {
+        value,
+        bits: S::Number::BITS,
+    })?;
+
+    // SAFETY: mapped values that are used as positions are never zero for
+    // in-base coordinates, as they are the upper bound of an entity, so this
+    // will always unwrap.
+    Ok(Position::try_from_number(width).unwrap())
+}
+
+/// Attempts to create an interval containing the entities within a non-empty,
+/// half-open range of interbase positions.
+fn entity_interval<S: System>(
+    contig: Contig,
+    strand: Strand,
+    start: u64,
+    end: u64,
+) -> Result<Interval<S>>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+{
+    let convert = |value: u64| {
+        S::Number::from_u64(value).ok_or(Error::Width {
+            value,
+            bits: S::Number::BITS,
+        })
+    };
+
+    // SAFETY: located ranges always contain at least one entity, so this will
+    // always unwrap.
+    Ok(
+        <Interval<S> as interval::r#trait::Interval<S>>::from_entity_bounds(
+            contig,
+            strand,
+            convert(start)?,
+            convert(end)?,
+        )
+        .unwrap(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Parsing
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A chain whose alignment data is still being parsed.
+#[derive(Debug)]
+struct Pending {
+    /// The line number of the chain header.
+    line: usize,
+
+    /// The chain.
+    chain: Chain,
+
+    /// The position of the next block within the source, in the coordinates
+    /// of the chain header.
+    source: u64,
+
+    /// The position of the next block within the destination, in the
+    /// coordinates of the chain header.
+    destination: u64,
+
+    /// The end of the source within the chain header.
+    source_end: u64,
+
+    /// The end of the destination within the chain header.
+    destination_end: u64,
+}
+
+/// A line-by-line parser of chain files.
+#[derive(Debug, Default)]
+struct Parser {
+    /// The chains parsed so far.
+    chains: Vec<Chain>,
+
+    /// The chain currently being parsed.
+    pending: Option<Pending>,
+
+    /// The pool of contigs seen so far.
+    pool: ContigPool,
+}
+
+impl Parser {
+    /// Parses a single line.
+    fn parse_line(&mut self, line: &str, number: usize) -> ParseResult<()> {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+
+        if fields[0] == HEADER_KEYWORD {
+            if let Some(pending) = &self.pending {
+                return Err(ParseError::Unterminated { line: pending.line });
+            }
+
+            self.pending = Some(self.parse_header(&fields, number)?);
+            return Ok(());
+        }
+
+        let Some(pending) = self.pending.as_mut() else {
+            return Err(ParseError::MissingHeader { line: number });
+        };
+
+        let (size, gaps) = match fields[..] {
+            [size] => (parse_number(size, "block size", number)?, None),
+            [size, source, destination] => (
+                parse_number(size, "block size", number)?,
+                Some((
+                    parse_number(source, "source gap", number)?,
+                    parse_number(destination, "destination gap", number)?,
+                )),
+            ),
+            _ => {
+                return Err(ParseError::Alignment {
+                    line: number,
+                    found: fields.len(),
+                });
+            }
+        };
+
+        let source_end = pending.source.checked_add(size);
+        let destination_end = pending.destination.checked_add(size);
+
+        let (Some(source_end), Some(destination_end)) = (source_end, destination_end) else {
+            return Err(ParseError::Invalid {
+                line: number,
+                field: "block size",
+                value: size.to_string(),
+            });
+        };
+
+        check_within(pending, "source", source_end, pending.source_end)?;
+        check_within(
+            pending,
+            "destination",
+            destination_end,
+            pending.destination_end,
+        )?;
+
+        if size > 0 {
+            let block = Block {
+                source: forward(
+                    pending.chain.source.strand(),
+                    pending.chain.source_length,
+                    pending.source,
+                    source_end,
+                ),
+                destination: forward(
+                    pending.chain.destination.strand(),
+                    pending.chain.destination_length,
+                    pending.destination,
+                    destination_end,
+                ),
+                size,
+            };
+
+            pending.chain.blocks.push(block);
+        }
+
+        pending.source = source_end;
+        pending.destination = destination_end;
+
+        match gaps {
+            Some((source, destination)) => {
+                pending.source = pending.source.saturating_add(source);
+                pending.destination = pending.destination.saturating_add(destination);
+                Ok(())
+            }
+            None => {
+                // SAFETY: we just checked that a chain is pending, so this will
+                // always unwrap.
+                let pending = self.pending.take().unwrap();
+                check_end(&pending, "source", pending.source, pending.source_end)?;
+                check_end(
+                    &pending,
+                    "destination",
+                    pending.destination,
+                    pending.destination_end,
+                )?;
+
+                self.chains.push(pending.chain);
+                Ok(())
+            }
+        }
+    }
+
+    /// Parses a chain header line.
+    fn parse_header(&mut self, fields: &[&str], line: usize) -> ParseResult<Pending> {
+        if let Some(field) = HEADER_FIELDS.get(fields.len()) {
+            return Err(ParseError::MissingField { line, field });
+        }
+
+        let score = parse_number(fields[1], "score", line)?;
+        let (source, source_length, source_start, source_end) =
+            self.parse_sequence(&fields[2..7], "source", line)?;
+        let (destination, destination_length, destination_start, destination_end) =
+            self.parse_sequence(&fields[7..12], "destination", line)?;
+        let id = parse_number(fields[12], "chain id", line)?;
+
+        Ok(Pending {
+            line,
+            chain: Chain {
+                id,
+                score,
+                source,
+                source_length,
+                destination,
+                destination_length,
+                blocks: Vec::new(),
+            },
+            source: source_start,
+            destination: destination_start,
+            source_end,
+            destination_end,
+        })
+    }
+
+    /// Parses the name, size, strand, start, and end fields describing one
+    /// sequence within a chain header.
+    ///
+    /// Returns the aligned region, the length of the contig, and the start and
+    /// end of the region in the coordinates of the chain header.
+    fn parse_sequence(
+        &mut self,
+        fields: &[&str],
+        sequence: &'static str,
+        line: usize,
+    ) -> ParseResult<(Interval<Interbase<u64>>, u64, u64, u64)> {
+        let contig = self
+            .pool
+            .contig(fields[0])
+            .map_err(|inner| ParseError::Contig { line, inner })?;
+        let length = parse_number(fields[1], "sequence size", line)?;
+        let strand = match fields[2] {
+            "+" => Strand::Positive,
+            "-" => Strand::Negative,
+            value => {
+                return Err(ParseError::Invalid {
+                    line,
+                    field: "strand",
+                    value: value.to_string(),
+                });
+            }
+        };
+        let start = parse_number(fields[3], "start", line)?;
+        let end = parse_number(fields[4], "end", line)?;
+
+        if start > end || end > length {
+            return Err(ParseError::Bounds {
+                line,
+                sequence,
+                start,
+                end,
+                length,
+            });
+        }
+
+        let lower = forward(strand, length, start, end);
+        let interval = Interval::from_bounds(
+            contig,
+            strand,
+            Position::new(lower),
+            Position::new(lower + (end - start)),
+        );
+
+        Ok((interval, length, start, end))
+    }
+
+    /// Consumes `self` and builds a liftover from the parsed chains.
+    fn finish(self) -> ParseResult<Liftover> {
+        if let Some(pending) = self.pending {
+            return Err(ParseError::Unterminated { line: pending.line });
+        }
+
+        Ok(self.chains.into_iter().collect())
+    }
+}
+
+/// Parses a number from a field on a particular line.
+fn parse_number(value: &str, field: &'static str, line: usize) -> ParseResult<u64> {
+    value.parse::<u64>().map_err(|_| ParseError::Invalid {
+        line,
+        field,
+        value: value.to_string(),
+    })
+}
+
+/// Gets the numerically lower position on the positive strand of a half-open
+/// range given in the coordinates of a chain header.
+///
+/// Ranges on the negative strand of a chain header are positioned from the
+/// end of the sequence.
+fn forward(strand: Strand, length: u64, start: u64, end: u64) -> u64 {
+    match strand {
+        Strand::Negative => length - end,
+        _ => start,
+    }
+}
+
+/// Ensures that a block does not extend past the end of its chain.
+fn check_within(
+    pending: &Pending,
+    sequence: &'static str,
+    end: u64,
+    limit: u64,
+) -> ParseResult<()> {
+    if end > limit {
+        return Err(ParseError::Mismatched {
+            line: pending.line,
+            sequence,
+            expected: limit,
+            found: end,
+        });
+    }
+
+    Ok(())
+}
+
+/// Ensures that the blocks of a chain end where the chain ends.
+fn check_end(
+    pending: &Pending,
+    sequence: &'static str,
+    end: u64,
+    expected: u64,
+) -> ParseResult<()> {
+    if end != expected {
+        return Err(ParseError::Mismatched {
+            line: pending.line,
+            sequence,
+            expected,
+            found: end,
+        });
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl FromIterator<Chain> for Liftover {
+    fn from_iter<T: IntoIterator<Item = Chain>>(iter: T) -> Self {
+        let chains = iter.into_iter().collect::<Vec<_>>();
+
+        let index = chains
+            .iter()
+            .enumerate()
+            .flat_map(|(i, chain)| {
+                chain.blocks.iter().enumerate().map(move |(j, block)| {
+                    let interval = Interval::from_bounds(
+                        chain.source.contig().clone(),
+                        Strand::Positive,
+                        Position::new(block.source),
+                        Position::new(block.source + block.size),
+                    );
+
+                    (interval, (i, j))
+                })
+            })
+            .collect();
+
+        Self { chains, index }
+    }
+}
+
+impl core::str::FromStr for Liftover {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        let mut parser = Parser::default();
+
+        for (i, line) in s.lines().enumerate() {
+            parser.parse_line(line, i + 1)?;
+        }
+
+        parser.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    /// A chain file with a gapped forward chain on `chr1`, a reversed chain
+    /// from `chr2` onto `chr9`, and a chain that overlaps the forward chain.
+    const CHAINS: &str = "\
+# A small, hand-written chain file.
+chain 1000 chr1 1000 + 100 300 chr1 2000 + 1000 1210 1
+50\t10\t0
+40\t0\t20
+100
+
+chain 500 chr2 500 + 0 100 chr9 400 - 50 150 2
+100
+
+chain 10 chr1 1000 + 280 320 chrUn 40 + 0 40 3
+40
+";
+
+    fn liftover() -> Liftover {
+        CHAINS.parse().unwrap()
+    }
+
+    fn base(value: &str) -> CoordinateMapping<Base> {
+        liftover()
+            .map_coordinate(&value.parse::<Coordinate<Base>>().unwrap())
+            .unwrap()
+    }
+
+    fn interbase(value: &str) -> CoordinateMapping<Interbase> {
+        liftover()
+            .map_coordinate(&value.parse::<Coordinate<Interbase>>().unwrap())
+            .unwrap()
+    }
+
+    fn interval(value: &str) -> IntervalMapping<Interbase> {
+        liftover()
+            .map_interval(&value.parse::<Interval<Interbase>>().unwrap())
+            .unwrap()
+    }
+
+    fn destinations<S: System>(segments: &[Segment<S>]) -> Vec<String>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        segments
+            .iter()
+            .map(|segment| segment.destination().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn parse() {
+        let liftover = liftover();
+        assert_eq!(liftover.len(), 3);
+
+        let chains = liftover.chains().collect::<Vec<_>>();
+        assert_eq!(chains[0].id(), 1);
+        assert_eq!(chains[0].score(), 1000);
+        assert_eq!(chains[0].source().to_string(), "chr1:+:100-300");
+        assert_eq!(chains[0].destination().to_string(), "chr1:+:1000-1210");
+        assert_eq!(chains[0].blocks.len(), 3);
+        assert!(!chains[0].is_reversed());
+
+        assert_eq!(chains[1].destination().to_string(), "chr9:-:350-250");
+        assert_eq!(chains[1].destination_length(), 400);
+        assert!(chains[1].is_reversed());
+
+        let empty = "# nothing here\n\n".parse::<Liftover>().unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "chain 1 chr1 10 + 0 10 chr1 10 + 0 10"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 1: missing chain id");
+
+        let err = "chain 1 chr1 10 * 0 10 chr1 10 + 0 10 1"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 1: invalid strand `*`");
+
+        let err = "chain 1 chr1 10 + 0 11 chr1 10 + 0 10 1"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: source bounds `0-11` do not fit within length `10`"
+        );
+
+        let err = "10\n".parse::<Liftover>().unwrap_err();
+        assert_eq!(err.to_string(), "line 1: alignment data outside of a chain");
+
+        let err = "chain 1 chr1 10 + 0 10 chr1 10 + 0 10 1\n5\t1\n"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2: expected 1 or 3 alignment fields, found 2"
+        );
+
+        let err = "chain 1 chr1 10 + 0 10 chr1 10 + 0 10 1\n5\t0\t1\n5\n"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: destination blocks end at `11`, but the chain ends at `10`"
+        );
+
+        let err = "chain 1 chr1 10 + 0 10 chr1 10 + 0 10 1\n5\t0\t0\n4\n"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 1: source blocks end at `9`, but the chain ends at `10`"
+        );
+
+        let err = "chain 1 chr1 10 + 0 10 chr1 10 + 0 10 1\n5\t0\t0\n"
+            .parse::<Liftover>()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "line 1: chain is not terminated");
+
+        #[cfg(feature = "std")]
+        {
+            let err = Liftover::read_chain("chain 1 chr1 10 + 0 10 chr1 10 + 0 ten 1\n".as_bytes())
+                .unwrap_err();
+            assert_eq!(err.to_string(), "parse error: line 1: invalid end `ten`");
+        }
+    }
+
+    #[test]
+    fn base_coordinates() {
+        // Within the first block.
+        assert_eq!(
+            base("chr1:+:101"),
+            CoordinateMapping::Mapped("chr1:+:1001".parse().unwrap())
+        );
+        assert_eq!(
+            base("chr1:-:150"),
+            CoordinateMapping::Mapped("chr1:-:1050".parse().unwrap())
+        );
+
+        // Within the source gap between the first and second blocks.
+        assert_eq!(base("chr1:+:151"), CoordinateMapping::Unmapped);
+
+        // Within the second block.
+        assert_eq!(
+            base("chr1:+:161"),
+            CoordinateMapping::Mapped("chr1:+:1051".parse().unwrap())
+        );
+
+        // Within both the third block and the overlapping chain.
+        assert_eq!(
+            base("chr1:.:290"),
+            CoordinateMapping::Multiple(vec![
+                "chr1:.:1200".parse().unwrap(),
+                "chrUn:.:10".parse().unwrap(),
+            ])
+        );
+
+        // Outside of any chain.
+        assert_eq!(base("chr1:+:1"), CoordinateMapping::Unmapped);
+        assert_eq!(base("chr3:+:1"), CoordinateMapping::Unmapped);
+    }
+
+    #[test]
+    fn reversed_coordinates() {
+        // The first entity of `chr2` maps to the last aligned entity of `chr9`.
+        assert_eq!(
+            base("chr2:+:1"),
+            CoordinateMapping::Mapped("chr9:-:350".parse().unwrap())
+        );
+        assert_eq!(
+            base("chr2:-:100"),
+            CoordinateMapping::Mapped("chr9:+:251".parse().unwrap())
+        );
+        assert_eq!(
+            base("chr2:.:100"),
+            CoordinateMapping::Mapped("chr9:.:251".parse().unwrap())
+        );
+
+        assert_eq!(
+            interbase("chr2:+:0"),
+            CoordinateMapping::Mapped("chr9:-:350".parse().unwrap())
+        );
+        assert_eq!(
+            interbase("chr2:+:100"),
+            CoordinateMapping::Mapped("chr9:-:250".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn interbase_coordinates() {
+        // On the boundary at the start of the first block.
+        assert_eq!(
+            interbase("chr1:+:100"),
+            CoordinateMapping::Mapped("chr1:+:1000".parse().unwrap())
+        );
+
+        // On the boundary between the second and third blocks, which are not
+        // adjacent within the destination.
+        assert_eq!(
+            interbase("chr1:+:200"),
+            CoordinateMapping::Multiple(vec![
+                "chr1:+:1090".parse().unwrap(),
+                "chr1:+:1110".parse().unwrap(),
+            ])
+        );
+
+        // Within the source gap.
+        assert_eq!(interbase("chr1:+:155"), CoordinateMapping::Unmapped);
+    }
+
+    #[test]
+    fn intervals() {
+        assert_eq!(
+            interval("chr1:+:100-150"),
+            IntervalMapping::Mapped("chr1:+:1000-1050".parse().unwrap())
+        );
+
+        // Spanning a destination gap maps every entity, so the mapped interval
+        // includes the entities that are only present in the destination.
+        assert_eq!(
+            interval("chr1:-:220-180"),
+            IntervalMapping::Mapped("chr1:-:1130-1070".parse().unwrap())
+        );
+
+        // Spanning a source gap leaves some entities unmapped.
+        match interval("chr1:+:140-170") {
+            IntervalMapping::Partial { interval, segments } => {
+                assert_eq!(interval.to_string(), "chr1:+:1040-1060");
+                assert_eq!(
+                    destinations(&segments),
+                    vec!["chr1:+:1040-1050", "chr1:+:1050-1060"]
+                );
+                assert_eq!(segments[0].source().to_string(), "chr1:+:140-150");
+                assert_eq!(segments[1].source().to_string(), "chr1:+:160-170");
+            }
+            mapping => panic!("unexpected mapping: {mapping:?}"),
+        }
+
+        // Overlapping the second chain splits the interval, and the segments
+        // follow the strand of the source interval.
+        match interval("chr1:-:310-290") {
+            IntervalMapping::Split(segments) => {
+                assert_eq!(
+                    destinations(&segments),
+                    vec!["chrUn:-:30-10", "chr1:-:1210-1200"]
+                );
+                assert_eq!(segments[0].chain(), 3);
+                assert_eq!(segments[1].chain(), 1);
+            }
+            mapping => panic!("unexpected mapping: {mapping:?}"),
+        }
+
+        assert_eq!(interval("chr1:+:150-160"), IntervalMapping::Unmapped);
+        assert_eq!(interval("chr4:+:0-10"), IntervalMapping::Unmapped);
+    }
+
+    #[test]
+    fn reversed_intervals() {
+        let liftover = liftover();
+
+        let interval = "chr2:+:1-10".parse::<Interval<Base>>().unwrap();
+        assert_eq!(
+            liftover.map_interval(&interval).unwrap(),
+            IntervalMapping::Mapped("chr9:-:350-341".parse().unwrap())
+        );
+
+        let interval = "chr2:-:100-91".parse::<Interval<Base>>().unwrap();
+        assert_eq!(
+            liftover.map_interval(&interval).unwrap(),
+            IntervalMapping::Mapped("chr9:+:251-260".parse().unwrap())
+        );
+
+        // An interval running off the end of the chain is partially mapped.
+        let interval = "chr2:+:95-105".parse::<Interval<Base>>().unwrap();
+        match liftover.map_interval(&interval).unwrap() {
+            IntervalMapping::Partial { interval, .. } => {
+                assert_eq!(interval.to_string(), "chr9:-:256-251");
+            }
+            mapping => panic!("unexpected mapping: {mapping:?}"),
+        }
+    }
+
+    #[test]
+    fn empty_intervals() {
+        assert_eq!(
+            interval("chr2:+:10-10"),
+            IntervalMapping::Mapped("chr9:-:340-340".parse().unwrap())
+        );
+
+        match interval("chr1:+:200-200") {
+            IntervalMapping::Split(segments) => {
+                assert_eq!(
+                    destinations(&segments),
+                    vec!["chr1:+:1090-1090", "chr1:+:1110-1110"]
+                );
+            }
+            mapping => panic!("unexpected mapping: {mapping:?}"),
+        }
+
+        assert_eq!(interval("chr1:+:155-155"), IntervalMapping::Unmapped);
+    }
+
+    #[test]
+    fn width() {
+        let liftover = "chain 1 chr1 10 + 0 10 chr1 5000000010 + 5000000000 5000000010 1\n10\n"
+            .parse::<Liftover>()
+            .unwrap();
+
+        let coordinate = "chr1:+:1".parse::<Coordinate<Base>>().unwrap();
+        let err = liftover.map_coordinate(&coordinate).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "mapped position `5000000001` cannot be represented as a 32-bit position"
+        );
+
+        let coordinate = "chr1:+:1".parse::<Coordinate<Base<u64>>>().unwrap();
+        assert_eq!(
+            liftover.map_coordinate(&coordinate).unwrap(),
+            CoordinateMapping::Mapped("chr1:+:5000000001".parse().unwrap())
+        );
+    }
+}
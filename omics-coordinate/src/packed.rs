@@ -0,0 +1,481 @@
+//! Compact, fixed-width representations of coordinates and intervals.
+//!
+//! A [`Coordinate`] holds a reference-counted contig name, which is convenient
+//! but comparatively heavy when hundreds of millions of coordinates are held in
+//! memory at once. The packed forms within this module instead store the index
+//! of the contig within an [`Assembly`] alongside the strand and position(s) in
+//! fixed-width integer words:
+//!
+//! * a [`PackedCoordinate`] is a single word holding the contig index, the
+//!   position, and the strand, and
+//! * a [`PackedInterval`] is two words: one holding the contig index and the
+//!   numerically lower position and one holding the numerically upper position
+//!   and the strand.
+//!
+//! The fields are laid out from most to least significant so that comparing
+//! the words directly matches the genomic order defined by
+//! [`Assembly::compare_coordinates()`] and [`Assembly::compare_intervals()`]
+//! for contigs within the assembly. Sorting packed values is therefore a plain
+//! integer sort.
+//!
+//! Packed values are only meaningful with respect to the assembly used to
+//! create them (and in the same contig order—reordering the assembly, for
+//! example with [`Assembly::sort_karyotypic()`], invalidates them).
+//! Converting to and from the rich types is lossless, with the exception that
+//! contigs referred to by an alias are unpacked with their canonical name.
+//!
+//! Packing is only available for coordinate systems with the default
+//! [position width](crate::position::Number), as wider positions would not
+//! leave room for the contig index within a single word.
+
+use core::marker::PhantomData;
+
+use thiserror::Error;
+
+use crate::Assembly;
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::interval;
+use crate::position;
+use crate::position::Number;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Constants and Types
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The word in which packed values are stored.
+type Word = u64;
+
+/// The number of bits used to store a position.
+const POSITION_BITS: u32 = Number::BITS;
+
+/// The number of bits used to store a strand.
+const STRAND_BITS: u32 = 2;
+
+/// The number of bits used to store a contig index within a packed coordinate.
+const CONTIG_BITS: u32 = Word::BITS - POSITION_BITS - STRAND_BITS;
+
+/// The mask for a strand.
+const STRAND_MASK: Word = (1 << STRAND_BITS) - 1;
+
+/// The mask for a position.
+const POSITION_MASK: Word = Number::MAX as Word;
+
+/// The maximum number of contigs that can be referred to by packed values.
+pub const MAX_CONTIGS: usize = 1 << CONTIG_BITS;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to packing or unpacking.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An unknown contig error.
+    ///
+    /// This error occurs when a coordinate or interval lies upon a contig that
+    /// is not present within the assembly.
+    #[error("unknown contig: `{0}`")]
+    UnknownContig(Contig),
+
+    /// A contig index overflow error.
+    ///
+    /// This error occurs when the index of a contig within the assembly is too
+    /// large to be packed (see [`MAX_CONTIGS`]).
+    #[error("contig index `{0}` is too large to be packed")]
+    IndexOverflow(usize),
+
+    /// A missing index error.
+    ///
+    /// This error occurs when a packed value refers to a contig index that is
+    /// not present within the assembly used to unpack it.
+    #[error("contig index `{0}` is not present within the assembly")]
+    MissingIndex(usize),
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// Looks up the packed contig index of a contig within an assembly.
+fn contig_index(assembly: &Assembly, contig: &Contig) -> Result<Word> {
+    let index = assembly
+        .index_of(contig.as_str())
+        .ok_or_else(|| Error::UnknownContig(contig.clone()))?;
+
+    if index >= MAX_CONTIGS {
+        return Err(Error::IndexOverflow(index));
+    }
+
+    Ok(index as Word)
+}
+
+/// Looks up the contig at a packed contig index within an assembly.
+fn contig_at(assembly: &Assembly, index: usize) -> Result<Contig> {
+    assembly
+        .iter()
+        .nth(index)
+        .map(|entry| entry.contig().clone())
+        .ok_or(Error::MissingIndex(index))
+}
+
+/// Decodes a strand from its packed representation.
+fn strand(bits: Word) -> Strand {
+    match bits & STRAND_MASK {
+        0 => Strand::Positive,
+        1 => Strand::Negative,
+        _ => Strand::Unstranded,
+    }
+}
+
+/// Decodes a position from its packed representation.
+fn position<S: System<Number = Number>>(bits: Word) -> Position<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    // SAFETY: packed positions are only ever created from valid positions, so
+    // this will always unwrap.
+    Position::<S>::try_from_number((bits & POSITION_MASK) as Number).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Packed coordinates
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A coordinate packed into a single fixed-width word.
+///
+/// See the [module documentation](self) for more information.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::Assembly;
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::packed::PackedCoordinate;
+/// use omics_coordinate::system::Base;
+///
+/// let assembly = Assembly::read_fai("chr2\t1000\nchr10\t1000\n".as_bytes())?;
+///
+/// let mut packed = ["chr10:+:1", "chr2:-:50", "chr2:+:10"]
+///     .into_iter()
+///     .map(|value| {
+///         let coordinate = value.parse::<Coordinate<Base>>()?;
+///         Ok(PackedCoordinate::pack(&coordinate, &assembly)?)
+///     })
+///     .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+///
+/// // Packed coordinates sort in the genomic order of the assembly.
+/// packed.sort();
+///
+/// let coordinates = packed
+///     .iter()
+///     .map(|p| Ok(p.unpack(&assembly)?.to_string()))
+///     .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+/// assert_eq!(coordinates, vec!["chr2:+:10", "chr2:-:50", "chr10:+:1"]);
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedCoordinate<S: System> {
+    /// The contig index, position, and strand (from most to least
+    /// significant).
+    word: Word,
+
+    /// The coordinate system.
+    system: PhantomData<S>,
+}
+
+impl<S: System<Number = Number>> PackedCoordinate<S>
+where
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Attempts to pack a coordinate using the contig order of an assembly.
+    ///
+    /// If the contig of the coordinate is not present within the assembly, an
+    /// [`Error::UnknownContig`] is returned.
+    pub fn pack(coordinate: &Coordinate<S>, assembly: &Assembly) -> Result<Self> {
+        let index = contig_index(assembly, coordinate.contig())?;
+
+        let word = (index << (POSITION_BITS + STRAND_BITS))
+            | ((coordinate.position().get() as Word) << STRAND_BITS)
+            | coordinate.strand() as Word;
+
+        Ok(Self {
+            word,
+            system: PhantomData,
+        })
+    }
+
+    /// Attempts to unpack the coordinate using the assembly with which it was
+    /// packed.
+    ///
+    /// If the contig index is not present within the assembly, an
+    /// [`Error::MissingIndex`] is returned.
+    pub fn unpack(&self, assembly: &Assembly) -> Result<Coordinate<S>> {
+        let contig = contig_at(assembly, self.contig_index())?;
+        Ok(Coordinate::new(contig, self.strand(), self.position()))
+    }
+
+    /// Gets the index of the contig within the assembly.
+    pub fn contig_index(&self) -> usize {
+        (self.word >> (POSITION_BITS + STRAND_BITS)) as usize
+    }
+
+    /// Gets the strand.
+    pub fn strand(&self) -> Strand {
+        strand(self.word)
+    }
+
+    /// Gets the position.
+    pub fn position(&self) -> Position<S> {
+        position(self.word >> STRAND_BITS)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Packed intervals
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An interval packed into two fixed-width words.
+///
+/// See the [module documentation](self) for more information.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::Assembly;
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::packed::PackedInterval;
+/// use omics_coordinate::system::Interbase;
+///
+/// let assembly = Assembly::read_fai("chr1\t1000\nchrM\t16569\n".as_bytes())?;
+///
+/// let interval = "chrM:-:200-100".parse::<Interval<Interbase>>()?;
+/// let packed = PackedInterval::pack(&interval, &assembly)?;
+///
+/// assert_eq!(packed.contig_index(), 1);
+/// assert_eq!(packed.start().get(), 200);
+/// assert_eq!(packed.end().get(), 100);
+/// assert_eq!(packed.unpack(&assembly)?, interval);
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackedInterval<S: System> {
+    /// The contig index and numerically lower position (from most to least
+    /// significant).
+    head: Word,
+
+    /// The numerically upper position and strand (from most to least
+    /// significant).
+    tail: Word,
+
+    /// The coordinate system.
+    system: PhantomData<S>,
+}
+
+impl<S: System<Number = Number>> PackedInterval<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Attempts to pack an interval using the contig order of an assembly.
+    ///
+    /// If the contig of the interval is not present within the assembly, an
+    /// [`Error::UnknownContig`] is returned.
+    pub fn pack(interval: &Interval<S>, assembly: &Assembly) -> Result<Self> {
+        let index = contig_index(assembly, interval.contig())?;
+
+        let head = (index << POSITION_BITS) | interval.lower().get() as Word;
+        let tail = ((interval.upper().get() as Word) << STRAND_BITS) | interval.strand() as Word;
+
+        Ok(Self {
+            head,
+            tail,
+            system: PhantomData,
+        })
+    }
+
+    /// Attempts to unpack the interval using the assembly with which it was
+    /// packed.
+    ///
+    /// If the contig index is not present within the assembly, an
+    /// [`Error::MissingIndex`] is returned.
+    pub fn unpack(&self, assembly: &Assembly) -> Result<Interval<S>> {
+        let contig = contig_at(assembly, self.contig_index())?;
+
+        Ok(Interval::from_bounds(
+            contig,
+            self.strand(),
+            self.lower(),
+            self.upper(),
+        ))
+    }
+
+    /// Gets the index of the contig within the assembly.
+    pub fn contig_index(&self) -> usize {
+        (self.head >> POSITION_BITS) as usize
+    }
+
+    /// Gets the strand.
+    pub fn strand(&self) -> Strand {
+        strand(self.tail)
+    }
+
+    /// Gets the start position.
+    pub fn start(&self) -> Position<S> {
+        match self.strand() {
+            Strand::Positive | Strand::Unstranded => self.lower(),
+            Strand::Negative => self.upper(),
+        }
+    }
+
+    /// Gets the end position.
+    pub fn end(&self) -> Position<S> {
+        match self.strand() {
+            Strand::Positive | Strand::Unstranded => self.upper(),
+            Strand::Negative => self.lower(),
+        }
+    }
+
+    /// Gets the numerically lower position.
+    fn lower(&self) -> Position<S> {
+        position(self.head)
+    }
+
+    /// Gets the numerically upper position.
+    fn upper(&self) -> Position<S> {
+        position(self.tail >> STRAND_BITS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::assembly::Entry;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    fn assembly() -> Assembly {
+        Assembly::try_new([
+            Entry::new(Contig::new_unchecked("chr2"), 4_294_967_295)
+                .with_aliases([Contig::new_unchecked("2")]),
+            Entry::new(Contig::new_unchecked("chr10"), 1000),
+            Entry::new(Contig::new_unchecked("chrM"), 16_569),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(
+            core::mem::size_of::<PackedCoordinate<Base>>(),
+            core::mem::size_of::<Word>()
+        );
+        assert_eq!(
+            core::mem::size_of::<PackedInterval<Base>>(),
+            2 * core::mem::size_of::<Word>()
+        );
+    }
+
+    #[test]
+    fn coordinates_round_trip_and_sort() {
+        let assembly = assembly();
+
+        let mut coordinates = [
+            "chrM:+:1",
+            "chr10:-:5",
+            "chr2:.:4294967295",
+            "chr2:-:0",
+            "chr10:+:5",
+            "chr2:+:0",
+        ]
+        .map(|value| value.parse::<Coordinate<Interbase>>().unwrap());
+
+        let mut packed = coordinates
+            .iter()
+            .map(|c| PackedCoordinate::pack(c, &assembly).unwrap())
+            .collect::<Vec<_>>();
+
+        for (coordinate, packed) in coordinates.iter().zip(&packed) {
+            assert_eq!(&packed.unpack(&assembly).unwrap(), coordinate);
+        }
+
+        coordinates.sort_by(|a, b| assembly.compare_coordinates(a, b));
+        packed.sort();
+
+        let unpacked = packed
+            .iter()
+            .map(|p| p.unpack(&assembly).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(unpacked, coordinates);
+    }
+
+    #[test]
+    fn intervals_round_trip_and_sort() {
+        let assembly = assembly();
+
+        let mut intervals = [
+            "chr10:+:1-10",
+            "chr2:-:10-1",
+            "chr2:+:1-10",
+            "chr2:+:1-9",
+            "chrM:.:1-16569",
+            "chr2:+:2-3",
+        ]
+        .map(|value| value.parse::<Interval<Base>>().unwrap());
+
+        let mut packed = intervals
+            .iter()
+            .map(|i| PackedInterval::pack(i, &assembly).unwrap())
+            .collect::<Vec<_>>();
+
+        for (interval, packed) in intervals.iter().zip(&packed) {
+            assert_eq!(&packed.unpack(&assembly).unwrap(), interval);
+        }
+
+        intervals.sort_by(|a, b| assembly.compare_intervals(a, b));
+        packed.sort();
+
+        let unpacked = packed
+            .iter()
+            .map(|p| p.unpack(&assembly).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(unpacked, intervals);
+    }
+
+    #[test]
+    fn aliases_and_errors() {
+        let assembly = assembly();
+
+        let coordinate = "2:+:10".parse::<Coordinate<Base>>().unwrap();
+        let packed = PackedCoordinate::pack(&coordinate, &assembly).unwrap();
+        assert_eq!(packed.contig_index(), 0);
+        assert_eq!(packed.unpack(&assembly).unwrap().to_string(), "chr2:+:10");
+
+        let coordinate = "chr1:+:10".parse::<Coordinate<Base>>().unwrap();
+        assert_eq!(
+            PackedCoordinate::pack(&coordinate, &assembly).unwrap_err(),
+            Error::UnknownContig(Contig::new_unchecked("chr1"))
+        );
+
+        let interval = "chrM:+:1-10".parse::<Interval<Base>>().unwrap();
+        let packed = PackedInterval::pack(&interval, &assembly).unwrap();
+        let smaller = Assembly::try_new([Entry::new(Contig::new_unchecked("chr2"), 10)]).unwrap();
+        assert_eq!(packed.unpack(&smaller).unwrap_err(), Error::MissingIndex(2));
+    }
+}
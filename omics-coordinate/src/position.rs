@@ -1,6 +1,7 @@
 //! Positions.
 
-use std::num::ParseIntError;
+use alloc::string::String;
+use core::num::ParseIntError;
 
 use thiserror::Error;
 
@@ -17,19 +18,160 @@ pub mod interbase;
 // Constants and Types
 ////////////////////////////////////////////////////////////////////////////////////////
 
-/// The inner representation for a numerical position value.
+/// The default inner representation for a numerical position value.
 ///
-/// Note that `u64` positions can be enabled by turning on the `position-u64`
-/// feature for the crate.
-#[cfg(not(feature = "position-u64"))]
+/// Coordinate systems are generic over the [`Width`] of their positions and
+/// default to this representation (i.e., [`Base`] is shorthand for
+/// `Base<u32>`). Positions with a different width, such as `Base<u64>` for
+/// contigs longer than [`u32::MAX`], can be used alongside positions of the
+/// default width within the same program.
 pub type Number = u32;
 
-/// The inner representation for a numerical position value.
+/// Sealing for the [`Width`] trait.
+mod sealed {
+    /// A sealed trait.
+    pub trait Sealed {}
+
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+}
+
+/// An unsigned integer that can be used as the inner representation for a
+/// numerical position value.
+///
+/// This trait is sealed and implemented for [`u32`] (the default—see
+/// [`Number`]) and [`u64`].
+///
+/// # Examples
 ///
-/// Note that `u32` positions can be enabled by turning off the `position-u64`
-/// feature for the crate.
-#[cfg(feature = "position-u64")]
-pub type Number = u64;
+/// ```
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::position::Width;
+/// use omics_coordinate::system::Interbase;
+///
+/// let small = "seq0:+:10".parse::<Coordinate<Interbase>>()?;
+/// let large = "seq0:+:5000000000".parse::<Coordinate<Interbase<u64>>>()?;
+///
+/// assert_eq!(small.position().get(), 10u32);
+/// assert_eq!(large.position().get(), 5_000_000_000u64);
+/// assert_eq!(<u64 as Width>::BITS, 64);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub trait Width:
+    sealed::Sealed
+    + Copy
+    + Default
+    + core::fmt::Debug
+    + core::fmt::Display
+    + Eq
+    + Ord
+    + core::hash::Hash
+    + Send
+    + Sync
+    + 'static
+    + core::str::FromStr<Err = ParseIntError>
+    + From<u8>
+    + From<u16>
+    + From<u32>
+    + Into<u64>
+    + TryFrom<u64>
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+    + core::ops::Rem<Output = Self>
+    + core::ops::AddAssign
+    + core::ops::SubAssign
+{
+    /// The zero value.
+    const ZERO: Self;
+
+    /// The one value.
+    const ONE: Self;
+
+    /// The largest representable value.
+    const MAX: Self;
+
+    /// The size of the representation in bits.
+    const BITS: u32;
+
+    /// Performs checked addition.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Performs checked subtraction.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Performs checked multiplication.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Performs saturating addition.
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    /// Performs saturating subtraction.
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    /// Converts the value into a [`u64`].
+    fn to_u64(self) -> u64 {
+        self.into()
+    }
+
+    /// Attempts to convert a [`u64`] into a value of this width.
+    ///
+    /// If the value cannot be represented, [`None`] is returned.
+    fn from_u64(value: u64) -> Option<Self> {
+        Self::try_from(value).ok()
+    }
+
+    /// Attempts to convert a [`usize`] into a value of this width.
+    ///
+    /// If the value cannot be represented, [`None`] is returned.
+    fn from_usize(value: usize) -> Option<Self> {
+        u64::try_from(value).ok().and_then(Self::from_u64)
+    }
+
+    /// Attempts to convert the value into a [`usize`].
+    ///
+    /// If the value cannot be represented, [`None`] is returned.
+    fn to_usize(self) -> Option<usize> {
+        usize::try_from(self.to_u64()).ok()
+    }
+}
+
+/// Implements [`Width`] for a primitive unsigned integer.
+macro_rules! width {
+    ($ty:ty) => {
+        impl Width for $ty {
+            const BITS: u32 = <$ty>::BITS;
+            const MAX: Self = <$ty>::MAX;
+            const ONE: Self = 1;
+            const ZERO: Self = 0;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_mul(self, rhs)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$ty>::saturating_add(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$ty>::saturating_sub(self, rhs)
+            }
+        }
+    };
+}
+
+width!(u32);
+width!(u64);
 
 ////////////////////////////////////////////////////////////////////////////////////////
 // Assertions
@@ -44,6 +186,8 @@ const _: () = {
     // passed around as such as well.
     is_copy::<Position<Interbase>>();
     is_copy::<Position<Base>>();
+    is_copy::<Position<Interbase<u64>>>();
+    is_copy::<Position<Base<u64>>>();
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -69,8 +213,8 @@ pub enum ParseError {
     },
 }
 
-/// A [`Result`](std::result::Result) with a [`ParseError`].
-pub type ParseResult<T> = std::result::Result<T, ParseError>;
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
 
 /// A position-related error.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -90,12 +234,25 @@ pub enum Error {
         system: &'static str,
 
         /// The incompatible value.
-        value: Number,
+        value: u64,
+    },
+
+    /// A width conversion error.
+    ///
+    /// This error occurs when a position is converted to a [`Width`] that
+    /// cannot represent its value.
+    #[error("position `{value}` cannot be represented as a {bits}-bit position")]
+    Width {
+        /// The value that could not be converted.
+        value: u64,
+
+        /// The number of bits in the target representation.
+        bits: u32,
     },
 }
 
-/// A [`Result`](std::result::Result) with an [`Error`](enum@Error).
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
 
 ///////////////////////////////////////////////////////////////////////////////////////
 // The `Position` trait
@@ -103,26 +260,27 @@ pub type Result<T> = std::result::Result<T, Error>;
 
 /// Traits related to a position.
 pub mod r#trait {
-    use std::num::NonZero;
-
     use super::*;
 
     /// Requirements to be a position.
     pub trait Position<S: System>:
-        std::fmt::Display
-        + std::fmt::Debug
+        core::fmt::Display
+        + core::fmt::Debug
         + PartialEq
         + Eq
         + PartialOrd
         + Ord
-        + std::str::FromStr<Err = Error>
-        + CheckedAdd<Number, Output = Self>
-        + CheckedSub<Number, Output = Self>
-        + TryFrom<Number>
-        + From<NonZero<Number>>
+        + core::str::FromStr<Err = Error>
+        + CheckedAdd<S::Number, Output = Self>
+        + CheckedSub<S::Number, Output = Self>
     where
         Self: Sized,
     {
+        /// Attempts to create a position from a numerical value.
+        ///
+        /// This is the generic counterpart to the [`TryFrom`] implementations
+        /// provided for each coordinate system.
+        fn try_from_number(value: S::Number) -> Result<Self>;
     }
 }
 
@@ -140,7 +298,7 @@ pub struct Position<S: System> {
     system: S,
 
     /// The inner value.
-    value: Number,
+    value: S::Number,
 }
 
 impl<S: System> Position<S> {
@@ -155,10 +313,31 @@ impl<S: System> Position<S> {
     /// let position = Position::<Interbase>::new(42);
     /// assert_eq!(position.get(), 42);
     /// ```
-    pub fn get(&self) -> Number {
+    pub fn get(&self) -> S::Number {
         self.value
     }
 
+    /// Attempts to create a position from a numerical value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use omics_coordinate::Position;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// assert!(Position::<Base>::try_from_number(0).is_err());
+    /// assert_eq!(Position::<Interbase>::try_from_number(0)?.get(), 0);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_number(value: S::Number) -> Result<Self>
+    where
+        Self: r#trait::Position<S>,
+    {
+        <Self as r#trait::Position<S>>::try_from_number(value)
+    }
+
     /// Performs checked addition.
     ///
     /// # Examples
@@ -172,11 +351,11 @@ impl<S: System> Position<S> {
     ///     .expect("addition to succeed");
     /// assert_eq!(position.get(), 50);
     /// ```
-    pub fn checked_add(&self, rhs: Number) -> Option<Self>
+    pub fn checked_add(&self, rhs: S::Number) -> Option<Self>
     where
         Self: r#trait::Position<S>,
     {
-        <Self as CheckedAdd<Number>>::checked_add(self, rhs)
+        <Self as CheckedAdd<S::Number>>::checked_add(self, rhs)
     }
 
     /// Performs checked subtraction.
@@ -192,11 +371,47 @@ impl<S: System> Position<S> {
     ///     .expect("subtraction to succeed");
     /// assert_eq!(position.get(), 40);
     /// ```
-    pub fn checked_sub(&self, rhs: Number) -> Option<Self>
+    pub fn checked_sub(&self, rhs: S::Number) -> Option<Self>
     where
         Self: r#trait::Position<S>,
     {
-        <Self as CheckedSub<Number>>::checked_sub(self, rhs)
+        <Self as CheckedSub<S::Number>>::checked_sub(self, rhs)
+    }
+
+    /// Attempts to convert the position into a position of the same coordinate
+    /// system with another [`Width`].
+    ///
+    /// If the value cannot be represented within the target width, an
+    /// [`Error::Width`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use omics_coordinate::Position;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let position = Position::<Base>::try_new(42)?;
+    /// let wide = position.try_into_width::<u64>()?;
+    /// assert_eq!(wide.get(), 42u64);
+    ///
+    /// let position = Position::<Base<u64>>::try_new(1 << 40)?;
+    /// assert!(position.try_into_width::<u32>().is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_into_width<W: Width>(self) -> Result<Position<S::WithWidth<W>>> {
+        let value = self.value.to_u64();
+
+        match W::from_u64(value) {
+            Some(value) => Ok(Position {
+                system: Default::default(),
+                value,
+            }),
+            None => Err(Error::Width {
+                value,
+                bits: W::BITS,
+            }),
+        }
     }
 
     /// Gets the magnitude of the distance between two positions.
@@ -214,7 +429,7 @@ impl<S: System> Position<S> {
     /// coordinates and calculate the distance between the two coordinates. If
     /// you think you have a legitimate use case where this would be useful,
     /// please file an issue.
-    pub(crate) fn distance_unchecked(&self, rhs: &Position<S>) -> Number {
+    pub(crate) fn distance_unchecked(&self, rhs: &Position<S>) -> S::Number {
         let a = self.get();
         let b = rhs.get();
 
@@ -229,8 +444,8 @@ impl<S: System> Position<S> {
     }
 }
 
-impl<S: System> std::fmt::Display for Position<S> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<S: System> core::fmt::Display for Position<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if !f.alternate() {
             write!(f, "{}", self.value)
         } else {
@@ -239,9 +454,39 @@ impl<S: System> std::fmt::Display for Position<S> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<S: System> serde::Serialize for Position<S>
+where
+    S::Number: serde::Serialize,
+{
+    /// Serializes a position as its numerical value.
+    fn serialize<Se: serde::Serializer>(
+        &self,
+        serializer: Se,
+    ) -> core::result::Result<Se::Ok, Se::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S: System> serde::Deserialize<'de> for Position<S>
+where
+    Position<S>: r#trait::Position<S>,
+    S::Number: serde::Deserialize<'de>,
+{
+    /// Deserializes a position from its numerical value, validating it as
+    /// [`Position::try_from_number()`] does.
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        let value = S::Number::deserialize(deserializer)?;
+        Self::try_from_number(value).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::fmt::Write as _;
+    use core::fmt::Write as _;
 
     use super::*;
     use crate::system::Interbase;
@@ -258,4 +503,32 @@ mod tests {
         write!(&mut buffer, "{position:#}").unwrap();
         assert_eq!(buffer, "0 (interbase coordinate system)");
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let position = Position::<Interbase>::new(0);
+        let json = serde_json::to_string(&position).unwrap();
+        assert_eq!(json, "0");
+        assert_eq!(
+            serde_json::from_str::<Position<Interbase>>(&json).unwrap(),
+            position
+        );
+
+        // In-base positions are validated just as they are when constructed.
+        assert!(serde_json::from_str::<Position<Base>>("0").is_err());
+        assert_eq!(
+            serde_json::from_str::<Position<Base>>("1").unwrap().get(),
+            1
+        );
+
+        // Wider positions are serialized and validated at their own width.
+        assert!(serde_json::from_str::<Position<Base>>("5000000000").is_err());
+        assert_eq!(
+            serde_json::from_str::<Position<Base<u64>>>("5000000000")
+                .unwrap()
+                .get(),
+            5_000_000_000
+        );
+    }
 }
@@ -1,14 +1,17 @@
 //! Base positions.
 
+use alloc::string::ToString;
+
 mod addition;
 mod subtraction;
 
-use std::num::NonZero;
+use core::num::NonZero;
 
 use crate::position::Error;
 use crate::position::Number;
 use crate::position::ParseError;
 use crate::position::Result;
+use crate::position::Width;
 use crate::system::Base;
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -23,6 +26,8 @@ const _: () = {
     /// A function to ensure that types are `Copy`.
     const fn is_copy<T: Copy>() {}
     is_copy::<Position>();
+
+    assert!(size_of::<crate::Position<Base<u64>>>() == size_of::<u64>());
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -31,10 +36,11 @@ const _: () = {
 
 /// A base position.
 ///
-/// Base positions start at one (`1`).
+/// Base positions start at one (`1`). This alias uses the default [`Width`];
+/// positions of other widths are available as `Position<Base<W>>`.
 pub type Position = crate::Position<Base>;
 
-impl Position {
+impl<W: Width> crate::Position<Base<W>> {
     /// Create a new base position if the value is not zero.
     ///
     /// # Examples
@@ -47,16 +53,16 @@ impl Position {
     ///
     /// # Ok::<(), omics_coordinate::position::Error>(())
     /// ```
-    pub const fn try_new(value: Number) -> Result<Self> {
-        if value == 0 {
+    pub fn try_new(value: W) -> Result<Self> {
+        if value == W::ZERO {
             return Err(Error::IncompatibleValue {
                 system: Base::NAME,
-                value,
+                value: value.to_u64(),
             });
         }
 
         Ok(Self {
-            system: Base,
+            system: Base::default(),
             value,
         })
     }
@@ -66,13 +72,17 @@ impl Position {
 // Trait implementations
 ////////////////////////////////////////////////////////////////////////////////////////
 
-impl super::r#trait::Position<Base> for Position {}
+impl<W: Width> super::r#trait::Position<Base<W>> for crate::Position<Base<W>> {
+    fn try_from_number(value: W) -> Result<Self> {
+        Self::try_new(value)
+    }
+}
 
-impl std::str::FromStr for Position {
+impl<W: Width> core::str::FromStr for crate::Position<Base<W>> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        Self::try_new(s.parse::<Number>().map_err(|error| ParseError::Int {
+        Self::try_new(s.parse::<W>().map_err(|error| ParseError::Int {
             system: Base::NAME,
             inner: error,
             value: s.to_string(),
@@ -80,56 +90,68 @@ impl std::str::FromStr for Position {
     }
 }
 
-impl TryFrom<Number> for Position {
-    type Error = Error;
+/// Creates implementations to convert from numbers to a position.
+macro_rules! position_from_number {
+    ($width:ty) => {
+        impl TryFrom<$width> for crate::Position<Base<$width>> {
+            type Error = Error;
 
-    fn try_from(value: Number) -> Result<Self> {
-        Self::try_new(value)
-    }
+            fn try_from(value: $width) -> Result<Self> {
+                Self::try_new(value)
+            }
+        }
+    };
 }
 
-impl From<NonZero<Number>> for Position {
-    fn from(value: NonZero<Number>) -> Self {
-        // SAFETY: because [`try_new()`] will only throw an error when zero
-        // (`0`) is passed in and `value` here is a non-zero number, this will
-        // always [`unwrap()`].
-        Self::try_new(value.get()).unwrap()
-    }
-}
+position_from_number!(u32);
+position_from_number!(u64);
 
 /// Creates implementations to convert from smaller numbers to a position.
 macro_rules! position_from_smaller_number {
-    ($from:ty) => {
-        impl From<NonZero<$from>> for Position {
+    ($from:ty, $width:ty) => {
+        impl From<NonZero<$from>> for crate::Position<Base<$width>> {
             fn from(value: NonZero<$from>) -> Self {
-                // SAFETY: because [`try_from()`] will only throw an error when zero
+                // SAFETY: because [`try_new()`] will only throw an error when zero
                 // (`0`) is passed in and `value` here is a non-zero number, this will
                 // always [`unwrap()`].
-                Self::try_new(value.get() as Number).unwrap()
+                Self::try_new(<$width>::from(value.get())).unwrap()
             }
         }
-
-        impl TryFrom<$from> for Position {
+    };
+    ($from:ty) => {
+        impl<W: Width> TryFrom<$from> for crate::Position<Base<W>> {
             type Error = Error;
 
             fn try_from(value: $from) -> Result<Self> {
-                Self::try_new(value as Number)
+                Self::try_new(W::from(value))
             }
         }
+
+        position_from_smaller_number!($from, u32);
+        position_from_smaller_number!($from, u64);
     };
 }
 
-#[cfg(feature = "position-u64")]
-position_from_smaller_number!(u32);
+position_from_smaller_number!(u64, u64);
+position_from_smaller_number!(u32, u32);
+position_from_smaller_number!(u32, u64);
 position_from_smaller_number!(u16);
 position_from_smaller_number!(u8);
 
+impl TryFrom<u32> for crate::Position<Base<u64>> {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        Self::try_new(u64::from(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroU8;
-    use std::num::NonZeroU16;
-    #[cfg(feature = "position-u64")]
-    use std::num::NonZeroU32;
+    use alloc::string::ToString;
+    use core::num::NonZeroU8;
+    use core::num::NonZeroU16;
+    use core::num::NonZeroU32;
 
     use crate::Position;
     use crate::position::Error;
@@ -188,15 +210,12 @@ mod tests {
 
     #[test]
     fn from_smaller_types() -> Result<()> {
-        #[cfg(feature = "position-u64")]
-        {
-            // u32
-            let position = Position::<Base>::try_from(1u32)?;
-            assert_eq!(position.get(), 1);
-
-            let position = Position::<Base>::from(NonZeroU32::new(1).unwrap());
-            assert_eq!(position.get(), 1);
-        }
+        // u32
+        let position = Position::<Base<u64>>::try_from(1u32)?;
+        assert_eq!(position.get(), 1);
+
+        let position = Position::<Base<u64>>::from(NonZeroU32::new(1).unwrap());
+        assert_eq!(position.get(), 1);
 
         // u16
         let position = Position::<Base>::try_from(1u16)?;
@@ -1,20 +1,21 @@
 //! Addition for base positions.
 
 use crate::math::CheckedAdd;
-use crate::position::Number;
-use crate::position::base::Position;
+use crate::position::Width;
+use crate::system::Base;
 
-impl CheckedAdd<Number> for Position {
+impl<W: Width> CheckedAdd<W> for crate::Position<Base<W>> {
     type Output = Self;
 
-    fn checked_add(&self, rhs: Number) -> Option<Self> {
-        self.get().checked_add(rhs).map(Position::try_new)?.ok()
+    fn checked_add(&self, rhs: W) -> Option<Self> {
+        self.get().checked_add(rhs).map(Self::try_new)?.ok()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::position::Number;
+    use crate::position::base::Position;
 
     #[test]
     fn addition() {
@@ -1,20 +1,21 @@
 //! Subtraction for base positions.
 
 use crate::math::CheckedSub;
-use crate::position::Number;
-use crate::position::base::Position;
+use crate::position::Width;
+use crate::system::Base;
 
-impl CheckedSub<Number> for Position {
+impl<W: Width> CheckedSub<W> for crate::Position<Base<W>> {
     type Output = Self;
 
-    fn checked_sub(&self, rhs: Number) -> Option<Self::Output> {
-        self.get().checked_sub(rhs).map(Position::try_new)?.ok()
+    fn checked_sub(&self, rhs: W) -> Option<Self::Output> {
+        self.get().checked_sub(rhs).map(Self::try_new)?.ok()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::position::Number;
+    use crate::position::base::Position;
 
     #[test]
     fn addition() {
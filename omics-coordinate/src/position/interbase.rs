@@ -1,11 +1,14 @@
 //! Interbase positions.
 
-use std::num::NonZero;
+use alloc::string::ToString;
+use core::marker::PhantomData;
+use core::num::NonZero;
 
 use crate::position::Error;
 use crate::position::Number;
 use crate::position::ParseError;
 use crate::position::Result;
+use crate::position::Width;
 use crate::system::Interbase;
 
 mod addition;
@@ -23,6 +26,8 @@ const _: () = {
     /// A function to ensure that types are `Copy`.
     const fn is_copy<T: Copy>() {}
     is_copy::<Position>();
+
+    assert!(size_of::<crate::Position<Interbase<u64>>>() == size_of::<u64>());
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -31,10 +36,12 @@ const _: () = {
 
 /// An interbase position.
 ///
-/// Interbase positions start at zero (`0`).
+/// Interbase positions start at zero (`0`). This alias uses the default
+/// [`Width`]; positions of other widths are available as
+/// `Position<Interbase<W>>`.
 pub type Position = crate::Position<Interbase>;
 
-impl Position {
+impl<W: Width> crate::Position<Interbase<W>> {
     /// Creates a new interbase position.
     ///
     /// # Examples
@@ -45,9 +52,9 @@ impl Position {
     /// let position = Position::new(0);
     /// assert_eq!(position.get(), 0);
     /// ```
-    pub const fn new(value: Number) -> Self {
+    pub const fn new(value: W) -> Self {
         Self {
-            system: Interbase,
+            system: Interbase(PhantomData),
             value,
         }
     }
@@ -56,13 +63,17 @@ impl Position {
 // Trait implementations
 ////////////////////////////////////////////////////////////////////////////////////////
 
-impl super::r#trait::Position<Interbase> for Position {}
+impl<W: Width> super::r#trait::Position<Interbase<W>> for crate::Position<Interbase<W>> {
+    fn try_from_number(value: W) -> Result<Self> {
+        Ok(Self::new(value))
+    }
+}
 
-impl std::str::FromStr for Position {
+impl<W: Width> core::str::FromStr for crate::Position<Interbase<W>> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        Ok(Self::new(s.parse::<Number>().map_err(|error| {
+        Ok(Self::new(s.parse::<W>().map_err(|error| {
             Error::Parse(ParseError::Int {
                 system: Interbase::NAME,
                 inner: error,
@@ -72,35 +83,51 @@ impl std::str::FromStr for Position {
     }
 }
 
+impl<W: Width> From<W> for crate::Position<Interbase<W>> {
+    fn from(value: W) -> Self {
+        Self::new(value)
+    }
+}
+
 /// Creates implementations to convert from smaller numbers to a position.
 macro_rules! position_from_smaller_number {
+    ($from:ty, $width:ty) => {
+        impl From<NonZero<$from>> for crate::Position<Interbase<$width>> {
+            fn from(value: NonZero<$from>) -> Self {
+                Self::new(<$width>::from(value.get()))
+            }
+        }
+    };
     ($from:ty) => {
-        impl From<$from> for Position {
+        impl<W: Width> From<$from> for crate::Position<Interbase<W>> {
             fn from(value: $from) -> Self {
-                Self::new(value as Number)
+                Self::new(W::from(value))
             }
         }
 
-        impl From<NonZero<$from>> for Position {
-            fn from(value: NonZero<$from>) -> Self {
-                Self::new(value.get() as Number)
-            }
-        }
+        position_from_smaller_number!($from, u32);
+        position_from_smaller_number!($from, u64);
     };
 }
 
-#[cfg(feature = "position-u64")]
-position_from_smaller_number!(u64);
-position_from_smaller_number!(u32);
+position_from_smaller_number!(u64, u64);
+position_from_smaller_number!(u32, u32);
+position_from_smaller_number!(u32, u64);
 position_from_smaller_number!(u16);
 position_from_smaller_number!(u8);
 
+impl From<u32> for crate::Position<Interbase<u64>> {
+    fn from(value: u32) -> Self {
+        Self::new(u64::from(value))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::num::NonZeroU8;
-    use std::num::NonZeroU16;
-    #[cfg(feature = "position-u64")]
-    use std::num::NonZeroU32;
+    use alloc::string::ToString;
+    use core::num::NonZeroU8;
+    use core::num::NonZeroU16;
+    use core::num::NonZeroU32;
 
     use crate::Position;
     use crate::position::Number;
@@ -146,15 +173,12 @@ mod tests {
 
     #[test]
     fn from_smaller_types() -> Result<()> {
-        #[cfg(feature = "position-u64")]
-        {
-            // u32
-            let position = Position::<Interbase>::from(0u32);
-            assert_eq!(position.get(), 0);
-
-            let position = Position::<Interbase>::from(NonZeroU32::new(1).unwrap());
-            assert_eq!(position.get(), 1);
-        }
+        // u32
+        let position = Position::<Interbase<u64>>::from(0u32);
+        assert_eq!(position.get(), 0);
+
+        let position = Position::<Interbase<u64>>::from(NonZeroU32::new(1).unwrap());
+        assert_eq!(position.get(), 1);
 
         // u16
         let position = Position::<Interbase>::from(0u16);
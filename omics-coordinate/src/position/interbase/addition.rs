@@ -1,20 +1,21 @@
 //! Addition for interbase positions.
 
 use crate::math::CheckedAdd;
-use crate::position::Number;
-use crate::position::interbase::Position;
+use crate::position::Width;
+use crate::system::Interbase;
 
-impl CheckedAdd<Number> for Position {
+impl<W: Width> CheckedAdd<W> for crate::Position<Interbase<W>> {
     type Output = Self;
 
-    fn checked_add(&self, rhs: Number) -> Option<Self> {
-        self.get().checked_add(rhs).map(Position::new)
+    fn checked_add(&self, rhs: W) -> Option<Self> {
+        self.get().checked_add(rhs).map(Self::new)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::position::Number;
+    use crate::position::interbase::Position;
 
     #[test]
     fn addition() {
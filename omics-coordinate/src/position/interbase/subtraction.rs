@@ -1,20 +1,21 @@
 //! Subtraction for interbase positions.
 
 use crate::math::CheckedSub;
-use crate::position::Number;
-use crate::position::interbase::Position;
+use crate::position::Width;
+use crate::system::Interbase;
 
-impl CheckedSub<Number> for Position {
+impl<W: Width> CheckedSub<W> for crate::Position<Interbase<W>> {
     type Output = Self;
 
-    fn checked_sub(&self, rhs: Number) -> Option<Self::Output> {
-        self.get().checked_sub(rhs).map(Position::new)
+    fn checked_sub(&self, rhs: W) -> Option<Self::Output> {
+        self.get().checked_sub(rhs).map(Self::new)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::position::Number;
+    use crate::position::interbase::Position;
 
     #[test]
     fn addition() {
@@ -0,0 +1,657 @@
+//! Region strings in the style of `samtools` and IGV.
+//!
+//! Regions are written as `contig`, `contig:start`, `contig:start-`,
+//! `contig:-end`, or `contig:start-end`, where positions are one-based and
+//! fully closed and may contain thousands separators (e.g.,
+//! `chr1:1,000-2,000`). As in `samtools`, a region with only a start position
+//! (`contig:start`) extends to the end of the contig. Contig names that contain
+//! colons may be wrapped in braces (e.g., `{HLA-A*01:01}:100-200`).
+//!
+//! Regions that are whole-contig or open-ended are resolved into an
+//! [`Interval<Base>`] using a [`LengthSource`] (such as an
+//! [`Assembly`](crate::Assembly)). Like coordinate systems, regions are generic
+//! over the [`Width`] of their positions, so `Region<u64>` resolves into an
+//! `Interval<Base<u64>>` for contigs longer than [`u32::MAX`].
+
+use alloc::string::String;
+use alloc::string::ToString;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::assembly::LengthSource;
+use crate::position::Number;
+use crate::position::Width;
+use crate::system::Base;
+
+/// The separator between a contig and its range.
+const CONTIG_SEPARATOR: char = ':';
+
+/// The separator between the start and end of a range.
+const RANGE_SEPARATOR: char = '-';
+
+/// The thousands separator permitted within positions.
+const THOUSANDS_SEPARATOR: char = ',';
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing a region.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// An empty region was provided.
+    #[error("region cannot be empty")]
+    Empty,
+
+    /// An invalid format was encountered.
+    #[error("invalid region format: {value}")]
+    Format {
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+
+    /// An invalid position was encountered.
+    #[error("invalid position `{value}`")]
+    Position {
+        /// The value that was attempted to be parsed.
+        value: String,
+    },
+
+    /// The start of the region is after its end.
+    #[error("start `{start}` is greater than end `{end}`")]
+    NegativelySized {
+        /// The start position.
+        start: u64,
+
+        /// The end position.
+        end: u64,
+    },
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to a region.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A parse error.
+    #[error("parse error: {0}")]
+    Parse(#[from] ParseError),
+
+    /// An unknown contig error.
+    #[error("unknown contig: `{0}`")]
+    UnknownContig(Contig),
+
+    /// An out of bounds error.
+    ///
+    /// This error occurs when a position lies beyond the end of its contig.
+    #[error("position `{position}` is out of bounds for contig `{contig}` of length `{length}`")]
+    OutOfBounds {
+        /// The contig.
+        contig: Contig,
+
+        /// The position.
+        position: u64,
+
+        /// The length of the contig.
+        length: u64,
+    },
+
+    /// A width error.
+    ///
+    /// This error occurs when a region extends to the end of a contig whose
+    /// length cannot be represented within the [`Width`] of the region.
+    #[error(
+        "length `{length}` of contig `{contig}` cannot be represented as a {bits}-bit position"
+    )]
+    Width {
+        /// The contig.
+        contig: Contig,
+
+        /// The length of the contig.
+        length: u64,
+
+        /// The number of bits in the position representation.
+        bits: u32,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Region
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A region on a contig.
+///
+/// Either bound of a region may be missing, in which case the region extends
+/// to the start or end of the contig respectively.
+///
+/// Regions are formatted without thousands separators by default and with
+/// thousands separators when the alternate flag (`{:#}`) is used.
+///
+/// The positions of a region are represented using `W` (see [`Width`]), which
+/// defaults to [`Number`].
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::region::Region;
+///
+/// let region = "chr1:1,000-2,000".parse::<Region>()?;
+/// assert_eq!(region.contig().as_str(), "chr1");
+/// assert_eq!(region.start(), Some(1_000));
+/// assert_eq!(region.end(), Some(2_000));
+///
+/// assert_eq!(region.to_string(), "chr1:1000-2000");
+/// assert_eq!(format!("{region:#}"), "chr1:1,000-2,000");
+///
+/// let region = "chr1:4,500,000,000-".parse::<Region<u64>>()?;
+/// assert_eq!(region.start(), Some(4_500_000_000));
+/// assert!("chr1:4,500,000,000-".parse::<Region>().is_err());
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Region<W: Width = Number> {
+    /// The contig.
+    contig: Contig,
+
+    /// The start position (if bounded).
+    start: Option<W>,
+
+    /// The end position (if bounded).
+    end: Option<W>,
+}
+
+impl<W: Width> Region<W> {
+    /// Attempts to create a new region.
+    ///
+    /// Positions must be one-based (i.e., non-zero), and the start must not be
+    /// after the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::region::Region;
+    ///
+    /// let region = Region::try_new(Contig::new_unchecked("chr1"), Some(500u32), None)?;
+    /// assert_eq!(region.to_string(), "chr1:500-");
+    ///
+    /// assert!(Region::try_new(Contig::new_unchecked("chr1"), Some(0u32), None).is_err());
+    /// assert!(Region::try_new(Contig::new_unchecked("chr1"), Some(5u32), Some(4)).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(contig: Contig, start: Option<W>, end: Option<W>) -> ParseResult<Self> {
+        for position in [start, end].into_iter().flatten() {
+            if position == W::ZERO {
+                return Err(ParseError::Position {
+                    value: position.to_string(),
+                });
+            }
+        }
+
+        if let (Some(start), Some(end)) = (start, end) {
+            if start > end {
+                return Err(ParseError::NegativelySized {
+                    start: start.to_u64(),
+                    end: end.to_u64(),
+                });
+            }
+        }
+
+        Ok(Self { contig, start, end })
+    }
+
+    /// Gets the contig.
+    pub fn contig(&self) -> &Contig {
+        &self.contig
+    }
+
+    /// Gets the start position (if bounded).
+    pub fn start(&self) -> Option<W> {
+        self.start
+    }
+
+    /// Gets the end position (if bounded).
+    pub fn end(&self) -> Option<W> {
+        self.end
+    }
+
+    /// Attempts to resolve the region into an interval using a source of
+    /// contig lengths.
+    ///
+    /// Missing bounds are filled in from the start and end of the contig. The
+    /// resulting interval is on the positive strand. If the contig is not
+    /// known, a bound lies beyond the end of the contig, or a missing end
+    /// cannot be represented within the [`Width`] of the region, an error is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use std::collections::HashMap;
+    ///
+    /// use omics_coordinate::Contig;
+    /// use omics_coordinate::region::Region;
+    ///
+    /// let lengths = HashMap::from([(Contig::new_unchecked("chr1"), 10_000_u64)]);
+    ///
+    /// let region = "chr1".parse::<Region>()?;
+    /// assert_eq!(region.resolve(&lengths)?.to_string(), "chr1:+:1-10000");
+    ///
+    /// let region = "chr1:500".parse::<Region>()?;
+    /// assert_eq!(region.resolve(&lengths)?.to_string(), "chr1:+:500-10000");
+    ///
+    /// let region = "chr1:-500".parse::<Region>()?;
+    /// assert_eq!(region.resolve(&lengths)?.to_string(), "chr1:+:1-500");
+    ///
+    /// let region = "chr1:500-10,001".parse::<Region>()?;
+    /// assert!(region.resolve(&lengths).is_err());
+    ///
+    /// let lengths = HashMap::from([(Contig::new_unchecked("chr1"), 5_000_000_000_u64)]);
+    ///
+    /// let region = "chr1".parse::<Region<u64>>()?;
+    /// assert_eq!(region.resolve(&lengths)?.to_string(), "chr1:+:1-5000000000");
+    ///
+    /// let region = "chr1".parse::<Region>()?;
+    /// assert!(region.resolve(&lengths).is_err());
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn resolve(&self, lengths: &impl LengthSource) -> Result<Interval<Base<W>>> {
+        let length = lengths
+            .contig_length(self.contig.as_str())
+            .ok_or_else(|| Error::UnknownContig(self.contig.clone()))?;
+
+        let start = self.start.unwrap_or(W::ONE);
+        let end = match self.end {
+            Some(end) => end,
+            None => W::from_u64(length).ok_or_else(|| Error::Width {
+                contig: self.contig.clone(),
+                length,
+                bits: W::BITS,
+            })?,
+        };
+
+        for position in [start, end] {
+            if position.to_u64() > length {
+                return Err(Error::OutOfBounds {
+                    contig: self.contig.clone(),
+                    position: position.to_u64(),
+                    length,
+                });
+            }
+        }
+
+        // SAFETY: `start` is either one or has been checked to be non-zero
+        // upon creation of the region. `end` is at least `start` because a
+        // missing end is the length of the contig (which was checked to be at
+        // least `start` above) and bounded regions are checked to not be
+        // negatively sized upon creation.
+        let start = Position::<Base<W>>::try_new(start).unwrap();
+        let end = Position::<Base<W>>::try_new(end).unwrap();
+
+        Ok(Interval::from_bounds(
+            self.contig.clone(),
+            Strand::Positive,
+            start,
+            end,
+        ))
+    }
+}
+
+/// Attempts to parse a region and resolve it into an interval in one step.
+///
+/// Unlike parsing a [`Region`] directly, this first checks whether the entire
+/// value is the name of a known contig, so contig names containing colons
+/// need not be wrapped in braces. The [`Width`] of the resulting interval is
+/// inferred from its use.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "std")] {
+/// use omics_coordinate::Assembly;
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::region;
+/// use omics_coordinate::system::Base;
+///
+/// let assembly = Assembly::read_fai("chr1\t2000\nHLA-A*01:01\t3503\n".as_bytes())?;
+///
+/// let interval: Interval<Base> = region::parse("chr1:1,000-2,000", &assembly)?;
+/// assert_eq!(interval.to_string(), "chr1:+:1000-2000");
+///
+/// let interval: Interval<Base<u64>> = region::parse("HLA-A*01:01", &assembly)?;
+/// assert_eq!(interval.to_string(), "HLA-A*01:01:+:1-3503");
+/// # }
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn parse<W: Width>(s: &str, lengths: &impl LengthSource) -> Result<Interval<Base<W>>> {
+    if lengths.contig_length(s).is_some() {
+        return Region::<W>::try_new(Contig::new_unchecked(s), None, None)?.resolve(lengths);
+    }
+
+    s.parse::<Region<W>>()?.resolve(lengths)
+}
+
+/// Parses a one-based position, ignoring any thousands separators.
+fn parse_position<W: Width>(value: &str) -> ParseResult<W> {
+    let digits = value
+        .chars()
+        .filter(|c| *c != THOUSANDS_SEPARATOR)
+        .collect::<String>();
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(ParseError::Position {
+            value: value.to_string(),
+        });
+    }
+
+    digits.parse::<W>().map_err(|_| ParseError::Position {
+        value: value.to_string(),
+    })
+}
+
+/// Returns whether a value looks like a range (rather than part of a contig
+/// name).
+fn is_range(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_digit() || c == THOUSANDS_SEPARATOR || c == RANGE_SEPARATOR)
+}
+
+/// Writes a position, optionally with thousands separators.
+fn write_position<W: Width>(
+    f: &mut core::fmt::Formatter<'_>,
+    position: W,
+    separated: bool,
+) -> core::fmt::Result {
+    if !separated {
+        return write!(f, "{position}");
+    }
+
+    let digits = position.to_string();
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            write!(f, "{THOUSANDS_SEPARATOR}")?;
+        }
+
+        write!(f, "{digit}")?;
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<W: Width> core::fmt::Display for Region<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.contig.contains(CONTIG_SEPARATOR) {
+            write!(f, "{{{}}}", self.contig)?;
+        } else {
+            write!(f, "{}", self.contig)?;
+        }
+
+        let separated = f.alternate();
+
+        match (self.start, self.end) {
+            (None, None) => {}
+            (start, end) => {
+                write!(f, "{CONTIG_SEPARATOR}")?;
+                write_position(f, start.unwrap_or(W::ONE), separated)?;
+                write!(f, "{RANGE_SEPARATOR}")?;
+
+                if let Some(end) = end {
+                    write_position(f, end, separated)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Width> core::str::FromStr for Region<W> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let format = || ParseError::Format {
+            value: s.to_string(),
+        };
+
+        let (name, range) = if let Some(rest) = s.strip_prefix('{') {
+            let (name, rest) = rest.split_once('}').ok_or_else(format)?;
+
+            match rest {
+                "" => (name, None),
+                rest => (
+                    name,
+                    Some(rest.strip_prefix(CONTIG_SEPARATOR).ok_or_else(format)?),
+                ),
+            }
+        } else {
+            match s.rsplit_once(CONTIG_SEPARATOR) {
+                Some((name, range)) if is_range(range) => (name, Some(range)),
+                _ => (s, None),
+            }
+        };
+
+        let contig = Contig::try_new(name).map_err(|_| format())?;
+
+        let (start, end) = match range {
+            None => (None, None),
+            Some(range) => match range.split_once(RANGE_SEPARATOR) {
+                Some(("", "")) => return Err(format()),
+                Some((start, end)) => (
+                    Some(start)
+                        .filter(|start| !start.is_empty())
+                        .map(parse_position)
+                        .transpose()?,
+                    Some(end)
+                        .filter(|end| !end.is_empty())
+                        .map(parse_position)
+                        .transpose()?,
+                ),
+                None => (Some(parse_position(range)?), None),
+            },
+        };
+
+        Self::try_new(contig, start, end)
+    }
+}
+
+impl<W: Width> From<&Interval<Base<W>>> for Region<W> {
+    fn from(interval: &Interval<Base<W>>) -> Self {
+        Self {
+            contig: interval.contig().clone(),
+            start: Some(interval.lower().get()),
+            end: Some(interval.upper().get()),
+        }
+    }
+}
+
+impl<W: Width> From<&Coordinate<Base<W>>> for Region<W> {
+    fn from(coordinate: &Coordinate<Base<W>>) -> Self {
+        Self {
+            contig: coordinate.contig().clone(),
+            start: Some(coordinate.position().get()),
+            end: Some(coordinate.position().get()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeMap;
+    use alloc::format;
+
+    use super::*;
+
+    fn lengths() -> BTreeMap<Contig, Number> {
+        BTreeMap::from([
+            (Contig::new_unchecked("chr1"), 248_956_422),
+            (Contig::new_unchecked("chrM"), 16_569),
+        ])
+    }
+
+    #[test]
+    fn parse_forms() {
+        let region = "chr1".parse::<Region>().unwrap();
+        assert_eq!((region.start(), region.end()), (None, None));
+
+        let region = "chr1:500".parse::<Region>().unwrap();
+        assert_eq!((region.start(), region.end()), (Some(500), None));
+
+        let region = "chr1:500-".parse::<Region>().unwrap();
+        assert_eq!((region.start(), region.end()), (Some(500), None));
+
+        let region = "chr1:-500".parse::<Region>().unwrap();
+        assert_eq!((region.start(), region.end()), (None, Some(500)));
+
+        let region = "chr1:1,000,000-2,000,000".parse::<Region>().unwrap();
+        assert_eq!(
+            (region.start(), region.end()),
+            (Some(1_000_000), Some(2_000_000))
+        );
+
+        let region = "{HLA-A*01:01}:10-20".parse::<Region>().unwrap();
+        assert_eq!(region.contig().as_str(), "HLA-A*01:01");
+        assert_eq!((region.start(), region.end()), (Some(10), Some(20)));
+
+        let region = "{HLA-A*01:01}".parse::<Region>().unwrap();
+        assert_eq!(region.contig().as_str(), "HLA-A*01:01");
+
+        // A trailing suffix that is not a range is part of the contig name.
+        let region = "chrUn:KI270302v1".parse::<Region>().unwrap();
+        assert_eq!(region.contig().as_str(), "chrUn:KI270302v1");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("".parse::<Region>().unwrap_err(), ParseError::Empty);
+
+        let err = "chr1:-".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid region format: chr1:-");
+
+        let err = "chr1:1-2-3".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid position `2-3`");
+
+        let err = "chr1:0-10".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid position `0`");
+
+        let err = "chr1:,-10".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid position `,`");
+
+        let err = "chr1:20-10".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "start `20` is greater than end `10`");
+
+        let err = "{chr1:10".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid region format: {chr1:10");
+
+        let err = "{chr1}10".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid region format: {chr1}10");
+    }
+
+    #[test]
+    fn resolve() {
+        let lengths = lengths();
+
+        let interval = "chrM".parse::<Region>().unwrap().resolve(&lengths).unwrap();
+        assert_eq!(interval.to_string(), "chrM:+:1-16569");
+        assert_eq!(interval.count_entities(), 16_569);
+
+        let interval = "chrM:16,569"
+            .parse::<Region>()
+            .unwrap()
+            .resolve(&lengths)
+            .unwrap();
+        assert_eq!(interval.to_string(), "chrM:+:16569-16569");
+
+        let err = "chr2:1-10"
+            .parse::<Region>()
+            .unwrap()
+            .resolve(&lengths)
+            .unwrap_err();
+        assert_eq!(err.to_string(), "unknown contig: `chr2`");
+
+        let err = "chrM:16,570"
+            .parse::<Region>()
+            .unwrap()
+            .resolve(&lengths)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "position `16570` is out of bounds for contig `chrM` of length `16569`"
+        );
+    }
+
+    #[test]
+    fn format_round_trips() {
+        for value in ["chr1", "chr1:500-", "chr1:1-500", "{a:b}:1-2", "{a:b}"] {
+            let region = value.parse::<Region>().unwrap();
+            assert_eq!(region.to_string(), value);
+            assert_eq!(region.to_string().parse::<Region>().unwrap(), region);
+        }
+
+        let interval = "chr1:-:2000000-1000".parse::<Interval<Base>>().unwrap();
+        let region = Region::from(&interval);
+        assert_eq!(format!("{region:#}"), "chr1:1,000-2,000,000");
+        assert_eq!(format!("{region}"), "chr1:1000-2000000");
+
+        let coordinate = "chr1:+:999".parse::<Coordinate<Base>>().unwrap();
+        assert_eq!(format!("{:#}", Region::from(&coordinate)), "chr1:999-999");
+    }
+
+    #[test]
+    fn wide_regions() {
+        let lengths = BTreeMap::from([(Contig::new_unchecked("chr4A"), 5_000_000_000_u64)]);
+
+        let region = "chr4A:4,500,000,001-".parse::<Region<u64>>().unwrap();
+        let interval = region.resolve(&lengths).unwrap();
+        assert_eq!(interval.to_string(), "chr4A:+:4500000001-5000000000");
+        assert_eq!(interval.count_entities(), 500_000_000);
+        assert_eq!(
+            Region::from(&interval),
+            Region::try_new(
+                Contig::new_unchecked("chr4A"),
+                Some(4_500_000_001),
+                Some(5_000_000_000)
+            )
+            .unwrap()
+        );
+
+        let interval: Interval<Base<u64>> = parse("chr4A", &lengths).unwrap();
+        assert_eq!(interval.to_string(), "chr4A:+:1-5000000000");
+
+        // A whole-contig region of the default width is an error rather than
+        // being truncated at the largest representable position.
+        let err = parse::<Number>("chr4A", &lengths).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "length `5000000000` of contig `chr4A` cannot be represented as a 32-bit position"
+        );
+
+        let err = "chr4A:4,500,000,001".parse::<Region>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid position `4,500,000,001`");
+    }
+}
@@ -0,0 +1,845 @@
+//! Sets of intervals.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::max;
+use core::cmp::min;
+use core::marker::PhantomData;
+
+use thiserror::Error;
+
+use crate::Assembly;
+use crate::Contig;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::assembly::LengthSource;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to an interval set.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An unknown contig error.
+    ///
+    /// This error occurs when the length of a contig is needed but the contig
+    /// is not present within the length source.
+    #[error("unknown contig: `{0}`")]
+    UnknownContig(Contig),
+
+    /// An out of bounds error.
+    ///
+    /// This error occurs when an interval extends beyond the end of its
+    /// contig.
+    #[error("position `{position}` is out of bounds for contig `{contig}` of length `{length}`")]
+    OutOfBounds {
+        /// The contig.
+        contig: Contig,
+
+        /// The position.
+        position: u64,
+
+        /// The length of the contig.
+        length: u64,
+    },
+
+    /// A width error.
+    ///
+    /// This error occurs when the length of a contig cannot be represented
+    /// within the [`Width`] of the positions in the set.
+    #[error(
+        "length `{length}` of contig `{contig}` cannot be represented as a {bits}-bit position"
+    )]
+    Width {
+        /// The contig.
+        contig: Contig,
+
+        /// The length of the contig.
+        length: u64,
+
+        /// The number of bits in the position representation.
+        bits: u32,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Ranges
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A half-open range of entities in interbase positions (see
+/// [`entity_bounds()`](interval::r#trait::Interval::entity_bounds)).
+type Range<W> = (W, W);
+
+/// Converts the length of a contig to a position of a particular [`Width`].
+fn width<W: Width>(contig: &Contig, length: u64) -> Result<W> {
+    W::from_u64(length).ok_or_else(|| Error::Width {
+        contig: contig.clone(),
+        length,
+        bits: W::BITS,
+    })
+}
+
+/// Sorts ranges and coalesces those that overlap or are directly adjacent.
+///
+/// Empty ranges are removed.
+fn normalize<W: Width>(mut ranges: Vec<Range<W>>) -> Vec<Range<W>> {
+    ranges.retain(|(start, end)| start < end);
+    ranges.sort_unstable();
+
+    let mut result: Vec<Range<W>> = Vec::with_capacity(ranges.len());
+
+    for (start, end) in ranges {
+        match result.last_mut() {
+            Some(last) if start <= last.1 => last.1 = max(last.1, end),
+            _ => result.push((start, end)),
+        }
+    }
+
+    result
+}
+
+/// Merges two normalized lists of ranges into their normalized union.
+fn union<W: Width>(a: &[Range<W>], b: &[Range<W>]) -> Vec<Range<W>> {
+    let mut result: Vec<Range<W>> = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() || j < b.len() {
+        let next = if j >= b.len() || (i < a.len() && a[i] <= b[j]) {
+            i += 1;
+            a[i - 1]
+        } else {
+            j += 1;
+            b[j - 1]
+        };
+
+        match result.last_mut() {
+            Some(last) if next.0 <= last.1 => last.1 = max(last.1, next.1),
+            _ => result.push(next),
+        }
+    }
+
+    result
+}
+
+/// Computes the intersection of two normalized lists of ranges.
+fn intersection<W: Width>(a: &[Range<W>], b: &[Range<W>]) -> Vec<Range<W>> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        let start = max(a[i].0, b[j].0);
+        let end = min(a[i].1, b[j].1);
+
+        if start < end {
+            result.push((start, end));
+        }
+
+        if a[i].1 <= b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    result
+}
+
+/// Computes the ranges in `a` that are not in `b` for two normalized lists of
+/// ranges.
+fn difference<W: Width>(a: &[Range<W>], b: &[Range<W>]) -> Vec<Range<W>> {
+    let mut result = Vec::new();
+    let mut j = 0;
+
+    for &(mut start, end) in a {
+        // Skip the ranges of `b` that end before this range starts.
+        while j < b.len() && b[j].1 <= start {
+            j += 1;
+        }
+
+        let mut k = j;
+
+        while k < b.len() && b[k].0 < end {
+            if b[k].0 > start {
+                result.push((start, b[k].0));
+            }
+
+            start = max(start, b[k].1);
+            k += 1;
+        }
+
+        if start < end {
+            result.push((start, end));
+        }
+    }
+
+    result
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Interval sets
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A set of intervals.
+///
+/// An interval set stores the entities covered by a collection of intervals in
+/// a normalized form: for each contig and strand, intervals are kept sorted
+/// and any intervals that overlap or are directly adjacent (book-ended) are
+/// coalesced. As such, an interval set behaves as a set of entities and
+/// supports the usual set operations ([`union()`](Self::union),
+/// [`intersection()`](Self::intersection),
+/// [`difference()`](Self::difference), and
+/// [`complement()`](Self::complement)).
+///
+/// Intervals on different contigs or strands never interact. Zero-sized
+/// interbase intervals contain no entities and are therefore ignored.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::IntervalSet;
+/// use omics_coordinate::system::Interbase;
+///
+/// let set = ["seq0:+:0-10", "seq0:+:5-20", "seq0:+:20-30", "seq0:+:40-50"]
+///     .into_iter()
+///     .map(|value| value.parse::<Interval<Interbase>>())
+///     .collect::<Result<IntervalSet<Interbase>, _>>()?;
+///
+/// let intervals = set.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+/// assert_eq!(intervals, vec!["seq0:+:0-30", "seq0:+:40-50"]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IntervalSet<S: System> {
+    /// The normalized ranges for each contig and strand.
+    partitions: BTreeMap<(Contig, Strand), Vec<Range<S::Number>>>,
+
+    /// The coordinate system.
+    system: PhantomData<S>,
+}
+
+impl<S: System> Default for IntervalSet<S> {
+    fn default() -> Self {
+        Self {
+            partitions: Default::default(),
+            system: PhantomData,
+        }
+    }
+}
+
+impl<S: System> IntervalSet<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a new, empty interval set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an interval set covering every contig of an assembly in its
+    /// entirety on the provided strand.
+    ///
+    /// This is useful as the universe for set operations that should include
+    /// contigs upon which no intervals lie. If the length of any contig cannot
+    /// be represented within the [`Width`] of the coordinate system, an
+    /// [`Error::Width`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalSet;
+    /// use omics_coordinate::Strand;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let assembly = Assembly::read_fai("seq0\t100\nseq1\t50\n".as_bytes())?;
+    /// let genome = IntervalSet::<Base>::whole_contigs(&assembly, Strand::Unstranded)?;
+    ///
+    /// let intervals = genome.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec!["seq0:.:1-100", "seq1:.:1-50"]);
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn whole_contigs(assembly: &Assembly, strand: Strand) -> Result<Self> {
+        let partitions = assembly
+            .iter()
+            .filter(|entry| entry.length() > 0)
+            .map(|entry| {
+                let length = width::<S::Number>(entry.contig(), entry.length())?;
+                Ok((
+                    (entry.contig().clone(), strand),
+                    vec![(S::Number::ZERO, length)],
+                ))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            partitions,
+            system: PhantomData,
+        })
+    }
+
+    /// Inserts an interval into the set, coalescing it with any intervals it
+    /// overlaps or is directly adjacent to.
+    pub fn insert(&mut self, interval: Interval<S>) {
+        let (start, end) =
+            <Interval<S> as interval::r#trait::Interval<S>>::entity_bounds(&interval);
+
+        if start >= end {
+            return;
+        }
+
+        let ranges = self
+            .partitions
+            .entry((interval.contig().clone(), interval.strand()))
+            .or_default();
+
+        // NOTE: these are the first range that ends at or after the start of
+        // the new range and the first range that starts strictly after the end
+        // of the new range. Everything in between touches the new range.
+        let first = ranges.partition_point(|range| range.1 < start);
+        let last = ranges.partition_point(|range| range.0 <= end);
+
+        let merged = ranges[first..last].iter().fold((start, end), |acc, range| {
+            (min(acc.0, range.0), max(acc.1, range.1))
+        });
+
+        ranges.splice(first..last, [merged]);
+    }
+
+    /// Gets the number of (normalized) intervals in the set.
+    pub fn len(&self) -> usize {
+        self.partitions.values().map(Vec::len).sum()
+    }
+
+    /// Returns whether or not the set contains any intervals.
+    pub fn is_empty(&self) -> bool {
+        self.partitions.is_empty()
+    }
+
+    /// Gets the total number of entities covered by the set.
+    ///
+    /// Because a set may span many contigs and strands, the total is returned
+    /// as a [`u64`].
+    pub fn count_entities(&self) -> u64 {
+        self.partitions
+            .values()
+            .flatten()
+            .map(|(start, end)| (*end - *start).to_u64())
+            .sum()
+    }
+
+    /// Gets an iterator over the (normalized) intervals in the set.
+    ///
+    /// Intervals are ordered by contig, then by strand, and then by position.
+    pub fn iter(&self) -> impl Iterator<Item = Interval<S>> + '_ {
+        self.partitions
+            .iter()
+            .flat_map(|((contig, strand), ranges)| {
+                ranges.iter().map(|(start, end)| {
+                    // SAFETY: every range within the set contains at least one
+                    // entity, so this will always unwrap.
+                    <Interval<S> as interval::r#trait::Interval<S>>::from_entity_bounds(
+                        contig.clone(),
+                        *strand,
+                        *start,
+                        *end,
+                    )
+                    .unwrap()
+                })
+            })
+    }
+
+    /// Combines the ranges of two sets partition by partition.
+    fn combine(
+        &self,
+        other: &Self,
+        include_self_only: bool,
+        include_other_only: bool,
+        f: impl Fn(&[Range<S::Number>], &[Range<S::Number>]) -> Vec<Range<S::Number>>,
+    ) -> Self {
+        let mut partitions = BTreeMap::new();
+
+        for (key, ranges) in &self.partitions {
+            let result = match other.partitions.get(key) {
+                Some(others) => f(ranges, others),
+                None if include_self_only => ranges.clone(),
+                None => continue,
+            };
+
+            if !result.is_empty() {
+                partitions.insert(key.clone(), result);
+            }
+        }
+
+        if include_other_only {
+            for (key, ranges) in &other.partitions {
+                if !self.partitions.contains_key(key) {
+                    partitions.insert(key.clone(), ranges.clone());
+                }
+            }
+        }
+
+        Self {
+            partitions,
+            system: PhantomData,
+        }
+    }
+
+    /// Gets the union of this set and another set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalSet;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let a = IntervalSet::from_iter(["seq0:+:1-10".parse::<Interval<Base>>()?]);
+    /// let b = IntervalSet::from_iter(["seq0:+:11-20".parse::<Interval<Base>>()?]);
+    ///
+    /// // Book-ended in-base intervals are coalesced.
+    /// let intervals = a
+    ///     .union(&b)
+    ///     .iter()
+    ///     .map(|i| i.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec!["seq0:+:1-20"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, true, true, union)
+    }
+
+    /// Gets the intersection of this set and another set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalSet;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let a = ["seq0:+:0-10", "seq0:+:20-30"]
+    ///     .into_iter()
+    ///     .map(|value| value.parse::<Interval<Interbase>>())
+    ///     .collect::<Result<IntervalSet<Interbase>, _>>()?;
+    /// let b = IntervalSet::from_iter(["seq0:+:5-25".parse::<Interval<Interbase>>()?]);
+    ///
+    /// let intervals = a
+    ///     .intersection(&b)
+    ///     .iter()
+    ///     .map(|i| i.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec!["seq0:+:5-10", "seq0:+:20-25"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, false, false, intersection)
+    }
+
+    /// Gets the entities in this set that are not in another set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalSet;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let a = IntervalSet::from_iter(["seq0:-:100-0".parse::<Interval<Interbase>>()?]);
+    /// let mask = ["seq0:-:60-40", "seq0:-:10-0"]
+    ///     .into_iter()
+    ///     .map(|value| value.parse::<Interval<Interbase>>())
+    ///     .collect::<Result<IntervalSet<Interbase>, _>>()?;
+    ///
+    /// let intervals = a
+    ///     .difference(&mask)
+    ///     .iter()
+    ///     .map(|i| i.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec!["seq0:-:40-10", "seq0:-:100-60"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, true, false, difference)
+    }
+
+    /// Gets the complement of this set with respect to the bounds of each
+    /// contig.
+    ///
+    /// The complement is computed for each contig and strand present in the
+    /// set. To also include contigs upon which no intervals lie, compute the
+    /// [`difference()`](Self::difference) from
+    /// [`whole_contigs()`](Self::whole_contigs) instead.
+    ///
+    /// If a contig is not present within the length source, an interval
+    /// extends beyond the end of its contig, or the length of a contig cannot
+    /// be represented within the [`Width`] of the coordinate system, an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::IntervalSet;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let assembly = Assembly::read_fai("seq0\t100\n".as_bytes())?;
+    /// let set = ["seq0:+:1-10", "seq0:+:50-60"]
+    ///     .into_iter()
+    ///     .map(|value| value.parse::<Interval<Base>>())
+    ///     .collect::<Result<IntervalSet<Base>, _>>()?;
+    ///
+    /// let intervals = set
+    ///     .complement(&assembly)?
+    ///     .iter()
+    ///     .map(|i| i.to_string())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(intervals, vec!["seq0:+:11-49", "seq0:+:61-100"]);
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn complement(&self, lengths: &impl LengthSource) -> Result<Self> {
+        let mut partitions = BTreeMap::new();
+
+        for ((contig, strand), ranges) in &self.partitions {
+            let length = lengths
+                .contig_length(contig.as_str())
+                .ok_or_else(|| Error::UnknownContig(contig.clone()))?;
+
+            // SAFETY: every partition within the set is non-empty.
+            let (_, end) = *ranges.last().unwrap();
+
+            if end.to_u64() > length {
+                return Err(Error::OutOfBounds {
+                    contig: contig.clone(),
+                    position: end.to_u64(),
+                    length,
+                });
+            }
+
+            let length = width::<S::Number>(contig, length)?;
+            let result = difference(&[(S::Number::ZERO, length)], ranges);
+
+            if !result.is_empty() {
+                partitions.insert((contig.clone(), *strand), result);
+            }
+        }
+
+        Ok(Self {
+            partitions,
+            system: PhantomData,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl<S: System> FromIterator<Interval<S>> for IntervalSet<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn from_iter<T: IntoIterator<Item = Interval<S>>>(iter: T) -> Self {
+        let mut groups = BTreeMap::<(Contig, Strand), Vec<Range<S::Number>>>::new();
+
+        for interval in iter {
+            let bounds = <Interval<S> as interval::r#trait::Interval<S>>::entity_bounds(&interval);
+            groups
+                .entry((interval.contig().clone(), interval.strand()))
+                .or_default()
+                .push(bounds);
+        }
+
+        let partitions = groups
+            .into_iter()
+            .map(|(key, ranges)| (key, normalize(ranges)))
+            .filter(|(_, ranges)| !ranges.is_empty())
+            .collect();
+
+        Self {
+            partitions,
+            system: PhantomData,
+        }
+    }
+}
+
+impl<S: System> Extend<Interval<S>> for IntervalSet<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    fn extend<T: IntoIterator<Item = Interval<S>>>(&mut self, iter: T) {
+        let other = Self::from_iter(iter);
+        *self = self.union(&other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::assembly::Entry;
+    use crate::position::Number;
+    use crate::system::Base;
+    use crate::system::Interbase;
+    use crate::testing::Lcg;
+
+    fn build<S: System>(values: &[&str]) -> IntervalSet<S>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        values
+            .iter()
+            .map(|value| value.parse::<Interval<S>>().unwrap())
+            .collect()
+    }
+
+    fn strings<S: System>(set: &IntervalSet<S>) -> Vec<String>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        set.iter().map(|interval| interval.to_string()).collect()
+    }
+
+    #[test]
+    fn normalization() {
+        let set = build::<Interbase>(&[
+            "seq1:+:0-5",
+            "seq0:+:10-20",
+            "seq0:+:0-10",
+            "seq0:-:15-5",
+            "seq0:+:25-25",
+            "seq0:+:30-40",
+            "seq0:+:35-36",
+        ]);
+
+        assert_eq!(
+            strings(&set),
+            vec!["seq0:+:0-20", "seq0:+:30-40", "seq0:-:15-5", "seq1:+:0-5"]
+        );
+        assert_eq!(set.len(), 4);
+        assert_eq!(set.count_entities(), 45);
+
+        let set = build::<Base>(&["seq0:+:1-5", "seq0:+:7-10"]);
+        assert_eq!(strings(&set), vec!["seq0:+:1-5", "seq0:+:7-10"]);
+
+        let set = build::<Base>(&["seq0:+:1-5", "seq0:+:6-10"]);
+        assert_eq!(strings(&set), vec!["seq0:+:1-10"]);
+
+        let set = build::<Interbase>(&["seq0:+:5-5"]);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn insert_matches_from_iter() {
+        let values = [
+            "seq0:+:50-60",
+            "seq0:+:0-10",
+            "seq0:+:20-30",
+            "seq0:+:10-20",
+            "seq0:+:55-70",
+            "seq0:+:80-90",
+            "seq0:+:5-85",
+            "seq0:+:100-100",
+        ];
+
+        let mut inserted = IntervalSet::<Interbase>::new();
+        for (i, value) in values.iter().enumerate() {
+            inserted.insert(value.parse().unwrap());
+            assert_eq!(inserted, build::<Interbase>(&values[..=i]));
+        }
+
+        assert_eq!(strings(&inserted), vec!["seq0:+:0-90"]);
+    }
+
+    #[test]
+    fn operations() {
+        let a = build::<Base>(&["seq0:+:1-100", "seq0:+:201-300", "seq1:+:1-10"]);
+        let b = build::<Base>(&["seq0:+:51-250", "seq0:-:1-1", "seq2:+:1-1"]);
+
+        assert_eq!(
+            strings(&a.union(&b)),
+            vec!["seq0:+:1-300", "seq0:-:1-1", "seq1:+:1-10", "seq2:+:1-1"]
+        );
+        assert_eq!(
+            strings(&a.intersection(&b)),
+            vec!["seq0:+:51-100", "seq0:+:201-250"]
+        );
+        assert_eq!(
+            strings(&a.difference(&b)),
+            vec!["seq0:+:1-50", "seq0:+:251-300", "seq1:+:1-10"]
+        );
+        assert_eq!(
+            strings(&b.difference(&a)),
+            vec!["seq0:+:101-200", "seq0:-:1-1", "seq2:+:1-1"]
+        );
+
+        let mut extended = a.clone();
+        extended.extend(b.iter());
+        assert_eq!(extended, a.union(&b));
+    }
+
+    #[test]
+    fn difference_with_many_holes() {
+        let a = build::<Interbase>(&["seq0:+:0-100", "seq0:+:150-160"]);
+        let b = build::<Interbase>(&[
+            "seq0:+:0-5",
+            "seq0:+:10-20",
+            "seq0:+:30-40",
+            "seq0:+:95-155",
+        ]);
+
+        assert_eq!(
+            strings(&a.difference(&b)),
+            vec![
+                "seq0:+:5-10",
+                "seq0:+:20-30",
+                "seq0:+:40-95",
+                "seq0:+:155-160"
+            ]
+        );
+    }
+
+    #[test]
+    fn complement() {
+        let lengths = BTreeMap::from([(Contig::new_unchecked("seq0"), 100_u64)]);
+
+        let set = build::<Interbase>(&["seq0:+:0-10", "seq0:+:90-100"]);
+        assert_eq!(
+            strings(&set.complement(&lengths).unwrap()),
+            vec!["seq0:+:10-90"]
+        );
+
+        let set = build::<Interbase>(&["seq0:+:0-100"]);
+        assert!(set.complement(&lengths).unwrap().is_empty());
+
+        let set = build::<Interbase>(&["seq0:+:0-101"]);
+        assert_eq!(
+            set.complement(&lengths).unwrap_err(),
+            Error::OutOfBounds {
+                contig: Contig::new_unchecked("seq0"),
+                position: 101,
+                length: 100
+            }
+        );
+
+        let set = build::<Interbase>(&["seq1:+:0-1"]);
+        assert_eq!(
+            set.complement(&lengths).unwrap_err(),
+            Error::UnknownContig(Contig::new_unchecked("seq1"))
+        );
+    }
+
+    #[test]
+    fn contigs_wider_than_positions() {
+        let assembly = Assembly::try_new([
+            Entry::new(Contig::new_unchecked("chr1"), 10),
+            Entry::new(Contig::new_unchecked("chr2"), 5_000_000_000),
+        ])
+        .unwrap();
+
+        let err = IntervalSet::<Base<u32>>::whole_contigs(&assembly, Strand::Positive).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "length `5000000000` of contig `chr2` cannot be represented as a 32-bit position"
+        );
+
+        let genome = IntervalSet::<Base<u64>>::whole_contigs(&assembly, Strand::Positive).unwrap();
+        assert_eq!(strings(&genome), vec!["chr1:+:1-10", "chr2:+:1-5000000000"]);
+
+        let set = build::<Interbase<u32>>(&["chr2:+:0-10"]);
+        assert_eq!(
+            set.complement(&assembly).unwrap_err(),
+            Error::Width {
+                contig: Contig::new_unchecked("chr2"),
+                length: 5_000_000_000,
+                bits: 32,
+            }
+        );
+
+        let set = build::<Interbase<u64>>(&["chr2:+:0-10"]);
+        assert_eq!(
+            strings(&set.complement(&assembly).unwrap()),
+            vec!["chr2:+:10-5000000000"]
+        );
+    }
+
+    #[test]
+    fn operations_match_brute_force() {
+        let mut lcg = Lcg::new(42);
+        let mut next = |bound: u64| lcg.below(bound);
+
+        for _ in 0..50 {
+            let mut random_set = || {
+                (0..20)
+                    .map(|_| {
+                        let start = next(200) as Number;
+                        let end = start + next(20) as Number;
+                        format!("seq0:+:{start}-{end}")
+                            .parse::<Interval<Interbase>>()
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let (a, b) = (random_set(), random_set());
+
+            let covered = |intervals: &[Interval<Interbase>]| {
+                let mut covered = [false; 256];
+                for interval in intervals {
+                    for i in interval.lower().get()..interval.upper().get() {
+                        covered[i as usize] = true;
+                    }
+                }
+                covered
+            };
+
+            let (ca, cb) = (covered(&a), covered(&b));
+            let (sa, sb) = (
+                IntervalSet::from_iter(a.clone()),
+                IntervalSet::from_iter(b.clone()),
+            );
+
+            let check = |set: IntervalSet<Interbase>, f: &dyn Fn(bool, bool) -> bool| {
+                let actual = covered(&set.iter().collect::<Vec<_>>());
+                for i in 0..256 {
+                    assert_eq!(actual[i], f(ca[i], cb[i]));
+                }
+            };
+
+            check(sa.union(&sb), &|a, b| a || b);
+            check(sa.intersection(&sb), &|a, b| a && b);
+            check(sa.difference(&sb), &|a, b| a && !b);
+        }
+    }
+}
@@ -1,5 +1,8 @@
 //! Strands of a molecule.
 
+use alloc::string::String;
+use alloc::string::ToString;
+
 use thiserror::Error;
 
 ///////////////////////////////////////////////////////////////////////////////////////
@@ -32,8 +35,8 @@ pub enum ParseError {
     },
 }
 
-/// A [`Result`](std::result::Result) with an [`ParseError`].
-pub type ParseResult<T> = std::result::Result<T, ParseError>;
+/// A [`Result`](core::result::Result) with an [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
 
 /// A strand-related error.
 #[derive(Error, Debug, PartialEq, Eq)]
@@ -43,8 +46,8 @@ pub enum Error {
     Parse(#[from] ParseError),
 }
 
-/// A [`Result`](std::result::Result) with an [`Error`](enum@Error).
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
 
 ////////////////////////////////////////////////////////////////////////////////////////
 // Strand
@@ -54,6 +57,10 @@ pub type Result<T> = std::result::Result<T, Error>;
 ///
 /// For a more in-depth discussion on this, please see [this section of the
 /// docs](crate#strand).
+///
+/// This enum is deliberately not `#[non_exhaustive]`: the three variants
+/// cover every strand notation (`+`, `-`, and `.`), so downstream code may
+/// match on it exhaustively.
 #[repr(u8)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Strand {
@@ -66,11 +73,21 @@ pub enum Strand {
     ///
     /// This is also known as the _antisense_ strand.
     Negative,
+
+    /// No strand (`.`).
+    ///
+    /// This denotes that a location is either unstranded or that its strand is
+    /// unknown. Unstranded locations are oriented like locations on the
+    /// positive strand (i.e., they move forward towards higher positions).
+    Unstranded,
 }
 
 impl Strand {
     /// Complements a strand.
     ///
+    /// An unstranded location has no opposite strand, so complementing
+    /// [`Strand::Unstranded`] returns [`Strand::Unstranded`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -78,22 +95,41 @@ impl Strand {
     ///
     /// assert_eq!(Strand::Positive.complement(), Strand::Negative);
     /// assert_eq!(Strand::Negative.complement(), Strand::Positive);
+    /// assert_eq!(Strand::Unstranded.complement(), Strand::Unstranded);
     /// ```
     pub fn complement(&self) -> Strand {
         match self {
             Strand::Positive => Strand::Negative,
             Strand::Negative => Strand::Positive,
+            Strand::Unstranded => Strand::Unstranded,
         }
     }
+
+    /// Returns whether or not the strand is known (i.e., is either
+    /// [`Strand::Positive`] or [`Strand::Negative`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Strand;
+    ///
+    /// assert!(Strand::Positive.is_stranded());
+    /// assert!(Strand::Negative.is_stranded());
+    /// assert!(!Strand::Unstranded.is_stranded());
+    /// ```
+    pub fn is_stranded(&self) -> bool {
+        !matches!(self, Strand::Unstranded)
+    }
 }
 
-impl std::str::FromStr for Strand {
+impl core::str::FromStr for Strand {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
         match s {
             "+" => Ok(Strand::Positive),
             "-" => Ok(Strand::Negative),
+            "." => Ok(Strand::Unstranded),
             _ => Err(Error::Parse(ParseError::Invalid {
                 value: s.to_string(),
             })),
@@ -108,20 +144,42 @@ impl std::str::FromStr for Strand {
 impl TryFrom<&str> for Strand {
     type Error = Error;
 
-    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+    fn try_from(value: &str) -> core::result::Result<Self, Self::Error> {
         value.parse()
     }
 }
 
-impl std::fmt::Display for Strand {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Strand {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Strand::Positive => write!(f, "+"),
             Strand::Negative => write!(f, "-"),
+            Strand::Unstranded => write!(f, "."),
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Strand {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Strand {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> core::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,6 +192,9 @@ mod tests {
         let s = "-".parse::<Strand>().unwrap();
         assert_eq!(s, Strand::Negative);
 
+        let s = ".".parse::<Strand>().unwrap();
+        assert_eq!(s, Strand::Unstranded);
+
         let err = "a".parse::<Strand>().unwrap_err();
         assert_eq!(err.to_string(), "parse error: invalid strand: a");
     }
@@ -142,11 +203,25 @@ mod tests {
     fn serialize() {
         assert_eq!(Strand::Positive.to_string(), "+");
         assert_eq!(Strand::Negative.to_string(), "-");
+        assert_eq!(Strand::Unstranded.to_string(), ".");
     }
 
     #[test]
     fn complement() {
         assert_eq!(Strand::Positive.complement(), Strand::Negative);
         assert_eq!(Strand::Negative.complement(), Strand::Positive);
+        assert_eq!(Strand::Unstranded.complement(), Strand::Unstranded);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        for strand in [Strand::Positive, Strand::Negative, Strand::Unstranded] {
+            let json = serde_json::to_string(&strand).unwrap();
+            assert_eq!(json, format!(r#""{strand}""#));
+            assert_eq!(serde_json::from_str::<Strand>(&json).unwrap(), strand);
+        }
+
+        assert!(serde_json::from_str::<Strand>(r#""*""#).is_err());
     }
 }
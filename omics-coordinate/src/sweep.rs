@@ -0,0 +1,1145 @@
+//! Sweep-line algorithms over sorted streams of intervals.
+//!
+//! When two inputs are already sorted, overlaps and nearest neighbors can be
+//! found in a single, simultaneous pass over both inputs without building an
+//! [`IntervalIndex`](crate::IntervalIndex). Only the features that might
+//! still be relevant to the current query are held in memory, so the memory
+//! used depends on the local density of the features rather than on the size
+//! of the inputs.
+//!
+//! Both inputs must be sorted by contig and then by the numerically lower
+//! position of each interval (i.e., the start position on the positive strand
+//! and the end position on the negative strand). Intervals on different
+//! strands of the same contig may be interleaved. By default, contigs are
+//! expected in lexicographical order (as produced by `sort -k1,1 -k2,2n`), but
+//! the canonical order of an [`Assembly`] may be used instead (see
+//! [`Sweep::with_assembly()`]). Contigs present in one input but absent from
+//! the other are allowed.
+//!
+//! Sorting is verified as the inputs are consumed. As soon as an interval is
+//! found out of order, an [`Error::Unsorted`] is yielded and the sweep ends.
+//! Results yielded before the error may be incomplete.
+
+use alloc::collections::BTreeMap;
+use alloc::collections::VecDeque;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+use core::iter::Peekable;
+
+use thiserror::Error;
+
+use crate::Assembly;
+use crate::Contig;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// One of the two inputs of a sweep.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Input {
+    /// The query intervals.
+    Queries,
+
+    /// The feature intervals.
+    Features,
+}
+
+impl core::fmt::Display for Input {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Input::Queries => write!(f, "query"),
+            Input::Features => write!(f, "feature"),
+        }
+    }
+}
+
+/// An error related to a sweep.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An unsorted input error.
+    ///
+    /// This error occurs when an input is not sorted by contig and then by
+    /// the numerically lower position of each interval.
+    #[error(
+        "unsorted {input} input: an interval starting at `{contig}:{position}` follows an \
+         interval starting at `{previous_contig}:{previous_position}`"
+    )]
+    Unsorted {
+        /// The unsorted input.
+        input: Input,
+
+        /// The contig of the previous interval.
+        previous_contig: Contig,
+
+        /// The numerically lower position of the previous interval.
+        previous_position: u64,
+
+        /// The contig of the current interval.
+        contig: Contig,
+
+        /// The numerically lower position of the current interval.
+        position: u64,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+/// A query and a feature that overlap, each with its payload.
+pub type Pair<S, A, B> = ((Interval<S>, A), (Interval<S>, B));
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Sweeps
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A description of the sort order shared by the inputs of a sweep.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::sweep::Sweep;
+/// use omics_coordinate::system::Interbase;
+///
+/// let parse = |values: &[(&str, usize)]| {
+///     values
+///         .iter()
+///         .map(|(interval, value)| Ok((interval.parse::<Interval<Interbase>>()?, *value)))
+///         .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()
+/// };
+///
+/// let queries = parse(&[("chr1:+:0-10", 0), ("chr1:+:20-30", 1), ("chr2:+:5-10", 2)])?;
+/// let features = parse(&[("chr1:-:5-0", 1), ("chr1:+:5-25", 0), ("chr2:+:0-6", 2)])?;
+///
+/// let pairs = Sweep::new()
+///     .overlapping(queries, features)
+///     .map(|pair| pair.map(|((_, query), (_, feature))| (query, feature)))
+///     .collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(pairs, vec![(0, 0), (1, 0), (2, 2)]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Sweep<'a> {
+    /// The assembly defining the order of contigs (if not lexicographical).
+    assembly: Option<&'a Assembly>,
+}
+
+impl<'a> Sweep<'a> {
+    /// Creates a new sweep over inputs whose contigs are sorted
+    /// lexicographically.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes `self` and returns a sweep over inputs whose contigs are
+    /// sorted in the canonical order of an assembly.
+    ///
+    /// Contigs that are not present within the assembly are expected after
+    /// all known contigs (see [`Assembly::compare_contigs()`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::sweep::Sweep;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let assembly = Assembly::read_fai("chr2\t100\nchr10\t100\n".as_bytes())?;
+    /// let intervals = ["chr2:+:1-10", "chr10:+:1-10"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Base>>()?, ())))
+    ///     .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let sweep = Sweep::new().with_assembly(&assembly);
+    /// let pairs = sweep.overlapping(intervals.clone(), intervals.clone());
+    /// assert_eq!(pairs.collect::<Result<Vec<_>, _>>()?.len(), 2);
+    ///
+    /// // Lexicographically, `chr10` sorts before `chr2`.
+    /// let mut pairs = Sweep::new().overlapping(intervals.clone(), intervals);
+    /// assert!(pairs.any(|pair| pair.is_err()));
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    #[must_use = "this method returns a new sweep"]
+    pub fn with_assembly(mut self, assembly: &'a Assembly) -> Self {
+        self.assembly = Some(assembly);
+        self
+    }
+
+    /// Compares two contigs by their expected order within the inputs.
+    fn compare_contigs(&self, a: &Contig, b: &Contig) -> Ordering {
+        match self.assembly {
+            Some(assembly) => assembly.compare_contigs(a, b),
+            None => a.cmp(b),
+        }
+    }
+
+    /// Finds every pair of a query and a feature that overlap.
+    ///
+    /// Overlap is defined as in [`Interval::overlaps()`], so only intervals
+    /// on the same contig and strand are paired. Pairs are yielded in the
+    /// order of the queries and, for each query, in the order of the
+    /// features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::sweep::Sweep;
+    /// use omics_coordinate::system::Base;
+    ///
+    /// let queries = ["seq0:+:10-20", "seq0:-:40-31"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Base>>()?, interval)))
+    ///     .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    /// let features = [
+    ///     "seq0:+:1-10",
+    ///     "seq0:-:30-15",
+    ///     "seq0:+:15-50",
+    ///     "seq0:-:50-35",
+    /// ]
+    /// .into_iter()
+    /// .map(|interval| Ok((interval.parse::<Interval<Base>>()?, interval)))
+    /// .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let pairs = Sweep::new()
+    ///     .overlapping(queries, features)
+    ///     .map(|pair| pair.map(|((_, query), (_, feature))| (query, feature)))
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq!(
+    ///     pairs,
+    ///     vec![
+    ///         ("seq0:+:10-20", "seq0:+:1-10"),
+    ///         ("seq0:+:10-20", "seq0:+:15-50"),
+    ///         ("seq0:-:40-31", "seq0:-:50-35"),
+    ///     ]
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn overlapping<S, A, B, I, J>(
+        &self,
+        queries: I,
+        features: J,
+    ) -> Overlapping<'a, S, A, B, I::IntoIter, J::IntoIter>
+    where
+        S: System,
+        I: IntoIterator<Item = (Interval<S>, A)>,
+        J: IntoIterator<Item = (Interval<S>, B)>,
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        Overlapping {
+            queries: queries.into_iter(),
+            sweeper: Sweeper::new(*self, features.into_iter()),
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Finds the nearest upstream and downstream neighbors of every query.
+    ///
+    /// A neighbor is a feature on the same contig and strand as the query that
+    /// lies entirely upstream or downstream of it—features that overlap the
+    /// query are not neighbors. Upstream and downstream are relative to the
+    /// strand of the query, with unstranded queries treated as being on the
+    /// positive strand. The distance to each neighbor is measured using
+    /// [`Interval::distance()`], so directly adjacent features have a distance
+    /// of zero (`0`). When multiple features are tied for the nearest, the
+    /// first in sorted order is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::sweep::Sweep;
+    /// use omics_coordinate::system::Interbase;
+    ///
+    /// let queries = ["seq0:+:20-30", "seq0:-:30-20"]
+    ///     .into_iter()
+    ///     .map(|interval| Ok((interval.parse::<Interval<Interbase>>()?, ())))
+    ///     .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    /// let features = [
+    ///     ("seq0:+:0-5", "a"),
+    ///     ("seq0:+:10-15", "b"),
+    ///     ("seq0:-:18-12", "c"),
+    ///     ("seq0:+:25-40", "d"),
+    ///     ("seq0:+:30-35", "e"),
+    /// ]
+    /// .into_iter()
+    /// .map(|(interval, value)| Ok((interval.parse::<Interval<Interbase>>()?, value)))
+    /// .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    ///
+    /// let neighbors = Sweep::new()
+    ///     .closest(queries, features)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// let upstream = neighbors[0].upstream().unwrap();
+    /// assert_eq!((*upstream.value(), upstream.distance()), ("b", 5));
+    /// let downstream = neighbors[0].downstream().unwrap();
+    /// assert_eq!((*downstream.value(), downstream.distance()), ("e", 0));
+    ///
+    /// // On the negative strand, downstream is numerically lower.
+    /// assert!(neighbors[1].upstream().is_none());
+    /// let downstream = neighbors[1].downstream().unwrap();
+    /// assert_eq!((*downstream.value(), downstream.distance()), ("c", 2));
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn closest<S, A, B, I, J>(
+        &self,
+        queries: I,
+        features: J,
+    ) -> Closest<'a, S, A, B, I::IntoIter, J::IntoIter>
+    where
+        S: System,
+        I: IntoIterator<Item = (Interval<S>, A)>,
+        J: IntoIterator<Item = (Interval<S>, B)>,
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        Closest {
+            queries: queries.into_iter().peekable(),
+            sweeper: Sweeper::new(*self, features.into_iter()),
+            below: BTreeMap::new(),
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Sweepers
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A verifier of the sort order of an input.
+#[derive(Debug)]
+struct Order<S: System> {
+    /// The input being verified.
+    input: Input,
+
+    /// The contig and numerically lower position of the previous interval.
+    previous: Option<(Contig, S::Number)>,
+}
+
+impl<S: System> Order<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a new verifier for an input.
+    fn new(input: Input) -> Self {
+        Self {
+            input,
+            previous: None,
+        }
+    }
+
+    /// Ensures that an interval does not sort before the previous interval.
+    fn check(&mut self, sweep: &Sweep<'_>, interval: &Interval<S>) -> Result<()> {
+        let position = interval.lower().get();
+
+        if let Some((contig, previous)) = &self.previous {
+            let unsorted = match sweep.compare_contigs(contig, interval.contig()) {
+                Ordering::Less => false,
+                Ordering::Equal => *previous > position,
+                Ordering::Greater => true,
+            };
+
+            if unsorted {
+                return Err(Error::Unsorted {
+                    input: self.input,
+                    previous_contig: contig.clone(),
+                    previous_position: previous.to_u64(),
+                    contig: interval.contig().clone(),
+                    position: position.to_u64(),
+                });
+            }
+        }
+
+        self.previous = Some((interval.contig().clone(), position));
+        Ok(())
+    }
+}
+
+/// The state shared by all sweeps: the sorted features that might still be
+/// relevant to the current query.
+#[derive(Debug)]
+struct Sweeper<'a, S: System, B, J: Iterator<Item = (Interval<S>, B)>> {
+    /// The sort order of the inputs.
+    sweep: Sweep<'a>,
+
+    /// The remaining features.
+    features: Peekable<J>,
+
+    /// The features that have been read but do not yet lie entirely below the
+    /// current query, in sorted order.
+    active: VecDeque<(Interval<S>, B)>,
+
+    /// The contig of the current query.
+    contig: Option<Contig>,
+
+    /// The verifier of the sort order of the queries.
+    query_order: Order<S>,
+
+    /// The verifier of the sort order of the features.
+    feature_order: Order<S>,
+}
+
+impl<'a, S: System, B, J: Iterator<Item = (Interval<S>, B)>> Sweeper<'a, S, B, J>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a new sweeper over sorted features.
+    fn new(sweep: Sweep<'a>, features: J) -> Self {
+        Self {
+            sweep,
+            features: features.peekable(),
+            active: VecDeque::new(),
+            contig: None,
+            query_order: Order::new(Input::Queries),
+            feature_order: Order::new(Input::Features),
+        }
+    }
+
+    /// Advances the sweep to the next query.
+    ///
+    /// Every feature that starts at or before the end of the query is read and
+    /// passed to `read`, and every feature that lies entirely below the start
+    /// of the query (and, as such, below every later query) is passed to
+    /// `retire`.
+    fn advance(
+        &mut self,
+        query: &Interval<S>,
+        mut read: impl FnMut(&(Interval<S>, B)),
+        mut retire: impl FnMut((Interval<S>, B)),
+    ) -> Result<()> {
+        self.query_order.check(&self.sweep, query)?;
+
+        if self.contig.as_ref() != Some(query.contig()) {
+            self.active.clear();
+            self.contig = Some(query.contig().clone());
+        }
+
+        let (start, end) = query.entity_range();
+        while let Some(feature) =
+            self.read(query.contig(), |feature| feature.entity_range().0 <= end)?
+        {
+            read(&feature);
+            self.active.push_back(feature);
+        }
+
+        for feature in core::mem::take(&mut self.active) {
+            match feature.0.entity_range().1 <= start {
+                true => retire(feature),
+                false => self.active.push_back(feature),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the next feature on a contig if it satisfies `predicate`.
+    ///
+    /// Features on contigs sorting before the contig are skipped. The feature
+    /// that was read (if any) is returned without being made active.
+    fn read(
+        &mut self,
+        contig: &Contig,
+        predicate: impl Fn(&Interval<S>) -> bool,
+    ) -> Result<Option<(Interval<S>, B)>> {
+        loop {
+            let Some((interval, _)) = self.features.peek() else {
+                return Ok(None);
+            };
+
+            let ordering = self.sweep.compare_contigs(interval.contig(), contig);
+
+            if ordering == Ordering::Greater
+                || (ordering == Ordering::Equal && !predicate(interval))
+            {
+                return Ok(None);
+            }
+
+            // SAFETY: the next feature was successfully peeked above.
+            let feature = self.features.next().unwrap();
+            self.feature_order.check(&self.sweep, &feature.0)?;
+
+            if ordering == Ordering::Equal {
+                return Ok(Some(feature));
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Overlaps
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the overlapping pairs of queries and features.
+///
+/// This is created by [`Sweep::overlapping()`].
+#[derive(Debug)]
+pub struct Overlapping<'a, S, A, B, I, J>
+where
+    S: System,
+    I: Iterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+{
+    /// The remaining queries.
+    queries: I,
+
+    /// The sweep over the features.
+    sweeper: Sweeper<'a, S, B, J>,
+
+    /// The pairs found for the current query that are yet to be yielded.
+    pending: VecDeque<Pair<S, A, B>>,
+
+    /// Whether or not the sweep has ended due to an error.
+    done: bool,
+}
+
+impl<S, A, B, I, J> Iterator for Overlapping<'_, S, A, B, I, J>
+where
+    S: System,
+    A: Clone,
+    B: Clone,
+    I: Iterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    type Item = Result<Pair<S, A, B>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pair) = self.pending.pop_front() {
+                return Some(Ok(pair));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let query = self.queries.next()?;
+
+            if let Err(err) = self.sweeper.advance(&query.0, |_| {}, drop) {
+                self.done = true;
+                return Some(Err(err));
+            }
+
+            for feature in &self.sweeper.active {
+                if feature.0.strand() == query.0.strand()
+                    && <Interval<S> as interval::r#trait::Interval<S>>::overlaps_unchecked(
+                        &query.0, &feature.0,
+                    )
+                {
+                    self.pending.push_back((query.clone(), feature.clone()));
+                }
+            }
+        }
+    }
+}
+
+impl<S, A, B, I, J> FusedIterator for Overlapping<'_, S, A, B, I, J>
+where
+    S: System,
+    A: Clone,
+    B: Clone,
+    I: FusedIterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Nearest neighbors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A feature neighboring a query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Neighbor<S: System, B> {
+    /// The interval of the feature.
+    interval: Interval<S>,
+
+    /// The payload of the feature.
+    value: B,
+
+    /// The distance between the query and the feature.
+    distance: S::Number,
+}
+
+impl<S: System, B> Neighbor<S, B> {
+    /// Gets the interval of the feature.
+    pub fn interval(&self) -> &Interval<S> {
+        &self.interval
+    }
+
+    /// Gets the payload of the feature.
+    pub fn value(&self) -> &B {
+        &self.value
+    }
+
+    /// Gets the distance between the query and the feature.
+    ///
+    /// This is the number of entities between them, so a feature directly
+    /// adjacent to the query has a distance of zero (`0`).
+    pub fn distance(&self) -> S::Number {
+        self.distance
+    }
+
+    /// Consumes `self` and returns the interval and payload of the feature.
+    pub fn into_parts(self) -> (Interval<S>, B) {
+        (self.interval, self.value)
+    }
+}
+
+/// The nearest upstream and downstream neighbors of a query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Neighbors<S: System, A, B> {
+    /// The interval of the query.
+    interval: Interval<S>,
+
+    /// The payload of the query.
+    value: A,
+
+    /// The nearest upstream neighbor.
+    upstream: Option<Neighbor<S, B>>,
+
+    /// The nearest downstream neighbor.
+    downstream: Option<Neighbor<S, B>>,
+}
+
+impl<S: System, A, B> Neighbors<S, A, B> {
+    /// Gets the interval of the query.
+    pub fn interval(&self) -> &Interval<S> {
+        &self.interval
+    }
+
+    /// Gets the payload of the query.
+    pub fn value(&self) -> &A {
+        &self.value
+    }
+
+    /// Gets the nearest upstream neighbor (if one exists).
+    pub fn upstream(&self) -> Option<&Neighbor<S, B>> {
+        self.upstream.as_ref()
+    }
+
+    /// Gets the nearest downstream neighbor (if one exists).
+    pub fn downstream(&self) -> Option<&Neighbor<S, B>> {
+        self.downstream.as_ref()
+    }
+}
+
+/// The nearest neighbors of a query that may still be incomplete.
+#[derive(Debug)]
+struct Search<S: System, A, B> {
+    /// The neighbors found so far.
+    neighbors: Neighbors<S, A, B>,
+
+    /// Whether or not the nearest neighbor lying numerically above the query
+    /// is known.
+    complete: bool,
+}
+
+impl<S: System, A, B: Clone> Search<S, A, B>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Creates a neighbor of the query from a feature.
+    fn neighbor(&self, feature: &(Interval<S>, B)) -> Neighbor<S, B> {
+        Neighbor {
+            // SAFETY: neighbors are only created from features located on
+            // the same contig and strand as the query, so this will always
+            // unwrap.
+            distance: self.neighbors.interval.distance(&feature.0).unwrap(),
+            interval: feature.0.clone(),
+            value: feature.1.clone(),
+        }
+    }
+
+    /// Returns whether or not a feature lies on the same strand as the query
+    /// and entirely above it.
+    fn is_above(&self, feature: &(Interval<S>, B)) -> bool {
+        feature.0.strand() == self.neighbors.interval.strand()
+            && feature.0.entity_range().0 >= self.neighbors.interval.entity_range().1
+    }
+
+    /// Completes the search if a feature is the nearest feature lying above
+    /// the query.
+    ///
+    /// Features must be offered in sorted order, so the first feature lying
+    /// above the query is the nearest.
+    fn offer(&mut self, feature: &(Interval<S>, B)) {
+        if !self.complete && self.is_above(feature) {
+            let neighbor = Some(self.neighbor(feature));
+
+            match self.neighbors.interval.strand() {
+                Strand::Positive | Strand::Unstranded => self.neighbors.downstream = neighbor,
+                Strand::Negative => self.neighbors.upstream = neighbor,
+            }
+
+            self.complete = true;
+        }
+    }
+}
+
+/// An iterator over the nearest neighbors of each query.
+///
+/// This is created by [`Sweep::closest()`].
+///
+/// Features are never read past the end of the current query. Instead, a
+/// query without a known neighbor lying above it waits until such a feature
+/// is read for a later query (or until the remaining features on its contig
+/// are scanned once the queries on that contig are exhausted). Only the
+/// waiting queries are held in memory, so features on other strands are never
+/// buffered while searching ahead.
+#[derive(Debug)]
+pub struct Closest<'a, S, A, B, I, J>
+where
+    S: System,
+    I: Iterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+{
+    /// The remaining queries.
+    queries: Peekable<I>,
+
+    /// The sweep over the features.
+    sweeper: Sweeper<'a, S, B, J>,
+
+    /// The nearest feature lying entirely below the current query on each
+    /// strand of the current contig.
+    below: BTreeMap<Strand, (Interval<S>, B)>,
+
+    /// The searches that are yet to be yielded, in the order of the queries.
+    pending: VecDeque<Search<S, A, B>>,
+
+    /// Whether or not the sweep has ended due to an error.
+    done: bool,
+}
+
+impl<S, A, B, I, J> Closest<'_, S, A, B, I, J>
+where
+    S: System,
+    B: Clone,
+    I: Iterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    /// Starts the search for the nearest neighbors of a query.
+    fn search(&mut self, query: (Interval<S>, A)) -> Result<()> {
+        let (interval, value) = query;
+
+        if self.sweeper.contig.as_ref() != Some(interval.contig()) {
+            self.below.clear();
+        }
+
+        let (below, pending) = (&mut self.below, &mut self.pending);
+        self.sweeper.advance(
+            &interval,
+            |feature| pending.iter_mut().for_each(|search| search.offer(feature)),
+            |feature| {
+                let end = feature.0.entity_range().1;
+
+                // NOTE: on ties, the feature read first is kept.
+                match below.get(&feature.0.strand()) {
+                    Some(nearest) if nearest.0.entity_range().1 >= end => {}
+                    _ => {
+                        below.insert(feature.0.strand(), feature);
+                    }
+                }
+            },
+        )?;
+
+        let mut search = Search {
+            neighbors: Neighbors {
+                interval,
+                value,
+                upstream: None,
+                downstream: None,
+            },
+            complete: false,
+        };
+
+        let strand = search.neighbors.interval.strand();
+        let below = self
+            .below
+            .get(&strand)
+            .map(|feature| search.neighbor(feature));
+        match strand {
+            Strand::Positive | Strand::Unstranded => search.neighbors.upstream = below,
+            Strand::Negative => search.neighbors.downstream = below,
+        }
+
+        for feature in &self.sweeper.active {
+            search.offer(feature);
+        }
+
+        self.pending.push_back(search);
+        Ok(())
+    }
+
+    /// Completes every pending search by scanning the remaining features on
+    /// the current contig.
+    ///
+    /// No more queries lie on the current contig, so the features are
+    /// discarded as they are scanned.
+    fn finish(&mut self) -> Result<()> {
+        if let Some(contig) = self.sweeper.contig.clone() {
+            while self.pending.iter().any(|search| !search.complete) {
+                let Some(feature) = self.sweeper.read(&contig, |_| true)? else {
+                    break;
+                };
+
+                for search in &mut self.pending {
+                    search.offer(&feature);
+                }
+            }
+        }
+
+        for search in &mut self.pending {
+            search.complete = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S, A, B, I, J> Iterator for Closest<'_, S, A, B, I, J>
+where
+    S: System,
+    B: Clone,
+    I: Iterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    type Item = Result<Neighbors<S, A, B>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.pending.front().is_some_and(|search| search.complete) {
+                return self.pending.pop_front().map(|search| Ok(search.neighbors));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let result = match self.queries.peek() {
+                Some((query, _)) if self.sweeper.contig.as_ref() == Some(query.contig()) => {
+                    // SAFETY: the next query was successfully peeked above.
+                    let query = self.queries.next().unwrap();
+                    self.search(query)
+                }
+                _ if !self.pending.is_empty() => self.finish(),
+                Some(_) => {
+                    // SAFETY: the next query was successfully peeked above.
+                    let query = self.queries.next().unwrap();
+                    self.search(query)
+                }
+                None => return None,
+            };
+
+            if let Err(err) = result {
+                self.done = true;
+                self.pending.clear();
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+impl<S, A, B, I, J> FusedIterator for Closest<'_, S, A, B, I, J>
+where
+    S: System,
+    B: Clone,
+    I: FusedIterator<Item = (Interval<S>, A)>,
+    J: Iterator<Item = (Interval<S>, B)>,
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::IntervalIndex;
+    use crate::system::Base;
+    use crate::system::Interbase;
+    use crate::testing::Lcg;
+
+    fn parse<S: System>(intervals: &[&str]) -> Vec<(Interval<S>, usize)>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        intervals
+            .iter()
+            .map(|interval| interval.parse::<Interval<S>>().unwrap())
+            .zip(0..)
+            .collect()
+    }
+
+    fn overlapping<S: System>(queries: &[&str], features: &[&str]) -> Result<Vec<(usize, usize)>>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        Sweep::new()
+            .overlapping(parse::<S>(queries), parse::<S>(features))
+            .map(|pair| pair.map(|((_, a), (_, b))| (a, b)))
+            .collect()
+    }
+
+    #[test]
+    fn overlaps_across_contigs_and_strands() {
+        let pairs = overlapping::<Interbase>(
+            &["chr1:+:0-10", "chr1:-:20-5", "chr1:+:5-5", "chr3:+:0-100"],
+            &[
+                "chr0:+:0-100",
+                "chr1:+:0-1",
+                "chr1:-:10-0",
+                "chr1:+:9-30",
+                "chr1:+:10-30",
+                "chr2:+:0-100",
+                "chr3:.:0-100",
+                "chr3:+:99-200",
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(pairs, vec![(0, 1), (0, 3), (1, 2), (3, 7)]);
+
+        let pairs = overlapping::<Base>(&["seq0:+:10-20"], &["seq0:+:1-10", "seq0:+:20-30"]);
+        assert_eq!(pairs.unwrap(), vec![(0, 0), (0, 1)]);
+
+        assert!(
+            overlapping::<Base>(&[], &["seq0:+:1-10"])
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            overlapping::<Base>(&["seq0:+:1-10"], &[])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn overlaps_match_an_index() {
+        let mut lcg = Lcg::new(0x2545_F491);
+        let mut next = move |bound: u32| u32::try_from(lcg.below(u64::from(bound))).unwrap();
+
+        let mut random = |count: usize| {
+            let mut intervals = (0..count)
+                .map(|_| {
+                    let contig = ["chr1", "chr2"][next(2) as usize];
+                    let strand = ["+", "-"][next(2) as usize];
+                    let lower = next(1_000);
+                    let upper = lower + next(50);
+                    let (start, end) = match strand {
+                        "+" => (lower, upper),
+                        _ => (upper, lower),
+                    };
+                    format!("{contig}:{strand}:{start}-{end}")
+                        .parse::<Interval<Interbase>>()
+                        .unwrap()
+                })
+                .collect::<Vec<_>>();
+
+            intervals.sort_by(|a, b| {
+                a.contig()
+                    .cmp(b.contig())
+                    .then_with(|| a.lower().cmp(b.lower()))
+            });
+            intervals.into_iter().zip(0..).collect::<Vec<_>>()
+        };
+
+        let queries = random(200);
+        let features = random(500);
+        let index = features.iter().cloned().collect::<IntervalIndex<_, _>>();
+
+        let expected = queries
+            .iter()
+            .flat_map(|(query, a)| {
+                index
+                    .find_overlapping(query)
+                    .into_iter()
+                    .map(move |(_, b)| (*a, *b))
+            })
+            .collect::<Vec<_>>();
+
+        let mut actual = Sweep::new()
+            .overlapping(queries, features)
+            .map(|pair| pair.map(|((_, a), (_, b))| (a, b)))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        // NOTE: the index sorts results by position, whereas the sweep yields
+        // them in the order of the features (which may differ on the negative
+        // strand when positions are tied).
+        let mut expected = expected;
+        expected.sort_unstable();
+        actual.sort_unstable();
+
+        assert!(!expected.is_empty());
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsorted_input() {
+        let err = overlapping::<Base>(&["seq0:+:10-20", "seq0:+:5-20"], &[]).unwrap_err();
+        assert_eq!(
+            err,
+            Error::Unsorted {
+                input: Input::Queries,
+                previous_contig: Contig::new_unchecked("seq0"),
+                previous_position: 10,
+                contig: Contig::new_unchecked("seq0"),
+                position: 5,
+            }
+        );
+
+        // Negative strand intervals are sorted by their end position.
+        let err = overlapping::<Base>(&["seq1:+:1-40"], &["seq1:-:30-20", "seq1:+:15-25"]);
+        assert!(err.is_err());
+
+        let err = overlapping::<Base>(&["seq1:+:1-10"], &["seq1:+:1-10", "seq0:+:1-10"]);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "unsorted feature input: an interval starting at `seq0:1` follows an interval \
+             starting at `seq1:1`"
+        );
+
+        // Iteration ends after an error.
+        let mut pairs =
+            Sweep::new().overlapping(parse::<Base>(&["seq0:+:2-3", "seq0:+:1-2"]), parse(&[]));
+        assert!(pairs.next().unwrap().is_err());
+        assert!(pairs.next().is_none());
+    }
+
+    #[test]
+    fn closest() {
+        let queries = parse::<Interbase>(&[
+            "chr1:+:20-30",
+            "chr1:-:30-20",
+            "chr1:.:20-30",
+            "chr1:+:40-40",
+            "chr2:+:0-10",
+        ]);
+        let features = parse::<Interbase>(&[
+            "chr1:+:0-15",
+            "chr1:+:5-15",
+            "chr1:-:10-5",
+            "chr1:+:25-26",
+            "chr1:-:36-32",
+            "chr1:+:32-36",
+            "chr1:+:32-50",
+            "chr2:-:20-10",
+        ]);
+
+        let neighbors = Sweep::new()
+            .closest(queries, features)
+            .map(|neighbors| {
+                let neighbors = neighbors.unwrap();
+                let describe = |neighbor: Option<&Neighbor<Interbase, usize>>| {
+                    neighbor.map(|neighbor| (*neighbor.value(), neighbor.distance()))
+                };
+                (
+                    *neighbors.value(),
+                    describe(neighbors.upstream()),
+                    describe(neighbors.downstream()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            neighbors,
+            vec![
+                (0, Some((0, 5)), Some((5, 2))),
+                (1, Some((4, 2)), Some((2, 10))),
+                (2, None, None),
+                (3, Some((5, 4)), None),
+                (4, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn closest_does_not_buffer_features_on_other_strands() {
+        let mut features = (0..1_000)
+            .map(|i| format!("seq0:-:{}-{}", i * 10 + 25, i * 10 + 20))
+            .collect::<Vec<_>>();
+        features.push(String::from("seq0:+:20000-20010"));
+        let features = features.iter().map(String::as_str).collect::<Vec<_>>();
+
+        let queries = parse::<Interbase>(&["seq0:+:0-10", "seq0:.:5-15", "seq0:+:30-40"]);
+        let mut closest = Sweep::new().closest(queries, parse::<Interbase>(&features));
+
+        let mut describe = || {
+            let neighbors = closest.next().unwrap().unwrap();
+            let downstream = neighbors.downstream().map(|neighbor| *neighbor.value());
+            (
+                *neighbors.value(),
+                downstream,
+                closest.sweeper.active.len(),
+                closest.pending.len(),
+            )
+        };
+
+        // Only the features near the last query are ever active (rather than
+        // every feature up to the next one on the positive strand), and only
+        // the queries waiting for a downstream neighbor are held.
+        assert_eq!(describe(), (0, Some(1_000), 2, 2));
+        assert_eq!(describe(), (1, None, 2, 1));
+        assert_eq!(describe(), (2, Some(1_000), 2, 0));
+        assert!(closest.next().is_none());
+    }
+
+    #[test]
+    fn closest_adjacent_features() {
+        let queries = parse::<Base>(&["seq0:+:10-20"]);
+        let features = parse::<Base>(&["seq0:+:1-9", "seq0:+:5-9", "seq0:+:21-30"]);
+
+        let neighbors = Sweep::new()
+            .closest(queries, features)
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(neighbors.interval().to_string(), "seq0:+:10-20");
+
+        let upstream = neighbors.upstream().unwrap();
+        assert_eq!(upstream.distance(), 0);
+        assert_eq!(upstream.clone().into_parts().1, 0);
+
+        let downstream = neighbors.downstream().unwrap();
+        assert_eq!(downstream.distance(), 0);
+        assert_eq!(downstream.interval().to_string(), "seq0:+:21-30");
+    }
+}
@@ -3,11 +3,25 @@
 pub mod base;
 pub mod interbase;
 
-use std::fmt::Debug;
-use std::fmt::Display;
+use core::fmt::Debug;
+use core::fmt::Display;
 
 pub use base::Base;
 pub use interbase::Interbase;
 
+use crate::position::Width;
+
 /// A coordinate system.
-pub trait System: Clone + Default + Debug + Display + PartialOrd {}
+///
+/// Coordinate systems are generic over the [`Width`] of the numerical values
+/// of their positions.
+pub trait System: Clone + Default + Debug + Display + PartialOrd {
+    /// A static string representing the name of the coordinate system.
+    const NAME: &'static str;
+
+    /// The inner representation for numerical position values.
+    type Number: Width;
+
+    /// The same coordinate system with positions of another width.
+    type WithWidth<W: Width>: System<Number = W>;
+}
@@ -1,5 +1,9 @@
 //! The base coordinate system.
 
+use core::marker::PhantomData;
+
+use crate::position::Number;
+use crate::position::Width;
 use crate::system::System;
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -7,10 +11,11 @@ use crate::system::System;
 ////////////////////////////////////////////////////////////////////////////////////////
 
 const _: () = {
-    use std::mem::size_of;
+    use core::mem::size_of;
 
     // This should never take up any space.
     assert!(size_of::<Base>() == 0);
+    assert!(size_of::<Base<u64>>() == 0);
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -21,18 +26,26 @@ const _: () = {
 ///
 /// This coordinate system is also known as the "1-based, fully-closed"
 /// coordinate system.
+///
+/// The numerical values of positions within the system are represented using
+/// `W` (see [`Width`]), which defaults to [`Number`].
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Base;
+pub struct Base<W: Width = Number>(pub(crate) PhantomData<W>);
 
 impl Base {
     /// A static string representing the name of the base coordinate system.
     pub const NAME: &str = "base coordinate system";
 }
 
-impl System for Base {}
+impl<W: Width> System for Base<W> {
+    type Number = W;
+    type WithWidth<V: Width> = Base<V>;
+
+    const NAME: &'static str = Base::NAME;
+}
 
-impl std::fmt::Display for Base {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", Self::NAME)
+impl<W: Width> core::fmt::Display for Base<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", <Self as System>::NAME)
     }
 }
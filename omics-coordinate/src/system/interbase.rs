@@ -1,5 +1,9 @@
 //! The interbase coordinate system.
 
+use core::marker::PhantomData;
+
+use crate::position::Number;
+use crate::position::Width;
 use crate::system::System;
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -7,10 +11,11 @@ use crate::system::System;
 ////////////////////////////////////////////////////////////////////////////////////////
 
 const _: () = {
-    use std::mem::size_of;
+    use core::mem::size_of;
 
     // This should never take up any space.
     assert!(size_of::<Interbase>() == 0);
+    assert!(size_of::<Interbase<u64>>() == 0);
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////
@@ -21,8 +26,11 @@ const _: () = {
 ///
 /// This coordinate system is also known as the "0-based, half-open" coordinate
 /// system.
+///
+/// The numerical values of positions within the system are represented using
+/// `W` (see [`Width`]), which defaults to [`Number`].
 #[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Interbase;
+pub struct Interbase<W: Width = Number>(pub(crate) PhantomData<W>);
 
 impl Interbase {
     /// A static string representing the name of the interbase coordinate
@@ -30,10 +38,15 @@ impl Interbase {
     pub const NAME: &str = "interbase coordinate system";
 }
 
-impl System for Interbase {}
+impl<W: Width> System for Interbase<W> {
+    type Number = W;
+    type WithWidth<V: Width> = Interbase<V>;
+
+    const NAME: &'static str = Interbase::NAME;
+}
 
-impl std::fmt::Display for Interbase {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", Self::NAME)
+impl<W: Width> core::fmt::Display for Interbase<W> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", <Self as System>::NAME)
     }
 }
@@ -0,0 +1,23 @@
+//! Helpers shared between the unit tests of the crate.
+
+/// A small linear congruential generator.
+///
+/// This keeps randomized tests deterministic without pulling in a dependency.
+#[derive(Debug)]
+pub(crate) struct Lcg(u64);
+
+impl Lcg {
+    /// Creates a new generator from a seed.
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Generates a value in the range `0..bound`.
+    pub(crate) fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (self.0 >> 33) % bound
+    }
+}
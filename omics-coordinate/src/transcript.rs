@@ -0,0 +1,1158 @@
+//! Transcript coordinates.
+//!
+//! Clinical variant descriptions locate changes relative to a transcript
+//! rather than to the genome. Following the [HGVS nomenclature], this module
+//! supports two numberings:
+//!
+//! * **Transcript** positions (`n.`) count the entities of the spliced
+//!   transcript starting from one at its first entity.
+//! * **CDS** positions (`c.`) count the entities of the spliced transcript
+//!   starting from one at the first entity of the start codon. Entities before
+//!   the start codon are counted backwards (`c.-12`), while entities after the
+//!   stop codon are counted forwards from its last entity (`c.*7`).
+//!
+//! Intronic entities are described by their offset from the nearest exonic
+//! entity: `c.123+5` is five entities downstream of `c.123` and `c.124-3` is
+//! three entities upstream of `c.124`. When an intronic entity is equidistant
+//! from both exons, the upstream exon is used. Entities beyond the ends of
+//! the transcript are numbered upstream (`n.-5`) or downstream (`n.*5`) of
+//! the transcript.
+//!
+//! A [`Transcript`] maps genomic [`Coordinate<Base>`]s to [`Location`]s within
+//! the transcript and maps [`TranscriptPosition`]s back to the genome. All
+//! positions are expressed in the direction of transcription, so positions on
+//! negative-strand transcripts decrease as the transcript position increases.
+//!
+//! Transcripts are generic over the [`Width`] of their genomic positions, so
+//! transcripts on contigs longer than [`u32::MAX`] can be described with
+//! `Transcript<u64>`. Transcript positions are always counted using [`u64`].
+//!
+//! [HGVS nomenclature]: https://hgvs-nomenclature.org/stable/background/numbering/
+
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use thiserror::Error;
+
+use crate::Contig;
+use crate::Coordinate;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::position::Number;
+use crate::position::Width;
+use crate::system::Base;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to parsing a transcript position.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// An invalid transcript position.
+    #[error("invalid transcript position: `{0}`")]
+    Invalid(String),
+}
+
+/// A [`Result`](core::result::Result) with a [`ParseError`].
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
+
+/// An error related to a transcript.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An empty transcript error.
+    ///
+    /// This error occurs when a transcript is created without any exons.
+    #[error("a transcript must have at least one exon")]
+    Empty,
+
+    /// A mismatched contig error.
+    ///
+    /// This error occurs when an exon, the CDS, or a coordinate does not lie
+    /// upon the contig of the transcript.
+    #[error("mismatched contigs: expected `{expected}`, found `{found}`")]
+    MismatchedContigs {
+        /// The contig of the transcript.
+        expected: Contig,
+
+        /// The contig that was found.
+        found: Contig,
+    },
+
+    /// A mismatched strand error.
+    ///
+    /// This error occurs when an exon or the CDS does not lie upon the strand
+    /// of the transcript.
+    #[error("mismatched strands: expected `{expected}`, found `{found}`")]
+    MismatchedStrands {
+        /// The strand of the transcript.
+        expected: Strand,
+
+        /// The strand that was found.
+        found: Strand,
+    },
+
+    /// An unstranded transcript error.
+    ///
+    /// This error occurs when the exons of a transcript are unstranded, as the
+    /// direction of transcription is then unknown.
+    #[error("the exons of a transcript must be stranded")]
+    Unstranded,
+
+    /// An unordered exon error.
+    ///
+    /// This error occurs when an exon overlaps or precedes the exon before it
+    /// in the direction of transcription.
+    #[error("exon {0} overlaps or precedes the previous exon")]
+    Unordered(usize),
+
+    /// A CDS boundary error.
+    ///
+    /// This error occurs when the start or end of the CDS (given here as its
+    /// genomic position) does not lie within an exon.
+    #[error("CDS boundary at position `{0}` does not lie within an exon")]
+    CdsBoundary(u64),
+
+    /// A non-coding transcript error.
+    ///
+    /// This error occurs when a CDS position is requested from a transcript
+    /// without a CDS.
+    #[error("the transcript has no CDS")]
+    NonCoding,
+
+    /// An out of bounds error.
+    ///
+    /// This error occurs when a transcript position does not lie within the
+    /// transcript or when it maps beyond the bounds of the contig.
+    #[error("transcript position `{0}` is out of bounds")]
+    OutOfBounds(TranscriptPosition),
+
+    /// An invalid offset error.
+    ///
+    /// This error occurs when an intronic offset is applied to an entity that
+    /// does not border an intron in the direction of the offset or when the
+    /// offset extends beyond the intron.
+    #[error("transcript position `{0}` does not lie within an intron")]
+    Offset(TranscriptPosition),
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Positions
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The anchor of a transcript position.
+///
+/// Within the transcript numbering (`n.`), the anchor is relative to the ends
+/// of the transcript. Within the CDS numbering (`c.`), the anchor is relative
+/// to the start and stop codons, so [`Anchor::Upstream`] covers the 5' UTR and
+/// [`Anchor::Downstream`] covers the 3' UTR.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// The given number of entities upstream of the first entity (`-k`).
+    Upstream(u64),
+
+    /// The entity at the given position (`p`).
+    Position(u64),
+
+    /// The given number of entities downstream of the last entity (`*k`).
+    Downstream(u64),
+}
+
+/// A position within a transcript numbering.
+///
+/// A transcript position is an [`Anchor`] and an intronic offset in the
+/// direction of transcription. Exonic positions have an offset of zero.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::transcript::Anchor;
+/// use omics_coordinate::transcript::TranscriptPosition;
+///
+/// let position = "123+5".parse::<TranscriptPosition>()?;
+/// assert_eq!(position.anchor(), Anchor::Position(123));
+/// assert_eq!(position.offset(), 5);
+/// assert!(position.is_intronic());
+///
+/// let position = "*7-2".parse::<TranscriptPosition>()?;
+/// assert_eq!(position.anchor(), Anchor::Downstream(7));
+/// assert_eq!(position.offset(), -2);
+///
+/// assert_eq!(
+///     TranscriptPosition::new(Anchor::Upstream(15), 0).to_string(),
+///     "-15"
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TranscriptPosition {
+    /// The anchor.
+    anchor: Anchor,
+
+    /// The intronic offset.
+    offset: i64,
+}
+
+impl TranscriptPosition {
+    /// Creates a new transcript position.
+    pub fn new(anchor: Anchor, offset: i64) -> Self {
+        Self { anchor, offset }
+    }
+
+    /// Creates a new exonic transcript position.
+    pub fn exonic(anchor: Anchor) -> Self {
+        Self::new(anchor, 0)
+    }
+
+    /// Gets the anchor.
+    pub fn anchor(&self) -> Anchor {
+        self.anchor
+    }
+
+    /// Gets the intronic offset.
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// Returns whether or not the position has an intronic offset.
+    pub fn is_intronic(&self) -> bool {
+        self.offset != 0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Locations
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The region of a transcript within which a genomic entity lies.
+///
+/// Exons and introns are numbered from one in the direction of transcription.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Upstream of the first exon.
+    Upstream,
+
+    /// Within the 5' UTR of the given exon.
+    FivePrimeUtr(usize),
+
+    /// Within the coding portion of the given exon.
+    Coding(usize),
+
+    /// Within the 3' UTR of the given exon.
+    ThreePrimeUtr(usize),
+
+    /// Within the given exon of a non-coding transcript.
+    NonCoding(usize),
+
+    /// Within the given intron.
+    Intron(usize),
+
+    /// Downstream of the last exon.
+    Downstream,
+}
+
+/// The location of a genomic entity within a transcript.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// The region.
+    region: Region,
+
+    /// The transcript position (`n.`).
+    transcript: TranscriptPosition,
+
+    /// The CDS position (`c.`).
+    cds: Option<TranscriptPosition>,
+}
+
+impl Location {
+    /// Gets the region.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Gets the transcript position (`n.`).
+    pub fn transcript(&self) -> TranscriptPosition {
+        self.transcript
+    }
+
+    /// Gets the CDS position (`c.`).
+    ///
+    /// This is [`None`] for non-coding transcripts.
+    pub fn cds(&self) -> Option<TranscriptPosition> {
+        self.cds
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Transcripts
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A spliced transcript.
+///
+/// The genomic positions of a transcript are represented using `W` (see
+/// [`Width`]), which defaults to [`Number`].
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Coordinate;
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::system::Base;
+/// use omics_coordinate::transcript::Region;
+/// use omics_coordinate::transcript::Transcript;
+///
+/// let exons = ["chr1:-:599-500", "chr1:-:399-300", "chr1:-:199-100"]
+///     .into_iter()
+///     .map(|exon| exon.parse::<Interval<Base>>())
+///     .collect::<Result<Vec<_>, _>>()?;
+/// let cds = "chr1:-:549-150".parse::<Interval<Base>>()?;
+/// let transcript = Transcript::try_new(exons, Some(cds))?;
+///
+/// // An intronic entity nearer to the second exon.
+/// let location = transcript.locate(&"chr1:+:405".parse::<Coordinate<Base>>()?)?;
+/// assert_eq!(location.region(), Region::Intron(1));
+/// assert_eq!(location.transcript().to_string(), "101-6");
+/// assert_eq!(location.cds().unwrap().to_string(), "51-6");
+///
+/// // And back again.
+/// let coordinate = transcript.cds_to_genome(&"51-6".parse()?)?;
+/// assert_eq!(coordinate.to_string(), "chr1:-:405");
+///
+/// // Transcripts on contigs longer than `u32::MAX` use 64-bit positions.
+/// let exons = ["chrLong:+:5000000001-5000000100"]
+///     .into_iter()
+///     .map(|exon| exon.parse::<Interval<Base<u64>>>())
+///     .collect::<Result<Vec<_>, _>>()?;
+/// let transcript = Transcript::try_new(exons, None)?;
+///
+/// let coordinate = "chrLong:+:5000000050".parse::<Coordinate<Base<u64>>>()?;
+/// assert_eq!(
+///     transcript.locate(&coordinate)?.transcript().to_string(),
+///     "50"
+/// );
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Transcript<W: Width = Number> {
+    /// The exons in the direction of transcription.
+    exons: Vec<Interval<Base<W>>>,
+
+    /// The transcript position of the first entity of each exon.
+    starts: Vec<u64>,
+
+    /// The number of entities in the spliced transcript.
+    length: u64,
+
+    /// The CDS.
+    cds: Option<Interval<Base<W>>>,
+
+    /// The transcript positions of the first and last entities of the CDS.
+    cds_bounds: Option<(u64, u64)>,
+}
+
+impl<W: Width> Transcript<W> {
+    /// Attempts to create a new transcript from its exons and its CDS (if it
+    /// has one).
+    ///
+    /// The exons must lie on the same contig and strand and must be provided
+    /// in the direction of transcription (so the exons of a negative-strand
+    /// transcript are in descending order). The CDS spans from the first
+    /// entity of the start codon to the last entity of the stop codon, and
+    /// both of those entities must lie within exons.
+    pub fn try_new(
+        exons: impl IntoIterator<Item = Interval<Base<W>>>,
+        cds: Option<Interval<Base<W>>>,
+    ) -> Result<Self> {
+        let exons = exons.into_iter().collect::<Vec<_>>();
+        let head = exons.first().ok_or(Error::Empty)?;
+
+        let contig = head.contig();
+        let strand = head.strand();
+
+        if strand == Strand::Unstranded {
+            return Err(Error::Unstranded);
+        }
+
+        let mut starts = Vec::with_capacity(exons.len());
+        let mut length = 0u64;
+
+        for (i, exon) in exons.iter().enumerate() {
+            check(exon, contig, strand)?;
+
+            if i > 0 && distance(strand, last(&exons[i - 1]), first(exon)) <= 0 {
+                return Err(Error::Unordered(i + 1));
+            }
+
+            starts.push(length + 1);
+            // SAFETY: the exons do not overlap and lie upon a single contig,
+            // so their combined length cannot exceed the maximum position.
+            length += exon.count_entities().to_u64();
+        }
+
+        let mut transcript = Self {
+            exons,
+            starts,
+            length,
+            cds: None,
+            cds_bounds: None,
+        };
+
+        if let Some(cds) = cds {
+            check(&cds, transcript.contig(), strand)?;
+
+            let bound = |position: W| {
+                transcript
+                    .exonic_position(genomic(position))
+                    .ok_or(Error::CdsBoundary(position.to_u64()))
+            };
+
+            let start = bound(cds.start().position().get())?;
+            let end = bound(cds.end().position().get())?;
+
+            transcript.cds_bounds = Some((start, end));
+            transcript.cds = Some(cds);
+        }
+
+        Ok(transcript)
+    }
+
+    /// Gets the contig.
+    pub fn contig(&self) -> &Contig {
+        self.exons[0].contig()
+    }
+
+    /// Gets the strand.
+    pub fn strand(&self) -> Strand {
+        self.exons[0].strand()
+    }
+
+    /// Gets the exons in the direction of transcription.
+    pub fn exons(&self) -> &[Interval<Base<W>>] {
+        &self.exons
+    }
+
+    /// Gets the CDS (if it exists).
+    pub fn cds(&self) -> Option<&Interval<Base<W>>> {
+        self.cds.as_ref()
+    }
+
+    /// Gets the number of entities in the spliced transcript.
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns whether or not the transcript is empty.
+    ///
+    /// Because a transcript always has at least one exon, this always returns
+    /// `false`.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns whether or not the transcript has a CDS.
+    pub fn is_coding(&self) -> bool {
+        self.cds.is_some()
+    }
+
+    /// Locates a genomic entity within the transcript.
+    ///
+    /// The strand of the coordinate is ignored: the position alone identifies
+    /// the entity regardless of the strand it is described upon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Coordinate;
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::transcript::Region;
+    /// use omics_coordinate::transcript::Transcript;
+    ///
+    /// let exons = ["chr1:+:100-199", "chr1:+:300-399", "chr1:+:500-599"]
+    ///     .into_iter()
+    ///     .map(|exon| exon.parse::<Interval<Base>>())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// let cds = "chr1:+:150-549".parse::<Interval<Base>>()?;
+    /// let transcript = Transcript::try_new(exons, Some(cds))?;
+    ///
+    /// let locate = |coordinate: &str| {
+    ///     let coordinate = coordinate.parse::<Coordinate<Base>>().unwrap();
+    ///     transcript.locate(&coordinate)
+    /// };
+    ///
+    /// let location = locate("chr1:+:150")?;
+    /// assert_eq!(location.region(), Region::Coding(1));
+    /// assert_eq!(location.transcript().to_string(), "51");
+    /// assert_eq!(location.cds().unwrap().to_string(), "1");
+    ///
+    /// let location = locate("chr1:+:120")?;
+    /// assert_eq!(location.region(), Region::FivePrimeUtr(1));
+    /// assert_eq!(location.cds().unwrap().to_string(), "-30");
+    ///
+    /// let location = locate("chr1:+:205")?;
+    /// assert_eq!(location.region(), Region::Intron(1));
+    /// assert_eq!(location.cds().unwrap().to_string(), "50+6");
+    ///
+    /// let location = locate("chr1:+:560")?;
+    /// assert_eq!(location.region(), Region::ThreePrimeUtr(3));
+    /// assert_eq!(location.cds().unwrap().to_string(), "*11");
+    ///
+    /// let location = locate("chr1:+:610")?;
+    /// assert_eq!(location.region(), Region::Downstream);
+    /// assert_eq!(location.transcript().to_string(), "*11");
+    /// assert_eq!(location.cds().unwrap().to_string(), "*61");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn locate(&self, coordinate: &Coordinate<Base<W>>) -> Result<Location> {
+        if coordinate.contig() != self.contig() {
+            return Err(Error::MismatchedContigs {
+                expected: self.contig().clone(),
+                found: coordinate.contig().clone(),
+            });
+        }
+
+        let strand = self.strand();
+        let position = genomic(coordinate.position().get());
+
+        // SAFETY: a transcript always has at least one exon.
+        let first_exon = self.exons.first().unwrap();
+        let last_exon = self.exons.last().unwrap();
+
+        let (region, transcript) = if distance(strand, position, first(first_exon)) > 0 {
+            let upstream = distance(strand, position, first(first_exon));
+            (Region::Upstream, upstream_position(upstream))
+        } else if distance(strand, last(last_exon), position) > 0 {
+            let downstream = distance(strand, last(last_exon), position);
+            (Region::Downstream, downstream_position(downstream))
+        } else if let Some(n) = self.exonic_position(position) {
+            // SAFETY: the position was just found within an exon.
+            let exon = self.exon_index(n).unwrap();
+            let region = match self.cds_bounds {
+                Some((start, _)) if n < start => Region::FivePrimeUtr(exon + 1),
+                Some((_, end)) if n > end => Region::ThreePrimeUtr(exon + 1),
+                Some(_) => Region::Coding(exon + 1),
+                None => Region::NonCoding(exon + 1),
+            };
+
+            (region, TranscriptPosition::exonic(Anchor::Position(n)))
+        } else {
+            // SAFETY: the position lies between the first and last exons but
+            // not within any exon, so it must lie within an intron.
+            let intron = self
+                .exons
+                .windows(2)
+                .position(|pair| {
+                    distance(strand, last(&pair[0]), position) > 0
+                        && distance(strand, position, first(&pair[1])) > 0
+                })
+                .unwrap();
+
+            let upstream = distance(strand, last(&self.exons[intron]), position);
+            let downstream = distance(strand, position, first(&self.exons[intron + 1]));
+
+            // SAFETY: the nearer exon is at most half of the distance across
+            // the intron away, which always fits within an offset.
+            let transcript = if upstream <= downstream {
+                let anchor = self.starts[intron] + self.exons[intron].count_entities().to_u64() - 1;
+                let offset = i64::try_from(upstream).unwrap();
+                TranscriptPosition::new(Anchor::Position(anchor), offset)
+            } else {
+                let anchor = self.starts[intron + 1];
+                let offset = i64::try_from(downstream).unwrap();
+                TranscriptPosition::new(Anchor::Position(anchor), -offset)
+            };
+
+            (Region::Intron(intron + 1), transcript)
+        };
+
+        let cds = match self.cds_bounds {
+            Some(_) => Some(self.transcript_to_cds(&transcript)?),
+            None => None,
+        };
+
+        Ok(Location {
+            region,
+            transcript,
+            cds,
+        })
+    }
+
+    /// Maps a transcript position (`n.`) to the genome.
+    ///
+    /// The returned coordinate lies upon the strand of the transcript.
+    pub fn transcript_to_genome(
+        &self,
+        position: &TranscriptPosition,
+    ) -> Result<Coordinate<Base<W>>> {
+        let strand = self.strand();
+        let out_of_bounds = || Error::OutOfBounds(*position);
+        let invalid_offset = || Error::Offset(*position);
+
+        // SAFETY: a transcript always has at least one exon.
+        let first_exon = self.exons.first().unwrap();
+        let last_exon = self.exons.last().unwrap();
+
+        let genomic = match position.anchor {
+            Anchor::Upstream(0) | Anchor::Position(0) | Anchor::Downstream(0) => {
+                return Err(out_of_bounds());
+            }
+            Anchor::Upstream(_) | Anchor::Downstream(_) if position.is_intronic() => {
+                return Err(invalid_offset());
+            }
+            Anchor::Upstream(k) => advance(strand, first(first_exon), -i128::from(k)),
+            Anchor::Downstream(k) => advance(strand, last(last_exon), i128::from(k)),
+            Anchor::Position(n) => {
+                let i = self.exon_index(n).ok_or_else(out_of_bounds)?;
+                let exon = &self.exons[i];
+                let genomic = advance(strand, first(exon), i128::from(n - self.starts[i]));
+
+                let valid = match position.offset {
+                    0 => true,
+                    // NOTE: an offset must stay within the intron, which
+                    // contains one fewer entity than the distance between the
+                    // bordering exonic entities.
+                    offset if offset > 0 => {
+                        genomic == last(exon)
+                            && self.exons.get(i + 1).is_some_and(|next| {
+                                i128::from(offset) < distance(strand, last(exon), first(next))
+                            })
+                    }
+                    offset => {
+                        genomic == first(exon)
+                            && i > 0
+                            && -i128::from(offset)
+                                < distance(strand, last(&self.exons[i - 1]), first(exon))
+                    }
+                };
+
+                if !valid {
+                    return Err(invalid_offset());
+                }
+
+                advance(strand, genomic, i128::from(position.offset))
+            }
+        };
+
+        let position = u64::try_from(genomic)
+            .ok()
+            .and_then(W::from_u64)
+            .and_then(|genomic| Position::<Base<W>>::try_new(genomic).ok())
+            .ok_or_else(out_of_bounds)?;
+
+        Ok(Coordinate::new(self.contig().clone(), strand, position))
+    }
+
+    /// Maps a CDS position (`c.`) to the genome.
+    ///
+    /// The returned coordinate lies upon the strand of the transcript.
+    pub fn cds_to_genome(&self, position: &TranscriptPosition) -> Result<Coordinate<Base<W>>> {
+        self.transcript_to_genome(&self.cds_to_transcript(position)?)
+    }
+
+    /// Converts a transcript position (`n.`) to a CDS position (`c.`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::transcript::Transcript;
+    ///
+    /// let exons = ["chr1:+:100-199", "chr1:+:300-399"]
+    ///     .into_iter()
+    ///     .map(|exon| exon.parse::<Interval<Base>>())
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    /// let cds = "chr1:+:150-349".parse::<Interval<Base>>()?;
+    /// let transcript = Transcript::try_new(exons, Some(cds))?;
+    ///
+    /// let cds = transcript.transcript_to_cds(&"100+3".parse()?)?;
+    /// assert_eq!(cds.to_string(), "50+3");
+    /// assert_eq!(transcript.cds_to_transcript(&cds)?.to_string(), "100+3");
+    ///
+    /// assert_eq!(
+    ///     transcript.transcript_to_cds(&"-2".parse()?)?.to_string(),
+    ///     "-52"
+    /// );
+    /// assert_eq!(
+    ///     transcript.transcript_to_cds(&"200".parse()?)?.to_string(),
+    ///     "*50"
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn transcript_to_cds(&self, position: &TranscriptPosition) -> Result<TranscriptPosition> {
+        let (start, end) = self.cds_bounds.ok_or(Error::NonCoding)?;
+        let (start, end, length) = (i128::from(start), i128::from(end), i128::from(self.length));
+
+        let anchor = match position.anchor {
+            Anchor::Upstream(k) if k > 0 => upstream_anchor(start - 1 + i128::from(k)),
+            Anchor::Downstream(k) if k > 0 => downstream_anchor(length - end + i128::from(k)),
+            Anchor::Position(n) if n > 0 && n <= self.length => {
+                let n = i128::from(n);
+
+                if n < start {
+                    upstream_anchor(start - n)
+                } else if n > end {
+                    downstream_anchor(n - end)
+                } else {
+                    position_anchor(n - start + 1)
+                }
+            }
+            _ => None,
+        };
+
+        anchor
+            .map(|anchor| TranscriptPosition::new(anchor, position.offset))
+            .ok_or(Error::OutOfBounds(*position))
+    }
+
+    /// Converts a CDS position (`c.`) to a transcript position (`n.`).
+    pub fn cds_to_transcript(&self, position: &TranscriptPosition) -> Result<TranscriptPosition> {
+        let (start, end) = self.cds_bounds.ok_or(Error::NonCoding)?;
+        let (start, end, length) = (i128::from(start), i128::from(end), i128::from(self.length));
+
+        let anchor = match position.anchor {
+            Anchor::Upstream(k) if k > 0 => {
+                let n = start - i128::from(k);
+
+                match n > 0 {
+                    true => position_anchor(n),
+                    false => upstream_anchor(1 - n),
+                }
+            }
+            Anchor::Position(c) if c > 0 && i128::from(c) <= end - start + 1 => {
+                position_anchor(start + i128::from(c) - 1)
+            }
+            Anchor::Downstream(k) if k > 0 => {
+                let n = end + i128::from(k);
+
+                match n <= length {
+                    true => position_anchor(n),
+                    false => downstream_anchor(n - length),
+                }
+            }
+            _ => None,
+        };
+
+        anchor
+            .map(|anchor| TranscriptPosition::new(anchor, position.offset))
+            .ok_or(Error::OutOfBounds(*position))
+    }
+
+    /// Gets the transcript position of a genomic position that lies within an
+    /// exon.
+    fn exonic_position(&self, position: i128) -> Option<u64> {
+        let strand = self.strand();
+
+        self.exons
+            .iter()
+            .zip(&self.starts)
+            .find(|(exon, _)| {
+                distance(strand, first(exon), position) >= 0
+                    && distance(strand, position, last(exon)) >= 0
+            })
+            .and_then(|(exon, start)| {
+                u64::try_from(i128::from(*start) + distance(strand, first(exon), position)).ok()
+            })
+    }
+
+    /// Gets the index of the exon containing a transcript position.
+    fn exon_index(&self, position: u64) -> Option<usize> {
+        if position == 0 || position > self.length {
+            return None;
+        }
+
+        Some(self.starts.partition_point(|start| *start <= position) - 1)
+    }
+}
+
+/// Ensures that an interval lies upon the provided contig and strand.
+fn check<W: Width>(interval: &Interval<Base<W>>, contig: &Contig, strand: Strand) -> Result<()> {
+    if interval.contig() != contig {
+        return Err(Error::MismatchedContigs {
+            expected: contig.clone(),
+            found: interval.contig().clone(),
+        });
+    }
+
+    if interval.strand() != strand {
+        return Err(Error::MismatchedStrands {
+            expected: strand,
+            found: interval.strand(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Widens a genomic position so that distances between positions of any
+/// width can be computed without overflow.
+fn genomic<W: Width>(position: W) -> i128 {
+    i128::from(position.to_u64())
+}
+
+/// Gets the genomic position of the first entity of an interval in the
+/// direction of its strand.
+fn first<W: Width>(interval: &Interval<Base<W>>) -> i128 {
+    genomic(interval.start().position().get())
+}
+
+/// Gets the genomic position of the last entity of an interval in the
+/// direction of its strand.
+fn last<W: Width>(interval: &Interval<Base<W>>) -> i128 {
+    genomic(interval.end().position().get())
+}
+
+/// Gets the distance from one genomic position to another in the direction of
+/// transcription.
+fn distance(strand: Strand, from: i128, to: i128) -> i128 {
+    match strand {
+        Strand::Negative => from - to,
+        _ => to - from,
+    }
+}
+
+/// Moves a genomic position by a distance in the direction of transcription.
+fn advance(strand: Strand, position: i128, distance: i128) -> i128 {
+    match strand {
+        Strand::Negative => position - distance,
+        _ => position + distance,
+    }
+}
+
+/// Creates an [`Anchor::Upstream`] anchor if the value is representable.
+fn upstream_anchor(value: i128) -> Option<Anchor> {
+    u64::try_from(value).ok().map(Anchor::Upstream)
+}
+
+/// Creates an [`Anchor::Position`] anchor if the value is representable.
+fn position_anchor(value: i128) -> Option<Anchor> {
+    u64::try_from(value).ok().map(Anchor::Position)
+}
+
+/// Creates an [`Anchor::Downstream`] anchor if the value is representable.
+fn downstream_anchor(value: i128) -> Option<Anchor> {
+    u64::try_from(value).ok().map(Anchor::Downstream)
+}
+
+/// Creates an exonic transcript position upstream of the transcript.
+fn upstream_position(distance: i128) -> TranscriptPosition {
+    // SAFETY: genomic distances always fit within a transcript position.
+    TranscriptPosition::exonic(upstream_anchor(distance).unwrap())
+}
+
+/// Creates an exonic transcript position downstream of the transcript.
+fn downstream_position(distance: i128) -> TranscriptPosition {
+    // SAFETY: genomic distances always fit within a transcript position.
+    TranscriptPosition::exonic(downstream_anchor(distance).unwrap())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Trait implementations
+////////////////////////////////////////////////////////////////////////////////////////
+
+impl core::fmt::Display for TranscriptPosition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.anchor {
+            Anchor::Upstream(k) => write!(f, "-{k}")?,
+            Anchor::Position(n) => write!(f, "{n}")?,
+            Anchor::Downstream(k) => write!(f, "*{k}")?,
+        }
+
+        match self.offset {
+            0 => Ok(()),
+            offset if offset > 0 => write!(f, "+{offset}"),
+            offset => write!(f, "{offset}"),
+        }
+    }
+}
+
+impl core::str::FromStr for TranscriptPosition {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> ParseResult<Self> {
+        let invalid = || ParseError::Invalid(s.to_string());
+
+        let (kind, rest) = match s.as_bytes().first() {
+            Some(b'-') => (Anchor::Upstream as fn(u64) -> Anchor, &s[1..]),
+            Some(b'*') => (Anchor::Downstream as fn(u64) -> Anchor, &s[1..]),
+            _ => (Anchor::Position as fn(u64) -> Anchor, s),
+        };
+
+        let (value, offset) = match rest.find(['+', '-']) {
+            Some(i) => (&rest[..i], Some(&rest[i..])),
+            None => (rest, None),
+        };
+
+        let nonzero = |value: &str| {
+            Some(value)
+                .filter(|value| value.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|value| value.parse::<u64>().ok())
+                .filter(|value| *value > 0)
+        };
+
+        let anchor = kind(nonzero(value).ok_or_else(invalid)?);
+
+        let offset = match offset {
+            Some(offset) => {
+                let magnitude = nonzero(&offset[1..])
+                    .and_then(|magnitude| i64::try_from(magnitude).ok())
+                    .ok_or_else(invalid)?;
+
+                match offset.starts_with('-') {
+                    true => -magnitude,
+                    false => magnitude,
+                }
+            }
+            None => 0,
+        };
+
+        Ok(Self::new(anchor, offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    fn transcript(exons: &[&str], cds: Option<&str>) -> Transcript {
+        Transcript::try_new(
+            exons.iter().map(|exon| exon.parse().unwrap()),
+            cds.map(|cds| cds.parse().unwrap()),
+        )
+        .unwrap()
+    }
+
+    fn coordinate(s: &str) -> Coordinate<Base> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn positive_strand() {
+        let transcript = transcript(
+            &["chr1:+:100-199", "chr1:+:300-399", "chr1:+:500-599"],
+            Some("chr1:+:150-549"),
+        );
+
+        assert_eq!(transcript.len(), 300);
+
+        let cases = [
+            (90, Region::Upstream, "-10", "-60"),
+            (100, Region::FivePrimeUtr(1), "1", "-50"),
+            (199, Region::Coding(1), "100", "50"),
+            (249, Region::Intron(1), "100+50", "50+50"),
+            (250, Region::Intron(1), "101-50", "51-50"),
+            (300, Region::Coding(2), "101", "51"),
+            (549, Region::Coding(3), "250", "200"),
+            (550, Region::ThreePrimeUtr(3), "251", "*1"),
+            (600, Region::Downstream, "*1", "*51"),
+        ];
+
+        for (position, region, n, c) in cases {
+            let location = transcript
+                .locate(&coordinate(&format!("chr1:+:{position}")))
+                .unwrap();
+
+            assert_eq!(location.region(), region);
+            assert_eq!(location.transcript().to_string(), n);
+            assert_eq!(location.cds().unwrap().to_string(), c);
+        }
+    }
+
+    #[test]
+    fn negative_strand() {
+        let transcript = transcript(
+            &["chr1:-:599-500", "chr1:-:399-300", "chr1:-:199-100"],
+            Some("chr1:-:549-150"),
+        );
+
+        let location = transcript.locate(&coordinate("chr1:-:599")).unwrap();
+        assert_eq!(location.region(), Region::FivePrimeUtr(1));
+        assert_eq!(location.transcript().to_string(), "1");
+        assert_eq!(location.cds().unwrap().to_string(), "-50");
+
+        let location = transcript.locate(&coordinate("chr1:+:549")).unwrap();
+        assert_eq!(location.region(), Region::Coding(1));
+        assert_eq!(location.cds().unwrap().to_string(), "1");
+
+        let location = transcript.locate(&coordinate("chr1:-:495")).unwrap();
+        assert_eq!(location.region(), Region::Intron(1));
+        assert_eq!(location.cds().unwrap().to_string(), "50+5");
+
+        let location = transcript.locate(&coordinate("chr1:-:150")).unwrap();
+        assert_eq!(location.region(), Region::Coding(3));
+        assert_eq!(location.cds().unwrap().to_string(), "200");
+
+        let location = transcript.locate(&coordinate("chr1:-:95")).unwrap();
+        assert_eq!(location.region(), Region::Downstream);
+        assert_eq!(location.transcript().to_string(), "*5");
+        assert_eq!(location.cds().unwrap().to_string(), "*55");
+    }
+
+    #[test]
+    fn round_trips() {
+        for (exons, cds) in [
+            (
+                ["chr1:+:100-199", "chr1:+:300-399", "chr1:+:500-599"],
+                "chr1:+:150-549",
+            ),
+            (
+                ["chr1:-:599-500", "chr1:-:399-300", "chr1:-:199-100"],
+                "chr1:-:549-150",
+            ),
+        ] {
+            let transcript = transcript(&exons, Some(cds));
+            let strand = transcript.strand();
+
+            for position in 1..700 {
+                let genomic = coordinate(&format!("chr1:{strand}:{position}"));
+                let location = transcript.locate(&genomic).unwrap();
+
+                assert_eq!(
+                    transcript
+                        .transcript_to_genome(&location.transcript())
+                        .unwrap(),
+                    genomic
+                );
+                assert_eq!(
+                    transcript.cds_to_genome(&location.cds().unwrap()).unwrap(),
+                    genomic
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn non_coding() {
+        let transcript = transcript(&["chr1:+:1-10", "chr1:+:21-30"], None);
+
+        let location = transcript.locate(&coordinate("chr1:+:25")).unwrap();
+        assert_eq!(location.region(), Region::NonCoding(2));
+        assert_eq!(location.transcript().to_string(), "15");
+        assert_eq!(location.cds(), None);
+
+        assert_eq!(
+            transcript.transcript_to_cds(&location.transcript()),
+            Err(Error::NonCoding)
+        );
+    }
+
+    #[test]
+    fn wide_positions() {
+        let transcript = Transcript::try_new(
+            [
+                "chr1:-:5000000599-5000000500",
+                "chr1:-:5000000199-5000000100",
+            ]
+            .into_iter()
+            .map(|exon| exon.parse::<Interval<Base<u64>>>().unwrap()),
+            Some("chr1:-:5000000549-5000000150".parse().unwrap()),
+        )
+        .unwrap();
+
+        let locate = |position: u64| {
+            let coordinate = Coordinate::<Base<u64>>::try_new("chr1", "-", position).unwrap();
+            transcript.locate(&coordinate).unwrap()
+        };
+
+        let location = locate(5_000_000_450);
+        assert_eq!(location.region(), Region::Intron(1));
+        assert_eq!(location.transcript().to_string(), "100+50");
+        assert_eq!(location.cds().unwrap().to_string(), "50+50");
+
+        let location = locate(1);
+        assert_eq!(location.region(), Region::Downstream);
+        assert_eq!(location.transcript().to_string(), "*5000000099");
+
+        for c in ["-10", "1", "50+50", "51-101", "100", "*10"] {
+            let genomic = transcript.cds_to_genome(&c.parse().unwrap()).unwrap();
+            let location = transcript.locate(&genomic).unwrap();
+            assert_eq!(location.cds().unwrap().to_string(), c);
+        }
+    }
+
+    #[test]
+    fn invalid_positions() {
+        let transcript = transcript(&["chr1:+:1-10", "chr1:+:21-30"], Some("chr1:+:5-25"));
+        let genome = |s: &str| transcript.transcript_to_genome(&s.parse().unwrap());
+
+        assert!(matches!(genome("21"), Err(Error::OutOfBounds(_))));
+        assert!(matches!(genome("-1"), Err(Error::OutOfBounds(_))));
+        assert!(matches!(genome("5+1"), Err(Error::Offset(_))));
+        assert!(matches!(genome("1-1"), Err(Error::Offset(_))));
+        assert!(matches!(genome("10+11"), Err(Error::Offset(_))));
+        assert_eq!(genome("10+10").unwrap(), coordinate("chr1:+:20"));
+        assert!(matches!(genome("20+1"), Err(Error::Offset(_))));
+        assert!(matches!(genome("*1+1"), Err(Error::Offset(_))));
+        assert_eq!(genome("10+9").unwrap(), coordinate("chr1:+:19"));
+        assert_eq!(genome("11-9").unwrap(), coordinate("chr1:+:12"));
+
+        assert!(matches!(
+            transcript.cds_to_transcript(&"12".parse().unwrap()),
+            Err(Error::OutOfBounds(_))
+        ));
+
+        let err = transcript.locate(&coordinate("chr2:+:5")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "mismatched contigs: expected `chr1`, found `chr2`"
+        );
+    }
+
+    #[test]
+    fn invalid_transcripts() {
+        let exons = |exons: &[&str]| {
+            exons
+                .iter()
+                .map(|exon| exon.parse::<Interval<Base>>().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            Transcript::<Number>::try_new(Vec::new(), None),
+            Err(Error::Empty)
+        );
+        assert_eq!(
+            Transcript::try_new(exons(&["chr1:+:21-30", "chr1:+:1-10"]), None),
+            Err(Error::Unordered(2))
+        );
+        assert_eq!(
+            Transcript::try_new(exons(&["chr1:+:1-10", "chr1:-:30-21"]), None),
+            Err(Error::MismatchedStrands {
+                expected: Strand::Positive,
+                found: Strand::Negative
+            })
+        );
+        assert_eq!(
+            Transcript::try_new(
+                exons(&["chr1:+:1-10", "chr1:+:21-30"]),
+                Some("chr1:+:5-15".parse().unwrap())
+            ),
+            Err(Error::CdsBoundary(15))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        for s in ["1", "-15", "*7", "123+5", "124-3", "-15+3", "*20-5"] {
+            assert_eq!(s.parse::<TranscriptPosition>().unwrap().to_string(), s);
+        }
+
+        for s in ["", "0", "-0", "*", "1+", "1+0", "+1", "1*2", "a", "1-2-3"] {
+            assert_eq!(
+                s.parse::<TranscriptPosition>(),
+                Err(ParseError::Invalid(s.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,551 @@
+//! Fixed-size windows over intervals and assemblies.
+//!
+//! A [`Tiling`] describes how to divide a span of entities into windows: each
+//! window contains `size` entities, and consecutive windows begin `step`
+//! entities apart. A step equal to the size produces adjacent windows, a
+//! smaller step produces overlapping windows (e.g., for sliding-window
+//! statistics), and a larger step leaves gaps between windows.
+//!
+//! Windows are laid out in the direction of the strand, starting at the start
+//! of the tiled interval. When the final window would extend beyond the end of
+//! the interval, [`Trailing`] controls whether it is truncated or dropped.
+
+use core::marker::PhantomData;
+
+use thiserror::Error;
+
+use crate::Assembly;
+use crate::Contig;
+use crate::Interval;
+use crate::Position;
+use crate::Strand;
+use crate::System;
+use crate::interval;
+use crate::position;
+use crate::position::Width;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Errors
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An error related to a tiling.
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// A zero size error.
+    ///
+    /// This error occurs when a tiling is created with windows of size zero.
+    #[error("window size must be non-zero")]
+    ZeroSize,
+
+    /// A zero step error.
+    ///
+    /// This error occurs when a tiling is created with a step of zero.
+    #[error("window step must be non-zero")]
+    ZeroStep,
+
+    /// A width error.
+    ///
+    /// This error occurs when an assembly contains a contig whose length
+    /// cannot be represented within the [`Width`] of the tiled positions.
+    #[error(
+        "length `{length}` of contig `{contig}` cannot be represented as a {bits}-bit position"
+    )]
+    Width {
+        /// The contig.
+        contig: Contig,
+
+        /// The length of the contig.
+        length: u64,
+
+        /// The number of bits in the position representation.
+        bits: u32,
+    },
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Tilings
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// The handling of a final window that would extend beyond the end of the
+/// tiled interval.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Trailing {
+    /// Partial windows are truncated at the end of the interval.
+    ///
+    /// This is the behavior of `bedtools makewindows`.
+    #[default]
+    Truncate,
+
+    /// Partial windows are dropped, so every window contains exactly `size`
+    /// entities.
+    Drop,
+}
+
+/// A description of how to divide intervals into windows.
+///
+/// Sizes and steps are independent of the [position
+/// width](crate::position::Width) of the tiled intervals, so the same tiling
+/// may be applied to intervals in any coordinate system.
+///
+/// # Examples
+///
+/// ```
+/// use omics_coordinate::Interval;
+/// use omics_coordinate::system::Base;
+/// use omics_coordinate::window::Tiling;
+/// use omics_coordinate::window::Trailing;
+///
+/// let interval = "seq0:+:1-25".parse::<Interval<Base>>()?;
+/// let tiling = Tiling::try_new(10, 10)?;
+///
+/// let windows = tiling
+///     .tile(&interval)
+///     .map(|w| w.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(windows, vec!["seq0:+:1-10", "seq0:+:11-20", "seq0:+:21-25"]);
+///
+/// let tiling = tiling.with_trailing(Trailing::Drop);
+/// let windows = tiling
+///     .tile(&interval)
+///     .map(|w| w.to_string())
+///     .collect::<Vec<_>>();
+/// assert_eq!(windows, vec!["seq0:+:1-10", "seq0:+:11-20"]);
+///
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Tiling {
+    /// The number of entities within each window.
+    size: u64,
+
+    /// The number of entities between the starts of consecutive windows.
+    step: u64,
+
+    /// The handling of partial trailing windows.
+    trailing: Trailing,
+}
+
+impl Tiling {
+    /// Attempts to create a new tiling.
+    ///
+    /// Both the size and the step must be non-zero. Partial trailing windows
+    /// are truncated unless otherwise specified with
+    /// [`with_trailing()`](Self::with_trailing).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::window::Tiling;
+    ///
+    /// let tiling = Tiling::try_new(100, 50)?;
+    /// assert_eq!(tiling.size(), 100);
+    /// assert_eq!(tiling.step(), 50);
+    ///
+    /// assert!(Tiling::try_new(0, 50).is_err());
+    /// assert!(Tiling::try_new(100, 0).is_err());
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_new(size: u64, step: u64) -> Result<Self> {
+        if size == 0 {
+            return Err(Error::ZeroSize);
+        }
+
+        if step == 0 {
+            return Err(Error::ZeroStep);
+        }
+
+        Ok(Self {
+            size,
+            step,
+            trailing: Default::default(),
+        })
+    }
+
+    /// Consumes `self` and returns a tiling with the provided handling of
+    /// partial trailing windows.
+    #[must_use = "this method returns a new tiling"]
+    pub fn with_trailing(mut self, trailing: Trailing) -> Self {
+        self.trailing = trailing;
+        self
+    }
+
+    /// Gets the number of entities within each window.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Gets the number of entities between the starts of consecutive windows.
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Gets the handling of partial trailing windows.
+    pub fn trailing(&self) -> Trailing {
+        self.trailing
+    }
+
+    /// Gets the number of windows produced for a span of `count` entities.
+    fn count_windows(&self, count: u64) -> u64 {
+        match self.trailing {
+            Trailing::Truncate => count.div_ceil(self.step),
+            Trailing::Drop if count < self.size => 0,
+            Trailing::Drop => (count - self.size) / self.step + 1,
+        }
+    }
+
+    /// Divides an interval into windows.
+    ///
+    /// Windows are produced in the direction of the strand and share the
+    /// contig, strand, and coordinate system of the interval. Zero-sized
+    /// interbase intervals produce no windows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_coordinate::Interval;
+    /// use omics_coordinate::system::Interbase;
+    /// use omics_coordinate::window::Tiling;
+    ///
+    /// let interval = "seq0:-:30-0".parse::<Interval<Interbase>>()?;
+    /// let tiling = Tiling::try_new(20, 10)?;
+    ///
+    /// let windows = tiling.tile(&interval);
+    /// assert_eq!(windows.len(), 3);
+    ///
+    /// let windows = windows.map(|w| w.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(windows, vec!["seq0:-:30-10", "seq0:-:20-0", "seq0:-:10-0"]);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tile<S: System>(&self, interval: &Interval<S>) -> Windows<S>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let (start, end) = <Interval<S> as interval::r#trait::Interval<S>>::entity_bounds(interval);
+        self.tile_entities(interval.contig().clone(), interval.strand(), start, end)
+    }
+
+    /// Divides every contig of an assembly into windows.
+    ///
+    /// Contigs are tiled in the canonical order of the assembly, and each
+    /// contig is tiled in its entirety on the provided strand. If the length
+    /// of any contig cannot be represented within the [`Width`] of the
+    /// coordinate system, an [`Error::Width`] is returned before any windows
+    /// are produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "std")] {
+    /// use omics_coordinate::Assembly;
+    /// use omics_coordinate::Strand;
+    /// use omics_coordinate::system::Base;
+    /// use omics_coordinate::window::Tiling;
+    ///
+    /// let assembly = Assembly::read_fai("chr1\t250\nchr2\t120\n".as_bytes())?;
+    /// let tiling = Tiling::try_new(100, 100)?;
+    ///
+    /// let windows = tiling
+    ///     .tile_assembly::<Base>(&assembly, Strand::Positive)?
+    ///     .map(|w| w.to_string())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     windows,
+    ///     vec![
+    ///         "chr1:+:1-100",
+    ///         "chr1:+:101-200",
+    ///         "chr1:+:201-250",
+    ///         "chr2:+:1-100",
+    ///         "chr2:+:101-120",
+    ///     ]
+    /// );
+    ///
+    /// let assembly = Assembly::read_fai("chr1\t5000000000\n".as_bytes())?;
+    /// assert!(
+    ///     tiling
+    ///         .tile_assembly::<Base>(&assembly, Strand::Positive)
+    ///         .is_err()
+    /// );
+    /// assert!(
+    ///     tiling
+    ///         .tile_assembly::<Base<u64>>(&assembly, Strand::Positive)
+    ///         .is_ok()
+    /// );
+    /// # }
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn tile_assembly<'a, S: System + 'a>(
+        self,
+        assembly: &'a Assembly,
+        strand: Strand,
+    ) -> Result<impl Iterator<Item = Interval<S>> + 'a>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        if let Some(entry) = assembly
+            .iter()
+            .find(|entry| S::Number::from_u64(entry.length()).is_none())
+        {
+            return Err(Error::Width {
+                contig: entry.contig().clone(),
+                length: entry.length(),
+                bits: S::Number::BITS,
+            });
+        }
+
+        Ok(assembly.iter().flat_map(move |entry| {
+            // SAFETY: the length of every contig was checked to be
+            // representable above.
+            let length = S::Number::from_u64(entry.length()).unwrap();
+            self.tile_entities(entry.contig().clone(), strand, S::Number::ZERO, length)
+        }))
+    }
+
+    /// Divides a half-open range of entities into windows.
+    fn tile_entities<S: System>(
+        &self,
+        contig: Contig,
+        strand: Strand,
+        start: S::Number,
+        end: S::Number,
+    ) -> Windows<S> {
+        let count = end.saturating_sub(start).to_u64();
+
+        Windows {
+            contig,
+            strand,
+            start,
+            end,
+            tiling: *self,
+            offset: 0,
+            remaining: self.count_windows(count),
+            system: PhantomData,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Windows
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the windows of an interval.
+///
+/// This is created by [`Tiling::tile()`].
+#[derive(Clone, Debug)]
+pub struct Windows<S: System> {
+    /// The contig.
+    contig: Contig,
+
+    /// The strand.
+    strand: Strand,
+
+    /// The start of the tiled entities as an interbase position.
+    start: S::Number,
+
+    /// The end of the tiled entities as an interbase position.
+    end: S::Number,
+
+    /// The tiling.
+    tiling: Tiling,
+
+    /// The offset of the next window from the start of the tiled interval in
+    /// the direction of the strand.
+    offset: u64,
+
+    /// The number of windows remaining.
+    remaining: u64,
+
+    /// The coordinate system.
+    system: PhantomData<S>,
+}
+
+impl<S: System> Iterator for Windows<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+    type Item = Interval<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let count = (self.end - self.start).to_u64();
+
+        // SAFETY: both offsets are clamped to the number of tiled entities,
+        // which is itself representable in the width of the system.
+        let near = S::Number::from_u64(self.offset.min(count)).unwrap();
+        let far =
+            S::Number::from_u64(self.offset.saturating_add(self.tiling.size).min(count)).unwrap();
+
+        let (start, end) = match self.strand {
+            Strand::Positive | Strand::Unstranded => (self.start + near, self.start + far),
+            Strand::Negative => (self.end - far, self.end - near),
+        };
+
+        self.remaining -= 1;
+        self.offset = self.offset.saturating_add(self.tiling.step);
+
+        // SAFETY: every window begins before the end of the tiled entities, so
+        // it contains at least one entity.
+        Some(
+            <Interval<S> as interval::r#trait::Interval<S>>::from_entity_bounds(
+                self.contig.clone(),
+                self.strand,
+                start,
+                end,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.remaining).unwrap_or(usize::MAX);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<S: System> ExactSizeIterator for Windows<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+}
+
+impl<S: System> core::iter::FusedIterator for Windows<S>
+where
+    Interval<S>: interval::r#trait::Interval<S>,
+    Position<S>: position::r#trait::Position<S>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::assembly::Entry;
+    use crate::position::Number;
+    use crate::system::Base;
+    use crate::system::Interbase;
+
+    fn tile<S: System>(tiling: Tiling, interval: &str) -> Vec<String>
+    where
+        Interval<S>: interval::r#trait::Interval<S>,
+        Position<S>: position::r#trait::Position<S>,
+    {
+        let interval = interval.parse::<Interval<S>>().unwrap();
+        let windows = tiling.tile(&interval);
+        let expected = windows.len();
+
+        let windows = windows.map(|w| w.to_string()).collect::<Vec<_>>();
+        assert_eq!(windows.len(), expected);
+        windows
+    }
+
+    #[test]
+    fn overlapping_and_gapped() {
+        let tiling = Tiling::try_new(4, 2).unwrap();
+        assert_eq!(
+            tile::<Base>(tiling, "seq0:+:1-8"),
+            vec!["seq0:+:1-4", "seq0:+:3-6", "seq0:+:5-8", "seq0:+:7-8"]
+        );
+        assert_eq!(
+            tile::<Base>(tiling.with_trailing(Trailing::Drop), "seq0:+:1-8"),
+            vec!["seq0:+:1-4", "seq0:+:3-6", "seq0:+:5-8"]
+        );
+
+        let tiling = Tiling::try_new(2, 5).unwrap();
+        assert_eq!(
+            tile::<Interbase>(tiling, "seq0:-:12-0"),
+            vec!["seq0:-:12-10", "seq0:-:7-5", "seq0:-:2-0"]
+        );
+    }
+
+    #[test]
+    fn small_intervals() {
+        let tiling = Tiling::try_new(10, 10).unwrap();
+        assert_eq!(tile::<Base>(tiling, "seq0:.:5-5"), vec!["seq0:.:5-5"]);
+        assert!(tile::<Interbase>(tiling, "seq0:+:5-5").is_empty());
+
+        let tiling = tiling.with_trailing(Trailing::Drop);
+        assert!(tile::<Base>(tiling, "seq0:+:1-9").is_empty());
+        assert_eq!(tile::<Base>(tiling, "seq0:+:1-10"), vec!["seq0:+:1-10"]);
+    }
+
+    #[test]
+    fn large_steps_do_not_overflow() {
+        let tiling = Tiling::try_new(u64::MAX, u64::MAX).unwrap();
+        let interval = format!("seq0:+:1-{}", Number::MAX)
+            .parse::<Interval<Base>>()
+            .unwrap();
+
+        let windows = tiling.tile(&interval).collect::<Vec<_>>();
+        assert_eq!(windows, vec![interval]);
+
+        let interval = format!("seq0:+:1-{}", u64::MAX)
+            .parse::<Interval<Base<u64>>>()
+            .unwrap();
+
+        let windows = tiling.tile(&interval).collect::<Vec<_>>();
+        assert_eq!(windows, vec![interval]);
+    }
+
+    #[test]
+    fn assembly() {
+        let assembly = Assembly::try_new([
+            Entry::new(Contig::new_unchecked("chr1"), 5),
+            Entry::new(Contig::new_unchecked("chr2"), 0),
+            Entry::new(Contig::new_unchecked("chr3"), 3),
+        ])
+        .unwrap();
+        let tiling = Tiling::try_new(3, 3).unwrap();
+
+        let windows = tiling
+            .tile_assembly::<Interbase>(&assembly, Strand::Negative)
+            .unwrap()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(windows, vec!["chr1:-:5-2", "chr1:-:2-0", "chr3:-:3-0"]);
+    }
+
+    #[test]
+    fn assembly_wider_than_positions() {
+        let assembly = Assembly::try_new([
+            Entry::new(Contig::new_unchecked("chr1"), 10),
+            Entry::new(Contig::new_unchecked("chr2"), 5_000_000_000),
+        ])
+        .unwrap();
+        let tiling = Tiling::try_new(1_000_000_000, 1_000_000_000).unwrap();
+
+        let err = tiling
+            .tile_assembly::<Base>(&assembly, Strand::Positive)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "length `5000000000` of contig `chr2` cannot be represented as a 32-bit position"
+        );
+
+        let windows = tiling
+            .tile_assembly::<Base<u64>>(&assembly, Strand::Positive)
+            .unwrap()
+            .map(|w| w.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(windows.len(), 6);
+        assert_eq!(windows[5], "chr2:+:4000000001-5000000000");
+    }
+}
@@ -1,4 +1,9 @@
 //! Common components used within the `omics` family of crates.
+//!
+//! The crate is `no_std` compatible when the default `std` feature is
+//! disabled.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 /// The separator character between elements within a variant.
 pub const VARIANT_SEPARATOR: &str = ":";
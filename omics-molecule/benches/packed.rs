@@ -0,0 +1,111 @@
+//! Benchmarks for packed DNA sequences.
+#![expect(
+    missing_docs,
+    reason = "criterion_group generates undocumented registration functions"
+)]
+
+use std::hint::black_box;
+
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use omics_molecule::polymer::dna;
+use omics_molecule::polymer::dna::packed::PackedSequence;
+use omics_molecule::sequence::Sequence;
+
+#[path = "../src/testing.rs"]
+mod testing;
+
+use testing::Lcg;
+
+/// The number of nucleotides in each benchmarked sequence.
+const LEN: usize = 1_000_000;
+
+/// Generates a pseudo-random DNA sequence of [`LEN`] nucleotides.
+fn generate(seed: u64) -> Sequence<dna::Nucleotide> {
+    let mut lcg = Lcg::new(seed);
+
+    let nucleotides = (0..LEN)
+        .map(|_| match lcg.below(4) {
+            0 => dna::Nucleotide::A,
+            1 => dna::Nucleotide::C,
+            2 => dna::Nucleotide::G,
+            _ => dna::Nucleotide::T,
+        })
+        .collect();
+
+    Sequence::new(nucleotides)
+}
+
+/// Registers reverse complement benchmarks for packed and unpacked sequences.
+fn reverse_complement_benches(c: &mut Criterion) {
+    let sequence = generate(0);
+    let packed = PackedSequence::from(&sequence);
+
+    c.bench_function("packed::reverse_complement", |b| {
+        b.iter(|| black_box(&packed).reverse_complement())
+    });
+
+    c.bench_function("packed::slice::reverse_complement", |b| {
+        b.iter(|| black_box(&packed).slice(13..LEN - 7).reverse_complement())
+    });
+
+    c.bench_function("sequence::reverse_complement", |b| {
+        b.iter(|| black_box(&sequence).reverse_complement())
+    });
+}
+
+/// Registers GC content benchmarks for packed and unpacked sequences.
+fn gc_content_benches(c: &mut Criterion) {
+    let sequence = generate(1);
+    let packed = PackedSequence::from(&sequence);
+    let molecule = dna::Molecule::from(sequence.into_inner());
+
+    c.bench_function("packed::gc_content", |b| {
+        b.iter(|| black_box(&packed).gc_content())
+    });
+
+    c.bench_function("molecule::gc_content", |b| {
+        b.iter(|| black_box(&molecule).gc_content())
+    });
+}
+
+/// Registers shared prefix benchmarks for packed and unpacked sequences that
+/// differ only in their final nucleotide.
+fn shared_prefix_benches(c: &mut Criterion) {
+    let a = generate(2);
+    let mut nucleotides = a.inner().to_vec();
+    nucleotides[LEN - 1] = match nucleotides[LEN - 1] {
+        dna::Nucleotide::A => dna::Nucleotide::C,
+        _ => dna::Nucleotide::A,
+    };
+    let b = Sequence::new(nucleotides);
+
+    let (packed_a, packed_b) = (PackedSequence::from(&a), PackedSequence::from(&b));
+
+    c.bench_function("packed::shared_prefix_len", |bencher| {
+        bencher.iter(|| black_box(&packed_a).shared_prefix_len(black_box(&packed_b)))
+    });
+
+    c.bench_function("sequence::shared_prefix_len", |bencher| {
+        bencher.iter(|| black_box(&a).shared_prefix_len(black_box(&b)))
+    });
+}
+
+/// Registers random access benchmarks for packed sequences.
+fn get_benches(c: &mut Criterion) {
+    let packed = PackedSequence::from(&generate(3));
+
+    c.bench_function("packed::get", |b| {
+        b.iter(|| black_box(&packed).get(black_box(LEN / 2 + 7)))
+    });
+}
+
+criterion_group!(
+    benches,
+    reverse_complement_benches,
+    gc_content_benches,
+    shared_prefix_benches,
+    get_benches
+);
+criterion_main!(benches);
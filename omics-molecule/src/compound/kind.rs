@@ -8,6 +8,10 @@ pub enum Kind {
 
     /// Pyrimidine nucleotide.
     Pyrimidine,
+
+    /// Ambiguous nucleotide that may be either a purine or a pyrimidine (e.g.,
+    /// the IUPAC code `N`).
+    Ambiguous,
 }
 
 #[cfg(test)]
@@ -8,10 +8,24 @@ use crate::compound::Kind;
 
 /// A marker trait that denotes a type of nucleotide.
 pub trait Nucleotide:
-    std::fmt::Debug + std::fmt::Display + Copy + Eq + PartialEq + std::hash::Hash + std::str::FromStr
+    core::fmt::Debug
+    + core::fmt::Display
+    + Copy
+    + Eq
+    + PartialEq
+    + core::hash::Hash
+    + core::str::FromStr
 {
     /// Gets the [`Kind`] type for a given [`Nucleotide`].
     fn kind(&self) -> Kind;
+
+    /// Returns whether this [`Nucleotide`] represents more than one strict
+    /// nucleotide (e.g., an IUPAC ambiguity code such as `R` or `N`).
+    ///
+    /// Strict nucleotides are never ambiguous, which is the default.
+    fn is_ambiguous(&self) -> bool {
+        false
+    }
 }
 
 /// A trait that provides methods to convert a [`Nucleotide`] to an analogous
@@ -15,6 +15,10 @@
 //! Importantly, both nucleotides cannot be missing. Attempting to
 //! create such a [`Relation`] will result in a [`Error::Empty`].
 
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+
 pub mod substitution;
 
 use omics_core::MISSING_NUCLEOTIDE;
@@ -40,8 +44,8 @@ pub enum Error<N: Nucleotide> {
     Substitution(#[from] substitution::Error<N>),
 }
 
-/// A [`Result`](std::result::Result) with an [`Error`].
-type Result<T, N> = std::result::Result<T, Error<N>>;
+/// A [`Result`](core::result::Result) with an [`Error`].
+type Result<T, N> = core::result::Result<T, Error<N>>;
 
 /// A relation between an expected [`Nucleotide`] and the existing
 /// [`Nucleotide`].
@@ -229,7 +233,7 @@ impl<N: Nucleotide> From<Relation<N>> for (Option<N>, Option<N>) {
     }
 }
 
-impl<N: Nucleotide> std::str::FromStr for Relation<N> {
+impl<N: Nucleotide> core::str::FromStr for Relation<N> {
     type Err = Error<N>;
 
     fn from_str(s: &str) -> Result<Self, N> {
@@ -269,6 +273,9 @@ impl<N: Nucleotide> std::str::FromStr for Relation<N> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::ToString;
+
     use super::*;
     use crate::compound::nucleotide::relation::substitution::Kind;
     use crate::polymer::dna;
@@ -276,7 +283,7 @@ mod tests {
 
     #[test]
     fn it_correctly_identifies_all_dna_transitions()
-    -> std::result::Result<(), Box<dyn std::error::Error>> {
+    -> core::result::Result<(), Box<dyn core::error::Error>> {
         let relation = "A:G".parse::<Relation<dna::Nucleotide>>()?;
         let substitution = relation.as_substitution().unwrap();
         assert_eq!(substitution.kind(), Kind::Transition);
@@ -298,7 +305,7 @@ mod tests {
 
     #[test]
     fn it_correctly_identifies_all_dna_transversions()
-    -> std::result::Result<(), Box<dyn std::error::Error>> {
+    -> core::result::Result<(), Box<dyn core::error::Error>> {
         let relation = "A:C".parse::<Relation<dna::Nucleotide>>()?;
         let substitution = relation.as_substitution().unwrap();
         assert_eq!(substitution.kind(), Kind::Transversion);
@@ -336,7 +343,7 @@ mod tests {
 
     #[test]
     fn it_correctly_identifies_all_rna_transitions()
-    -> std::result::Result<(), Box<dyn std::error::Error>> {
+    -> core::result::Result<(), Box<dyn core::error::Error>> {
         let relation = "A:G".parse::<Relation<rna::Nucleotide>>()?;
         let substitution = relation.as_substitution().unwrap();
         assert_eq!(substitution.kind(), Kind::Transition);
@@ -358,7 +365,7 @@ mod tests {
 
     #[test]
     fn it_correctly_identifies_all_rna_transversions()
-    -> std::result::Result<(), Box<dyn std::error::Error>> {
+    -> core::result::Result<(), Box<dyn core::error::Error>> {
         let relation = "A:C".parse::<Relation<rna::Nucleotide>>()?;
         let substitution = relation.as_substitution().unwrap();
         assert_eq!(substitution.kind(), Kind::Transversion);
@@ -395,8 +402,8 @@ mod tests {
     }
 
     #[test]
-    fn it_correctly_identifies_an_insertion() -> std::result::Result<(), Box<dyn std::error::Error>>
-    {
+    fn it_correctly_identifies_an_insertion()
+    -> core::result::Result<(), Box<dyn core::error::Error>> {
         assert_eq!(
             ".:T".parse::<Relation<dna::Nucleotide>>()?,
             Relation::Insertion(dna::Nucleotide::T)
@@ -406,7 +413,8 @@ mod tests {
     }
 
     #[test]
-    fn it_correctly_identifies_a_deletion() -> std::result::Result<(), Box<dyn std::error::Error>> {
+    fn it_correctly_identifies_a_deletion() -> core::result::Result<(), Box<dyn core::error::Error>>
+    {
         assert_eq!(
             "T:.".parse::<Relation<dna::Nucleotide>>()?,
             Relation::Deletion(dna::Nucleotide::T)
@@ -416,7 +424,7 @@ mod tests {
     }
 
     #[test]
-    fn it_does_not_allow_an_empty_relation() -> std::result::Result<(), Box<dyn std::error::Error>>
+    fn it_does_not_allow_an_empty_relation() -> core::result::Result<(), Box<dyn core::error::Error>>
     {
         assert_eq!(
             ".:."
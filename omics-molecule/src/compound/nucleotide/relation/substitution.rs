@@ -1,5 +1,6 @@
 //! Substitutions.
 
+use crate::compound::Kind as CompoundKind;
 use crate::compound::Nucleotide;
 
 mod kind;
@@ -16,8 +17,8 @@ pub enum Error<N: Nucleotide> {
     Identical(N),
 }
 
-/// A [`Result`](std::result::Result) with an [`Error<N>`].
-type Result<T, N> = std::result::Result<T, Error<N>>;
+/// A [`Result`](core::result::Result) with an [`Error<N>`].
+type Result<T, N> = core::result::Result<T, Error<N>>;
 
 /// The substitution of a reference nucleotide wth an alternate nucleotide.
 #[derive(Debug, Eq, PartialEq)]
@@ -59,6 +60,11 @@ impl<N: Nucleotide> Substitution<N> {
 
     /// Gets the [`Kind`] for this [`Substitution`].
     ///
+    /// If either nucleotide is [ambiguous](Nucleotide::is_ambiguous), the
+    /// substitution is [`Kind::Ambiguous`], even when every nucleotide it
+    /// stands for has the same compound kind (e.g., `A` to `R`): the
+    /// alternate may be the reference itself, so no change can be asserted.
+    ///
     /// ```
     /// use omics_molecule::compound::nucleotide::relation::Substitution;
     /// use omics_molecule::compound::nucleotide::relation::substitution::Kind;
@@ -71,10 +77,14 @@ impl<N: Nucleotide> Substitution<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn kind(&self) -> Kind {
-        if self.reference.kind() == self.alternate.kind() {
-            Kind::Transition
-        } else {
-            Kind::Transversion
+        if self.reference.is_ambiguous() || self.alternate.is_ambiguous() {
+            return Kind::Ambiguous;
+        }
+
+        match (self.reference.kind(), self.alternate.kind()) {
+            (CompoundKind::Ambiguous, _) | (_, CompoundKind::Ambiguous) => Kind::Ambiguous,
+            (reference, alternate) if reference == alternate => Kind::Transition,
+            _ => Kind::Transversion,
         }
     }
 
@@ -129,11 +139,13 @@ impl<N: Nucleotide> Substitution<N> {
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
+
     use super::*;
     use crate::polymer::dna::Nucleotide;
 
     #[test]
-    fn it_correctly_creates_a_substitution() -> std::result::Result<(), Box<dyn std::error::Error>>
+    fn it_correctly_creates_a_substitution() -> core::result::Result<(), Box<dyn core::error::Error>>
     {
         let substitution = Substitution::try_new(Nucleotide::A, Nucleotide::T)?;
 
@@ -147,7 +159,7 @@ mod tests {
 
     #[test]
     fn it_correctly_refuses_to_create_a_substitution_with_identical_nucleotides()
-    -> std::result::Result<(), Box<dyn std::error::Error>> {
+    -> core::result::Result<(), Box<dyn core::error::Error>> {
         let substitution = Substitution::try_new(Nucleotide::A, Nucleotide::T)?;
 
         assert_eq!(substitution.kind(), Kind::Transversion);
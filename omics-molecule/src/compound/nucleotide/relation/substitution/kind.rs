@@ -12,4 +12,9 @@ pub enum Kind {
     /// nucleobase of a different [compound
     /// kind](crate::compound::nucleotide::Kind).
     Transversion,
+
+    /// The reference or alternate nucleotide is
+    /// [ambiguous](crate::compound::Nucleotide::is_ambiguous), so the
+    /// substitution cannot be classified.
+    Ambiguous,
 }
@@ -1,5 +1,17 @@
 //! Molecules.
+//!
+//! The crate depends on the standard library through the `std` feature, which
+//! is enabled by default. Disabling default features builds the crate for
+//! `no_std` targets that provide a global allocator (`alloc`) without any loss
+//! of functionality.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod compound;
 pub mod polymer;
 pub mod sequence;
+
+#[cfg(test)]
+mod testing;
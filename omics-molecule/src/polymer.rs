@@ -1,4 +1,5 @@
 //! A substance comprised of repeating subunits of macromolecules.
 
 pub mod dna;
+pub mod protein;
 pub mod rna;
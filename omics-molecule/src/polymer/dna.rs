@@ -1,6 +1,10 @@
 //! Deoxyribonucleic Acid.
 
+use alloc::vec::Vec;
+
+pub mod iupac;
 mod nucleotide;
+pub mod packed;
 
 pub use nucleotide::Nucleotide;
 use thiserror::Error;
@@ -85,7 +89,7 @@ impl From<Vec<Nucleotide>> for Molecule {
     }
 }
 
-impl std::str::FromStr for Molecule {
+impl core::str::FromStr for Molecule {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -98,6 +102,10 @@ impl std::str::FromStr for Molecule {
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::ToString;
+    use alloc::vec;
+
     use super::*;
 
     #[test]
@@ -109,7 +117,7 @@ mod tests {
     }
 
     #[test]
-    fn it_parses_a_molecule_from_a_valid_string() -> Result<(), Box<dyn std::error::Error>> {
+    fn it_parses_a_molecule_from_a_valid_string() -> Result<(), Box<dyn core::error::Error>> {
         Ok("ACGT".parse::<Molecule>().map(|_| ())?)
     }
 
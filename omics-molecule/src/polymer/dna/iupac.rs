@@ -0,0 +1,523 @@
+//! IUPAC nucleotides in DNA.
+//!
+//! Reference sequences contain runs of unknown bases (`N`) and primers often
+//! contain degenerate bases (e.g., `R` for either `A` or `G`). The
+//! [IUPAC](https://iupac.qmul.ac.uk/misc/naabb.html) [`Nucleotide`] represents
+//! each code as the set of [strict nucleotides](dna::Nucleotide) that it
+//! stands for, so it can be used wherever a strict nucleotide can (e.g., within
+//! a [`Sequence`](crate::sequence::Sequence)) while still supporting ambiguity.
+//!
+//! # Examples
+//!
+//! ```
+//! use omics_molecule::compound::Complement;
+//! use omics_molecule::polymer::dna;
+//! use omics_molecule::polymer::dna::iupac::Nucleotide;
+//! use omics_molecule::sequence::Sequence;
+//!
+//! let primer = "ACRN".parse::<Sequence<Nucleotide>>()?;
+//! assert_eq!(primer.reverse_complement().to_string(), "NYGT");
+//!
+//! assert!(Nucleotide::R.matches(&Nucleotide::A));
+//! assert!(!Nucleotide::R.matches(&Nucleotide::C));
+//! assert!(Nucleotide::R.is_compatible(&Nucleotide::M));
+//!
+//! assert_eq!(Nucleotide::from(dna::Nucleotide::G), Nucleotide::G);
+//! assert_eq!(
+//!     dna::Nucleotide::try_from(Nucleotide::G)?,
+//!     dna::Nucleotide::G
+//! );
+//! assert!(dna::Nucleotide::try_from(Nucleotide::N).is_err());
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::string::String;
+use alloc::string::ToString;
+
+use thiserror::Error;
+
+use crate::compound::Complement;
+use crate::compound::Kind;
+use crate::compound::nucleotide::Analogous;
+use crate::compound::nucleotide::Transcribe;
+use crate::polymer::dna;
+use crate::polymer::rna;
+
+/// The bit denoting adenine.
+const A: u8 = 0b0001;
+
+/// The bit denoting cytosine.
+const C: u8 = 0b0010;
+
+/// The bit denoting guanine.
+const G: u8 = 0b0100;
+
+/// The bit denoting thymine.
+const T: u8 = 0b1000;
+
+/// An error when parsing an IUPAC nucleotide.
+#[derive(Error, Debug)]
+pub enum ParseError {
+    /// An invalid format was attempted to be parsed.
+    #[error("invalid nucleotide format `{0}`")]
+    InvalidFormat(String),
+
+    /// An invalid nucleotide was attempted to be parsed.
+    #[error("invalid nucleotide `{0}`")]
+    InvalidNucleotide(char),
+}
+
+/// An error related to an IUPAC [`Nucleotide`].
+#[derive(Error, Debug)]
+pub enum Error {
+    /// An invalid nucleotide was attempted to be created from a [`char`].
+    #[error("invalid nucleotide `{0}`")]
+    InvalidNucleotide(char),
+
+    /// An ambiguous nucleotide was attempted to be converted to a strict
+    /// nucleotide.
+    #[error("ambiguous nucleotide `{0}` cannot be converted to a strict nucleotide")]
+    Ambiguous(Nucleotide),
+
+    /// A parse error.
+    #[error(transparent)]
+    ParseError(#[from] ParseError),
+}
+
+/// An IUPAC nucleotide in a DNA context.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Nucleotide {
+    /// Adenine.
+    A,
+
+    /// Cytosine.
+    C,
+
+    /// Guanine.
+    G,
+
+    /// Thymine.
+    T,
+
+    /// A purine (`A` or `G`).
+    R,
+
+    /// A pyrimidine (`C` or `T`).
+    Y,
+
+    /// A strong base (`C` or `G`).
+    S,
+
+    /// A weak base (`A` or `T`).
+    W,
+
+    /// A keto base (`G` or `T`).
+    K,
+
+    /// An amino base (`A` or `C`).
+    M,
+
+    /// Not `A` (`C`, `G`, or `T`).
+    B,
+
+    /// Not `C` (`A`, `G`, or `T`).
+    D,
+
+    /// Not `G` (`A`, `C`, or `T`).
+    H,
+
+    /// Not `T` (`A`, `C`, or `G`).
+    V,
+
+    /// Any base (`A`, `C`, `G`, or `T`).
+    N,
+}
+
+impl Nucleotide {
+    /// Gets the set of strict nucleotides represented by this nucleotide as
+    /// a bitmask.
+    fn bits(&self) -> u8 {
+        match self {
+            Nucleotide::A => A,
+            Nucleotide::C => C,
+            Nucleotide::G => G,
+            Nucleotide::T => T,
+            Nucleotide::R => A | G,
+            Nucleotide::Y => C | T,
+            Nucleotide::S => C | G,
+            Nucleotide::W => A | T,
+            Nucleotide::K => G | T,
+            Nucleotide::M => A | C,
+            Nucleotide::B => C | G | T,
+            Nucleotide::D => A | G | T,
+            Nucleotide::H => A | C | T,
+            Nucleotide::V => A | C | G,
+            Nucleotide::N => A | C | G | T,
+        }
+    }
+
+    /// Creates a nucleotide from a non-empty bitmask of strict nucleotides.
+    fn from_bits(bits: u8) -> Self {
+        match bits {
+            A => Nucleotide::A,
+            C => Nucleotide::C,
+            G => Nucleotide::G,
+            T => Nucleotide::T,
+            0b0101 => Nucleotide::R,
+            0b1010 => Nucleotide::Y,
+            0b0110 => Nucleotide::S,
+            0b1001 => Nucleotide::W,
+            0b1100 => Nucleotide::K,
+            0b0011 => Nucleotide::M,
+            0b1110 => Nucleotide::B,
+            0b1101 => Nucleotide::D,
+            0b1011 => Nucleotide::H,
+            0b0111 => Nucleotide::V,
+            _ => Nucleotide::N,
+        }
+    }
+
+    /// Returns whether this nucleotide represents more than one strict
+    /// nucleotide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna::iupac::Nucleotide;
+    ///
+    /// assert!(!Nucleotide::A.is_ambiguous());
+    /// assert!(Nucleotide::N.is_ambiguous());
+    /// ```
+    pub fn is_ambiguous(&self) -> bool {
+        self.bits().count_ones() > 1
+    }
+
+    /// Gets the strict nucleotides represented by this nucleotide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna;
+    /// use omics_molecule::polymer::dna::iupac::Nucleotide;
+    ///
+    /// assert_eq!(
+    ///     Nucleotide::B.bases().collect::<Vec<_>>(),
+    ///     vec![dna::Nucleotide::C, dna::Nucleotide::G, dna::Nucleotide::T]
+    /// );
+    /// ```
+    pub fn bases(self) -> impl Iterator<Item = dna::Nucleotide> {
+        [
+            dna::Nucleotide::A,
+            dna::Nucleotide::C,
+            dna::Nucleotide::G,
+            dna::Nucleotide::T,
+        ]
+        .into_iter()
+        .filter(move |base| self.matches(&Nucleotide::from(*base)))
+    }
+
+    /// Returns whether every strict nucleotide represented by `other` is also
+    /// represented by this nucleotide.
+    ///
+    /// For example, `N` matches every nucleotide, `R` matches `A`, `G`, and
+    /// `R`, and `A` only matches `A`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna::iupac::Nucleotide;
+    ///
+    /// assert!(Nucleotide::N.matches(&Nucleotide::R));
+    /// assert!(Nucleotide::R.matches(&Nucleotide::G));
+    /// assert!(!Nucleotide::G.matches(&Nucleotide::R));
+    /// ```
+    pub fn matches(&self, other: &Nucleotide) -> bool {
+        self.bits() & other.bits() == other.bits()
+    }
+
+    /// Returns whether this nucleotide and `other` represent at least one
+    /// strict nucleotide in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna::iupac::Nucleotide;
+    ///
+    /// assert!(Nucleotide::R.is_compatible(&Nucleotide::S));
+    /// assert!(Nucleotide::G.is_compatible(&Nucleotide::R));
+    /// assert!(!Nucleotide::R.is_compatible(&Nucleotide::Y));
+    /// ```
+    pub fn is_compatible(&self, other: &Nucleotide) -> bool {
+        self.bits() & other.bits() != 0
+    }
+}
+
+impl crate::compound::Nucleotide for Nucleotide {
+    fn kind(&self) -> Kind {
+        match self {
+            Nucleotide::A | Nucleotide::G | Nucleotide::R => Kind::Purine,
+            Nucleotide::C | Nucleotide::T | Nucleotide::Y => Kind::Pyrimidine,
+            _ => Kind::Ambiguous,
+        }
+    }
+
+    fn is_ambiguous(&self) -> bool {
+        Nucleotide::is_ambiguous(self)
+    }
+}
+
+impl Analogous<rna::iupac::Nucleotide> for Nucleotide {
+    fn analogous(&self) -> rna::iupac::Nucleotide {
+        match self {
+            Nucleotide::A => rna::iupac::Nucleotide::A,
+            Nucleotide::C => rna::iupac::Nucleotide::C,
+            Nucleotide::G => rna::iupac::Nucleotide::G,
+            Nucleotide::T => rna::iupac::Nucleotide::U,
+            Nucleotide::R => rna::iupac::Nucleotide::R,
+            Nucleotide::Y => rna::iupac::Nucleotide::Y,
+            Nucleotide::S => rna::iupac::Nucleotide::S,
+            Nucleotide::W => rna::iupac::Nucleotide::W,
+            Nucleotide::K => rna::iupac::Nucleotide::K,
+            Nucleotide::M => rna::iupac::Nucleotide::M,
+            Nucleotide::B => rna::iupac::Nucleotide::B,
+            Nucleotide::D => rna::iupac::Nucleotide::D,
+            Nucleotide::H => rna::iupac::Nucleotide::H,
+            Nucleotide::V => rna::iupac::Nucleotide::V,
+            Nucleotide::N => rna::iupac::Nucleotide::N,
+        }
+    }
+}
+
+impl Transcribe<rna::iupac::Nucleotide> for Nucleotide {
+    fn transcribe(&self) -> rna::iupac::Nucleotide {
+        self.complement().analogous()
+    }
+}
+
+impl From<dna::Nucleotide> for Nucleotide {
+    fn from(nucleotide: dna::Nucleotide) -> Self {
+        match nucleotide {
+            dna::Nucleotide::A => Nucleotide::A,
+            dna::Nucleotide::C => Nucleotide::C,
+            dna::Nucleotide::G => Nucleotide::G,
+            dna::Nucleotide::T => Nucleotide::T,
+        }
+    }
+}
+
+impl TryFrom<Nucleotide> for dna::Nucleotide {
+    type Error = Error;
+
+    fn try_from(nucleotide: Nucleotide) -> Result<Self, Self::Error> {
+        match nucleotide {
+            Nucleotide::A => Ok(dna::Nucleotide::A),
+            Nucleotide::C => Ok(dna::Nucleotide::C),
+            Nucleotide::G => Ok(dna::Nucleotide::G),
+            Nucleotide::T => Ok(dna::Nucleotide::T),
+            _ => Err(Error::Ambiguous(nucleotide)),
+        }
+    }
+}
+
+impl core::fmt::Display for Nucleotide {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let c = match self {
+            Nucleotide::A => 'A',
+            Nucleotide::C => 'C',
+            Nucleotide::G => 'G',
+            Nucleotide::T => 'T',
+            Nucleotide::R => 'R',
+            Nucleotide::Y => 'Y',
+            Nucleotide::S => 'S',
+            Nucleotide::W => 'W',
+            Nucleotide::K => 'K',
+            Nucleotide::M => 'M',
+            Nucleotide::B => 'B',
+            Nucleotide::D => 'D',
+            Nucleotide::H => 'H',
+            Nucleotide::V => 'V',
+            Nucleotide::N => 'N',
+        };
+
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<char> for Nucleotide {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'A' => Ok(Nucleotide::A),
+            'C' => Ok(Nucleotide::C),
+            'G' => Ok(Nucleotide::G),
+            'T' => Ok(Nucleotide::T),
+            'R' => Ok(Nucleotide::R),
+            'Y' => Ok(Nucleotide::Y),
+            'S' => Ok(Nucleotide::S),
+            'W' => Ok(Nucleotide::W),
+            'K' => Ok(Nucleotide::K),
+            'M' => Ok(Nucleotide::M),
+            'B' => Ok(Nucleotide::B),
+            'D' => Ok(Nucleotide::D),
+            'H' => Ok(Nucleotide::H),
+            'V' => Ok(Nucleotide::V),
+            'N' => Ok(Nucleotide::N),
+            _ => Err(Error::InvalidNucleotide(c)),
+        }
+    }
+}
+
+impl core::str::FromStr for Nucleotide {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Nucleotide::try_from(c)
+                .map_err(|_| Error::ParseError(ParseError::InvalidNucleotide(c))),
+            _ => Err(Error::ParseError(ParseError::InvalidFormat(s.to_string()))),
+        }
+    }
+}
+
+impl crate::compound::Complement for Nucleotide {
+    fn complement(&self) -> Self {
+        // NOTE: complementing swaps `A` with `T` and `C` with `G` within the
+        // set of represented nucleotides.
+        let bits = self.bits();
+        let swapped = ((bits & A) << 3) | ((bits & T) >> 3) | ((bits & C) << 1) | ((bits & G) >> 1);
+        Self::from_bits(swapped)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Nucleotide {
+    /// Serializes a nucleotide as its single-letter code.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Nucleotide {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compound::nucleotide::relation::Substitution;
+    use crate::compound::nucleotide::relation::substitution;
+
+    /// Every IUPAC nucleotide.
+    const ALL: [Nucleotide; 15] = [
+        Nucleotide::A,
+        Nucleotide::C,
+        Nucleotide::G,
+        Nucleotide::T,
+        Nucleotide::R,
+        Nucleotide::Y,
+        Nucleotide::S,
+        Nucleotide::W,
+        Nucleotide::K,
+        Nucleotide::M,
+        Nucleotide::B,
+        Nucleotide::D,
+        Nucleotide::H,
+        Nucleotide::V,
+        Nucleotide::N,
+    ];
+
+    #[test]
+    fn it_complements_each_iupac_base() {
+        let complements = "TGCAYRSWMKVHDBN";
+
+        for (nucleotide, expected) in ALL.iter().zip(complements.chars()) {
+            assert_eq!(nucleotide.complement().to_string(), expected.to_string());
+            assert_eq!(nucleotide.complement().complement(), *nucleotide);
+        }
+    }
+
+    #[test]
+    fn it_round_trips_through_characters_and_bits() {
+        for nucleotide in ALL {
+            let s = nucleotide.to_string();
+            assert_eq!(s.parse::<Nucleotide>().unwrap(), nucleotide);
+            assert_eq!(s.to_lowercase().parse::<Nucleotide>().unwrap(), nucleotide);
+            assert_eq!(Nucleotide::from_bits(nucleotide.bits()), nucleotide);
+        }
+
+        let err = "U".parse::<Nucleotide>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid nucleotide `U`");
+
+        let err = "NN".parse::<Nucleotide>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid nucleotide format `NN`");
+    }
+
+    #[test]
+    fn it_converts_to_and_from_strict_nucleotides() {
+        for strict in [
+            dna::Nucleotide::A,
+            dna::Nucleotide::C,
+            dna::Nucleotide::G,
+            dna::Nucleotide::T,
+        ] {
+            let nucleotide = Nucleotide::from(strict);
+            assert!(!nucleotide.is_ambiguous());
+            assert_eq!(dna::Nucleotide::try_from(nucleotide).unwrap(), strict);
+        }
+
+        let err = dna::Nucleotide::try_from(Nucleotide::Y).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ambiguous nucleotide `Y` cannot be converted to a strict nucleotide"
+        );
+    }
+
+    #[test]
+    fn it_matches_sets_of_nucleotides() {
+        for nucleotide in ALL {
+            assert!(Nucleotide::N.matches(&nucleotide));
+            assert!(nucleotide.matches(&nucleotide));
+            assert!(nucleotide.is_compatible(&Nucleotide::N));
+            assert_eq!(
+                nucleotide.bases().count(),
+                nucleotide.bits().count_ones() as usize
+            );
+        }
+
+        assert!(Nucleotide::D.matches(&Nucleotide::W));
+        assert!(!Nucleotide::W.matches(&Nucleotide::D));
+        assert!(!Nucleotide::S.is_compatible(&Nucleotide::W));
+        assert!(Nucleotide::K.is_compatible(&Nucleotide::Y));
+    }
+
+    #[test]
+    fn it_classifies_substitutions_with_ambiguous_nucleotides() {
+        let substitution = Substitution::try_new(Nucleotide::A, Nucleotide::G).unwrap();
+        assert_eq!(substitution.kind(), substitution::Kind::Transition);
+
+        let substitution = Substitution::try_new(Nucleotide::A, Nucleotide::R).unwrap();
+        assert_eq!(substitution.kind(), substitution::Kind::Ambiguous);
+
+        let substitution = Substitution::try_new(Nucleotide::R, Nucleotide::Y).unwrap();
+        assert_eq!(substitution.kind(), substitution::Kind::Ambiguous);
+
+        let substitution = Substitution::try_new(Nucleotide::A, Nucleotide::N).unwrap();
+        assert_eq!(substitution.kind(), substitution::Kind::Ambiguous);
+    }
+
+    #[test]
+    fn it_transcribes_iupac_nucleotides() {
+        assert_eq!(Nucleotide::T.analogous(), rna::iupac::Nucleotide::U);
+        assert_eq!(Nucleotide::R.transcribe(), rna::iupac::Nucleotide::Y);
+        assert_eq!(Nucleotide::N.transcribe(), rna::iupac::Nucleotide::N);
+    }
+}
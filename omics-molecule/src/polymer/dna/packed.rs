@@ -0,0 +1,970 @@
+//! Two-bit packed DNA sequences.
+//!
+//! A [`Sequence`] or a [`Molecule`](dna::Molecule) stores one enum per byte,
+//! which is four times larger than necessary for the four DNA nucleotides. A
+//! [`PackedSequence`] instead stores 32 nucleotides within each [`u64`] word
+//! using two bits per nucleotide:
+//!
+//! | Nucleotide | Bits |
+//! | ---------- | ---- |
+//! | `A`        | `00` |
+//! | `C`        | `01` |
+//! | `G`        | `10` |
+//! | `T`        | `11` |
+//!
+//! The encoding is chosen so that complementing a nucleotide is a bitwise
+//! negation. Reverse complements, GC content, and shared prefix and suffix
+//! lengths are all computed a word at a time rather than a nucleotide at a
+//! time.
+//!
+//! Reference genomes contain long runs of unknown nucleotides (`N`), which
+//! cannot be represented within two bits. These are kept within a side table
+//! of runs (their bits within the words are zero), so a sequence with few
+//! runs of `N`s costs almost nothing extra.
+//!
+//! [Slicing](PackedSequence::slice()) produces a borrowed [`PackedSlice`]
+//! without copying any words. Indexing a single nucleotide is not constant
+//! time: looking up whether the nucleotide is unknown is a binary search, so it
+//! is logarithmic in the number of runs of `N`s.
+//!
+//! # Examples
+//!
+//! ```
+//! use omics_molecule::polymer::dna::iupac;
+//! use omics_molecule::polymer::dna::packed::PackedSequence;
+//!
+//! let sequence = "NNACGTTGCA".parse::<PackedSequence>()?;
+//! assert_eq!(sequence.len(), 10);
+//! assert_eq!(sequence.get(0), Some(iupac::Nucleotide::N));
+//! assert_eq!(sequence.get(3), Some(iupac::Nucleotide::C));
+//!
+//! let slice = sequence.slice(2..6);
+//! assert_eq!(slice.to_string(), "ACGT");
+//! assert_eq!(slice.gc_content(), Some(0.5));
+//!
+//! assert_eq!(sequence.reverse_complement().to_string(), "TGCAACGTNN");
+//!
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::ops::Bound;
+use core::ops::Range;
+use core::ops::RangeBounds;
+
+use thiserror::Error;
+
+use crate::polymer::dna;
+use crate::polymer::dna::iupac;
+use crate::sequence::Sequence;
+
+/// The number of nucleotides stored within a word.
+const NUCLEOTIDES_PER_WORD: usize = 32;
+
+/// A mask selecting the low bit of every nucleotide within a word.
+const LOW_BITS: u64 = 0x5555_5555_5555_5555;
+
+/// An error related to a [`PackedSequence`].
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// An invalid nucleotide was encountered while parsing.
+    #[error("invalid nucleotide `{0}`")]
+    InvalidNucleotide(char),
+
+    /// An ambiguous nucleotide other than `N` was encountered.
+    ///
+    /// Only unknown nucleotides (`N`) can be represented within a packed
+    /// sequence.
+    #[error("ambiguous nucleotide `{nucleotide}` at position {position} cannot be packed")]
+    Ambiguous {
+        /// The position of the nucleotide.
+        position: usize,
+
+        /// The nucleotide.
+        nucleotide: iupac::Nucleotide,
+    },
+
+    /// An unknown nucleotide (`N`) was encountered while converting to a
+    /// sequence of strict nucleotides.
+    #[error("unknown nucleotide at position {0} cannot be converted to a strict nucleotide")]
+    Unknown(usize),
+}
+
+/// A [`Result`](core::result::Result) with an [`Error`](enum@Error).
+pub type Result<T> = core::result::Result<T, Error>;
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Words
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// Encodes a strict nucleotide within two bits.
+fn encode(nucleotide: dna::Nucleotide) -> u64 {
+    match nucleotide {
+        dna::Nucleotide::A => 0b00,
+        dna::Nucleotide::C => 0b01,
+        dna::Nucleotide::G => 0b10,
+        dna::Nucleotide::T => 0b11,
+    }
+}
+
+/// Decodes a strict nucleotide from the low two bits of a value.
+fn decode(bits: u64) -> dna::Nucleotide {
+    match bits & 0b11 {
+        0b00 => dna::Nucleotide::A,
+        0b01 => dna::Nucleotide::C,
+        0b10 => dna::Nucleotide::G,
+        _ => dna::Nucleotide::T,
+    }
+}
+
+/// Gets a mask selecting the first `n` nucleotides of a word.
+fn mask(n: usize) -> u64 {
+    match n {
+        NUCLEOTIDES_PER_WORD => u64::MAX,
+        n => (1 << (2 * n)) - 1,
+    }
+}
+
+/// Extracts the word of nucleotides beginning at the provided nucleotide
+/// index, which need not be aligned to a word.
+///
+/// Nucleotides beyond the end of the words are zero.
+fn extract(words: &[u64], start: usize) -> u64 {
+    let index = start / NUCLEOTIDES_PER_WORD;
+    let shift = (start % NUCLEOTIDES_PER_WORD) * 2;
+
+    let low = words.get(index).copied().unwrap_or_default() >> shift;
+
+    match shift {
+        0 => low,
+        shift => low | (words.get(index + 1).copied().unwrap_or_default() << (64 - shift)),
+    }
+}
+
+/// Reverse complements a full word of nucleotides.
+fn reverse_complement_word(word: u64) -> u64 {
+    // NOTE: reversing the bytes and then reversing the four nucleotides within
+    // each byte reverses the order of the nucleotides while leaving the two
+    // bits of each nucleotide in place.
+    let word = (!word).swap_bytes();
+    let word = ((word >> 4) & 0x0F0F_0F0F_0F0F_0F0F) | ((word & 0x0F0F_0F0F_0F0F_0F0F) << 4);
+    ((word >> 2) & 0x3333_3333_3333_3333) | ((word & 0x3333_3333_3333_3333) << 2)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Runs of unknown nucleotides
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether a position lies within a set of sorted runs.
+fn within(runs: &[Range<usize>], position: usize) -> bool {
+    let index = runs.partition_point(|run| run.end <= position);
+    runs.get(index).is_some_and(|run| run.start <= position)
+}
+
+/// Gets the first position before `limit` that lies within exactly one of two
+/// sets of sorted runs (or `limit` if there is no such position).
+fn first_difference(a: &[Range<usize>], b: &[Range<usize>], limit: usize) -> usize {
+    // NOTE: membership within the runs only changes at the boundaries of the
+    // runs, so only those positions need to be checked.
+    let mut boundaries = core::iter::once(0)
+        .chain(a.iter().chain(b).flat_map(|run| [run.start, run.end]))
+        .filter(|position| *position < limit)
+        .collect::<Vec<_>>();
+    boundaries.sort_unstable();
+
+    boundaries
+        .into_iter()
+        .find(|position| within(a, *position) != within(b, *position))
+        .unwrap_or(limit)
+}
+
+/// Mirrors sorted runs within a sequence of the provided length so that they
+/// are measured from the end of the sequence.
+fn mirror(runs: &[Range<usize>], len: usize) -> Vec<Range<usize>> {
+    runs.iter()
+        .rev()
+        .map(|run| (len - run.end)..(len - run.start))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Slices
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed view into a [`PackedSequence`].
+#[derive(Clone, Copy, Debug)]
+pub struct PackedSlice<'a> {
+    /// The words of the underlying sequence.
+    words: &'a [u64],
+
+    /// The runs of unknown nucleotides within the underlying sequence.
+    unknown: &'a [Range<usize>],
+
+    /// The index of the first nucleotide within the underlying sequence.
+    start: usize,
+
+    /// The number of nucleotides.
+    len: usize,
+}
+
+impl<'a> PackedSlice<'a> {
+    /// Gets the number of nucleotides.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the nucleotide at an index (if it exists).
+    ///
+    /// This is not constant time: checking whether the nucleotide is unknown
+    /// is a binary search over the runs of `N`s, so a lookup takes `O(log r)`
+    /// time for `r` runs.
+    pub fn get(&self, index: usize) -> Option<iupac::Nucleotide> {
+        if index >= self.len {
+            return None;
+        }
+
+        let index = self.start + index;
+
+        if within(self.unknown, index) {
+            return Some(iupac::Nucleotide::N);
+        }
+
+        let word = self.words[index / NUCLEOTIDES_PER_WORD];
+        let shift = (index % NUCLEOTIDES_PER_WORD) * 2;
+        Some(iupac::Nucleotide::from(decode(word >> shift)))
+    }
+
+    /// Gets a sub-slice of this slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> PackedSlice<'a> {
+        let range = bounds(range, self.len);
+
+        PackedSlice {
+            start: self.start + range.start,
+            len: range.len(),
+            ..*self
+        }
+    }
+
+    /// Gets an iterator over the nucleotides.
+    pub fn iter(&self) -> Iter<'a> {
+        Iter {
+            slice: *self,
+            index: 0,
+        }
+    }
+
+    /// Gets the runs of unknown nucleotides relative to the start of the
+    /// slice.
+    fn unknown_runs(&self) -> Vec<Range<usize>> {
+        let end = self.start + self.len;
+        let first = self.unknown.partition_point(|run| run.end <= self.start);
+
+        self.unknown[first..]
+            .iter()
+            .take_while(|run| run.start < end)
+            .map(|run| (run.start.max(self.start) - self.start)..(run.end.min(end) - self.start))
+            .collect()
+    }
+
+    /// Counts the unknown nucleotides.
+    pub fn count_unknown(&self) -> usize {
+        self.unknown_runs().iter().map(|run| run.len()).sum()
+    }
+
+    /// Gets the word of nucleotides beginning at an index within the slice,
+    /// masked to the provided number of nucleotides.
+    fn word(&self, index: usize, n: usize) -> u64 {
+        extract(self.words, self.start + index) & mask(n)
+    }
+
+    /// Gets the GC content.
+    ///
+    /// Unknown nucleotides are excluded from both the numerator and the
+    /// denominator. If the slice contains no known nucleotides (including when
+    /// it is empty), the GC content is undefined and [`None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna::packed::PackedSequence;
+    ///
+    /// let sequence = "GGNNAT".parse::<PackedSequence>()?;
+    /// assert_eq!(sequence.as_slice().gc_content(), Some(0.5));
+    /// assert_eq!(sequence.slice(2..4).gc_content(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn gc_content(&self) -> Option<f32> {
+        let known = self.len - self.count_unknown();
+
+        if known == 0 {
+            return None;
+        }
+
+        let mut gc = 0;
+
+        for index in (0..self.len).step_by(NUCLEOTIDES_PER_WORD) {
+            let n = NUCLEOTIDES_PER_WORD.min(self.len - index);
+            let word = self.word(index, n);
+
+            // NOTE: `C` (`01`) and `G` (`10`) are the only nucleotides whose
+            // two bits differ.
+            gc += ((word ^ (word >> 1)) & LOW_BITS & mask(n)).count_ones() as usize;
+        }
+
+        Some(gc as f32 / known as f32)
+    }
+
+    /// Counts the leading nucleotides shared with `other`.
+    ///
+    /// Unknown nucleotides are only shared with other unknown nucleotides.
+    pub fn shared_prefix_len(&self, other: &PackedSlice<'_>) -> usize {
+        let limit = self.len.min(other.len);
+        let mut shared = limit;
+
+        for index in (0..limit).step_by(NUCLEOTIDES_PER_WORD) {
+            let n = NUCLEOTIDES_PER_WORD.min(limit - index);
+            let difference = self.word(index, n) ^ other.word(index, n);
+
+            if difference != 0 {
+                shared = index + difference.trailing_zeros() as usize / 2;
+                break;
+            }
+        }
+
+        first_difference(&self.unknown_runs(), &other.unknown_runs(), shared)
+    }
+
+    /// Counts the trailing nucleotides shared with `other`, excluding any
+    /// already counted as a shared prefix (so prefix + suffix never exceeds the
+    /// shorter length).
+    ///
+    /// Unknown nucleotides are only shared with other unknown nucleotides.
+    pub fn shared_suffix_len(&self, other: &PackedSlice<'_>) -> usize {
+        let limit = self.len.min(other.len) - self.shared_prefix_len(other);
+        let mut shared = limit;
+
+        for index in (0..limit).step_by(NUCLEOTIDES_PER_WORD) {
+            let n = NUCLEOTIDES_PER_WORD.min(limit - index);
+            let difference =
+                self.word(self.len - index - n, n) ^ other.word(other.len - index - n, n);
+
+            if difference != 0 {
+                let last = (63 - difference.leading_zeros() as usize) / 2;
+                shared = index + n - 1 - last;
+                break;
+            }
+        }
+
+        first_difference(
+            &mirror(&self.unknown_runs(), self.len),
+            &mirror(&other.unknown_runs(), other.len),
+            shared,
+        )
+    }
+
+    /// Gets the reverse complement.
+    ///
+    /// Unknown nucleotides remain unknown.
+    pub fn reverse_complement(&self) -> PackedSequence {
+        // NOTE: the words spanning the slice are reverse complemented in
+        // reverse order, after which the slice begins `skip` nucleotides into
+        // the reversed words.
+        let first = self.start / NUCLEOTIDES_PER_WORD;
+        let last = (self.start + self.len).div_ceil(NUCLEOTIDES_PER_WORD);
+        let skip = last * NUCLEOTIDES_PER_WORD - (self.start + self.len);
+
+        let mut words = self.words[first..last]
+            .iter()
+            .rev()
+            .map(|word| reverse_complement_word(*word))
+            .collect::<Vec<_>>();
+
+        if skip > 0 {
+            let shift = 2 * skip;
+
+            for i in 1..words.len() {
+                words[i - 1] = (words[i - 1] >> shift) | (words[i] << (64 - shift));
+            }
+
+            // SAFETY: the slice spans at least one word when `skip` is nonzero.
+            *words.last_mut().unwrap() >>= shift;
+            words.truncate(self.len.div_ceil(NUCLEOTIDES_PER_WORD));
+        }
+
+        let mut sequence = PackedSequence {
+            words,
+            unknown: mirror(&self.unknown_runs(), self.len),
+            len: self.len,
+        };
+        sequence.clear_padding();
+        sequence.clear_unknown_bits();
+        sequence
+    }
+
+    /// Copies the slice into an owned [`PackedSequence`].
+    pub fn to_packed_sequence(&self) -> PackedSequence {
+        let words = (0..self.len)
+            .step_by(NUCLEOTIDES_PER_WORD)
+            .map(|index| self.word(index, NUCLEOTIDES_PER_WORD.min(self.len - index)))
+            .collect();
+
+        PackedSequence {
+            words,
+            unknown: self.unknown_runs(),
+            len: self.len,
+        }
+    }
+}
+
+/// Resolves a range of indices against a length.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds.
+fn bounds(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end && end <= len,
+        "range {start}..{end} out of bounds for a sequence of length {len}"
+    );
+
+    start..end
+}
+
+/// An iterator over the nucleotides of a [`PackedSlice`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    /// The slice.
+    slice: PackedSlice<'a>,
+
+    /// The index of the next nucleotide.
+    index: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = iupac::Nucleotide;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let nucleotide = self.slice.get(self.index)?;
+        self.index += 1;
+        Some(nucleotide)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl PartialEq for PackedSlice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.shared_prefix_len(other) == self.len
+    }
+}
+
+impl Eq for PackedSlice<'_> {}
+
+impl core::fmt::Display for PackedSlice<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for nucleotide in self.iter() {
+            write!(f, "{nucleotide}")?;
+        }
+
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////
+// Sequences
+////////////////////////////////////////////////////////////////////////////////////////
+
+/// A two-bit packed DNA sequence.
+///
+/// Every nucleotide is either a strict nucleotide or unknown (`N`).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PackedSequence {
+    /// The packed words.
+    ///
+    /// The bits of unknown nucleotides and of any padding beyond the last
+    /// nucleotide are always zero.
+    words: Vec<u64>,
+
+    /// The sorted, non-adjacent runs of unknown nucleotides.
+    unknown: Vec<Range<usize>>,
+
+    /// The number of nucleotides.
+    len: usize,
+}
+
+impl PackedSequence {
+    /// Creates a new, empty packed sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new, empty packed sequence with space for at least
+    /// `capacity` nucleotides.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(capacity.div_ceil(NUCLEOTIDES_PER_WORD)),
+            ..Default::default()
+        }
+    }
+
+    /// Gets the number of nucleotides.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Gets the packed words.
+    ///
+    /// Nucleotide `i` is stored within bits `2 * (i % 32)` and
+    /// `2 * (i % 32) + 1` of word `i / 32`.
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// Gets the sorted runs of unknown nucleotides.
+    pub fn unknown_runs(&self) -> &[Range<usize>] {
+        &self.unknown
+    }
+
+    /// Appends a strict nucleotide.
+    pub fn push(&mut self, nucleotide: dna::Nucleotide) {
+        let shift = (self.len % NUCLEOTIDES_PER_WORD) * 2;
+
+        if shift == 0 {
+            self.words.push(0);
+        }
+
+        // SAFETY: a word was just pushed if the new nucleotide begins a word.
+        *self.words.last_mut().unwrap() |= encode(nucleotide) << shift;
+        self.len += 1;
+    }
+
+    /// Appends an unknown nucleotide (`N`).
+    pub fn push_unknown(&mut self) {
+        let position = self.len;
+        self.push(dna::Nucleotide::A);
+
+        match self.unknown.last_mut() {
+            Some(run) if run.end == position => run.end += 1,
+            _ => self.unknown.push(position..position + 1),
+        }
+    }
+
+    /// Gets the nucleotide at an index (if it exists).
+    ///
+    /// See [`PackedSlice::get()`] for the cost of a lookup.
+    pub fn get(&self, index: usize) -> Option<iupac::Nucleotide> {
+        self.as_slice().get(index)
+    }
+
+    /// Gets the whole sequence as a [`PackedSlice`].
+    pub fn as_slice(&self) -> PackedSlice<'_> {
+        PackedSlice {
+            words: &self.words,
+            unknown: &self.unknown,
+            start: 0,
+            len: self.len,
+        }
+    }
+
+    /// Gets a slice of the sequence without copying any nucleotides.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> PackedSlice<'_> {
+        self.as_slice().slice(range)
+    }
+
+    /// Gets an iterator over the nucleotides.
+    pub fn iter(&self) -> Iter<'_> {
+        self.as_slice().iter()
+    }
+
+    /// Gets the GC content.
+    ///
+    /// Unknown nucleotides are excluded from both the numerator and the
+    /// denominator. If the sequence contains no known nucleotides (including
+    /// when it is empty), the GC content is undefined and [`None`] is
+    /// returned.
+    pub fn gc_content(&self) -> Option<f32> {
+        self.as_slice().gc_content()
+    }
+
+    /// Gets the reverse complement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna::packed::PackedSequence;
+    ///
+    /// let sequence = "AACGNT".parse::<PackedSequence>()?;
+    /// assert_eq!(sequence.reverse_complement().to_string(), "ANCGTT");
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reverse_complement(&self) -> PackedSequence {
+        self.as_slice().reverse_complement()
+    }
+
+    /// Counts the leading nucleotides shared with `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::dna::packed::PackedSequence;
+    ///
+    /// let a = "AATG".parse::<PackedSequence>()?;
+    /// let b = "AACG".parse::<PackedSequence>()?;
+    /// assert_eq!(a.shared_prefix_len(&b), 2);
+    /// assert_eq!(a.shared_suffix_len(&b), 1);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn shared_prefix_len(&self, other: &PackedSequence) -> usize {
+        self.as_slice().shared_prefix_len(&other.as_slice())
+    }
+
+    /// Counts the trailing nucleotides shared with `other`, excluding any
+    /// already counted as a shared prefix.
+    pub fn shared_suffix_len(&self, other: &PackedSequence) -> usize {
+        self.as_slice().shared_suffix_len(&other.as_slice())
+    }
+
+    /// Zeroes the bits beyond the last nucleotide.
+    fn clear_padding(&mut self) {
+        let n = self.len % NUCLEOTIDES_PER_WORD;
+
+        if let (Some(word), true) = (self.words.last_mut(), n > 0) {
+            *word &= mask(n);
+        }
+    }
+
+    /// Zeroes the bits of every unknown nucleotide.
+    ///
+    /// Each run is cleared a word at a time rather than a nucleotide at a time.
+    fn clear_unknown_bits(&mut self) {
+        for run in self.unknown.iter().filter(|run| !run.is_empty()) {
+            let first = run.start / NUCLEOTIDES_PER_WORD;
+            let last = (run.end - 1) / NUCLEOTIDES_PER_WORD;
+
+            for (index, word) in self.words[first..=last].iter_mut().enumerate() {
+                let offset = (first + index) * NUCLEOTIDES_PER_WORD;
+                let lower = run.start.saturating_sub(offset);
+                let upper = (run.end - offset).min(NUCLEOTIDES_PER_WORD);
+                *word &= !(mask(upper) & !mask(lower));
+            }
+        }
+    }
+}
+
+impl Extend<dna::Nucleotide> for PackedSequence {
+    fn extend<T: IntoIterator<Item = dna::Nucleotide>>(&mut self, iter: T) {
+        for nucleotide in iter {
+            self.push(nucleotide);
+        }
+    }
+}
+
+impl FromIterator<dna::Nucleotide> for PackedSequence {
+    fn from_iter<T: IntoIterator<Item = dna::Nucleotide>>(iter: T) -> Self {
+        let mut sequence = Self::new();
+        sequence.extend(iter);
+        sequence
+    }
+}
+
+impl From<&Sequence<dna::Nucleotide>> for PackedSequence {
+    fn from(sequence: &Sequence<dna::Nucleotide>) -> Self {
+        sequence.inner().iter().copied().collect()
+    }
+}
+
+impl TryFrom<&Sequence<iupac::Nucleotide>> for PackedSequence {
+    type Error = Error;
+
+    fn try_from(sequence: &Sequence<iupac::Nucleotide>) -> Result<Self> {
+        let mut packed = PackedSequence::with_capacity(sequence.len());
+
+        for (position, nucleotide) in sequence.inner().iter().enumerate() {
+            match (nucleotide, dna::Nucleotide::try_from(*nucleotide)) {
+                (_, Ok(nucleotide)) => packed.push(nucleotide),
+                (iupac::Nucleotide::N, _) => packed.push_unknown(),
+                (nucleotide, _) => {
+                    return Err(Error::Ambiguous {
+                        position,
+                        nucleotide: *nucleotide,
+                    });
+                }
+            }
+        }
+
+        Ok(packed)
+    }
+}
+
+impl TryFrom<&PackedSequence> for Sequence<dna::Nucleotide> {
+    type Error = Error;
+
+    fn try_from(sequence: &PackedSequence) -> Result<Self> {
+        if let Some(run) = sequence.unknown.first() {
+            return Err(Error::Unknown(run.start));
+        }
+
+        let nucleotides = (0..sequence.len)
+            .map(|index| {
+                let word = sequence.words[index / NUCLEOTIDES_PER_WORD];
+                decode(word >> ((index % NUCLEOTIDES_PER_WORD) * 2))
+            })
+            .collect();
+
+        Ok(Sequence::new(nucleotides))
+    }
+}
+
+impl From<&PackedSequence> for Sequence<iupac::Nucleotide> {
+    fn from(sequence: &PackedSequence) -> Self {
+        Sequence::new(sequence.iter().collect())
+    }
+}
+
+impl core::str::FromStr for PackedSequence {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut sequence = PackedSequence::with_capacity(s.len());
+
+        for c in s.chars() {
+            match c {
+                'N' | 'n' => sequence.push_unknown(),
+                c => sequence
+                    .push(dna::Nucleotide::try_from(c).map_err(|_| Error::InvalidNucleotide(c))?),
+            }
+        }
+
+        Ok(sequence)
+    }
+}
+
+impl core::fmt::Display for PackedSequence {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::testing::Lcg;
+
+    /// Generates a pseudo-random sequence of the provided length, with
+    /// occasional runs of unknown nucleotides.
+    fn generate(len: usize, seed: u64) -> String {
+        let mut lcg = Lcg::new(seed);
+
+        (0..len)
+            .map(|_| match lcg.below(23) {
+                0 => 'N',
+                n => ['A', 'C', 'G', 'T'][(n % 4) as usize],
+            })
+            .collect()
+    }
+
+    /// Reverse complements a string nucleotide by nucleotide.
+    fn naive_reverse_complement(s: &str) -> String {
+        s.chars()
+            .rev()
+            .map(|c| match c {
+                'A' => 'T',
+                'C' => 'G',
+                'G' => 'C',
+                'T' => 'A',
+                c => c,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_round_trips_through_strings() {
+        for len in [0, 1, 31, 32, 33, 64, 100] {
+            let s = generate(len, len as u64);
+            let sequence = s.parse::<PackedSequence>().unwrap();
+
+            assert_eq!(sequence.len(), len);
+            assert_eq!(sequence.to_string(), s);
+            assert_eq!(sequence.iter().len(), len);
+        }
+
+        let err = "ACGU".parse::<PackedSequence>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid nucleotide `U`");
+    }
+
+    #[test]
+    fn it_reverse_complements_words() {
+        for len in [0, 1, 5, 31, 32, 33, 63, 64, 65, 200] {
+            let s = generate(len, 7 + len as u64);
+            let sequence = s.parse::<PackedSequence>().unwrap();
+            let expected = naive_reverse_complement(&s);
+
+            let reverse_complement = sequence.reverse_complement();
+            assert_eq!(reverse_complement.to_string(), expected);
+            assert_eq!(reverse_complement, expected.parse().unwrap());
+            assert_eq!(reverse_complement.reverse_complement(), sequence);
+
+            if len > 10 {
+                let slice = sequence.slice(3..len - 4);
+                assert_eq!(
+                    slice.reverse_complement().to_string(),
+                    naive_reverse_complement(&s[3..len - 4])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn it_clears_unknown_runs_spanning_words() {
+        let s = format!("GGT{}ACGTT{}C", "N".repeat(100), "N".repeat(29));
+        let sequence = s.parse::<PackedSequence>().unwrap();
+        let reverse_complement = sequence.reverse_complement();
+
+        let expected = naive_reverse_complement(&s);
+        assert_eq!(reverse_complement.to_string(), expected);
+        assert_eq!(reverse_complement, expected.parse().unwrap());
+        assert_eq!(reverse_complement.words()[2..4], [0, 0]);
+    }
+
+    #[test]
+    fn it_slices_without_copying() {
+        let s = generate(150, 42);
+        let sequence = s.parse::<PackedSequence>().unwrap();
+
+        for (start, end) in [(0, 150), (1, 2), (31, 97), (64, 128), (100, 100)] {
+            let slice = sequence.slice(start..end);
+
+            assert_eq!(slice.to_string(), s[start..end]);
+            assert_eq!(slice.to_packed_sequence().to_string(), s[start..end]);
+            assert_eq!(
+                slice.count_unknown(),
+                s[start..end].chars().filter(|c| *c == 'N').count()
+            );
+        }
+
+        let slice = sequence.slice(10..50).slice(5..);
+        assert_eq!(slice.to_string(), s[15..50]);
+        assert_eq!(slice.get(0).unwrap().to_string(), s[15..16]);
+        assert_eq!(slice.get(35), None);
+    }
+
+    #[test]
+    fn it_computes_gc_content() {
+        let s = generate(1000, 3);
+        let sequence = s.parse::<PackedSequence>().unwrap();
+
+        let gc = s.chars().filter(|c| matches!(c, 'C' | 'G')).count();
+        let known = s.chars().filter(|c| *c != 'N').count();
+        assert_eq!(sequence.gc_content(), Some(gc as f32 / known as f32));
+
+        let slice = sequence.slice(17..503);
+        let gc = s[17..503]
+            .chars()
+            .filter(|c| matches!(c, 'C' | 'G'))
+            .count();
+        let known = s[17..503].chars().filter(|c| *c != 'N').count();
+        assert_eq!(slice.gc_content(), Some(gc as f32 / known as f32));
+
+        assert_eq!(sequence.slice(17..17).gc_content(), None);
+        assert_eq!("".parse::<PackedSequence>().unwrap().gc_content(), None);
+        assert_eq!("NNNN".parse::<PackedSequence>().unwrap().gc_content(), None);
+    }
+
+    #[test]
+    fn it_computes_shared_prefixes_and_suffixes() {
+        let s = generate(300, 11);
+
+        for (a, b) in [
+            (s[..200].to_string(), s[..150].to_string() + "A" + &s[151..]),
+            (s.clone(), s[..70].to_string() + "N" + &s[71..]),
+            (s[..40].to_string() + "TTTT", s[..40].to_string() + "TT"),
+            ("N".repeat(40), "N".repeat(35) + "A"),
+            ("ACGT".to_string(), "ACGT".to_string()),
+            (String::new(), "A".to_string()),
+        ] {
+            let naive_a = a.parse::<Sequence<iupac::Nucleotide>>().ok();
+            let naive_b = b.parse::<Sequence<iupac::Nucleotide>>().ok();
+            let packed_a = a.parse::<PackedSequence>().unwrap();
+            let packed_b = b.parse::<PackedSequence>().unwrap();
+
+            let (prefix, suffix) = match (naive_a, naive_b) {
+                (Some(naive_a), Some(naive_b)) => (
+                    naive_a.shared_prefix_len(&naive_b),
+                    naive_a.shared_suffix_len(&naive_b),
+                ),
+                _ => (0, 0),
+            };
+
+            assert_eq!(packed_a.shared_prefix_len(&packed_b), prefix);
+            assert_eq!(packed_a.shared_suffix_len(&packed_b), suffix);
+        }
+    }
+
+    #[test]
+    fn it_converts_to_and_from_sequences() {
+        let strict = "ACGTTGCA".parse::<Sequence<dna::Nucleotide>>().unwrap();
+        let packed = PackedSequence::from(&strict);
+        assert_eq!(
+            Sequence::<dna::Nucleotide>::try_from(&packed).unwrap(),
+            strict
+        );
+
+        let ambiguous = "ACNNGT".parse::<Sequence<iupac::Nucleotide>>().unwrap();
+        let packed = PackedSequence::try_from(&ambiguous).unwrap();
+        assert_eq!(packed.unknown_runs(), &[Range { start: 2, end: 4 }]);
+        assert_eq!(Sequence::<iupac::Nucleotide>::from(&packed), ambiguous);
+        assert_eq!(
+            Sequence::<dna::Nucleotide>::try_from(&packed),
+            Err(Error::Unknown(2))
+        );
+
+        let ambiguous = "ACRT".parse::<Sequence<iupac::Nucleotide>>().unwrap();
+        let err = PackedSequence::try_from(&ambiguous).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "ambiguous nucleotide `R` at position 2 cannot be packed"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Proteins.
+
+use alloc::vec::Vec;
+
+mod amino_acid;
+
+pub use amino_acid::AminoAcid;
+pub use amino_acid::Charge;
+pub use amino_acid::Hydrophobicity;
+use thiserror::Error;
+
+/// The monoisotopic mass of water in daltons.
+const WATER: f64 = 18.01056;
+
+/// An error related to a [`Molecule`].
+#[derive(Error, Debug)]
+pub enum Error {
+    /// An error when processing an [`AminoAcid`].
+    #[error(transparent)]
+    AminoAcidError(#[from] amino_acid::Error),
+}
+
+/// A molecule representing a protein (or a peptide).
+#[derive(Debug)]
+pub struct Molecule(Vec<AminoAcid>);
+
+impl Molecule {
+    /// Gets the inner [`Vec<AminoAcid>`] by reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::Molecule;
+    ///
+    /// let m = "MAGW*".parse::<Molecule>()?;
+    /// assert_eq!(m.inner().len(), 5);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn inner(&self) -> &Vec<AminoAcid> {
+        self.0.as_ref()
+    }
+
+    /// Consumes the [`Molecule`] and returns the inner [`Vec<AminoAcid>`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::AminoAcid;
+    /// use omics_molecule::polymer::protein::Molecule;
+    ///
+    /// let m = "MAGW".parse::<Molecule>()?;
+    /// let amino_acids = m.into_inner();
+    ///
+    /// assert_eq!(
+    ///     amino_acids,
+    ///     vec![
+    ///         AminoAcid::Met,
+    ///         AminoAcid::Ala,
+    ///         AminoAcid::Gly,
+    ///         AminoAcid::Trp
+    ///     ]
+    /// );
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn into_inner(self) -> Vec<AminoAcid> {
+        self.0
+    }
+
+    /// Gets the monoisotopic mass of this [`Molecule`] in daltons.
+    ///
+    /// The mass is the sum of the residue masses plus one water for the
+    /// terminal groups. A trailing stop is ignored, but the mass is unknown if
+    /// the molecule contains any other stop or an unknown amino acid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::Molecule;
+    ///
+    /// let m = "GG*".parse::<Molecule>()?;
+    /// assert!((m.mass().unwrap() - 132.05348).abs() < 1e-9);
+    ///
+    /// let m = "GXG".parse::<Molecule>()?;
+    /// assert_eq!(m.mass(), None);
+    ///
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn mass(&self) -> Option<f64> {
+        let residues = match self.0.split_last() {
+            Some((AminoAcid::Stop, rest)) => rest,
+            _ => &self.0[..],
+        };
+
+        residues
+            .iter()
+            .map(|amino_acid| amino_acid.mass())
+            .sum::<Option<f64>>()
+            .map(|mass| mass + WATER)
+    }
+}
+
+impl From<Vec<AminoAcid>> for Molecule {
+    fn from(v: Vec<AminoAcid>) -> Self {
+        Self(v)
+    }
+}
+
+impl core::str::FromStr for Molecule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .map(|c| AminoAcid::try_from(c).map_err(Error::AminoAcidError))
+            .collect::<Result<Vec<_>, Error>>()
+            .map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::ToString;
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_creates_a_molecule_from_a_vec_of_amino_acids() {
+        let amino_acids = vec![AminoAcid::Met, AminoAcid::Ala, AminoAcid::Stop];
+
+        let protein = Molecule::from(amino_acids);
+        assert_eq!(protein.inner().len(), 3);
+    }
+
+    #[test]
+    fn it_parses_a_molecule_from_a_valid_string() -> Result<(), Box<dyn core::error::Error>> {
+        Ok("MKTAYIAKQRUOX*".parse::<Molecule>().map(|_| ())?)
+    }
+
+    #[test]
+    fn it_fails_to_parse_a_molecule_from_an_invalid_string() {
+        let err = "MKB".parse::<Molecule>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid amino acid `B`");
+    }
+
+    #[test]
+    fn it_computes_the_mass_of_a_molecule() -> Result<(), Box<dyn core::error::Error>> {
+        let m = "".parse::<Molecule>()?;
+        assert_eq!(m.mass(), Some(WATER));
+
+        let m = "G*G".parse::<Molecule>()?;
+        assert_eq!(m.mass(), None);
+
+        Ok(())
+    }
+}
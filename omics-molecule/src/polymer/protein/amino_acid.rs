@@ -0,0 +1,574 @@
+//! Amino acids in proteins.
+
+use alloc::string::String;
+use alloc::string::ToString;
+
+use thiserror::Error;
+
+/// An error when parsing an amino acid.
+#[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub enum ParseError {
+    /// An invalid format was attempted to be parsed.
+    #[error("invalid amino acid format `{0}`")]
+    InvalidFormat(String),
+
+    /// An invalid one-letter code was attempted to be parsed.
+    #[error("invalid amino acid `{0}`")]
+    InvalidAminoAcid(char),
+
+    /// An invalid three-letter code was attempted to be parsed.
+    #[error("invalid amino acid `{0}`")]
+    InvalidCode(String),
+}
+
+/// An error related to an [`AminoAcid`].
+#[derive(Error, Debug)]
+pub enum Error {
+    /// An invalid amino acid was attempted to be created from a [`char`].
+    #[error("invalid amino acid `{0}`")]
+    InvalidAminoAcid(char),
+
+    /// A parse error.
+    #[error(transparent)]
+    ParseError(#[from] ParseError),
+}
+
+/// The hydrophobicity class of an amino acid.
+///
+/// The classes follow the IMGT grouping of the standard amino acids by their
+/// Kyte-Doolittle hydropathy.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Hydrophobicity {
+    /// A hydrophobic side chain.
+    Hydrophobic,
+
+    /// A side chain that is neither hydrophobic nor hydrophilic.
+    Neutral,
+
+    /// A hydrophilic side chain.
+    Hydrophilic,
+}
+
+/// The charge of an amino acid side chain at physiological pH.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Charge {
+    /// A positively charged (basic) side chain.
+    Positive,
+
+    /// A negatively charged (acidic) side chain.
+    Negative,
+
+    /// An uncharged side chain.
+    Neutral,
+}
+
+/// An amino acid in a protein context.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AminoAcid {
+    /// Alanine.
+    Ala,
+
+    /// Arginine.
+    Arg,
+
+    /// Asparagine.
+    Asn,
+
+    /// Aspartic acid.
+    Asp,
+
+    /// Cysteine.
+    Cys,
+
+    /// Glutamine.
+    Gln,
+
+    /// Glutamic acid.
+    Glu,
+
+    /// Glycine.
+    Gly,
+
+    /// Histidine.
+    His,
+
+    /// Isoleucine.
+    Ile,
+
+    /// Leucine.
+    Leu,
+
+    /// Lysine.
+    Lys,
+
+    /// Methionine.
+    Met,
+
+    /// Phenylalanine.
+    Phe,
+
+    /// Proline.
+    Pro,
+
+    /// Serine.
+    Ser,
+
+    /// Threonine.
+    Thr,
+
+    /// Tryptophan.
+    Trp,
+
+    /// Tyrosine.
+    Tyr,
+
+    /// Valine.
+    Val,
+
+    /// Selenocysteine.
+    Sec,
+
+    /// Pyrrolysine.
+    Pyl,
+
+    /// A translation stop.
+    Stop,
+
+    /// An unknown amino acid.
+    Xaa,
+}
+
+impl AminoAcid {
+    /// Gets the one-letter code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::AminoAcid;
+    ///
+    /// assert_eq!(AminoAcid::Trp.one_letter_code(), 'W');
+    /// assert_eq!(AminoAcid::Stop.one_letter_code(), '*');
+    /// ```
+    pub fn one_letter_code(&self) -> char {
+        match self {
+            AminoAcid::Ala => 'A',
+            AminoAcid::Arg => 'R',
+            AminoAcid::Asn => 'N',
+            AminoAcid::Asp => 'D',
+            AminoAcid::Cys => 'C',
+            AminoAcid::Gln => 'Q',
+            AminoAcid::Glu => 'E',
+            AminoAcid::Gly => 'G',
+            AminoAcid::His => 'H',
+            AminoAcid::Ile => 'I',
+            AminoAcid::Leu => 'L',
+            AminoAcid::Lys => 'K',
+            AminoAcid::Met => 'M',
+            AminoAcid::Phe => 'F',
+            AminoAcid::Pro => 'P',
+            AminoAcid::Ser => 'S',
+            AminoAcid::Thr => 'T',
+            AminoAcid::Trp => 'W',
+            AminoAcid::Tyr => 'Y',
+            AminoAcid::Val => 'V',
+            AminoAcid::Sec => 'U',
+            AminoAcid::Pyl => 'O',
+            AminoAcid::Stop => '*',
+            AminoAcid::Xaa => 'X',
+        }
+    }
+
+    /// Gets the three-letter code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::AminoAcid;
+    ///
+    /// assert_eq!(AminoAcid::Trp.three_letter_code(), "Trp");
+    /// assert_eq!(AminoAcid::Stop.three_letter_code(), "Ter");
+    /// ```
+    pub fn three_letter_code(&self) -> &'static str {
+        match self {
+            AminoAcid::Ala => "Ala",
+            AminoAcid::Arg => "Arg",
+            AminoAcid::Asn => "Asn",
+            AminoAcid::Asp => "Asp",
+            AminoAcid::Cys => "Cys",
+            AminoAcid::Gln => "Gln",
+            AminoAcid::Glu => "Glu",
+            AminoAcid::Gly => "Gly",
+            AminoAcid::His => "His",
+            AminoAcid::Ile => "Ile",
+            AminoAcid::Leu => "Leu",
+            AminoAcid::Lys => "Lys",
+            AminoAcid::Met => "Met",
+            AminoAcid::Phe => "Phe",
+            AminoAcid::Pro => "Pro",
+            AminoAcid::Ser => "Ser",
+            AminoAcid::Thr => "Thr",
+            AminoAcid::Trp => "Trp",
+            AminoAcid::Tyr => "Tyr",
+            AminoAcid::Val => "Val",
+            AminoAcid::Sec => "Sec",
+            AminoAcid::Pyl => "Pyl",
+            AminoAcid::Stop => "Ter",
+            AminoAcid::Xaa => "Xaa",
+        }
+    }
+
+    /// Gets the monoisotopic residue mass in daltons.
+    ///
+    /// The residue mass is the mass of the free amino acid less one water, as
+    /// the amino acid appears within a peptide chain. Stops and unknown amino
+    /// acids have no mass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::AminoAcid;
+    ///
+    /// assert_eq!(AminoAcid::Gly.mass(), Some(57.02146));
+    /// assert_eq!(AminoAcid::Xaa.mass(), None);
+    /// ```
+    pub fn mass(&self) -> Option<f64> {
+        match self {
+            AminoAcid::Ala => Some(71.03711),
+            AminoAcid::Arg => Some(156.10111),
+            AminoAcid::Asn => Some(114.04293),
+            AminoAcid::Asp => Some(115.02694),
+            AminoAcid::Cys => Some(103.00919),
+            AminoAcid::Gln => Some(128.05858),
+            AminoAcid::Glu => Some(129.04259),
+            AminoAcid::Gly => Some(57.02146),
+            AminoAcid::His => Some(137.05891),
+            AminoAcid::Ile => Some(113.08406),
+            AminoAcid::Leu => Some(113.08406),
+            AminoAcid::Lys => Some(128.09496),
+            AminoAcid::Met => Some(131.04049),
+            AminoAcid::Phe => Some(147.06841),
+            AminoAcid::Pro => Some(97.05276),
+            AminoAcid::Ser => Some(87.03203),
+            AminoAcid::Thr => Some(101.04768),
+            AminoAcid::Trp => Some(186.07931),
+            AminoAcid::Tyr => Some(163.06333),
+            AminoAcid::Val => Some(99.06841),
+            AminoAcid::Sec => Some(150.95364),
+            AminoAcid::Pyl => Some(237.14773),
+            AminoAcid::Stop | AminoAcid::Xaa => None,
+        }
+    }
+
+    /// Gets the [`Hydrophobicity`] class.
+    ///
+    /// Only the twenty standard amino acids are classified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::AminoAcid;
+    /// use omics_molecule::polymer::protein::Hydrophobicity;
+    ///
+    /// assert_eq!(
+    ///     AminoAcid::Leu.hydrophobicity(),
+    ///     Some(Hydrophobicity::Hydrophobic)
+    /// );
+    /// assert_eq!(
+    ///     AminoAcid::Lys.hydrophobicity(),
+    ///     Some(Hydrophobicity::Hydrophilic)
+    /// );
+    /// assert_eq!(AminoAcid::Sec.hydrophobicity(), None);
+    /// ```
+    pub fn hydrophobicity(&self) -> Option<Hydrophobicity> {
+        match self {
+            AminoAcid::Ala
+            | AminoAcid::Cys
+            | AminoAcid::Ile
+            | AminoAcid::Leu
+            | AminoAcid::Met
+            | AminoAcid::Phe
+            | AminoAcid::Trp
+            | AminoAcid::Val => Some(Hydrophobicity::Hydrophobic),
+            AminoAcid::Gly
+            | AminoAcid::His
+            | AminoAcid::Pro
+            | AminoAcid::Ser
+            | AminoAcid::Thr
+            | AminoAcid::Tyr => Some(Hydrophobicity::Neutral),
+            AminoAcid::Arg
+            | AminoAcid::Asn
+            | AminoAcid::Asp
+            | AminoAcid::Gln
+            | AminoAcid::Glu
+            | AminoAcid::Lys => Some(Hydrophobicity::Hydrophilic),
+            AminoAcid::Sec | AminoAcid::Pyl | AminoAcid::Stop | AminoAcid::Xaa => None,
+        }
+    }
+
+    /// Gets the side chain [`Charge`] at physiological pH.
+    ///
+    /// Histidine is grouped with the basic amino acids by convention. Only the
+    /// twenty standard amino acids are classified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use omics_molecule::polymer::protein::AminoAcid;
+    /// use omics_molecule::polymer::protein::Charge;
+    ///
+    /// assert_eq!(AminoAcid::Arg.charge(), Some(Charge::Positive));
+    /// assert_eq!(AminoAcid::Glu.charge(), Some(Charge::Negative));
+    /// assert_eq!(AminoAcid::Ala.charge(), Some(Charge::Neutral));
+    /// ```
+    pub fn charge(&self) -> Option<Charge> {
+        match self {
+            AminoAcid::Arg | AminoAcid::His | AminoAcid::Lys => Some(Charge::Positive),
+            AminoAcid::Asp | AminoAcid::Glu => Some(Charge::Negative),
+            AminoAcid::Sec | AminoAcid::Pyl | AminoAcid::Stop | AminoAcid::Xaa => None,
+            _ => Some(Charge::Neutral),
+        }
+    }
+}
+
+impl core::fmt::Display for AminoAcid {
+    /// Formats the one-letter code by default or the three-letter code with
+    /// the alternate flag (`{:#}`).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.three_letter_code())
+        } else {
+            write!(f, "{}", self.one_letter_code())
+        }
+    }
+}
+
+impl TryFrom<char> for AminoAcid {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'A' => Ok(AminoAcid::Ala),
+            'R' => Ok(AminoAcid::Arg),
+            'N' => Ok(AminoAcid::Asn),
+            'D' => Ok(AminoAcid::Asp),
+            'C' => Ok(AminoAcid::Cys),
+            'Q' => Ok(AminoAcid::Gln),
+            'E' => Ok(AminoAcid::Glu),
+            'G' => Ok(AminoAcid::Gly),
+            'H' => Ok(AminoAcid::His),
+            'I' => Ok(AminoAcid::Ile),
+            'L' => Ok(AminoAcid::Leu),
+            'K' => Ok(AminoAcid::Lys),
+            'M' => Ok(AminoAcid::Met),
+            'F' => Ok(AminoAcid::Phe),
+            'P' => Ok(AminoAcid::Pro),
+            'S' => Ok(AminoAcid::Ser),
+            'T' => Ok(AminoAcid::Thr),
+            'W' => Ok(AminoAcid::Trp),
+            'Y' => Ok(AminoAcid::Tyr),
+            'V' => Ok(AminoAcid::Val),
+            'U' => Ok(AminoAcid::Sec),
+            'O' => Ok(AminoAcid::Pyl),
+            '*' => Ok(AminoAcid::Stop),
+            'X' => Ok(AminoAcid::Xaa),
+            _ => Err(Error::InvalidAminoAcid(c)),
+        }
+    }
+}
+
+impl core::str::FromStr for AminoAcid {
+    type Err = Error;
+
+    /// Parses an amino acid from either its one-letter or its three-letter
+    /// code (case-insensitively).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => {
+                return AminoAcid::try_from(c)
+                    .map_err(|_| Error::ParseError(ParseError::InvalidAminoAcid(c)));
+            }
+            _ if s.len() == 3 => {}
+            _ => return Err(Error::ParseError(ParseError::InvalidFormat(s.to_string()))),
+        }
+
+        match s.to_ascii_lowercase().as_str() {
+            "ala" => Ok(AminoAcid::Ala),
+            "arg" => Ok(AminoAcid::Arg),
+            "asn" => Ok(AminoAcid::Asn),
+            "asp" => Ok(AminoAcid::Asp),
+            "cys" => Ok(AminoAcid::Cys),
+            "gln" => Ok(AminoAcid::Gln),
+            "glu" => Ok(AminoAcid::Glu),
+            "gly" => Ok(AminoAcid::Gly),
+            "his" => Ok(AminoAcid::His),
+            "ile" => Ok(AminoAcid::Ile),
+            "leu" => Ok(AminoAcid::Leu),
+            "lys" => Ok(AminoAcid::Lys),
+            "met" => Ok(AminoAcid::Met),
+            "phe" => Ok(AminoAcid::Phe),
+            "pro" => Ok(AminoAcid::Pro),
+            "ser" => Ok(AminoAcid::Ser),
+            "thr" => Ok(AminoAcid::Thr),
+            "trp" => Ok(AminoAcid::Trp),
+            "tyr" => Ok(AminoAcid::Tyr),
+            "val" => Ok(AminoAcid::Val),
+            "sec" => Ok(AminoAcid::Sec),
+            "pyl" => Ok(AminoAcid::Pyl),
+            "ter" => Ok(AminoAcid::Stop),
+            "xaa" => Ok(AminoAcid::Xaa),
+            _ => Err(Error::ParseError(ParseError::InvalidCode(s.to_string()))),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AminoAcid {
+    /// Serializes an amino acid as its one-letter code.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AminoAcid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+    use alloc::format;
+
+    use super::*;
+
+    /// Every amino acid.
+    const ALL: [AminoAcid; 24] = [
+        AminoAcid::Ala,
+        AminoAcid::Arg,
+        AminoAcid::Asn,
+        AminoAcid::Asp,
+        AminoAcid::Cys,
+        AminoAcid::Gln,
+        AminoAcid::Glu,
+        AminoAcid::Gly,
+        AminoAcid::His,
+        AminoAcid::Ile,
+        AminoAcid::Leu,
+        AminoAcid::Lys,
+        AminoAcid::Met,
+        AminoAcid::Phe,
+        AminoAcid::Pro,
+        AminoAcid::Ser,
+        AminoAcid::Thr,
+        AminoAcid::Trp,
+        AminoAcid::Tyr,
+        AminoAcid::Val,
+        AminoAcid::Sec,
+        AminoAcid::Pyl,
+        AminoAcid::Stop,
+        AminoAcid::Xaa,
+    ];
+
+    #[test]
+    fn it_roundtrips_one_and_three_letter_codes() -> Result<(), Box<dyn core::error::Error>> {
+        for amino_acid in ALL {
+            assert_eq!(
+                AminoAcid::try_from(amino_acid.one_letter_code())?,
+                amino_acid
+            );
+            assert_eq!(amino_acid.to_string().parse::<AminoAcid>()?, amino_acid);
+            assert_eq!(format!("{amino_acid:#}").parse::<AminoAcid>()?, amino_acid);
+            assert_eq!(
+                amino_acid
+                    .three_letter_code()
+                    .to_uppercase()
+                    .parse::<AminoAcid>()?,
+                amino_acid
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_correctly_creates_an_amino_acid_from_a_char() -> Result<(), Box<dyn core::error::Error>> {
+        assert_eq!(AminoAcid::try_from('m')?, AminoAcid::Met);
+        assert_eq!(AminoAcid::try_from('M')?, AminoAcid::Met);
+
+        let err = AminoAcid::try_from('B').unwrap_err();
+        assert_eq!(err.to_string(), "invalid amino acid `B`");
+
+        let err = AminoAcid::try_from('1').unwrap_err();
+        assert_eq!(err.to_string(), "invalid amino acid `1`");
+
+        Ok(())
+    }
+
+    #[test]
+    fn it_fails_to_parse_invalid_amino_acids() {
+        let err = "Methionine".parse::<AminoAcid>().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ParseError(ParseError::InvalidFormat(_))
+        ));
+        assert_eq!(err.to_string(), "invalid amino acid format `Methionine`");
+
+        let err = "Asx".parse::<AminoAcid>().unwrap_err();
+        assert!(matches!(err, Error::ParseError(ParseError::InvalidCode(_))));
+        assert_eq!(err.to_string(), "invalid amino acid `Asx`");
+
+        let err = "J".parse::<AminoAcid>().unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ParseError(ParseError::InvalidAminoAcid('J'))
+        ));
+        assert_eq!(err.to_string(), "invalid amino acid `J`");
+    }
+
+    #[test]
+    fn it_classifies_the_standard_amino_acids() {
+        let standard = &ALL[..20];
+
+        for amino_acid in standard {
+            assert!(amino_acid.mass().is_some());
+            assert!(amino_acid.hydrophobicity().is_some());
+            assert!(amino_acid.charge().is_some());
+        }
+
+        let charged = standard
+            .iter()
+            .filter(|amino_acid| amino_acid.charge() != Some(Charge::Neutral))
+            .count();
+        assert_eq!(charged, 5);
+
+        assert_eq!(AminoAcid::Sec.mass(), Some(150.95364));
+        assert_eq!(AminoAcid::Pyl.mass(), Some(237.14773));
+        assert_eq!(AminoAcid::Stop.mass(), None);
+        assert_eq!(AminoAcid::Stop.charge(), None);
+        assert_eq!(AminoAcid::Xaa.hydrophobicity(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let json = serde_json::to_string(&AminoAcid::Stop).unwrap();
+        assert_eq!(json, r#""*""#);
+        assert_eq!(
+            serde_json::from_str::<AminoAcid>(&json).unwrap(),
+            AminoAcid::Stop
+        );
+
+        assert_eq!(
+            serde_json::from_str::<AminoAcid>(r#""Trp""#).unwrap(),
+            AminoAcid::Trp
+        );
+        assert!(serde_json::from_str::<AminoAcid>(r#""B""#).is_err());
+    }
+}
@@ -1,5 +1,8 @@
 //! Nucleotides in RNA.
 
+use alloc::string::String;
+use alloc::string::ToString;
+
 use thiserror::Error;
 
 use crate::compound::Kind;
@@ -91,8 +94,8 @@ impl ReverseTranscribe<dna::Nucleotide> for Nucleotide {
     }
 }
 
-impl std::fmt::Display for Nucleotide {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Nucleotide {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Nucleotide::A => write!(f, "A"),
             Nucleotide::C => write!(f, "C"),
@@ -116,7 +119,7 @@ impl TryFrom<char> for Nucleotide {
     }
 }
 
-impl std::str::FromStr for Nucleotide {
+impl core::str::FromStr for Nucleotide {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -148,8 +151,27 @@ impl crate::compound::Complement for Nucleotide {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Nucleotide {
+    /// Serializes a nucleotide as its single-letter code.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Nucleotide {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
+
     use super::*;
 
     #[test]
@@ -186,7 +208,7 @@ mod tests {
     }
 
     #[test]
-    fn it_correctly_creates_a_nucleotide_from_a_char() -> Result<(), Box<dyn std::error::Error>> {
+    fn it_correctly_creates_a_nucleotide_from_a_char() -> Result<(), Box<dyn core::error::Error>> {
         assert_eq!(Nucleotide::try_from('a')?, Nucleotide::A);
         assert_eq!(Nucleotide::try_from('A')?, Nucleotide::A);
         assert_eq!(Nucleotide::try_from('c')?, Nucleotide::C);
@@ -209,7 +231,7 @@ mod tests {
     }
 
     #[test]
-    fn it_correctly_deserializes_nucleotides() -> Result<(), Box<dyn std::error::Error>> {
+    fn it_correctly_deserializes_nucleotides() -> Result<(), Box<dyn core::error::Error>> {
         assert_eq!("a".parse::<Nucleotide>()?, Nucleotide::A);
         assert_eq!("A".parse::<Nucleotide>()?, Nucleotide::A);
         assert_eq!("c".parse::<Nucleotide>()?, Nucleotide::C);
@@ -249,4 +271,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let json = serde_json::to_string(&Nucleotide::U).unwrap();
+        assert_eq!(json, r#""U""#);
+        assert_eq!(
+            serde_json::from_str::<Nucleotide>(&json).unwrap(),
+            Nucleotide::U
+        );
+
+        assert!(serde_json::from_str::<Nucleotide>(r#""T""#).is_err());
+        assert!(serde_json::from_str::<Nucleotide>(r#""AC""#).is_err());
+    }
 }
@@ -6,7 +6,10 @@
 //! valid allele (it denotes a missing side of an insertion or deletion) and is
 //! rendered/parsed using [`omics_core::MISSING_NUCLEOTIDE`].
 
-use std::str::FromStr;
+use alloc::string::String;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::str::FromStr;
 
 use omics_core::MISSING_NUCLEOTIDE;
 use thiserror::Error;
@@ -145,7 +148,7 @@ impl<N: Nucleotide> Sequence<N> {
     /// ```
     pub fn shared_suffix_len(&self, other: &Sequence<N>) -> usize {
         let prefix = self.shared_prefix_len(other);
-        let max = std::cmp::min(self.0.len(), other.0.len()) - prefix;
+        let max = core::cmp::min(self.0.len(), other.0.len()) - prefix;
         self.0
             .iter()
             .rev()
@@ -205,8 +208,8 @@ impl<N: Nucleotide> TryFrom<&str> for Sequence<N> {
     }
 }
 
-impl<N: Nucleotide> std::fmt::Display for Sequence<N> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<N: Nucleotide> core::fmt::Display for Sequence<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.0.is_empty() {
             return write!(f, "{MISSING_NUCLEOTIDE}");
         }
@@ -219,13 +222,33 @@ impl<N: Nucleotide> std::fmt::Display for Sequence<N> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<N: Nucleotide> serde::Serialize for Sequence<N> {
+    /// Serializes a sequence as a string of single-letter codes (or the
+    /// missing nucleotide placeholder for an empty sequence).
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N: Nucleotide> serde::Deserialize<'de> for Sequence<N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
+
     use super::*;
     use crate::polymer::dna;
 
     #[test]
-    fn it_parses_and_displays_a_multibase_sequence() -> Result<(), Box<dyn std::error::Error>> {
+    fn it_parses_and_displays_a_multibase_sequence() -> Result<(), Box<dyn core::error::Error>> {
         let seq = "ACGT".parse::<Sequence<dna::Nucleotide>>()?;
         assert_eq!(seq.len(), 4);
         assert!(!seq.is_empty());
@@ -234,7 +257,7 @@ mod tests {
     }
 
     #[test]
-    fn it_parses_and_displays_the_missing_allele() -> Result<(), Box<dyn std::error::Error>> {
+    fn it_parses_and_displays_the_missing_allele() -> Result<(), Box<dyn core::error::Error>> {
         let seq = ".".parse::<Sequence<dna::Nucleotide>>()?;
         assert!(seq.is_empty());
         assert_eq!(seq.to_string(), ".");
@@ -254,7 +277,7 @@ mod tests {
     }
 
     #[test]
-    fn it_computes_shared_prefix_and_suffix() -> Result<(), Box<dyn std::error::Error>> {
+    fn it_computes_shared_prefix_and_suffix() -> Result<(), Box<dyn core::error::Error>> {
         let a = "AATG".parse::<Sequence<dna::Nucleotide>>()?;
         let b = "AACG".parse::<Sequence<dna::Nucleotide>>()?;
         assert_eq!(a.shared_prefix_len(&b), 2); // "AA"
@@ -277,7 +300,7 @@ mod tests {
     }
 
     #[test]
-    fn shared_prefix_and_suffix_do_not_overlap_count() -> Result<(), Box<dyn std::error::Error>> {
+    fn shared_prefix_and_suffix_do_not_overlap_count() -> Result<(), Box<dyn core::error::Error>> {
         // Identical single base: prefix counts it, suffix must not double-count.
         let a = "A".parse::<Sequence<dna::Nucleotide>>()?;
         let b = "A".parse::<Sequence<dna::Nucleotide>>()?;
@@ -285,4 +308,27 @@ mod tests {
         assert_eq!(a.shared_suffix_len(&b), 0);
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let sequence = "ACGT".parse::<Sequence<dna::Nucleotide>>().unwrap();
+        let json = serde_json::to_string(&sequence).unwrap();
+        assert_eq!(json, r#""ACGT""#);
+        assert_eq!(
+            serde_json::from_str::<Sequence<dna::Nucleotide>>(&json).unwrap(),
+            sequence
+        );
+
+        let empty = Sequence::<dna::Nucleotide>::new(Vec::new());
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(json, r#"".""#);
+        assert_eq!(
+            serde_json::from_str::<Sequence<dna::Nucleotide>>(&json).unwrap(),
+            empty
+        );
+
+        assert!(serde_json::from_str::<Sequence<dna::Nucleotide>>(r#""ACGU""#).is_err());
+        assert!(serde_json::from_str::<Sequence<dna::Nucleotide>>(r#""""#).is_err());
+    }
 }
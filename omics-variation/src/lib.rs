@@ -105,6 +105,11 @@
 //! `Coordinate<Interbase>` and exposes a zero-width `Interval<Interbase>` with
 //! [`insertion::Variant::interbase_interval`].
 //!
+//! Every variant is also generic over the position width of its coordinates,
+//! defaulting to that of `omics-coordinate` (e.g., `Variant<N>` is shorthand
+//! for `Variant<N, u32>`). Use `Variant<N, u64>` for contigs longer than
+//! [`u32::MAX`].
+//!
 //! ```
 //! use omics_molecule::polymer::dna;
 //! use omics_variation::Variant;
@@ -168,6 +173,7 @@ use std::str::FromStr;
 use omics_coordinate::Coordinate;
 use omics_coordinate::Strand;
 use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Base;
 use omics_coordinate::system::Interbase;
 use omics_core::VARIANT_SEPARATOR;
@@ -254,12 +260,12 @@ pub enum Error {
 /// [`Variant::reference_interval`]. Callers that inspect the replacement
 /// sequence usually want [`Variant::alternate_interval`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum VariantInterval {
+pub enum VariantInterval<W: Width = Number> {
     /// A base interval.
-    Base(omics_coordinate::Interval<Base>),
+    Base(omics_coordinate::Interval<Base<W>>),
 
     /// An interbase interval.
-    Interbase(omics_coordinate::Interval<Interbase>),
+    Interbase(omics_coordinate::Interval<Interbase<W>>),
 }
 
 /// A coordinate-system qualifier parsed from a serialized variant position.
@@ -322,7 +328,7 @@ pub(crate) fn split_qualified_position(position: &str) -> Option<(&str, Position
 }
 
 /// Formats a numeric position with its coordinate-system qualifier.
-pub(crate) fn qualified_position(position: Number, qualifier: PositionQualifier) -> String {
+pub(crate) fn qualified_position<W: Width>(position: W, qualifier: PositionQualifier) -> String {
     format!("{position}{}", qualifier.suffix())
 }
 
@@ -333,20 +339,20 @@ pub(crate) fn qualified_position(position: Number, qualifier: PositionQualifier)
 /// are **not** equal unless they are first put through
 /// [`normalize`](Self::normalize).
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Variant<N: Nucleotide> {
+pub enum Variant<N: Nucleotide, W: Width = Number> {
     /// A single nucleotide variant.
-    Snv(snv::Variant<N>),
+    Snv(snv::Variant<N, W>),
     /// A multi-nucleotide variant.
-    Mnv(mnv::Variant<N>),
+    Mnv(mnv::Variant<N, W>),
     /// An insertion.
-    Insertion(insertion::Variant<N>),
+    Insertion(insertion::Variant<N, W>),
     /// A deletion.
-    Deletion(deletion::Variant<N>),
+    Deletion(deletion::Variant<N, W>),
     /// A combined deletion-insertion.
-    Delins(delins::Variant<N>),
+    Delins(delins::Variant<N, W>),
 }
 
-impl<N: Nucleotide> Variant<N> {
+impl<N: Nucleotide, W: Width> Variant<N, W> {
     /// Gets the [`Kind`] of this variant.
     ///
     /// # Examples
@@ -394,7 +400,7 @@ impl<N: Nucleotide> Variant<N> {
     /// ));
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn reference_interval(&self) -> VariantInterval {
+    pub fn reference_interval(&self) -> VariantInterval<W> {
         match self {
             Variant::Snv(variant) => VariantInterval::Base(variant.interval()),
             Variant::Mnv(variant) => VariantInterval::Base(variant.interval()),
@@ -429,7 +435,7 @@ impl<N: Nucleotide> Variant<N> {
     /// ));
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn alternate_interval(&self) -> Option<VariantInterval> {
+    pub fn alternate_interval(&self) -> Option<VariantInterval<W>> {
         match self {
             Variant::Snv(variant) => Some(VariantInterval::Base(variant.interval())),
             Variant::Mnv(variant) => Some(VariantInterval::Base(variant.interval())),
@@ -459,7 +465,7 @@ impl<N: Nucleotide> Variant<N> {
     /// assert_eq!(variant.normalize()?.to_string(), "seq0:+:101(b):T:G");
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn normalize(&self) -> Result<Variant<N>, Error> {
+    pub fn normalize(&self) -> Result<Variant<N, W>, Error> {
         // Only the four base-coordinate kinds can trim; insertions cannot.
         let (start, alteration) = match self {
             Variant::Insertion(_) => return Ok(self.clone()),
@@ -470,8 +476,7 @@ impl<N: Nucleotide> Variant<N> {
         };
 
         let (prefix, trimmed) = alteration.trimmed();
-        let prefix = omics_coordinate::position::Number::try_from(prefix)
-            .map_err(|_| Error::NormalizeOverflow)?;
+        let prefix = W::from_usize(prefix).ok_or(Error::NormalizeOverflow)?;
 
         Ok(match trimmed.kind() {
             Kind::Insertion => {
@@ -512,7 +517,7 @@ impl<N: Nucleotide> Variant<N> {
     }
 }
 
-impl<N: Nucleotide> FromStr for Variant<N> {
+impl<N: Nucleotide, W: Width> FromStr for Variant<N, W> {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -550,30 +555,30 @@ impl<N: Nucleotide> FromStr for Variant<N> {
 
         Ok(match kind {
             Kind::Insertion => {
-                let coordinate = coord.parse::<Coordinate<Interbase>>()?;
+                let coordinate = coord.parse::<Coordinate<Interbase<W>>>()?;
                 Variant::Insertion(insertion::Variant::try_from((coordinate, alteration))?)
             }
             Kind::Snv => {
-                let coordinate = coord.parse::<Coordinate<Base>>()?;
+                let coordinate = coord.parse::<Coordinate<Base<W>>>()?;
                 Variant::Snv(snv::Variant::try_from((coordinate, alteration))?)
             }
             Kind::Mnv => {
-                let coordinate = coord.parse::<Coordinate<Base>>()?;
+                let coordinate = coord.parse::<Coordinate<Base<W>>>()?;
                 Variant::Mnv(mnv::Variant::try_from((coordinate, alteration))?)
             }
             Kind::Deletion => {
-                let coordinate = coord.parse::<Coordinate<Base>>()?;
+                let coordinate = coord.parse::<Coordinate<Base<W>>>()?;
                 Variant::Deletion(deletion::Variant::try_from((coordinate, alteration))?)
             }
             Kind::Delins => {
-                let coordinate = coord.parse::<Coordinate<Base>>()?;
+                let coordinate = coord.parse::<Coordinate<Base<W>>>()?;
                 Variant::Delins(delins::Variant::try_from((coordinate, alteration))?)
             }
         })
     }
 }
 
-impl<N: Nucleotide> std::fmt::Display for Variant<N> {
+impl<N: Nucleotide, W: Width> std::fmt::Display for Variant<N, W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         // The coordinate types differ (insertion is interbase), so read the
         // display primitives per arm rather than unifying the coordinate type.
@@ -626,6 +631,23 @@ impl<N: Nucleotide> std::fmt::Display for Variant<N> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<N: Nucleotide, W: Width> serde::Serialize for Variant<N, W> {
+    /// Serializes a variant in its canonical string form.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N: Nucleotide, W: Width> serde::Deserialize<'de> for Variant<N, W> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use omics_coordinate::Strand;
@@ -705,6 +727,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_supports_wide_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let input = "seq0:+:5000000000(b):AT:AG";
+        assert!(input.parse::<Variant<dna::Nucleotide>>().is_err());
+
+        let variant = input.parse::<Variant<dna::Nucleotide, u64>>()?;
+        assert_eq!(variant.to_string(), input);
+        assert_eq!(variant.normalize()?.to_string(), "seq0:+:5000000001(b):T:G");
+
+        match variant.reference_interval() {
+            VariantInterval::Base(interval) => {
+                assert_eq!(interval.end().position().get(), 5_000_000_001)
+            }
+            VariantInterval::Interbase(_) => panic!("expected base reference interval"),
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn it_rejects_missing_coordinate_system_qualifiers() {
         let err = "seq0:+:100:A:C"
@@ -904,4 +945,23 @@ mod tests {
         }
         Ok(())
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_through_serde() -> Result<(), Box<dyn std::error::Error>> {
+        let variant = "seq0:-:100(b):AT:.".parse::<Variant<dna::Nucleotide>>()?;
+        let json = serde_json::to_string(&variant)?;
+        assert_eq!(json, r#""seq0:-:100(b):AT:.""#);
+        assert_eq!(
+            serde_json::from_str::<Variant<dna::Nucleotide>>(&json)?,
+            variant
+        );
+
+        // Deserialization validates just as parsing does.
+        assert!(
+            serde_json::from_str::<Variant<dna::Nucleotide>>(r#""seq0:+:100(i):A:C""#).is_err()
+        );
+        assert!(serde_json::from_str::<Variant<dna::Nucleotide>>(r#""seq0:+:0(b):A:C""#).is_err());
+        Ok(())
+    }
 }
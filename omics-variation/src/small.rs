@@ -27,7 +27,7 @@
 
 use omics_coordinate::Coordinate;
 use omics_coordinate::Interval;
-use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Base;
 use omics_coordinate::system::Interbase;
 use omics_molecule::compound::Nucleotide;
@@ -56,21 +56,28 @@ pub enum Kind {
 }
 
 /// Builds a base interval from a start coordinate and allele length.
-pub(crate) fn base_interval(start: &Coordinate<Base>, len: usize) -> Option<Interval<Base>> {
-    let span = Number::try_from(len.checked_sub(1)?).ok()?;
+pub(crate) fn base_interval<W: Width>(
+    start: &Coordinate<Base<W>>,
+    len: usize,
+) -> Option<Interval<Base<W>>> {
+    let span = W::from_usize(len.checked_sub(1)?)?;
     let end = start.clone().into_move_forward(span)?;
     Interval::try_new(start.clone(), end).ok()
 }
 
 /// Builds a zero-width interbase interval at a boundary coordinate.
-pub(crate) fn interbase_interval(coordinate: &Coordinate<Interbase>) -> Interval<Interbase> {
+pub(crate) fn interbase_interval<W: Width>(
+    coordinate: &Coordinate<Interbase<W>>,
+) -> Interval<Interbase<W>> {
     // SAFETY: an interbase interval whose start equals its end is always
     // valid.
     Interval::try_new(coordinate.clone(), coordinate.clone()).unwrap()
 }
 
 /// Builds a zero-width interbase interval immediately before a base coordinate.
-pub(crate) fn interbase_interval_before_base(coordinate: &Coordinate<Base>) -> Interval<Interbase> {
+pub(crate) fn interbase_interval_before_base<W: Width>(
+    coordinate: &Coordinate<Base<W>>,
+) -> Interval<Interbase<W>> {
     // SAFETY: every valid base coordinate has a preceding interbase boundary.
     let boundary = coordinate.clone().nudge_backward().unwrap();
     interbase_interval(&boundary)
@@ -4,6 +4,7 @@ use omics_coordinate::Coordinate;
 use omics_coordinate::Interval;
 use omics_coordinate::coordinate;
 use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Base;
 use omics_molecule::compound::Nucleotide;
 use omics_molecule::sequence;
@@ -20,13 +21,13 @@ use crate::small::base_interval;
 /// qualifier. Interbase coordinates with `(i)` are rejected because a delins
 /// replaces existing bases over a base interval.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Variant<N: Nucleotide> {
+pub struct Variant<N: Nucleotide, W: Width = Number> {
     /// The coordinate of the first replaced base.
     ///
     /// Construction guarantees that advancing this forward by
     /// `reference.len() - 1` stays within the position bounds, so
     /// [`interval`](Self::interval) cannot overflow.
-    coordinate: Coordinate<Base>,
+    coordinate: Coordinate<Base<W>>,
 
     /// Both alleles non-empty and of differing lengths.
     ///
@@ -34,7 +35,7 @@ pub struct Variant<N: Nucleotide> {
     alteration: Alteration<N>,
 }
 
-impl<N: Nucleotide> Variant<N> {
+impl<N: Nucleotide, W: Width> Variant<N, W> {
     /// Attempts to create a new delins from a coordinate and the reference and
     /// alternate bases.
     ///
@@ -49,7 +50,7 @@ impl<N: Nucleotide> Variant<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn try_new(
-        coordinate: impl TryInto<Coordinate<Base>, Error = coordinate::Error>,
+        coordinate: impl TryInto<Coordinate<Base<W>>, Error = coordinate::Error>,
         reference: impl TryInto<Sequence<N>, Error = sequence::ParseError>,
         alternate: impl TryInto<Sequence<N>, Error = sequence::ParseError>,
     ) -> Result<Self, KindError> {
@@ -71,7 +72,7 @@ impl<N: Nucleotide> Variant<N> {
     /// assert_eq!(variant.coordinate().position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn coordinate(&self) -> &Coordinate<Base> {
+    pub fn coordinate(&self) -> &Coordinate<Base<W>> {
         &self.coordinate
     }
 
@@ -119,7 +120,7 @@ impl<N: Nucleotide> Variant<N> {
     /// assert_eq!(variant.interval().end().position().get(), 101);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn reference_interval(&self) -> Interval<Base> {
+    pub fn reference_interval(&self) -> Interval<Base<W>> {
         // SAFETY: construction validated that this span is representable.
         base_interval(&self.coordinate, self.alteration.reference().len()).unwrap()
     }
@@ -148,12 +149,12 @@ impl<N: Nucleotide> Variant<N> {
     /// );
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn alternate_interval(&self) -> Option<Interval<Base>> {
+    pub fn alternate_interval(&self) -> Option<Interval<Base<W>>> {
         base_interval(&self.coordinate, self.alteration.alternate().len())
     }
 
     /// Gets the interval spanned by the reference allele.
-    pub fn interval(&self) -> Interval<Base> {
+    pub fn interval(&self) -> Interval<Base<W>> {
         self.reference_interval()
     }
 
@@ -173,7 +174,7 @@ impl<N: Nucleotide> Variant<N> {
     }
 }
 
-impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
+impl<N: Nucleotide, W: Width> TryFrom<(Coordinate<Base<W>>, Alteration<N>)> for Variant<N, W> {
     type Error = KindError;
 
     /// Builds a delins from a base [`Coordinate`] and a classified delins
@@ -195,7 +196,7 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     fn try_from(
-        (coordinate, alteration): (Coordinate<Base>, Alteration<N>),
+        (coordinate, alteration): (Coordinate<Base<W>>, Alteration<N>),
     ) -> Result<Self, Self::Error> {
         let found = alteration.kind();
         if found != Kind::Delins {
@@ -206,8 +207,8 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
         }
 
         // Validate the span is representable now so `interval` cannot panic.
-        let span = Number::try_from(alteration.reference().len() - 1)
-            .map_err(|_| KindError::SpanOverflow)?;
+        let span =
+            W::from_usize(alteration.reference().len() - 1).ok_or(KindError::SpanOverflow)?;
         coordinate
             .clone()
             .into_move_forward(span)
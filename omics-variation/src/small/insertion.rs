@@ -3,6 +3,8 @@
 use omics_coordinate::Coordinate;
 use omics_coordinate::Interval;
 use omics_coordinate::coordinate;
+use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Base;
 use omics_coordinate::system::Interbase;
 use omics_molecule::compound::Nucleotide;
@@ -21,9 +23,9 @@ use crate::small::interbase_interval;
 /// qualifier. Base coordinates with `(b)` are rejected because an insertion
 /// occurs at a boundary between bases rather than on an existing base.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Variant<N: Nucleotide> {
+pub struct Variant<N: Nucleotide, W: Width = Number> {
     /// The interbase boundary at which the alternate allele is inserted.
-    coordinate: Coordinate<Interbase>,
+    coordinate: Coordinate<Interbase<W>>,
 
     /// An empty reference allele paired with a non-empty alternate allele.
     ///
@@ -31,7 +33,7 @@ pub struct Variant<N: Nucleotide> {
     alteration: Alteration<N>,
 }
 
-impl<N: Nucleotide> Variant<N> {
+impl<N: Nucleotide, W: Width> Variant<N, W> {
     /// Attempts to create a new insertion from an interbase coordinate and the
     /// inserted bases.
     ///
@@ -46,7 +48,7 @@ impl<N: Nucleotide> Variant<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn try_new(
-        coordinate: impl TryInto<Coordinate<Interbase>, Error = coordinate::Error>,
+        coordinate: impl TryInto<Coordinate<Interbase<W>>, Error = coordinate::Error>,
         alternate: impl TryInto<Sequence<N>, Error = sequence::ParseError>,
     ) -> Result<Self, KindError> {
         let coordinate = coordinate.try_into()?;
@@ -66,7 +68,7 @@ impl<N: Nucleotide> Variant<N> {
     /// assert_eq!(variant.coordinate().position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn coordinate(&self) -> &Coordinate<Interbase> {
+    pub fn coordinate(&self) -> &Coordinate<Interbase<W>> {
         &self.coordinate
     }
 
@@ -103,7 +105,7 @@ impl<N: Nucleotide> Variant<N> {
     /// );
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn reference_interval(&self) -> Interval<Interbase> {
+    pub fn reference_interval(&self) -> Interval<Interbase<W>> {
         interbase_interval(&self.coordinate)
     }
 
@@ -131,13 +133,13 @@ impl<N: Nucleotide> Variant<N> {
     /// );
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn alternate_interval(&self) -> Option<Interval<Base>> {
+    pub fn alternate_interval(&self) -> Option<Interval<Base<W>>> {
         let start = self.coordinate.clone().nudge_forward()?;
         base_interval(&start, self.alteration.alternate().len())
     }
 
     /// Gets the zero-width interbase interval at the insertion boundary.
-    pub fn interbase_interval(&self) -> Interval<Interbase> {
+    pub fn interbase_interval(&self) -> Interval<Interbase<W>> {
         self.reference_interval()
     }
 
@@ -157,7 +159,7 @@ impl<N: Nucleotide> Variant<N> {
     }
 }
 
-impl<N: Nucleotide> TryFrom<(Coordinate<Interbase>, Alteration<N>)> for Variant<N> {
+impl<N: Nucleotide, W: Width> TryFrom<(Coordinate<Interbase<W>>, Alteration<N>)> for Variant<N, W> {
     type Error = KindError;
 
     /// Builds an insertion from an interbase [`Coordinate`] and a classified
@@ -179,7 +181,7 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Interbase>, Alteration<N>)> for Variant<
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     fn try_from(
-        (coordinate, alteration): (Coordinate<Interbase>, Alteration<N>),
+        (coordinate, alteration): (Coordinate<Interbase<W>>, Alteration<N>),
     ) -> Result<Self, Self::Error> {
         let found = alteration.kind();
         if found != Kind::Insertion {
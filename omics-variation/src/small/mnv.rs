@@ -4,6 +4,7 @@ use omics_coordinate::Coordinate;
 use omics_coordinate::Interval;
 use omics_coordinate::coordinate;
 use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Base;
 use omics_molecule::compound::Nucleotide;
 use omics_molecule::sequence;
@@ -21,13 +22,13 @@ use crate::small::base_interval;
 /// qualifier. Interbase coordinates with `(i)` are rejected because an `MNV`
 /// substitutes existing bases over a base interval.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Variant<N: Nucleotide> {
+pub struct Variant<N: Nucleotide, W: Width = Number> {
     /// The coordinate of the first substituted base.
     ///
     /// Construction guarantees that advancing this forward by
     /// `reference.len() - 1` stays within the position bounds, so
     /// [`interval`](Self::interval) cannot overflow.
-    coordinate: Coordinate<Base>,
+    coordinate: Coordinate<Base<W>>,
 
     /// Equal-length, non-empty, differing reference and alternate alleles.
     ///
@@ -35,7 +36,7 @@ pub struct Variant<N: Nucleotide> {
     alteration: Alteration<N>,
 }
 
-impl<N: Nucleotide> Variant<N> {
+impl<N: Nucleotide, W: Width> Variant<N, W> {
     /// Attempts to create a new MNV from a coordinate and the reference and
     /// alternate bases.
     ///
@@ -50,7 +51,7 @@ impl<N: Nucleotide> Variant<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn try_new(
-        coordinate: impl TryInto<Coordinate<Base>, Error = coordinate::Error>,
+        coordinate: impl TryInto<Coordinate<Base<W>>, Error = coordinate::Error>,
         reference: impl TryInto<Sequence<N>, Error = sequence::ParseError>,
         alternate: impl TryInto<Sequence<N>, Error = sequence::ParseError>,
     ) -> Result<Self, KindError> {
@@ -72,7 +73,7 @@ impl<N: Nucleotide> Variant<N> {
     /// assert_eq!(variant.coordinate().position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn coordinate(&self) -> &Coordinate<Base> {
+    pub fn coordinate(&self) -> &Coordinate<Base<W>> {
         &self.coordinate
     }
 
@@ -120,7 +121,7 @@ impl<N: Nucleotide> Variant<N> {
     /// assert_eq!(variant.interval().end().position().get(), 101);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn interval(&self) -> Interval<Base> {
+    pub fn interval(&self) -> Interval<Base<W>> {
         // SAFETY: construction validated that this span is representable.
         base_interval(&self.coordinate, self.alteration.reference().len()).unwrap()
     }
@@ -141,7 +142,7 @@ impl<N: Nucleotide> Variant<N> {
     }
 }
 
-impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
+impl<N: Nucleotide, W: Width> TryFrom<(Coordinate<Base<W>>, Alteration<N>)> for Variant<N, W> {
     type Error = KindError;
 
     /// Builds an MNV from a base [`Coordinate`] and a classified MNV
@@ -163,7 +164,7 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     fn try_from(
-        (coordinate, alteration): (Coordinate<Base>, Alteration<N>),
+        (coordinate, alteration): (Coordinate<Base<W>>, Alteration<N>),
     ) -> Result<Self, Self::Error> {
         let found = alteration.kind();
         if found != Kind::Mnv {
@@ -174,8 +175,8 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
         }
 
         // Validate the span is representable now so `interval` cannot panic.
-        let span = Number::try_from(alteration.reference().len() - 1)
-            .map_err(|_| KindError::SpanOverflow)?;
+        let span =
+            W::from_usize(alteration.reference().len() - 1).ok_or(KindError::SpanOverflow)?;
         coordinate
             .clone()
             .into_move_forward(span)
@@ -6,6 +6,8 @@ use omics_coordinate::Coordinate;
 use omics_coordinate::Interval;
 use omics_coordinate::Strand;
 use omics_coordinate::coordinate;
+use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Base;
 use omics_core::VARIANT_SEPARATOR;
 use omics_molecule::compound::Nucleotide;
@@ -78,9 +80,9 @@ where
 /// Interbase coordinates with `(i)` are rejected because an `SNV` substitutes
 /// one existing base.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Variant<N: Nucleotide> {
+pub struct Variant<N: Nucleotide, W: Width = Number> {
     /// The coordinate of the substituted base.
-    coordinate: Coordinate<Base>,
+    coordinate: Coordinate<Base<W>>,
 
     /// Reference and alternate alleles, each exactly one base.
     ///
@@ -88,7 +90,7 @@ pub struct Variant<N: Nucleotide> {
     alteration: Alteration<N>,
 }
 
-impl<N: Nucleotide> Variant<N>
+impl<N: Nucleotide, W: Width> Variant<N, W>
 where
     <N as FromStr>::Err: std::fmt::Debug + std::fmt::Display,
 {
@@ -111,7 +113,7 @@ where
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn try_new(
-        coordinate: impl Into<Coordinate<Base>>,
+        coordinate: impl Into<Coordinate<Base<W>>>,
         reference_nucleotide: impl Into<N>,
         alternate_nucleotide: impl Into<N>,
     ) -> Result<Self, Error<N>> {
@@ -135,7 +137,7 @@ where
     }
 }
 
-impl<N: Nucleotide> Variant<N> {
+impl<N: Nucleotide, W: Width> Variant<N, W> {
     /// Gets the [`Coordinate`] for this [`Variant`].
     ///
     /// # Examples
@@ -155,7 +157,7 @@ impl<N: Nucleotide> Variant<N> {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn coordinate(&self) -> &Coordinate<Base> {
+    pub fn coordinate(&self) -> &Coordinate<Base<W>> {
         &self.coordinate
     }
 
@@ -215,7 +217,7 @@ impl<N: Nucleotide> Variant<N> {
     ///
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn interval(&self) -> Interval<Base> {
+    pub fn interval(&self) -> Interval<Base<W>> {
         // SAFETY: an SNV reference allele always has exactly one base.
         base_interval(&self.coordinate, self.alteration.reference().len()).unwrap()
     }
@@ -239,7 +241,7 @@ impl<N: Nucleotide> Variant<N> {
     }
 }
 
-impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
+impl<N: Nucleotide, W: Width> TryFrom<(Coordinate<Base<W>>, Alteration<N>)> for Variant<N, W> {
     type Error = KindError;
 
     /// Builds a single nucleotide [`Variant`] from a base [`Coordinate`] and a
@@ -262,7 +264,7 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     fn try_from(
-        (coordinate, alteration): (Coordinate<Base>, Alteration<N>),
+        (coordinate, alteration): (Coordinate<Base<W>>, Alteration<N>),
     ) -> Result<Self, Self::Error> {
         let found = alteration.kind();
         if found != Kind::Snv {
@@ -279,7 +281,7 @@ impl<N: Nucleotide> TryFrom<(Coordinate<Base>, Alteration<N>)> for Variant<N> {
     }
 }
 
-impl<N: Nucleotide> std::str::FromStr for Variant<N>
+impl<N: Nucleotide, W: Width> std::str::FromStr for Variant<N, W>
 where
     <N as FromStr>::Err: std::fmt::Debug + std::fmt::Display,
 {
@@ -329,7 +331,7 @@ where
         }
         let coordinate = [contig, strand.as_str(), position].join(VARIANT_SEPARATOR);
 
-        let coordinate = match coordinate.parse::<Coordinate<Base>>() {
+        let coordinate = match coordinate.parse::<Coordinate<Base<W>>>() {
             Ok(coordinate) => coordinate,
             Err(err) => return Err(Error::Parse(ParseError::CoordinateError(err))),
         };
@@ -354,7 +356,7 @@ where
     }
 }
 
-impl<N: Nucleotide> std::fmt::Display for Variant<N> {
+impl<N: Nucleotide, W: Width> std::fmt::Display for Variant<N, W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let coordinate = [
             self.coordinate().contig().to_string(),
@@ -394,6 +396,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_creates_a_variant_with_an_ambiguous_allele() -> Result<(), Box<dyn std::error::Error>> {
+        let variant = "seq0:+:1(b):N:R".parse::<Variant<dna::iupac::Nucleotide>>()?;
+
+        assert_eq!(variant.reference(), dna::iupac::Nucleotide::N);
+        assert_eq!(variant.alternate(), dna::iupac::Nucleotide::R);
+        assert!(variant.reference().matches(&variant.alternate()));
+        assert_eq!(variant.to_string(), "seq0:+:1(b):N:R");
+
+        Ok(())
+    }
+
     #[test]
     fn it_creates_a_variant_in_a_rna_context() -> Result<(), Box<dyn std::error::Error>> {
         let variant = "seq0:+:1(b):U:C".parse::<Variant<rna::Nucleotide>>()?;
@@ -160,6 +160,7 @@ use std::fmt;
 use std::str::FromStr;
 
 use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_molecule::compound::Complement;
 use omics_molecule::compound::Nucleotide;
 use thiserror::Error;
@@ -268,12 +269,12 @@ pub enum ParseError {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct StructuralVariant<N: Nucleotide> {
+pub struct StructuralVariant<N: Nucleotide, W: Width = Number> {
     /// The adjacencies making up the event, held in normalized order.
-    adjacencies: Vec<Adjacency<N>>,
+    adjacencies: Vec<Adjacency<N, W>>,
 }
 
-impl<N: Nucleotide> StructuralVariant<N> {
+impl<N: Nucleotide, W: Width> StructuralVariant<N, W> {
     /// Attempts to create a new [`StructuralVariant`].
     ///
     /// The supplied adjacencies are normalized, meaning they are sorted by a
@@ -297,7 +298,7 @@ impl<N: Nucleotide> StructuralVariant<N> {
     /// assert_eq!(variant.adjacencies().len(), 1);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn try_new(mut adjacencies: Vec<Adjacency<N>>) -> Result<Self, Error> {
+    pub fn try_new(mut adjacencies: Vec<Adjacency<N, W>>) -> Result<Self, Error> {
         if adjacencies.is_empty() {
             return Err(Error::Empty);
         }
@@ -326,7 +327,7 @@ impl<N: Nucleotide> StructuralVariant<N> {
     /// assert_eq!(variant.adjacencies().len(), 1);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn adjacencies(&self) -> &[Adjacency<N>] {
+    pub fn adjacencies(&self) -> &[Adjacency<N, W>] {
         &self.adjacencies
     }
 
@@ -359,7 +360,7 @@ impl<N: Nucleotide> StructuralVariant<N> {
     }
 }
 
-impl<N: Nucleotide> fmt::Display for StructuralVariant<N> {
+impl<N: Nucleotide, W: Width> fmt::Display for StructuralVariant<N, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let tokens = self
             .adjacencies
@@ -370,7 +371,7 @@ impl<N: Nucleotide> fmt::Display for StructuralVariant<N> {
     }
 }
 
-impl<N: Nucleotide + Complement> FromStr for StructuralVariant<N> {
+impl<N: Nucleotide + Complement, W: Width> FromStr for StructuralVariant<N, W> {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -380,7 +381,7 @@ impl<N: Nucleotide + Complement> FromStr for StructuralVariant<N> {
 
         let adjacencies = s
             .split(EVENT_SEPARATOR)
-            .map(|token| token.parse::<Adjacency<N>>())
+            .map(|token| token.parse::<Adjacency<N, W>>())
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(StructuralVariant::try_new(adjacencies)?)
@@ -391,10 +392,10 @@ impl<N: Nucleotide + Complement> FromStr for StructuralVariant<N> {
 ///
 /// It mirrors [`Breakend::canonical_key`] but owns its contig so it can back a
 /// sortable key for a whole adjacency.
-type BreakendKey = (String, Number, u8);
+type BreakendKey<W> = (String, W, u8);
 
 /// Gets the owned canonical ordering key for a breakend.
-fn breakend_key(breakend: &Breakend) -> BreakendKey {
+fn breakend_key<W: Width>(breakend: &Breakend<W>) -> BreakendKey<W> {
     let (contig, position, rank) = breakend.canonical_key();
     (contig.to_string(), position, rank)
 }
@@ -404,9 +405,9 @@ fn breakend_key(breakend: &Breakend) -> BreakendKey {
 /// The key tags paired adjacencies ahead of single ones, then orders by the
 /// canonical breakend keys and finally by the insertion sequence. It backs the
 /// normalized ordering of the adjacencies in a [`StructuralVariant`].
-fn adjacency_key<N: Nucleotide>(
-    adjacency: &Adjacency<N>,
-) -> (u8, BreakendKey, BreakendKey, String) {
+fn adjacency_key<N: Nucleotide, W: Width>(
+    adjacency: &Adjacency<N, W>,
+) -> (u8, BreakendKey<W>, BreakendKey<W>, String) {
     match adjacency {
         Adjacency::Paired(paired) => (
             0,
@@ -417,14 +418,16 @@ fn adjacency_key<N: Nucleotide>(
         Adjacency::Single(single) => (
             1,
             breakend_key(single.breakend()),
-            (String::new(), 0, 0),
+            (String::new(), W::ZERO, 0),
             single.insertion().to_string(),
         ),
     }
 }
 
 /// Gets the two breakends of a paired adjacency, or `None` for a single one.
-fn paired_breakends<N: Nucleotide>(adjacency: &Adjacency<N>) -> Option<(&Breakend, &Breakend)> {
+fn paired_breakends<N: Nucleotide, W: Width>(
+    adjacency: &Adjacency<N, W>,
+) -> Option<(&Breakend<W>, &Breakend<W>)> {
     match adjacency {
         Adjacency::Paired(paired) => Some((paired.a(), paired.b())),
         Adjacency::Single(_) => None,
@@ -435,7 +438,7 @@ fn paired_breakends<N: Nucleotide>(adjacency: &Adjacency<N>) -> Option<(&Breaken
 ///
 /// A single-ended adjacency has no second known locus, so its presence makes
 /// the answer `false`.
-fn one_contig<N: Nucleotide>(adjacencies: &[&Adjacency<N>]) -> bool {
+fn one_contig<N: Nucleotide, W: Width>(adjacencies: &[&Adjacency<N, W>]) -> bool {
     let mut contig: Option<&str> = None;
     for adjacency in adjacencies {
         let Some((a, b)) = paired_breakends(adjacency) else {
@@ -455,7 +458,9 @@ fn one_contig<N: Nucleotide>(adjacencies: &[&Adjacency<N>]) -> bool {
 /// Gets the shared orientation of a fold-back adjacency, or `None` otherwise.
 ///
 /// A fold-back is a paired adjacency whose two breakends share an orientation.
-fn fold_back_orientation<N: Nucleotide>(adjacency: &Adjacency<N>) -> Option<Orientation> {
+fn fold_back_orientation<N: Nucleotide, W: Width>(
+    adjacency: &Adjacency<N, W>,
+) -> Option<Orientation> {
     match paired_breakends(adjacency) {
         Some((a, b)) if a.orientation() == b.orientation() => Some(a.orientation()),
         _ => None,
@@ -463,14 +468,14 @@ fn fold_back_orientation<N: Nucleotide>(adjacency: &Adjacency<N>) -> Option<Orie
 }
 
 /// Gets the sorted pair of interbase positions of a paired adjacency.
-fn position_pair<N: Nucleotide>(adjacency: &Adjacency<N>) -> Option<(Number, Number)> {
+fn position_pair<N: Nucleotide, W: Width>(adjacency: &Adjacency<N, W>) -> Option<(W, W)> {
     let (a, b) = paired_breakends(adjacency)?;
     let (lo, hi) = (a.position().get(), b.position().get());
     Some(if lo <= hi { (lo, hi) } else { (hi, lo) })
 }
 
 /// Classifies a structural variant made of exactly one adjacency.
-fn classify_single<N: Nucleotide>(adjacency: &Adjacency<N>) -> Kind {
+fn classify_single<N: Nucleotide, W: Width>(adjacency: &Adjacency<N, W>) -> Kind {
     let Adjacency::Paired(paired) = adjacency else {
         // A single-ended breakend.
         return Kind::Breakend;
@@ -511,7 +516,10 @@ fn classify_single<N: Nucleotide>(adjacency: &Adjacency<N>) -> Kind {
 /// An inversion is exactly two paired fold-back adjacencies on one contig over
 /// the same two boundary positions, one both-`LowerFlank` and one
 /// both-`HigherFlank`. Anything else is [`Kind::Complex`].
-fn classify_pair<N: Nucleotide>(first: &Adjacency<N>, second: &Adjacency<N>) -> Kind {
+fn classify_pair<N: Nucleotide, W: Width>(
+    first: &Adjacency<N, W>,
+    second: &Adjacency<N, W>,
+) -> Kind {
     if !one_contig(&[first, second]) {
         return Kind::Complex;
     }
@@ -541,10 +549,10 @@ fn classify_pair<N: Nucleotide>(first: &Adjacency<N>, second: &Adjacency<N>) ->
 /// where each boundary appears exactly once as a `LowerFlank` and exactly once
 /// as a `HigherFlank` across the three junctions. Anything else, including a
 /// triple that reuses a flank or one with a fold-back, is [`Kind::Complex`].
-fn classify_triple<N: Nucleotide>(
-    first: &Adjacency<N>,
-    second: &Adjacency<N>,
-    third: &Adjacency<N>,
+fn classify_triple<N: Nucleotide, W: Width>(
+    first: &Adjacency<N, W>,
+    second: &Adjacency<N, W>,
+    third: &Adjacency<N, W>,
 ) -> Kind {
     let adjacencies = [first, second, third];
     if !one_contig(&adjacencies) {
@@ -568,7 +576,7 @@ fn classify_triple<N: Nucleotide>(
     // Tally, per boundary position, how often it appears as a `LowerFlank` and
     // as a `HigherFlank`. The triangle requires exactly three distinct
     // boundaries, each appearing once as each flank.
-    let mut counts: Vec<(Number, usize, usize)> = Vec::new();
+    let mut counts: Vec<(W, usize, usize)> = Vec::new();
     for adjacency in adjacencies {
         if let Some((a, b)) = paired_breakends(adjacency) {
             for breakend in [a, b] {
@@ -605,6 +613,25 @@ fn classify_triple<N: Nucleotide>(
     }
 }
 
+#[cfg(feature = "serde")]
+impl<N: Nucleotide, W: Width> serde::Serialize for StructuralVariant<N, W> {
+    /// Serializes a structural variant in its canonical string form.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, N: Nucleotide + Complement, W: Width> serde::Deserialize<'de>
+    for StructuralVariant<N, W>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use omics_coordinate::position::Number;
@@ -1035,4 +1062,16 @@ mod tests {
         assert_eq!(canonical.kind(), with_duplicate.kind());
         assert_eq!(with_duplicate.kind(), Kind::Inversion);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn it_round_trips_through_serde() {
+        let variant = "seq0:>:100(i)::seq0:<:200(i)::.".parse::<Sv>().unwrap();
+        let json = serde_json::to_string(&variant).unwrap();
+        assert_eq!(json, r#""seq0:>:100(i)::seq0:<:200(i)::.""#);
+        assert_eq!(serde_json::from_str::<Sv>(&json).unwrap(), variant);
+
+        assert!(serde_json::from_str::<Sv>(r#""""#).is_err());
+        assert!(serde_json::from_str::<Sv>(r#""seq0:>:100(i)::AT""#).is_err());
+    }
 }
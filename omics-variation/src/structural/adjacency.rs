@@ -3,6 +3,8 @@
 use std::fmt;
 use std::str::FromStr;
 
+use omics_coordinate::position::Number;
+use omics_coordinate::position::Width;
 use omics_molecule::compound::Complement;
 use omics_molecule::compound::Nucleotide;
 use omics_molecule::sequence;
@@ -66,18 +68,18 @@ pub enum ParseError {
 /// A value of this type can only be built through [`PairedAdjacency::try_new`],
 /// so it always upholds those invariants.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct PairedAdjacency<N: Nucleotide> {
+pub struct PairedAdjacency<N: Nucleotide, W: Width = Number> {
     /// The canonical lower-locus breakend.
-    a: Breakend,
+    a: Breakend<W>,
 
     /// The canonical higher-locus breakend.
-    b: Breakend,
+    b: Breakend<W>,
 
     /// The non-templated insertion, in the reading frame of `a`.
     insertion: Sequence<N>,
 }
 
-impl<N: Nucleotide + Complement> PairedAdjacency<N> {
+impl<N: Nucleotide + Complement, W: Width> PairedAdjacency<N, W> {
     /// Attempts to create a [`PairedAdjacency`] in canonical form.
     ///
     /// The two breakends are ordered by their canonical key, which sorts by
@@ -106,7 +108,7 @@ impl<N: Nucleotide + Complement> PairedAdjacency<N> {
     /// assert_eq!(paired.a().position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn try_new(x: Breakend, y: Breakend, insertion: Sequence<N>) -> Result<Self, Error> {
+    pub fn try_new(x: Breakend<W>, y: Breakend<W>, insertion: Sequence<N>) -> Result<Self, Error> {
         let (a, b, insertion) = if x.canonical_key() <= y.canonical_key() {
             (x, y, insertion)
         } else {
@@ -131,7 +133,7 @@ impl<N: Nucleotide + Complement> PairedAdjacency<N> {
     }
 }
 
-impl<N: Nucleotide> PairedAdjacency<N> {
+impl<N: Nucleotide, W: Width> PairedAdjacency<N, W> {
     /// Gets the canonical lower-locus breakend.
     ///
     /// # Examples
@@ -147,7 +149,7 @@ impl<N: Nucleotide> PairedAdjacency<N> {
     /// assert_eq!(paired.a().position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn a(&self) -> &Breakend {
+    pub fn a(&self) -> &Breakend<W> {
         &self.a
     }
 
@@ -166,7 +168,7 @@ impl<N: Nucleotide> PairedAdjacency<N> {
     /// assert_eq!(paired.b().position().get(), 200);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn b(&self) -> &Breakend {
+    pub fn b(&self) -> &Breakend<W> {
         &self.b
     }
 
@@ -190,7 +192,7 @@ impl<N: Nucleotide> PairedAdjacency<N> {
     }
 }
 
-impl<N: Nucleotide> fmt::Display for PairedAdjacency<N> {
+impl<N: Nucleotide, W: Width> fmt::Display for PairedAdjacency<N, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -205,15 +207,15 @@ impl<N: Nucleotide> fmt::Display for PairedAdjacency<N> {
 /// One side is a known breakend and the other is open, so the adjacency carries
 /// a single locus and the non-templated insertion at its open side.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct SingleAdjacency<N: Nucleotide> {
+pub struct SingleAdjacency<N: Nucleotide, W: Width = Number> {
     /// The single known breakend.
-    breakend: Breakend,
+    breakend: Breakend<W>,
 
     /// The non-templated insertion at the open side.
     insertion: Sequence<N>,
 }
 
-impl<N: Nucleotide> SingleAdjacency<N> {
+impl<N: Nucleotide, W: Width> SingleAdjacency<N, W> {
     /// Creates a [`SingleAdjacency`].
     ///
     /// # Examples
@@ -229,7 +231,7 @@ impl<N: Nucleotide> SingleAdjacency<N> {
     /// assert_eq!(single.insertion().to_string(), "AT");
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new(breakend: Breakend, insertion: Sequence<N>) -> Self {
+    pub fn new(breakend: Breakend<W>, insertion: Sequence<N>) -> Self {
         Self {
             breakend,
             insertion,
@@ -250,7 +252,7 @@ impl<N: Nucleotide> SingleAdjacency<N> {
     /// assert_eq!(single.breakend().position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn breakend(&self) -> &Breakend {
+    pub fn breakend(&self) -> &Breakend<W> {
         &self.breakend
     }
 
@@ -273,7 +275,7 @@ impl<N: Nucleotide> SingleAdjacency<N> {
     }
 }
 
-impl<N: Nucleotide> fmt::Display for SingleAdjacency<N> {
+impl<N: Nucleotide, W: Width> fmt::Display for SingleAdjacency<N, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
@@ -306,16 +308,16 @@ impl<N: Nucleotide> fmt::Display for SingleAdjacency<N> {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum Adjacency<N: Nucleotide> {
+pub enum Adjacency<N: Nucleotide, W: Width = Number> {
     /// Two loci joined, with optional non-templated bases between them.
-    Paired(PairedAdjacency<N>),
+    Paired(PairedAdjacency<N, W>),
 
     /// One locus joined to novel or unassembled sequence, open on the other
     /// side.
-    Single(SingleAdjacency<N>),
+    Single(SingleAdjacency<N, W>),
 }
 
-impl<N: Nucleotide + Complement> Adjacency<N> {
+impl<N: Nucleotide + Complement, W: Width> Adjacency<N, W> {
     /// Attempts to create a paired [`Adjacency`] in canonical form.
     ///
     /// This is a convenience wrapper over [`PairedAdjacency::try_new`].
@@ -334,14 +336,18 @@ impl<N: Nucleotide + Complement> Adjacency<N> {
     /// assert!(matches!(adjacency, Adjacency::Paired(_)));
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn try_new_paired(x: Breakend, y: Breakend, insertion: Sequence<N>) -> Result<Self, Error> {
+    pub fn try_new_paired(
+        x: Breakend<W>,
+        y: Breakend<W>,
+        insertion: Sequence<N>,
+    ) -> Result<Self, Error> {
         Ok(Adjacency::Paired(PairedAdjacency::try_new(
             x, y, insertion,
         )?))
     }
 }
 
-impl<N: Nucleotide> Adjacency<N> {
+impl<N: Nucleotide, W: Width> Adjacency<N, W> {
     /// Creates a single-ended [`Adjacency`].
     ///
     /// This is a convenience wrapper over [`SingleAdjacency::new`].
@@ -359,12 +365,12 @@ impl<N: Nucleotide> Adjacency<N> {
     /// assert!(matches!(adjacency, Adjacency::Single(_)));
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn new_single(breakend: Breakend, insertion: Sequence<N>) -> Self {
+    pub fn new_single(breakend: Breakend<W>, insertion: Sequence<N>) -> Self {
         Adjacency::Single(SingleAdjacency::new(breakend, insertion))
     }
 }
 
-impl<N: Nucleotide> fmt::Display for Adjacency<N> {
+impl<N: Nucleotide, W: Width> fmt::Display for Adjacency<N, W> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Adjacency::Paired(paired) => write!(f, "{paired}"),
@@ -373,7 +379,7 @@ impl<N: Nucleotide> fmt::Display for Adjacency<N> {
     }
 }
 
-impl<N: Nucleotide + Complement> FromStr for Adjacency<N> {
+impl<N: Nucleotide + Complement, W: Width> FromStr for Adjacency<N, W> {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -385,11 +391,11 @@ impl<N: Nucleotide + Complement> FromStr for Adjacency<N> {
         let insertion = insertion.parse::<Sequence<N>>()?;
 
         if *middle == OPEN_SIDE {
-            let breakend = first.parse::<Breakend>()?;
+            let breakend = first.parse::<Breakend<W>>()?;
             Ok(Adjacency::new_single(breakend, insertion))
         } else {
-            let a = first.parse::<Breakend>()?;
-            let b = middle.parse::<Breakend>()?;
+            let a = first.parse::<Breakend<W>>()?;
+            let b = middle.parse::<Breakend<W>>()?;
             Ok(Adjacency::try_new_paired(a, b, insertion)?)
         }
     }
@@ -6,7 +6,7 @@ use omics_coordinate::Contig;
 use omics_coordinate::Position;
 use omics_coordinate::contig;
 use omics_coordinate::position::Number;
-use omics_coordinate::position::interbase::Position as InterbasePosition;
+use omics_coordinate::position::Width;
 use omics_coordinate::system::Interbase;
 use omics_core::VARIANT_SEPARATOR;
 use thiserror::Error;
@@ -50,7 +50,7 @@ pub enum ParseError {
 /// documentation](crate::structural) for why read direction lives on the
 /// adjacency pair rather than on a breakend.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Breakend {
+pub struct Breakend<W: Width = Number> {
     /// The contig the breakend sits on.
     contig: Contig,
 
@@ -58,15 +58,16 @@ pub struct Breakend {
     orientation: Orientation,
 
     /// The interbase boundary the breakend sits at.
-    position: Position<Interbase>,
+    position: Position<Interbase<W>>,
 }
 
-impl Breakend {
+impl<W: Width> Breakend<W> {
     /// Attempts to create a new [`Breakend`].
     ///
-    /// The `position` is a raw [`Number`] rather than a generic `impl Into`,
-    /// following the crate convention that non-trait constructors take
-    /// positions concretely so call sites do not need type disambiguation.
+    /// The `position` is a raw value of the position width rather than a
+    /// generic `impl Into`, following the crate convention that non-trait
+    /// constructors take positions concretely so call sites do not need type
+    /// disambiguation.
     ///
     /// # Examples
     ///
@@ -74,19 +75,19 @@ impl Breakend {
     /// use omics_variation::structural::breakend::Breakend;
     /// use omics_variation::structural::orientation::Orientation;
     ///
-    /// let breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
+    /// let breakend: Breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
     /// assert_eq!(breakend.position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn try_new(
         contig: impl TryInto<Contig, Error = contig::Error>,
         orientation: Orientation,
-        position: Number,
+        position: W,
     ) -> Result<Self, contig::Error> {
         Ok(Self {
             contig: contig.try_into()?,
             orientation,
-            position: InterbasePosition::new(position),
+            position: Position::<Interbase<W>>::new(position),
         })
     }
 
@@ -97,7 +98,7 @@ impl Breakend {
     /// ```
     /// # use omics_variation::structural::breakend::Breakend;
     /// # use omics_variation::structural::orientation::Orientation;
-    /// let breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
+    /// let breakend: Breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
     /// assert_eq!(breakend.contig().as_str(), "seq0");
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
@@ -112,7 +113,7 @@ impl Breakend {
     /// ```
     /// # use omics_variation::structural::breakend::Breakend;
     /// # use omics_variation::structural::orientation::Orientation;
-    /// let breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
+    /// let breakend: Breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
     /// assert_eq!(breakend.orientation(), Orientation::LowerFlank);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
@@ -127,11 +128,11 @@ impl Breakend {
     /// ```
     /// # use omics_variation::structural::breakend::Breakend;
     /// # use omics_variation::structural::orientation::Orientation;
-    /// let breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
+    /// let breakend: Breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100)?;
     /// assert_eq!(breakend.position().get(), 100);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
-    pub fn position(&self) -> &Position<Interbase> {
+    pub fn position(&self) -> &Position<Interbase<W>> {
         &self.position
     }
 
@@ -140,7 +141,7 @@ impl Breakend {
     /// The key orders breakends first by contig, then by interbase position,
     /// and finally by orientation rank. It backs the canonical form of a
     /// paired adjacency.
-    pub(crate) fn canonical_key(&self) -> (&str, Number, u8) {
+    pub(crate) fn canonical_key(&self) -> (&str, W, u8) {
         (
             self.contig.as_str(),
             self.position.get(),
@@ -149,7 +150,7 @@ impl Breakend {
     }
 }
 
-impl FromStr for Breakend {
+impl<W: Width> FromStr for Breakend<W> {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -164,14 +165,14 @@ impl FromStr for Breakend {
                 position: (*position).to_string(),
             })?;
 
-        let position = token.parse::<InterbasePosition>()?;
+        let position = token.parse::<Position<Interbase<W>>>()?;
 
         Breakend::try_new(*contig, orientation.parse::<Orientation>()?, position.get())
             .map_err(ParseError::Contig)
     }
 }
 
-impl std::fmt::Display for Breakend {
+impl<W: Width> std::fmt::Display for Breakend<W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
@@ -190,7 +191,7 @@ mod tests {
 
     #[test]
     fn it_builds_and_accesses_a_breakend() {
-        let breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100).unwrap();
+        let breakend: Breakend = Breakend::try_new("seq0", Orientation::LowerFlank, 100).unwrap();
         assert_eq!(breakend.contig().as_str(), "seq0");
         assert_eq!(breakend.orientation(), Orientation::LowerFlank);
         assert_eq!(breakend.position().get(), 100);
@@ -204,6 +205,15 @@ mod tests {
         assert_eq!(breakend.to_string(), "seq0:>:100(i)");
     }
 
+    #[test]
+    fn it_supports_wide_positions() {
+        let breakend = "seq0:<:5000000000(i)".parse::<Breakend<u64>>().unwrap();
+        assert_eq!(breakend.position().get(), 5_000_000_000);
+        assert_eq!(breakend.to_string(), "seq0:<:5000000000(i)");
+
+        assert!("seq0:<:5000000000(i)".parse::<Breakend>().is_err());
+    }
+
     #[test]
     fn it_rejects_a_missing_interbase_qualifier() {
         let err = "seq0:>:100".parse::<Breakend>().unwrap_err();
@@ -32,6 +32,22 @@
 //! );
 //! # }
 //! ```
+//!
+//! # Serialization
+//!
+//! Enable the `serde` feature to implement `serde::Serialize` and
+//! `serde::Deserialize` for the types of every enabled component. Most types
+//! serialize as their canonical string form, and deserialization validates the
+//! same invariants as the corresponding constructors.
+//!
+//! # `no_std` support
+//!
+//! The `std` feature is enabled by default. When it is disabled, the `core`,
+//! `coordinate`, and `molecule` components build for `no_std` targets that
+//! provide a global allocator (`alloc`). The `alignment` and `variation`
+//! components always require the standard library.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "alignment")]
 #[doc(inline)]
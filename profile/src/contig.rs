@@ -1,15 +1,30 @@
 //! Profiling of contigs.
 
 pub mod alloc;
+pub mod compare;
 
 use clap::Parser;
 use clap::Subcommand;
+use clap::ValueEnum;
+
+/// The mode in which contigs are created.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Mode {
+    /// Each contig is allocated independently.
+    Owned,
+
+    /// Each contig is retrieved from a shared contig pool.
+    Interned,
+}
 
 /// The different aspects of contigs that can be profiled.
 #[derive(Subcommand)]
 pub enum Command {
     /// Allocations of contigs.
     Alloc(alloc::Args),
+
+    /// Comparisons and hashing of contigs.
+    Compare(compare::Args),
 }
 
 /// Profiling of contigs.
@@ -24,5 +39,6 @@ pub struct Args {
 pub fn main(args: Args) {
     match args.command {
         Command::Alloc(args) => alloc::main(args),
+        Command::Compare(args) => compare::main(args),
     }
 }
@@ -4,6 +4,9 @@ use std::hint::black_box;
 
 use clap::Parser;
 use omics_coordinate::Contig;
+use omics_coordinate::contig::ContigPool;
+
+use super::Mode;
 
 /// Profiling of contig allocations.
 #[derive(Parser, Debug)]
@@ -11,11 +14,28 @@ pub struct Args {
     /// The number of allocations to use.
     #[arg(default_value_t = 100_000_000)]
     n: usize,
+
+    /// The mode in which contigs are created.
+    #[arg(long, value_enum, default_value_t = Mode::Owned)]
+    mode: Mode,
 }
 
 /// The main method.
 pub fn main(args: Args) {
-    for _ in 0..args.n {
-        let _ = black_box(Contig::new_unchecked("seq0"));
+    match args.mode {
+        Mode::Owned => {
+            for _ in 0..args.n {
+                let _ = black_box(Contig::new_unchecked("seq0"));
+            }
+        }
+        Mode::Interned => {
+            let mut pool = ContigPool::new();
+
+            for _ in 0..args.n {
+                // SAFETY: the contig name is not empty, so this will always
+                // unwrap.
+                let _ = black_box(pool.contig("seq0").unwrap());
+            }
+        }
     }
 }
@@ -0,0 +1,60 @@
+//! Profiling of contig comparisons.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::hint::black_box;
+
+use clap::Parser;
+use omics_coordinate::Contig;
+use omics_coordinate::contig::ContigPool;
+
+use super::Mode;
+
+/// The contig names that are compared.
+///
+/// Long names are used so that comparing the names themselves is noticeably
+/// more expensive than comparing integers.
+const NAMES: [&str; 2] = [
+    "chrUn_JTFH01000001v1_decoy_sequence_one",
+    "chrUn_JTFH01000001v1_decoy_sequence_two",
+];
+
+/// Profiling of contig comparisons.
+#[derive(Parser, Debug)]
+pub struct Args {
+    /// The number of comparisons to make.
+    #[arg(default_value_t = 100_000_000)]
+    n: usize,
+
+    /// The mode in which contigs are created.
+    #[arg(long, value_enum, default_value_t = Mode::Owned)]
+    mode: Mode,
+}
+
+/// The main method.
+pub fn main(args: Args) {
+    // NOTE: each name is created twice so that, in the owned mode, equal
+    // contigs do not share an allocation.
+    let contigs = match args.mode {
+        Mode::Owned => [NAMES[0], NAMES[0], NAMES[1]].map(Contig::new_unchecked),
+        Mode::Interned => {
+            let mut pool = ContigPool::new();
+
+            // SAFETY: the contig names are not empty, so these will always
+            // unwrap.
+            [NAMES[0], NAMES[0], NAMES[1]].map(|name| pool.contig(name).unwrap())
+        }
+    };
+
+    for i in 0..args.n {
+        let a = &contigs[i % 3];
+        let b = &contigs[(i + 1) % 3];
+
+        let _ = black_box(black_box(a) == black_box(b));
+
+        let mut hasher = DefaultHasher::new();
+        black_box(a).hash(&mut hasher);
+        let _ = black_box(hasher.finish());
+    }
+}